@@ -0,0 +1,496 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Asset Registry Module
+//!
+//! ## Overview
+//!
+//! The governance-managed registry of the foreign assets. A foreign asset is
+//! represented by `CurrencyId::ForeignAsset(ForeignAssetId)`, the id is
+//! assigned on registration, so new assets can be listed without a runtime
+//! upgrade. The registry holds the metadata (name, symbol, decimals and
+//! minimal balance) and the XCM `MultiLocation` of every foreign asset, and
+//! provides the two way mapping between `MultiLocation` and `CurrencyId`.
+//!
+//! The tokens of the sibling parachains can be mapped to their
+//! `MultiLocation` by `register_location` too, so the runtime XCM config
+//! doesn't need to hard-code them. The native and locally issued tokens and
+//! the ones already hard-coded by the runtime can't be registered. Each
+//! registered `MultiLocation` can be given a fee rate, the units of the asset
+//! charged per second of XCM execution weight, which is used by the
+//! `FixedRateOfAsset` weight trader.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::{
+	pallet_prelude::*,
+	traits::{Contains, Currency},
+	transactional,
+	weights::constants::WEIGHT_PER_SECOND,
+};
+use frame_system::pallet_prelude::*;
+use module_support::ForeignAssetIdMapping;
+use primitives::{AssetMetadata, CurrencyId, ForeignAssetId};
+use sp_runtime::{
+	traits::{One, Zero},
+	ArithmeticError,
+};
+use sp_std::{boxed::Box, convert::TryInto, marker::PhantomData};
+use xcm::{
+	latest::{AssetId::Concrete, Error as XcmError, MultiAsset, MultiLocation},
+	VersionedMultiLocation,
+};
+use xcm_builder::TakeRevenue;
+use xcm_executor::{traits::WeightTrader, Assets};
+
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+pub use weights::WeightInfo;
+
+pub type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The currency to define the type of the minimal balance.
+		type Currency: Currency<Self::AccountId>;
+
+		/// The origin which may register and update the foreign assets, the
+		/// locations and the fee rates.
+		type RegisterOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The tokens which can't have a location registered, i.e. the native
+		/// and locally issued ones and the ones of which the location is
+		/// hard-coded in the runtime.
+		type FixedLocationTokens: Contains<CurrencyId>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The given location could not be used (e.g. because it cannot be
+		/// expressed in the desired version of XCM).
+		BadLocation,
+		/// MultiLocation existed
+		MultiLocationExisted,
+		/// AssetId not exists
+		AssetIdNotExists,
+		/// The location of ForeignAsset must be managed by the foreign asset
+		/// registry, and only the tokens not in `FixedLocationTokens` can have
+		/// a location registered
+		CurrencyIdNotSupported,
+		/// CurrencyId has no location registered
+		LocationNotRegistered,
+		/// The minimal balance of a foreign asset must be greater than zero
+		InvalidMinimalBalance,
+		/// The minimal balance of a registered foreign asset can't be changed
+		MinimalBalanceChanged,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(fn deposit_event)]
+	#[pallet::metadata(AssetMetadata<BalanceOf<T>> = "AssetMetadata")]
+	pub enum Event<T: Config> {
+		/// The foreign asset is registered. \[foreign_asset_id, location,
+		/// metadata\]
+		ForeignAssetRegistered(ForeignAssetId, MultiLocation, AssetMetadata<BalanceOf<T>>),
+		/// The foreign asset is updated. \[foreign_asset_id, location,
+		/// metadata\]
+		ForeignAssetUpdated(ForeignAssetId, MultiLocation, AssetMetadata<BalanceOf<T>>),
+		/// The location of the currency is registered. \[currency_id,
+		/// location\]
+		LocationRegistered(CurrencyId, MultiLocation),
+		/// The location of the currency is deregistered. \[currency_id,
+		/// location\]
+		LocationDeregistered(CurrencyId, MultiLocation),
+		/// The fee rate of the location is updated. \[location,
+		/// units_per_second\]
+		FeeRateUpdated(MultiLocation, Option<u128>),
+	}
+
+	/// Next available Foreign AssetId ID.
+	///
+	/// NextForeignAssetId: ForeignAssetId
+	#[pallet::storage]
+	#[pallet::getter(fn next_foreign_asset_id)]
+	pub type NextForeignAssetId<T: Config> = StorageValue<_, ForeignAssetId, ValueQuery>;
+
+	/// The storages for MultiLocations.
+	///
+	/// ForeignAssetLocations: map ForeignAssetId => Option<MultiLocation>
+	#[pallet::storage]
+	#[pallet::getter(fn foreign_asset_locations)]
+	pub type ForeignAssetLocations<T: Config> = StorageMap<_, Twox64Concat, ForeignAssetId, MultiLocation, OptionQuery>;
+
+	/// The storages for CurrencyIds.
+	///
+	/// LocationToCurrencyIds: map MultiLocation => Option<CurrencyId>
+	#[pallet::storage]
+	#[pallet::getter(fn location_to_currency_ids)]
+	pub type LocationToCurrencyIds<T: Config> = StorageMap<_, Twox64Concat, MultiLocation, CurrencyId, OptionQuery>;
+
+	/// The storages for the MultiLocations of the currencies other than
+	/// ForeignAsset.
+	///
+	/// CurrencyIdLocations: map CurrencyId => Option<MultiLocation>
+	#[pallet::storage]
+	#[pallet::getter(fn currency_id_locations)]
+	pub type CurrencyIdLocations<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, MultiLocation, OptionQuery>;
+
+	/// The units of the asset charged per second of XCM execution weight.
+	///
+	/// FeeRates: map MultiLocation => Option<u128>
+	#[pallet::storage]
+	#[pallet::getter(fn fee_rates)]
+	pub type FeeRates<T: Config> = StorageMap<_, Twox64Concat, MultiLocation, u128, OptionQuery>;
+
+	/// The storages for AssetMetadatas.
+	///
+	/// AssetMetadatas: map ForeignAssetId => Option<AssetMetadata>
+	#[pallet::storage]
+	#[pallet::getter(fn asset_metadatas)]
+	pub type AssetMetadatas<T: Config> =
+		StorageMap<_, Twox64Concat, ForeignAssetId, AssetMetadata<BalanceOf<T>>, OptionQuery>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Register a foreign asset, assign the next foreign asset id to it.
+		///
+		/// The dispatch origin of this call must be `RegisterOrigin`.
+		#[pallet::weight(T::WeightInfo::register_foreign_asset())]
+		#[transactional]
+		pub fn register_foreign_asset(
+			origin: OriginFor<T>,
+			location: Box<VersionedMultiLocation>,
+			metadata: Box<AssetMetadata<BalanceOf<T>>>,
+		) -> DispatchResult {
+			T::RegisterOrigin::ensure_origin(origin)?;
+
+			let location: MultiLocation = (*location).try_into().map_err(|()| Error::<T>::BadLocation)?;
+			let foreign_asset_id = Self::do_register_foreign_asset(&location, &metadata)?;
+
+			Self::deposit_event(Event::<T>::ForeignAssetRegistered(
+				foreign_asset_id,
+				location,
+				*metadata,
+			));
+			Ok(())
+		}
+
+		/// Update the location and the metadata of a registered foreign
+		/// asset. The minimal balance can't be changed, as the existing
+		/// balances of the asset were kept above the registered one.
+		///
+		/// The dispatch origin of this call must be `RegisterOrigin`.
+		#[pallet::weight(T::WeightInfo::update_foreign_asset())]
+		#[transactional]
+		pub fn update_foreign_asset(
+			origin: OriginFor<T>,
+			foreign_asset_id: ForeignAssetId,
+			location: Box<VersionedMultiLocation>,
+			metadata: Box<AssetMetadata<BalanceOf<T>>>,
+		) -> DispatchResult {
+			T::RegisterOrigin::ensure_origin(origin)?;
+
+			let location: MultiLocation = (*location).try_into().map_err(|()| Error::<T>::BadLocation)?;
+			Self::do_update_foreign_asset(foreign_asset_id, &location, &metadata)?;
+
+			Self::deposit_event(Event::<T>::ForeignAssetUpdated(foreign_asset_id, location, *metadata));
+			Ok(())
+		}
+
+		/// Register the location of a token, replace the registered one if
+		/// exists. The tokens in `FixedLocationTokens` and the currencies other
+		/// than token are not supported.
+		///
+		/// The dispatch origin of this call must be `RegisterOrigin`.
+		#[pallet::weight(T::WeightInfo::register_location())]
+		#[transactional]
+		pub fn register_location(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			location: Box<VersionedMultiLocation>,
+		) -> DispatchResult {
+			T::RegisterOrigin::ensure_origin(origin)?;
+			ensure!(
+				currency_id.is_token_currency_id() && !T::FixedLocationTokens::contains(&currency_id),
+				Error::<T>::CurrencyIdNotSupported
+			);
+
+			let location: MultiLocation = (*location).try_into().map_err(|()| Error::<T>::BadLocation)?;
+			CurrencyIdLocations::<T>::try_mutate(currency_id, |maybe_location| -> DispatchResult {
+				Self::do_update_location(currency_id, maybe_location.as_ref(), &location)?;
+				*maybe_location = Some(location.clone());
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::<T>::LocationRegistered(currency_id, location));
+			Ok(())
+		}
+
+		/// Deregister the location of a currency other than ForeignAsset, the
+		/// fee rate of the location is removed too.
+		///
+		/// The dispatch origin of this call must be `RegisterOrigin`.
+		#[pallet::weight(T::WeightInfo::deregister_location())]
+		#[transactional]
+		pub fn deregister_location(origin: OriginFor<T>, currency_id: CurrencyId) -> DispatchResult {
+			T::RegisterOrigin::ensure_origin(origin)?;
+			ensure!(
+				!currency_id.is_foreign_asset_currency_id(),
+				Error::<T>::CurrencyIdNotSupported
+			);
+
+			let location = CurrencyIdLocations::<T>::take(currency_id).ok_or(Error::<T>::LocationNotRegistered)?;
+			LocationToCurrencyIds::<T>::remove(&location);
+			FeeRates::<T>::remove(&location);
+
+			Self::deposit_event(Event::<T>::LocationDeregistered(currency_id, location));
+			Ok(())
+		}
+
+		/// Set the fee rate of a registered location, remove it if
+		/// `units_per_second` is `None`.
+		///
+		/// The dispatch origin of this call must be `RegisterOrigin`.
+		#[pallet::weight(T::WeightInfo::set_fee_rate())]
+		#[transactional]
+		pub fn set_fee_rate(
+			origin: OriginFor<T>,
+			location: Box<VersionedMultiLocation>,
+			units_per_second: Option<u128>,
+		) -> DispatchResult {
+			T::RegisterOrigin::ensure_origin(origin)?;
+
+			let location: MultiLocation = (*location).try_into().map_err(|()| Error::<T>::BadLocation)?;
+			ensure!(
+				LocationToCurrencyIds::<T>::contains_key(&location),
+				Error::<T>::LocationNotRegistered
+			);
+			FeeRates::<T>::set(&location, units_per_second);
+
+			Self::deposit_event(Event::<T>::FeeRateUpdated(location, units_per_second));
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	fn get_next_foreign_asset_id() -> Result<ForeignAssetId, DispatchError> {
+		NextForeignAssetId::<T>::try_mutate(|current| -> Result<ForeignAssetId, DispatchError> {
+			let id = *current;
+			*current = current.checked_add(One::one()).ok_or(ArithmeticError::Overflow)?;
+			Ok(id)
+		})
+	}
+
+	fn do_register_foreign_asset(
+		location: &MultiLocation,
+		metadata: &AssetMetadata<BalanceOf<T>>,
+	) -> Result<ForeignAssetId, DispatchError> {
+		ensure!(!metadata.minimal_balance.is_zero(), Error::<T>::InvalidMinimalBalance);
+
+		let foreign_asset_id = Self::get_next_foreign_asset_id()?;
+		Self::do_update_location(CurrencyId::ForeignAsset(foreign_asset_id), None, location)?;
+		ForeignAssetLocations::<T>::insert(foreign_asset_id, location);
+		AssetMetadatas::<T>::insert(foreign_asset_id, metadata);
+
+		Ok(foreign_asset_id)
+	}
+
+	fn do_update_foreign_asset(
+		foreign_asset_id: ForeignAssetId,
+		location: &MultiLocation,
+		metadata: &AssetMetadata<BalanceOf<T>>,
+	) -> DispatchResult {
+		ForeignAssetLocations::<T>::try_mutate(foreign_asset_id, |maybe_multi_locations| -> DispatchResult {
+			let old_multi_locations = maybe_multi_locations.as_mut().ok_or(Error::<T>::AssetIdNotExists)?;
+
+			Self::do_update_location(
+				CurrencyId::ForeignAsset(foreign_asset_id),
+				Some(&*old_multi_locations),
+				location,
+			)?;
+			*old_multi_locations = location.clone();
+			Ok(())
+		})?;
+		AssetMetadatas::<T>::try_mutate(foreign_asset_id, |maybe_metadata| -> DispatchResult {
+			let old_metadata = maybe_metadata.as_mut().ok_or(Error::<T>::AssetIdNotExists)?;
+			ensure!(
+				old_metadata.minimal_balance == metadata.minimal_balance,
+				Error::<T>::MinimalBalanceChanged
+			);
+			*old_metadata = metadata.clone();
+			Ok(())
+		})?;
+
+		Ok(())
+	}
+
+	/// Move the mapping of `currency_id` from `old_location` to `location`,
+	/// the fee rate of `old_location` is moved too.
+	fn do_update_location(
+		currency_id: CurrencyId,
+		old_location: Option<&MultiLocation>,
+		location: &MultiLocation,
+	) -> DispatchResult {
+		if old_location == Some(location) {
+			return Ok(());
+		}
+
+		LocationToCurrencyIds::<T>::try_mutate(location, |maybe_currency_ids| -> DispatchResult {
+			ensure!(maybe_currency_ids.is_none(), Error::<T>::MultiLocationExisted);
+			*maybe_currency_ids = Some(currency_id);
+			Ok(())
+		})?;
+
+		if let Some(old_location) = old_location {
+			LocationToCurrencyIds::<T>::remove(old_location);
+			if let Some(units_per_second) = FeeRates::<T>::take(old_location) {
+				FeeRates::<T>::insert(location, units_per_second);
+			}
+		}
+		Ok(())
+	}
+
+	/// The registered MultiLocation of the currency.
+	pub fn currency_id_to_location(currency_id: CurrencyId) -> Option<MultiLocation> {
+		match currency_id {
+			CurrencyId::ForeignAsset(foreign_asset_id) => Self::foreign_asset_locations(foreign_asset_id),
+			_ => Self::currency_id_locations(currency_id),
+		}
+	}
+}
+
+impl<T: Config> ForeignAssetIdMapping<ForeignAssetId, MultiLocation, AssetMetadata<BalanceOf<T>>> for Pallet<T> {
+	fn get_asset_metadata(foreign_asset_id: ForeignAssetId) -> Option<AssetMetadata<BalanceOf<T>>> {
+		Pallet::<T>::asset_metadatas(foreign_asset_id)
+	}
+
+	fn get_multi_location(foreign_asset_id: ForeignAssetId) -> Option<MultiLocation> {
+		Pallet::<T>::foreign_asset_locations(foreign_asset_id)
+	}
+
+	fn get_currency_id(multi_location: MultiLocation) -> Option<CurrencyId> {
+		Pallet::<T>::location_to_currency_ids(multi_location)
+	}
+}
+
+/// Simple fee calculator that requires payment in the asset of which the fee
+/// rate is set by `set_fee_rate`.
+///
+/// The asset is the first one of the payment that has a fee rate, the unused
+/// weight is refunded in the same asset and the rest is taken by `R` as revenue.
+pub struct FixedRateOfAsset<T, R: TakeRevenue> {
+	/// The weight bought.
+	weight: Weight,
+	/// The amount charged for the weight bought.
+	amount: u128,
+	/// The location and the units per second of the asset used to pay.
+	asset_location_and_units_per_second: Option<(MultiLocation, u128)>,
+	_marker: PhantomData<(T, R)>,
+}
+
+impl<T: Config, R: TakeRevenue> FixedRateOfAsset<T, R> {
+	fn fee_of(weight: Weight, units_per_second: u128) -> u128 {
+		units_per_second.saturating_mul(weight as u128) / (WEIGHT_PER_SECOND as u128)
+	}
+}
+
+impl<T: Config, R: TakeRevenue> WeightTrader for FixedRateOfAsset<T, R> {
+	fn new() -> Self {
+		Self {
+			weight: 0,
+			amount: 0,
+			asset_location_and_units_per_second: None,
+			_marker: PhantomData,
+		}
+	}
+
+	fn buy_weight(&mut self, weight: Weight, payment: Assets) -> Result<Assets, XcmError> {
+		if weight == 0 {
+			return Ok(payment);
+		}
+
+		// keep paying in the same asset once it's chosen
+		let (location, units_per_second) = match self.asset_location_and_units_per_second.clone() {
+			Some(v) => v,
+			None => payment
+				.fungible
+				.keys()
+				.find_map(|id| match id {
+					Concrete(location) => FeeRates::<T>::get(location).map(|v| (location.clone(), v)),
+					_ => None,
+				})
+				.ok_or(XcmError::TooExpensive)?,
+		};
+
+		let amount = Self::fee_of(weight, units_per_second);
+		if amount == 0 {
+			return Ok(payment);
+		}
+		let required: MultiAsset = (location.clone(), amount).into();
+		let unused = payment.checked_sub(required).map_err(|_| XcmError::TooExpensive)?;
+
+		self.weight = self.weight.saturating_add(weight);
+		self.amount = self.amount.saturating_add(amount);
+		self.asset_location_and_units_per_second = Some((location, units_per_second));
+		Ok(unused)
+	}
+
+	fn refund_weight(&mut self, weight: Weight) -> Option<MultiAsset> {
+		let (location, units_per_second) = self.asset_location_and_units_per_second.clone()?;
+		let weight = weight.min(self.weight);
+		let amount = Self::fee_of(weight, units_per_second).min(self.amount);
+
+		self.weight = self.weight.saturating_sub(weight);
+		self.amount = self.amount.saturating_sub(amount);
+		if amount > 0 {
+			Some((location, amount).into())
+		} else {
+			None
+		}
+	}
+}
+
+impl<T, R: TakeRevenue> Drop for FixedRateOfAsset<T, R> {
+	fn drop(&mut self) {
+		if let Some((location, _)) = self.asset_location_and_units_per_second.take() {
+			if self.amount > 0 {
+				R::take_revenue((location, self.amount).into());
+			}
+		}
+	}
+}
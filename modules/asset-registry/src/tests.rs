@@ -0,0 +1,509 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the asset registry module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use mock::{Event, *};
+use primitives::TokenSymbol;
+use sp_runtime::traits::BadOrigin;
+use xcm::latest::prelude::*;
+
+fn dot_location() -> MultiLocation {
+	MultiLocation::parent()
+}
+
+fn bnc_location() -> MultiLocation {
+	MultiLocation::new(1, X2(Parachain(2001), GeneralKey([0, 1].to_vec())))
+}
+
+fn asset_metadata(name: &[u8], symbol: &[u8]) -> AssetMetadata<Balance> {
+	AssetMetadata {
+		name: name.to_vec(),
+		symbol: symbol.to_vec(),
+		decimals: 12,
+		minimal_balance: 1,
+	}
+}
+
+#[test]
+fn register_foreign_asset_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			AssetRegistry::register_foreign_asset(
+				Origin::signed(BOB),
+				Box::new(dot_location().into()),
+				Box::new(asset_metadata(b"Polkadot", b"DOT")),
+			),
+			BadOrigin
+		);
+
+		assert_ok!(AssetRegistry::register_foreign_asset(
+			Origin::signed(ALICE),
+			Box::new(dot_location().into()),
+			Box::new(asset_metadata(b"Polkadot", b"DOT")),
+		));
+		System::assert_last_event(Event::AssetRegistry(crate::Event::ForeignAssetRegistered(
+			0,
+			dot_location(),
+			asset_metadata(b"Polkadot", b"DOT"),
+		)));
+
+		assert_eq!(AssetRegistry::next_foreign_asset_id(), 1);
+		assert_eq!(AssetRegistry::foreign_asset_locations(0), Some(dot_location()));
+		assert_eq!(
+			AssetRegistry::location_to_currency_ids(dot_location()),
+			Some(CurrencyId::ForeignAsset(0))
+		);
+		assert_eq!(
+			AssetRegistry::asset_metadatas(0),
+			Some(asset_metadata(b"Polkadot", b"DOT"))
+		);
+
+		assert_ok!(AssetRegistry::register_foreign_asset(
+			Origin::signed(ALICE),
+			Box::new(bnc_location().into()),
+			Box::new(asset_metadata(b"Bifrost", b"BNC")),
+		));
+		assert_eq!(
+			AssetRegistry::location_to_currency_ids(bnc_location()),
+			Some(CurrencyId::ForeignAsset(1))
+		);
+	});
+}
+
+#[test]
+fn register_foreign_asset_should_not_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(AssetRegistry::register_foreign_asset(
+			Origin::signed(ALICE),
+			Box::new(dot_location().into()),
+			Box::new(asset_metadata(b"Polkadot", b"DOT")),
+		));
+
+		assert_noop!(
+			AssetRegistry::register_foreign_asset(
+				Origin::signed(ALICE),
+				Box::new(dot_location().into()),
+				Box::new(asset_metadata(b"Polkadot", b"DOT")),
+			),
+			Error::<Runtime>::MultiLocationExisted
+		);
+
+		assert_noop!(
+			AssetRegistry::register_foreign_asset(
+				Origin::signed(ALICE),
+				Box::new(bnc_location().into()),
+				Box::new(AssetMetadata {
+					minimal_balance: 0,
+					..asset_metadata(b"Bifrost", b"BNC")
+				}),
+			),
+			Error::<Runtime>::InvalidMinimalBalance
+		);
+
+		NextForeignAssetId::<Runtime>::set(ForeignAssetId::MAX);
+		assert_noop!(
+			AssetRegistry::register_foreign_asset(
+				Origin::signed(ALICE),
+				Box::new(bnc_location().into()),
+				Box::new(asset_metadata(b"Bifrost", b"BNC")),
+			),
+			ArithmeticError::Overflow
+		);
+	});
+}
+
+#[test]
+fn update_foreign_asset_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(AssetRegistry::register_foreign_asset(
+			Origin::signed(ALICE),
+			Box::new(dot_location().into()),
+			Box::new(asset_metadata(b"Polkadot", b"DOT")),
+		));
+
+		assert_noop!(
+			AssetRegistry::update_foreign_asset(
+				Origin::signed(BOB),
+				0,
+				Box::new(dot_location().into()),
+				Box::new(asset_metadata(b"New Polkadot", b"DOT")),
+			),
+			BadOrigin
+		);
+
+		// update metadata only
+		assert_ok!(AssetRegistry::update_foreign_asset(
+			Origin::signed(ALICE),
+			0,
+			Box::new(dot_location().into()),
+			Box::new(asset_metadata(b"New Polkadot", b"DOT")),
+		));
+		System::assert_last_event(Event::AssetRegistry(crate::Event::ForeignAssetUpdated(
+			0,
+			dot_location(),
+			asset_metadata(b"New Polkadot", b"DOT"),
+		)));
+		assert_eq!(
+			AssetRegistry::asset_metadatas(0),
+			Some(asset_metadata(b"New Polkadot", b"DOT"))
+		);
+		assert_eq!(
+			AssetRegistry::location_to_currency_ids(dot_location()),
+			Some(CurrencyId::ForeignAsset(0))
+		);
+
+		// update location
+		assert_ok!(AssetRegistry::update_foreign_asset(
+			Origin::signed(ALICE),
+			0,
+			Box::new(bnc_location().into()),
+			Box::new(asset_metadata(b"Bifrost", b"BNC")),
+		));
+		assert_eq!(AssetRegistry::foreign_asset_locations(0), Some(bnc_location()));
+		assert_eq!(AssetRegistry::location_to_currency_ids(dot_location()), None);
+		assert_eq!(
+			AssetRegistry::location_to_currency_ids(bnc_location()),
+			Some(CurrencyId::ForeignAsset(0))
+		);
+	});
+}
+
+#[test]
+fn update_foreign_asset_should_not_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			AssetRegistry::update_foreign_asset(
+				Origin::signed(ALICE),
+				0,
+				Box::new(dot_location().into()),
+				Box::new(asset_metadata(b"Polkadot", b"DOT")),
+			),
+			Error::<Runtime>::AssetIdNotExists
+		);
+
+		assert_ok!(AssetRegistry::register_foreign_asset(
+			Origin::signed(ALICE),
+			Box::new(dot_location().into()),
+			Box::new(asset_metadata(b"Polkadot", b"DOT")),
+		));
+		assert_ok!(AssetRegistry::register_foreign_asset(
+			Origin::signed(ALICE),
+			Box::new(bnc_location().into()),
+			Box::new(asset_metadata(b"Bifrost", b"BNC")),
+		));
+
+		assert_noop!(
+			AssetRegistry::update_foreign_asset(
+				Origin::signed(ALICE),
+				0,
+				Box::new(bnc_location().into()),
+				Box::new(asset_metadata(b"Polkadot", b"DOT")),
+			),
+			Error::<Runtime>::MultiLocationExisted
+		);
+
+		assert_noop!(
+			AssetRegistry::update_foreign_asset(
+				Origin::signed(ALICE),
+				0,
+				Box::new(dot_location().into()),
+				Box::new(AssetMetadata {
+					minimal_balance: 2,
+					..asset_metadata(b"Polkadot", b"DOT")
+				}),
+			),
+			Error::<Runtime>::MinimalBalanceChanged
+		);
+	});
+}
+
+#[test]
+fn foreign_asset_id_mapping_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(AssetRegistry::register_foreign_asset(
+			Origin::signed(ALICE),
+			Box::new(dot_location().into()),
+			Box::new(asset_metadata(b"Polkadot", b"DOT")),
+		));
+
+		assert_eq!(
+			<AssetRegistry as ForeignAssetIdMapping<_, _, _>>::get_asset_metadata(0),
+			Some(asset_metadata(b"Polkadot", b"DOT"))
+		);
+		assert_eq!(
+			<AssetRegistry as ForeignAssetIdMapping<_, _, AssetMetadata<Balance>>>::get_multi_location(0),
+			Some(dot_location())
+		);
+		assert_eq!(
+			<AssetRegistry as ForeignAssetIdMapping<_, _, AssetMetadata<Balance>>>::get_currency_id(dot_location()),
+			Some(CurrencyId::ForeignAsset(0))
+		);
+		assert_eq!(
+			<AssetRegistry as ForeignAssetIdMapping<_, _, AssetMetadata<Balance>>>::get_multi_location(1),
+			None
+		);
+	});
+}
+
+#[test]
+fn register_location_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let bnc = CurrencyId::Token(TokenSymbol::BNC);
+		assert_noop!(
+			AssetRegistry::register_location(Origin::signed(BOB), bnc, Box::new(bnc_location().into())),
+			BadOrigin
+		);
+		assert_noop!(
+			AssetRegistry::register_location(
+				Origin::signed(ALICE),
+				CurrencyId::ForeignAsset(0),
+				Box::new(bnc_location().into())
+			),
+			Error::<Runtime>::CurrencyIdNotSupported
+		);
+		assert_noop!(
+			AssetRegistry::register_location(
+				Origin::signed(ALICE),
+				CurrencyId::Token(TokenSymbol::KAR),
+				Box::new(bnc_location().into())
+			),
+			Error::<Runtime>::CurrencyIdNotSupported
+		);
+		assert_noop!(
+			AssetRegistry::register_location(
+				Origin::signed(ALICE),
+				CurrencyId::Token(TokenSymbol::KSM),
+				Box::new(bnc_location().into())
+			),
+			Error::<Runtime>::CurrencyIdNotSupported
+		);
+		assert_noop!(
+			AssetRegistry::register_location(
+				Origin::signed(ALICE),
+				CurrencyId::Erc20(Default::default()),
+				Box::new(bnc_location().into())
+			),
+			Error::<Runtime>::CurrencyIdNotSupported
+		);
+
+		assert_ok!(AssetRegistry::register_location(
+			Origin::signed(ALICE),
+			bnc,
+			Box::new(bnc_location().into())
+		));
+		System::assert_last_event(Event::AssetRegistry(crate::Event::LocationRegistered(
+			bnc,
+			bnc_location(),
+		)));
+		assert_eq!(AssetRegistry::currency_id_to_location(bnc), Some(bnc_location()));
+		assert_eq!(AssetRegistry::location_to_currency_ids(bnc_location()), Some(bnc));
+
+		// the location is taken by another currency
+		assert_noop!(
+			AssetRegistry::register_location(
+				Origin::signed(ALICE),
+				CurrencyId::Token(TokenSymbol::VSKSM),
+				Box::new(bnc_location().into())
+			),
+			Error::<Runtime>::MultiLocationExisted
+		);
+
+		// replace the location, the fee rate is moved
+		assert_ok!(AssetRegistry::set_fee_rate(
+			Origin::signed(ALICE),
+			Box::new(bnc_location().into()),
+			Some(1_000)
+		));
+		assert_ok!(AssetRegistry::register_location(
+			Origin::signed(ALICE),
+			bnc,
+			Box::new(dot_location().into())
+		));
+		assert_eq!(AssetRegistry::currency_id_to_location(bnc), Some(dot_location()));
+		assert_eq!(AssetRegistry::location_to_currency_ids(bnc_location()), None);
+		assert_eq!(AssetRegistry::location_to_currency_ids(dot_location()), Some(bnc));
+		assert_eq!(AssetRegistry::fee_rates(bnc_location()), None);
+		assert_eq!(AssetRegistry::fee_rates(dot_location()), Some(1_000));
+	});
+}
+
+#[test]
+fn deregister_location_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let bnc = CurrencyId::Token(TokenSymbol::BNC);
+		assert_noop!(
+			AssetRegistry::deregister_location(Origin::signed(ALICE), bnc),
+			Error::<Runtime>::LocationNotRegistered
+		);
+
+		assert_ok!(AssetRegistry::register_location(
+			Origin::signed(ALICE),
+			bnc,
+			Box::new(bnc_location().into())
+		));
+		assert_ok!(AssetRegistry::set_fee_rate(
+			Origin::signed(ALICE),
+			Box::new(bnc_location().into()),
+			Some(1_000)
+		));
+
+		assert_noop!(AssetRegistry::deregister_location(Origin::signed(BOB), bnc), BadOrigin);
+		assert_ok!(AssetRegistry::deregister_location(Origin::signed(ALICE), bnc));
+		System::assert_last_event(Event::AssetRegistry(crate::Event::LocationDeregistered(
+			bnc,
+			bnc_location(),
+		)));
+		assert_eq!(AssetRegistry::currency_id_to_location(bnc), None);
+		assert_eq!(AssetRegistry::location_to_currency_ids(bnc_location()), None);
+		assert_eq!(AssetRegistry::fee_rates(bnc_location()), None);
+	});
+}
+
+#[test]
+fn set_fee_rate_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			AssetRegistry::set_fee_rate(Origin::signed(ALICE), Box::new(dot_location().into()), Some(1_000)),
+			Error::<Runtime>::LocationNotRegistered
+		);
+
+		assert_ok!(AssetRegistry::register_foreign_asset(
+			Origin::signed(ALICE),
+			Box::new(dot_location().into()),
+			Box::new(asset_metadata(b"Polkadot", b"DOT")),
+		));
+		assert_noop!(
+			AssetRegistry::set_fee_rate(Origin::signed(BOB), Box::new(dot_location().into()), Some(1_000)),
+			BadOrigin
+		);
+		assert_ok!(AssetRegistry::set_fee_rate(
+			Origin::signed(ALICE),
+			Box::new(dot_location().into()),
+			Some(1_000)
+		));
+		System::assert_last_event(Event::AssetRegistry(crate::Event::FeeRateUpdated(
+			dot_location(),
+			Some(1_000),
+		)));
+		assert_eq!(AssetRegistry::fee_rates(dot_location()), Some(1_000));
+
+		// the fee rate is moved with the location of the foreign asset
+		assert_ok!(AssetRegistry::update_foreign_asset(
+			Origin::signed(ALICE),
+			0,
+			Box::new(bnc_location().into()),
+			Box::new(asset_metadata(b"Polkadot", b"DOT")),
+		));
+		assert_eq!(AssetRegistry::fee_rates(dot_location()), None);
+		assert_eq!(AssetRegistry::fee_rates(bnc_location()), Some(1_000));
+
+		assert_ok!(AssetRegistry::set_fee_rate(
+			Origin::signed(ALICE),
+			Box::new(bnc_location().into()),
+			None
+		));
+		assert_eq!(AssetRegistry::fee_rates(bnc_location()), None);
+	});
+}
+
+fn assets(location: MultiLocation, amount: u128) -> Assets {
+	let asset: MultiAsset = (location, amount).into();
+	asset.into()
+}
+
+#[test]
+fn fixed_rate_of_asset_buy_and_refund_weight_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(AssetRegistry::register_foreign_asset(
+			Origin::signed(ALICE),
+			Box::new(dot_location().into()),
+			Box::new(asset_metadata(b"Polkadot", b"DOT")),
+		));
+
+		let mut trader = FixedRateOfAsset::<Runtime, MockTakeRevenue>::new();
+		// no fee rate
+		assert_eq!(
+			trader.buy_weight(100, assets(dot_location(), 1_000)),
+			Err(XcmError::TooExpensive)
+		);
+
+		// 2 units per weight
+		assert_ok!(AssetRegistry::set_fee_rate(
+			Origin::signed(ALICE),
+			Box::new(dot_location().into()),
+			Some(2 * WEIGHT_PER_SECOND as u128)
+		));
+
+		assert_eq!(
+			trader.buy_weight(0, assets(dot_location(), 1_000)),
+			Ok(assets(dot_location(), 1_000))
+		);
+		assert_eq!(
+			trader.buy_weight(100, assets(bnc_location(), 1_000)),
+			Err(XcmError::TooExpensive)
+		);
+		assert_eq!(
+			trader.buy_weight(100, assets(dot_location(), 199)),
+			Err(XcmError::TooExpensive)
+		);
+		assert_eq!(
+			trader.buy_weight(100, assets(dot_location(), 1_000)),
+			Ok(assets(dot_location(), 800))
+		);
+		assert_eq!(trader.buy_weight(50, assets(dot_location(), 100)), Ok(Assets::new()));
+
+		assert_eq!(trader.refund_weight(20), Some((dot_location(), 40u128).into()));
+		// can't refund more than bought
+		assert_eq!(trader.refund_weight(1_000), Some((dot_location(), 260u128).into()));
+		assert_eq!(trader.refund_weight(10), None);
+
+		drop(trader);
+		assert_eq!(revenues(), vec![]);
+	});
+}
+
+#[test]
+fn fixed_rate_of_asset_take_revenue_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(AssetRegistry::register_foreign_asset(
+			Origin::signed(ALICE),
+			Box::new(dot_location().into()),
+			Box::new(asset_metadata(b"Polkadot", b"DOT")),
+		));
+		assert_ok!(AssetRegistry::set_fee_rate(
+			Origin::signed(ALICE),
+			Box::new(dot_location().into()),
+			Some(2 * WEIGHT_PER_SECOND as u128)
+		));
+
+		let mut trader = FixedRateOfAsset::<Runtime, MockTakeRevenue>::new();
+		assert_eq!(
+			trader.buy_weight(100, assets(dot_location(), 1_000)),
+			Ok(assets(dot_location(), 800))
+		);
+		assert_eq!(trader.refund_weight(20), Some((dot_location(), 40u128).into()));
+
+		drop(trader);
+		assert_eq!(revenues(), vec![(dot_location(), 160u128).into()]);
+	});
+}
@@ -55,7 +55,8 @@ use sp_runtime::{
 };
 use sp_std::prelude::*;
 use support::{
-	CDPTreasury, CDPTreasuryExtended, EmergencyShutdown, ExchangeRate, Price, PriceProvider, Rate, Ratio, RiskManager,
+	CDPTreasury, CDPTreasuryExtended, EmergencyShutdown, ExchangeRate, Price, PriceCircuitBreaker, PriceProvider, Rate,
+	Ratio, RiskManager,
 };
 
 mod debit_exchange_rate_convertor;
@@ -171,6 +172,10 @@ pub mod module {
 		/// The price source of all types of currencies related to CDP
 		type PriceSource: PriceProvider<CurrencyId>;
 
+		/// The price circuit breaker, the liquidations of the collateral are paused when it's
+		/// triggered.
+		type PriceCircuitBreaker: PriceCircuitBreaker<CurrencyId>;
+
 		/// A configuration for base priority of unsigned transactions.
 		///
 		/// This is exposed so that it can be tuned for particular runtime, when
@@ -224,6 +229,8 @@ pub mod module {
 		MustAfterShutdown,
 		/// Failed to swap debit by default path list
 		SwapDebitFailed,
+		/// The liquidation of the collateral is paused by the price circuit breaker
+		LiquidationPaused,
 	}
 
 	#[pallet::event]
@@ -515,6 +522,7 @@ pub mod module {
 						Self::check_cdp_status(*currency_id, collateral, debit),
 						CDPStatus::Unsafe
 					) || T::EmergencyShutdown::is_shutdown()
+						|| T::PriceCircuitBreaker::is_triggered(*currency_id)
 					{
 						return InvalidTransaction::Stale.into();
 					}
@@ -653,6 +661,7 @@ impl<T: Config> Pallet<T> {
 
 		let currency_id = collateral_currency_ids[collateral_position as usize];
 		let is_shutdown = T::EmergencyShutdown::is_shutdown();
+		let is_liquidation_paused = T::PriceCircuitBreaker::is_triggered(currency_id);
 
 		// If start key is Some(value) continue iterating from that point in storage otherwise start
 		// iterating from the beginning of <loans::Positons<T>>
@@ -668,6 +677,7 @@ impl<T: Config> Pallet<T> {
 		#[allow(clippy::while_let_on_iterator)]
 		while let Some((who, Position { collateral, debit })) = map_iterator.next() {
 			if !is_shutdown
+				&& !is_liquidation_paused
 				&& matches!(
 					Self::check_cdp_status(currency_id, collateral, debit),
 					CDPStatus::Unsafe
@@ -902,6 +912,10 @@ impl<T: Config> Pallet<T> {
 
 	// liquidate unsafe cdp
 	pub fn liquidate_unsafe_cdp(who: T::AccountId, currency_id: CurrencyId) -> DispatchResult {
+		ensure!(
+			!T::PriceCircuitBreaker::is_triggered(currency_id),
+			Error::<T>::LiquidationPaused
+		);
 		let Position { collateral, debit } = <LoansOf<T>>::positions(currency_id, &who);
 
 		// ensure the cdp is unsafe
@@ -31,7 +31,7 @@ use sp_runtime::{
 	traits::{AccountIdConversion, IdentityLookup, One as OneT},
 };
 use sp_std::cell::RefCell;
-use support::{AuctionManager, EmergencyShutdown};
+use support::{AuctionManager, EmergencyShutdown, PriceCircuitBreaker};
 
 pub type AccountId = u128;
 pub type BlockNumber = u64;
@@ -263,6 +263,21 @@ impl EmergencyShutdown for MockEmergencyShutdown {
 	}
 }
 
+thread_local! {
+	static CIRCUIT_BREAKER_TRIGGERED: RefCell<bool> = RefCell::new(false);
+}
+
+pub fn mock_circuit_breaker_triggered(triggered: bool) {
+	CIRCUIT_BREAKER_TRIGGERED.with(|v| *v.borrow_mut() = triggered)
+}
+
+pub struct MockPriceCircuitBreaker;
+impl PriceCircuitBreaker<CurrencyId> for MockPriceCircuitBreaker {
+	fn is_triggered(_currency_id: CurrencyId) -> bool {
+		CIRCUIT_BREAKER_TRIGGERED.with(|v| *v.borrow())
+	}
+}
+
 ord_parameter_types! {
 	pub const One: AccountId = 1;
 }
@@ -284,6 +299,7 @@ parameter_types! {
 impl Config for Runtime {
 	type Event = Event;
 	type PriceSource = MockPriceSource;
+	type PriceCircuitBreaker = MockPriceCircuitBreaker;
 	type CollateralCurrencyIds = CollateralCurrencyIds;
 	type DefaultLiquidationRatio = DefaultLiquidationRatio;
 	type DefaultDebitExchangeRate = DefaultDebitExchangeRate;
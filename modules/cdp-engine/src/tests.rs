@@ -456,6 +456,15 @@ fn liquidate_unsafe_cdp_by_collateral_auction() {
 			Change::NoChange,
 			Change::NoChange,
 		));
+
+		// liquidation is paused when the price circuit breaker is triggered
+		mock_circuit_breaker_triggered(true);
+		assert_noop!(
+			CDPEngineModule::liquidate_unsafe_cdp(ALICE, BTC),
+			Error::<Runtime>::LiquidationPaused,
+		);
+		mock_circuit_breaker_triggered(false);
+
 		assert_ok!(CDPEngineModule::liquidate_unsafe_cdp(ALICE, BTC));
 		System::assert_last_event(Event::CDPEngineModule(crate::Event::LiquidateUnsafeCDP(
 			BTC,
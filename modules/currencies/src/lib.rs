@@ -17,6 +17,15 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 //! Currencies module.
+//!
+//! The reserved and the locked balances of `CurrencyId::Erc20` are escrowed: they are
+//! transferred from the EVM address of the account to an escrow address derived from it, so
+//! they can't be moved by calling the Erc20 contract directly. A lock of an Erc20 balance
+//! can't exceed the free balance of the account.
+//!
+//! The reserves of `CurrencyId::Erc20` can be named by a `ReserveIdentifier`, the named amounts
+//! are tracked in `Erc20NamedReserves` on top of the escrowed balance. The other currencies don't
+//! support named reserves through this module, as the tokens module doesn't.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
@@ -26,6 +35,7 @@ use codec::Codec;
 use frame_support::{
 	pallet_prelude::*,
 	traits::{
+		tokens::{fungible, fungibles, DepositConsequence, WithdrawConsequence},
 		Currency as PalletCurrency, ExistenceRequirement, Get, LockableCurrency as PalletLockableCurrency,
 		ReservableCurrency as PalletReservableCurrency, WithdrawReasons,
 	},
@@ -35,10 +45,10 @@ use frame_system::pallet_prelude::*;
 use orml_traits::{
 	arithmetic::{Signed, SimpleArithmetic},
 	currency::TransferAll,
-	BalanceStatus, BasicCurrency, BasicCurrencyExtended, BasicLockableCurrency, BasicReservableCurrency,
+	BalanceStatus, BasicCurrency, BasicCurrencyExtended, BasicLockableCurrency, BasicReservableCurrency, Happened,
 	LockIdentifier, MultiCurrency, MultiCurrencyExtended, MultiLockableCurrency, MultiReservableCurrency, OnDust,
 };
-use primitives::{evm::EvmAddress, CurrencyId};
+use primitives::{evm::EvmAddress, CurrencyId, ReserveIdentifier};
 use sp_io::hashing::blake2_256;
 use sp_runtime::{
 	traits::{CheckedSub, MaybeSerializeDeserialize, Saturating, StaticLookup, Zero},
@@ -50,7 +60,7 @@ use sp_std::{
 	marker, result,
 	vec::Vec,
 };
-use support::{AddressMapping, EVMBridge, InvokeContext};
+use support::{AddressMapping, CurrencyOperationHook, EVMBridge, InvokeContext};
 
 mod mock;
 mod tests;
@@ -76,10 +86,12 @@ pub mod module {
 		type MultiCurrency: TransferAll<Self::AccountId>
 			+ MultiCurrencyExtended<Self::AccountId, CurrencyId = CurrencyId>
 			+ MultiLockableCurrency<Self::AccountId, CurrencyId = CurrencyId>
-			+ MultiReservableCurrency<Self::AccountId, CurrencyId = CurrencyId>;
+			+ MultiReservableCurrency<Self::AccountId, CurrencyId = CurrencyId>
+			+ fungibles::Inspect<Self::AccountId, AssetId = CurrencyId, Balance = BalanceOf<Self>>;
 		type NativeCurrency: BasicCurrencyExtended<Self::AccountId, Balance = BalanceOf<Self>, Amount = AmountOf<Self>>
 			+ BasicLockableCurrency<Self::AccountId, Balance = BalanceOf<Self>>
-			+ BasicReservableCurrency<Self::AccountId, Balance = BalanceOf<Self>>;
+			+ BasicReservableCurrency<Self::AccountId, Balance = BalanceOf<Self>>
+			+ fungible::Inspect<Self::AccountId, Balance = BalanceOf<Self>>;
 
 		/// The native currency id
 		#[pallet::constant]
@@ -97,6 +109,10 @@ pub mod module {
 
 		/// Handler to burn or transfer account's dust
 		type OnDust: OnDust<Self::AccountId, CurrencyId, BalanceOf<Self>>;
+
+		/// Hooks to veto or record the transfer, deposit and withdrawal of
+		/// currencies
+		type CurrencyHook: CurrencyOperationHook<Self::AccountId, CurrencyId, BalanceOf<Self>>;
 	}
 
 	#[pallet::error]
@@ -111,6 +127,10 @@ pub mod module {
 		EvmAccountNotFound,
 		/// Real origin not found
 		RealOriginNotFound,
+		/// Failed because liquidity restrictions due to locking
+		LiquidityRestrictions,
+		/// Named reserves are only supported by Erc20
+		NamedReserveNotSupported,
 	}
 
 	#[pallet::event]
@@ -129,6 +149,36 @@ pub mod module {
 		DustSwept(CurrencyIdOf<T>, T::AccountId, BalanceOf<T>),
 	}
 
+	/// The locks of Erc20 balances.
+	///
+	/// Erc20Locks: double_map EvmAddress, AccountId => Vec<(LockIdentifier, Balance)>
+	#[pallet::storage]
+	#[pallet::getter(fn erc20_locks)]
+	pub type Erc20Locks<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		EvmAddress,
+		Twox64Concat,
+		T::AccountId,
+		Vec<(LockIdentifier, BalanceOf<T>)>,
+		ValueQuery,
+	>;
+
+	/// The named reserves of Erc20 balances, they are part of the escrowed reserved balance.
+	///
+	/// Erc20NamedReserves: double_map EvmAddress, AccountId => Vec<(ReserveIdentifier, Balance)>
+	#[pallet::storage]
+	#[pallet::getter(fn erc20_named_reserves)]
+	pub type Erc20NamedReserves<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		EvmAddress,
+		Twox64Concat,
+		T::AccountId,
+		Vec<(ReserveIdentifier, BalanceOf<T>)>,
+		ValueQuery,
+	>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
@@ -166,7 +216,10 @@ pub mod module {
 		) -> DispatchResult {
 			let from = ensure_signed(origin)?;
 			let to = T::Lookup::lookup(dest)?;
+			let native_currency_id = T::GetNativeCurrencyId::get();
+			T::CurrencyHook::pre_transfer(native_currency_id, &from, &to, amount)?;
 			T::NativeCurrency::transfer(&from, &to, amount)?;
+			T::CurrencyHook::post_transfer(native_currency_id, &from, &to, amount);
 
 			Self::deposit_event(Event::Transferred(T::GetNativeCurrencyId::get(), from, to, amount));
 			Ok(())
@@ -188,6 +241,50 @@ pub mod module {
 			Ok(())
 		}
 
+		/// Transfer some balances of different currencies to different
+		/// accounts in a single call.
+		///
+		/// The dispatch origin for this call must be `Signed` by the
+		/// transactor.
+		///
+		/// - `transfers`: list of `(dest, currency_id, amount)`, all the
+		///   transfers will be reverted if any of them failed.
+		#[pallet::weight(T::WeightInfo::transfer_batch(transfers.len() as u32))]
+		#[transactional]
+		pub fn transfer_batch(
+			origin: OriginFor<T>,
+			transfers: Vec<(<T::Lookup as StaticLookup>::Source, CurrencyIdOf<T>, BalanceOf<T>)>,
+		) -> DispatchResult {
+			let from = ensure_signed(origin)?;
+			for (dest, currency_id, amount) in transfers {
+				let to = T::Lookup::lookup(dest)?;
+				<Self as MultiCurrency<T::AccountId>>::transfer(currency_id, &from, &to, amount)?;
+			}
+			Ok(())
+		}
+
+		/// Transfer all the transferable balance of `currency_id` to
+		/// another account.
+		///
+		/// The dispatch origin for this call must be `Signed` by the
+		/// transactor.
+		///
+		/// - `keep_alive`: keep the existential deposit in the account of
+		///   the transactor. The locked balance is never transferred.
+		#[pallet::weight(T::WeightInfo::transfer_all())]
+		pub fn transfer_all(
+			origin: OriginFor<T>,
+			dest: <T::Lookup as StaticLookup>::Source,
+			currency_id: CurrencyIdOf<T>,
+			keep_alive: bool,
+		) -> DispatchResult {
+			let from = ensure_signed(origin)?;
+			let to = T::Lookup::lookup(dest)?;
+			let amount = Self::transferable_balance(currency_id, &from, keep_alive);
+			<Self as MultiCurrency<T::AccountId>>::transfer(currency_id, &from, &to, amount)?;
+			Ok(())
+		}
+
 		#[pallet::weight(T::WeightInfo::sweep_dust(accounts.len() as u32))]
 		pub fn sweep_dust(
 			origin: OriginFor<T>,
@@ -217,6 +314,99 @@ pub mod module {
 	}
 }
 
+impl<T: Config> Pallet<T> {
+	/// The free balance of `who` that can be transferred out by `transfer_all`.
+	fn transferable_balance(currency_id: CurrencyId, who: &T::AccountId, keep_alive: bool) -> BalanceOf<T> {
+		match currency_id {
+			CurrencyId::Erc20(contract) => {
+				Self::free_balance(currency_id, who).saturating_sub(Self::erc20_frozen_balance(contract, who))
+			}
+			id if id == T::GetNativeCurrencyId::get() => {
+				<T::NativeCurrency as fungible::Inspect<_>>::reducible_balance(who, keep_alive)
+			}
+			_ => <T::MultiCurrency as fungibles::Inspect<_>>::reducible_balance(currency_id, who, keep_alive),
+		}
+	}
+
+	/// The balance of `address` in the Erc20 contract.
+	fn erc20_balance_of(contract: EvmAddress, address: EvmAddress) -> BalanceOf<T> {
+		let context = InvokeContext {
+			contract,
+			sender: Default::default(),
+			origin: Default::default(),
+		};
+		T::EVMBridge::balance_of(context, address).unwrap_or_default()
+	}
+
+	/// The Erc20 balance frozen by the locks of the account.
+	fn erc20_frozen_balance(contract: EvmAddress, who: &T::AccountId) -> BalanceOf<T> {
+		Self::erc20_locks(contract, who)
+			.into_iter()
+			.map(|(_, amount)| amount)
+			.max()
+			.unwrap_or_default()
+	}
+
+	/// Update the lock of the Erc20 balance by `f`, the lock is removed if `f` returns `None`.
+	///
+	/// The frozen balance is escrowed at the lock address of the account.
+	fn update_erc20_lock(
+		lock_id: LockIdentifier,
+		contract: EvmAddress,
+		who: &T::AccountId,
+		f: impl FnOnce(Option<BalanceOf<T>>) -> Option<BalanceOf<T>>,
+	) -> DispatchResult {
+		let mut locks = Self::erc20_locks(contract, who);
+		let index = locks.iter().position(|(id, _)| *id == lock_id);
+		match (index, f(index.map(|i| locks[i].1))) {
+			(Some(i), Some(amount)) => locks[i].1 = amount,
+			(Some(i), None) => {
+				locks.remove(i);
+			}
+			(None, Some(amount)) => locks.push((lock_id, amount)),
+			(None, None) => return Ok(()),
+		}
+
+		let address = T::AddressMapping::get_evm_address(who).ok_or(Error::<T>::EvmAccountNotFound)?;
+		let escrow = lock_address(address);
+		let frozen = locks.iter().map(|(_, amount)| *amount).max().unwrap_or_default();
+		let escrowed = Self::erc20_balance_of(contract, escrow);
+		if frozen > escrowed {
+			let amount = frozen - escrowed;
+			ensure!(
+				Self::erc20_balance_of(contract, address) >= amount,
+				Error::<T>::BalanceTooLow
+			);
+			T::EVMBridge::transfer(
+				InvokeContext {
+					contract,
+					sender: address,
+					origin: address,
+				},
+				escrow,
+				amount,
+			)?;
+		} else if escrowed > frozen {
+			T::EVMBridge::transfer(
+				InvokeContext {
+					contract,
+					sender: escrow,
+					origin: address,
+				},
+				address,
+				escrowed - frozen,
+			)?;
+		}
+
+		if locks.is_empty() {
+			Erc20Locks::<T>::remove(contract, who);
+		} else {
+			Erc20Locks::<T>::insert(contract, who, locks);
+		}
+		Ok(())
+	}
+}
+
 impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T> {
 	type CurrencyId = CurrencyIdOf<T>;
 	type Balance = BalanceOf<T>;
@@ -224,8 +414,8 @@ impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T> {
 	fn minimum_balance(currency_id: Self::CurrencyId) -> Self::Balance {
 		match currency_id {
 			CurrencyId::Erc20(_) => Default::default(),
-			id if id == T::GetNativeCurrencyId::get() => T::NativeCurrency::minimum_balance(),
-			_ => T::MultiCurrency::minimum_balance(currency_id),
+			id if id == T::GetNativeCurrencyId::get() => <T::NativeCurrency as BasicCurrency<_>>::minimum_balance(),
+			_ => <T::MultiCurrency as MultiCurrency<_>>::minimum_balance(currency_id),
 		}
 	}
 
@@ -237,23 +427,15 @@ impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T> {
 				origin: Default::default(),
 			})
 			.unwrap_or_default(),
-			id if id == T::GetNativeCurrencyId::get() => T::NativeCurrency::total_issuance(),
-			_ => T::MultiCurrency::total_issuance(currency_id),
+			id if id == T::GetNativeCurrencyId::get() => <T::NativeCurrency as BasicCurrency<_>>::total_issuance(),
+			_ => <T::MultiCurrency as MultiCurrency<_>>::total_issuance(currency_id),
 		}
 	}
 
 	fn total_balance(currency_id: Self::CurrencyId, who: &T::AccountId) -> Self::Balance {
 		match currency_id {
-			CurrencyId::Erc20(contract) => {
-				if let Some(address) = T::AddressMapping::get_evm_address(who) {
-					let context = InvokeContext {
-						contract,
-						sender: Default::default(),
-						origin: Default::default(),
-					};
-					return T::EVMBridge::balance_of(context, address).unwrap_or_default();
-				}
-				Default::default()
+			CurrencyId::Erc20(_) => {
+				Self::free_balance(currency_id, who).saturating_add(Self::reserved_balance(currency_id, who))
 			}
 			id if id == T::GetNativeCurrencyId::get() => T::NativeCurrency::total_balance(who),
 			_ => T::MultiCurrency::total_balance(currency_id, who),
@@ -264,12 +446,9 @@ impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T> {
 		match currency_id {
 			CurrencyId::Erc20(contract) => {
 				if let Some(address) = T::AddressMapping::get_evm_address(who) {
-					let context = InvokeContext {
-						contract,
-						sender: Default::default(),
-						origin: Default::default(),
-					};
-					return T::EVMBridge::balance_of(context, address).unwrap_or_default();
+					// the locked balance is still free, but escrowed
+					return Self::erc20_balance_of(contract, address)
+						.saturating_add(Self::erc20_balance_of(contract, lock_address(address)));
 				}
 				Default::default()
 			}
@@ -282,16 +461,14 @@ impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T> {
 		match currency_id {
 			CurrencyId::Erc20(contract) => {
 				let address = T::AddressMapping::get_evm_address(who).ok_or(Error::<T>::EvmAccountNotFound)?;
-				let balance = T::EVMBridge::balance_of(
-					InvokeContext {
-						contract,
-						sender: Default::default(),
-						origin: Default::default(),
-					},
-					address,
-				)
-				.unwrap_or_default();
-				ensure!(balance >= amount, Error::<T>::BalanceTooLow);
+				let new_free_balance = Self::free_balance(currency_id, who)
+					.checked_sub(&amount)
+					.ok_or(Error::<T>::BalanceTooLow)?;
+				ensure!(
+					new_free_balance >= Self::erc20_frozen_balance(contract, who)
+						&& Self::erc20_balance_of(contract, address) >= amount,
+					Error::<T>::LiquidityRestrictions
+				);
 				Ok(())
 			}
 			id if id == T::GetNativeCurrencyId::get() => T::NativeCurrency::ensure_can_withdraw(who, amount),
@@ -308,9 +485,12 @@ impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T> {
 		if amount.is_zero() || from == to {
 			return Ok(());
 		}
+		T::CurrencyHook::pre_transfer(currency_id, from, to, amount)?;
 
 		match currency_id {
 			CurrencyId::Erc20(contract) => {
+				// the locked and reserved balances can't be transferred
+				Self::ensure_can_withdraw(currency_id, from, amount)?;
 				let sender = T::AddressMapping::get_evm_address(from).ok_or(Error::<T>::EvmAccountNotFound)?;
 				let origin = T::EVMBridge::get_origin().ok_or(Error::<T>::RealOriginNotFound)?;
 				let origin_address = T::AddressMapping::get_or_create_evm_address(&origin);
@@ -329,6 +509,7 @@ impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T> {
 			_ => T::MultiCurrency::transfer(currency_id, from, to, amount)?,
 		}
 
+		T::CurrencyHook::post_transfer(currency_id, from, to, amount);
 		Self::deposit_event(Event::Transferred(currency_id, from.clone(), to.clone(), amount));
 		Ok(())
 	}
@@ -337,11 +518,13 @@ impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T> {
 		if amount.is_zero() {
 			return Ok(());
 		}
+		T::CurrencyHook::pre_deposit(currency_id, who, amount)?;
 		match currency_id {
 			CurrencyId::Erc20(_) => return Err(Error::<T>::Erc20InvalidOperation.into()),
 			id if id == T::GetNativeCurrencyId::get() => T::NativeCurrency::deposit(who, amount)?,
 			_ => T::MultiCurrency::deposit(currency_id, who, amount)?,
 		}
+		T::CurrencyHook::post_deposit(currency_id, who, amount);
 		Self::deposit_event(Event::Deposited(currency_id, who.clone(), amount));
 		Ok(())
 	}
@@ -350,11 +533,13 @@ impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T> {
 		if amount.is_zero() {
 			return Ok(());
 		}
+		T::CurrencyHook::pre_withdraw(currency_id, who, amount)?;
 		match currency_id {
 			CurrencyId::Erc20(_) => return Err(Error::<T>::Erc20InvalidOperation.into()),
 			id if id == T::GetNativeCurrencyId::get() => T::NativeCurrency::withdraw(who, amount)?,
 			_ => T::MultiCurrency::withdraw(currency_id, who, amount)?,
 		}
+		T::CurrencyHook::post_withdraw(currency_id, who, amount);
 		Self::deposit_event(Event::Withdrawn(currency_id, who.clone(), amount));
 		Ok(())
 	}
@@ -368,11 +553,14 @@ impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T> {
 	}
 
 	fn slash(currency_id: Self::CurrencyId, who: &T::AccountId, amount: Self::Balance) -> Self::Balance {
-		match currency_id {
-			CurrencyId::Erc20(_) => Default::default(),
+		let gap = match currency_id {
+			CurrencyId::Erc20(_) => return Default::default(),
 			id if id == T::GetNativeCurrencyId::get() => T::NativeCurrency::slash(who, amount),
 			_ => T::MultiCurrency::slash(currency_id, who, amount),
-		}
+		};
+		// the slash can't be vetoed, it's only recorded
+		T::CurrencyHook::post_withdraw(currency_id, who, amount.saturating_sub(gap));
+		gap
 	}
 }
 
@@ -380,11 +568,25 @@ impl<T: Config> MultiCurrencyExtended<T::AccountId> for Pallet<T> {
 	type Amount = AmountOf<T>;
 
 	fn update_balance(currency_id: Self::CurrencyId, who: &T::AccountId, by_amount: Self::Amount) -> DispatchResult {
+		let amount: BalanceOf<T> = by_amount
+			.abs()
+			.try_into()
+			.map_err(|_| Error::<T>::AmountIntoBalanceFailed)?;
+		if by_amount.is_positive() {
+			T::CurrencyHook::pre_deposit(currency_id, who, amount)?;
+		} else if by_amount.is_negative() {
+			T::CurrencyHook::pre_withdraw(currency_id, who, amount)?;
+		}
 		match currency_id {
 			CurrencyId::Erc20(_) => return Err(Error::<T>::Erc20InvalidOperation.into()),
 			id if id == T::GetNativeCurrencyId::get() => T::NativeCurrency::update_balance(who, by_amount)?,
 			_ => T::MultiCurrency::update_balance(currency_id, who, by_amount)?,
 		}
+		if by_amount.is_positive() {
+			T::CurrencyHook::post_deposit(currency_id, who, amount);
+		} else if by_amount.is_negative() {
+			T::CurrencyHook::post_withdraw(currency_id, who, amount);
+		}
 		Self::deposit_event(Event::BalanceUpdated(currency_id, who.clone(), by_amount));
 		Ok(())
 	}
@@ -400,7 +602,12 @@ impl<T: Config> MultiLockableCurrency<T::AccountId> for Pallet<T> {
 		amount: Self::Balance,
 	) -> DispatchResult {
 		match currency_id {
-			CurrencyId::Erc20(_) => Err(Error::<T>::Erc20InvalidOperation.into()),
+			CurrencyId::Erc20(contract) => {
+				if amount.is_zero() {
+					return Ok(());
+				}
+				Self::update_erc20_lock(lock_id, contract, who, |_| Some(amount))
+			}
 			id if id == T::GetNativeCurrencyId::get() => T::NativeCurrency::set_lock(lock_id, who, amount),
 			_ => T::MultiCurrency::set_lock(lock_id, currency_id, who, amount),
 		}
@@ -413,7 +620,14 @@ impl<T: Config> MultiLockableCurrency<T::AccountId> for Pallet<T> {
 		amount: Self::Balance,
 	) -> DispatchResult {
 		match currency_id {
-			CurrencyId::Erc20(_) => Err(Error::<T>::Erc20InvalidOperation.into()),
+			CurrencyId::Erc20(contract) => {
+				if amount.is_zero() {
+					return Ok(());
+				}
+				Self::update_erc20_lock(lock_id, contract, who, |maybe_amount| {
+					Some(maybe_amount.map_or(amount, |locked| locked.max(amount)))
+				})
+			}
 			id if id == T::GetNativeCurrencyId::get() => T::NativeCurrency::extend_lock(lock_id, who, amount),
 			_ => T::MultiCurrency::extend_lock(lock_id, currency_id, who, amount),
 		}
@@ -421,7 +635,7 @@ impl<T: Config> MultiLockableCurrency<T::AccountId> for Pallet<T> {
 
 	fn remove_lock(lock_id: LockIdentifier, currency_id: Self::CurrencyId, who: &T::AccountId) -> DispatchResult {
 		match currency_id {
-			CurrencyId::Erc20(_) => Err(Error::<T>::Erc20InvalidOperation.into()),
+			CurrencyId::Erc20(contract) => Self::update_erc20_lock(lock_id, contract, who, |_| None),
 			id if id == T::GetNativeCurrencyId::get() => T::NativeCurrency::remove_lock(lock_id, who),
 			_ => T::MultiCurrency::remove_lock(lock_id, currency_id, who),
 		}
@@ -438,26 +652,21 @@ impl<T: Config> MultiReservableCurrency<T::AccountId> for Pallet<T> {
 	}
 
 	fn slash_reserved(currency_id: Self::CurrencyId, who: &T::AccountId, value: Self::Balance) -> Self::Balance {
-		match currency_id {
-			CurrencyId::Erc20(_) => value,
+		let gap = match currency_id {
+			CurrencyId::Erc20(_) => return value,
 			id if id == T::GetNativeCurrencyId::get() => T::NativeCurrency::slash_reserved(who, value),
 			_ => T::MultiCurrency::slash_reserved(currency_id, who, value),
-		}
+		};
+		// the slash can't be vetoed, it's only recorded
+		T::CurrencyHook::post_withdraw(currency_id, who, value.saturating_sub(gap));
+		gap
 	}
 
 	fn reserved_balance(currency_id: Self::CurrencyId, who: &T::AccountId) -> Self::Balance {
 		match currency_id {
 			CurrencyId::Erc20(contract) => {
 				if let Some(address) = T::AddressMapping::get_evm_address(who) {
-					return T::EVMBridge::balance_of(
-						InvokeContext {
-							contract,
-							sender: Default::default(),
-							origin: Default::default(),
-						},
-						reserve_address(address),
-					)
-					.unwrap_or_default();
+					return Self::erc20_balance_of(contract, reserve_address(address));
 				}
 				Default::default()
 			}
@@ -472,6 +681,7 @@ impl<T: Config> MultiReservableCurrency<T::AccountId> for Pallet<T> {
 				if value.is_zero() {
 					return Ok(());
 				}
+				Self::ensure_can_withdraw(currency_id, who, value)?;
 				let address = T::AddressMapping::get_evm_address(who).ok_or(Error::<T>::EvmAccountNotFound)?;
 				T::EVMBridge::transfer(
 					InvokeContext {
@@ -496,16 +706,7 @@ impl<T: Config> MultiReservableCurrency<T::AccountId> for Pallet<T> {
 				}
 				if let Some(address) = T::AddressMapping::get_evm_address(who) {
 					let sender = reserve_address(address);
-					let reserved_balance = T::EVMBridge::balance_of(
-						InvokeContext {
-							contract,
-							sender: Default::default(),
-							origin: Default::default(),
-						},
-						sender,
-					)
-					.unwrap_or_default();
-					let actual = reserved_balance.min(value);
+					let actual = Self::erc20_balance_of(contract, sender).min(value);
 					return match T::EVMBridge::transfer(
 						InvokeContext {
 							contract,
@@ -533,7 +734,11 @@ impl<T: Config> MultiReservableCurrency<T::AccountId> for Pallet<T> {
 		value: Self::Balance,
 		status: BalanceStatus,
 	) -> result::Result<Self::Balance, DispatchError> {
-		match currency_id {
+		if slashed != beneficiary {
+			T::CurrencyHook::pre_transfer(currency_id, slashed, beneficiary, value)?;
+		}
+
+		let remaining = match currency_id {
 			CurrencyId::Erc20(contract) => {
 				if value.is_zero() {
 					return Ok(value);
@@ -552,45 +757,158 @@ impl<T: Config> MultiReservableCurrency<T::AccountId> for Pallet<T> {
 				let beneficiary_address = T::AddressMapping::get_or_create_evm_address(beneficiary);
 
 				let slashed_reserve_address = reserve_address(slashed_address);
-				let beneficiary_reserve_address = reserve_address(beneficiary_address);
-
-				let slashed_reserved_balance = T::EVMBridge::balance_of(
+				let actual = Self::erc20_balance_of(contract, slashed_reserve_address).min(value);
+				let to = match status {
+					BalanceStatus::Free => beneficiary_address,
+					BalanceStatus::Reserved => reserve_address(beneficiary_address),
+				};
+				T::EVMBridge::transfer(
 					InvokeContext {
 						contract,
-						sender: Default::default(),
-						origin: Default::default(),
+						sender: slashed_reserve_address,
+						origin: slashed_address,
 					},
-					slashed_reserve_address,
+					to,
+					actual,
 				)
-				.unwrap_or_default();
-				let actual = slashed_reserved_balance.min(value);
-				match status {
-					BalanceStatus::Free => T::EVMBridge::transfer(
-						InvokeContext {
-							contract,
-							sender: slashed_reserve_address,
-							origin: slashed_address,
-						},
-						beneficiary_address,
-						actual,
-					),
-					BalanceStatus::Reserved => T::EVMBridge::transfer(
-						InvokeContext {
-							contract,
-							sender: slashed_reserve_address,
-							origin: slashed_address,
-						},
-						beneficiary_reserve_address,
-						actual,
-					),
-				}
 				.map(|_| value - actual)
 			}
 			id if id == T::GetNativeCurrencyId::get() => {
 				T::NativeCurrency::repatriate_reserved(slashed, beneficiary, value, status)
 			}
 			_ => T::MultiCurrency::repatriate_reserved(currency_id, slashed, beneficiary, value, status),
+		}?;
+
+		if slashed != beneficiary {
+			T::CurrencyHook::post_transfer(currency_id, slashed, beneficiary, value.saturating_sub(remaining));
+		}
+		Ok(remaining)
+	}
+}
+
+// Named reserves of `CurrencyId::Erc20`
+impl<T: Config> Pallet<T> {
+	/// The amount reserved by the account under the identifier.
+	pub fn reserved_balance_named(id: &ReserveIdentifier, currency_id: CurrencyId, who: &T::AccountId) -> BalanceOf<T> {
+		match currency_id {
+			CurrencyId::Erc20(contract) => Self::erc20_named_reserves(contract, who)
+				.into_iter()
+				.find(|(reserve_id, _)| reserve_id == id)
+				.map(|(_, amount)| amount)
+				.unwrap_or_default(),
+			_ => Default::default(),
+		}
+	}
+
+	/// Reserve `value` of the account under the identifier.
+	pub fn reserve_named(
+		id: &ReserveIdentifier,
+		currency_id: CurrencyId,
+		who: &T::AccountId,
+		value: BalanceOf<T>,
+	) -> DispatchResult {
+		let contract = match currency_id {
+			CurrencyId::Erc20(contract) => contract,
+			_ => return Err(Error::<T>::NamedReserveNotSupported.into()),
+		};
+		if value.is_zero() {
+			return Ok(());
+		}
+		<Self as MultiReservableCurrency<_>>::reserve(currency_id, who, value)?;
+		Self::mutate_erc20_named_reserve(id, contract, who, |amount| amount.saturating_add(value));
+		Ok(())
+	}
+
+	/// Unreserve at most `value` of the account reserved under the identifier, return the amount
+	/// which can't be unreserved.
+	pub fn unreserve_named(
+		id: &ReserveIdentifier,
+		currency_id: CurrencyId,
+		who: &T::AccountId,
+		value: BalanceOf<T>,
+	) -> BalanceOf<T> {
+		let contract = match currency_id {
+			CurrencyId::Erc20(contract) => contract,
+			_ => return value,
+		};
+		let to_unreserve = Self::reserved_balance_named(id, currency_id, who).min(value);
+		if to_unreserve.is_zero() {
+			return value;
+		}
+		let gap = <Self as MultiReservableCurrency<_>>::unreserve(currency_id, who, to_unreserve);
+		let unreserved = to_unreserve.saturating_sub(gap);
+		Self::mutate_erc20_named_reserve(id, contract, who, |amount| amount.saturating_sub(unreserved));
+		value.saturating_sub(unreserved)
+	}
+
+	/// Move at most `value` reserved under the identifier from `slashed` to `beneficiary`, to
+	/// the reserve under the same identifier if `status` is `Reserved`. Return the amount which
+	/// can't be moved.
+	pub fn repatriate_reserved_named(
+		id: &ReserveIdentifier,
+		currency_id: CurrencyId,
+		slashed: &T::AccountId,
+		beneficiary: &T::AccountId,
+		value: BalanceOf<T>,
+		status: BalanceStatus,
+	) -> result::Result<BalanceOf<T>, DispatchError> {
+		let contract = match currency_id {
+			CurrencyId::Erc20(contract) => contract,
+			_ => return Err(Error::<T>::NamedReserveNotSupported.into()),
+		};
+		let to_move = Self::reserved_balance_named(id, currency_id, slashed).min(value);
+		if to_move.is_zero() {
+			return Ok(value);
+		}
+		if slashed == beneficiary {
+			return match status {
+				BalanceStatus::Free => Ok(Self::unreserve_named(id, currency_id, slashed, value)),
+				BalanceStatus::Reserved => Ok(value.saturating_sub(to_move)),
+			};
+		}
+
+		let gap = <Self as MultiReservableCurrency<_>>::repatriate_reserved(
+			currency_id,
+			slashed,
+			beneficiary,
+			to_move,
+			status,
+		)?;
+		let moved = to_move.saturating_sub(gap);
+		Self::mutate_erc20_named_reserve(id, contract, slashed, |amount| amount.saturating_sub(moved));
+		if status == BalanceStatus::Reserved {
+			Self::mutate_erc20_named_reserve(id, contract, beneficiary, |amount| amount.saturating_add(moved));
 		}
+		Ok(value.saturating_sub(moved))
+	}
+
+	/// Update the amount reserved under the identifier by `f`, the zero amount is removed.
+	fn mutate_erc20_named_reserve(
+		id: &ReserveIdentifier,
+		contract: EvmAddress,
+		who: &T::AccountId,
+		f: impl FnOnce(BalanceOf<T>) -> BalanceOf<T>,
+	) {
+		Erc20NamedReserves::<T>::mutate_exists(contract, who, |maybe_reserves| {
+			let mut reserves = maybe_reserves.take().unwrap_or_default();
+			match reserves.iter().position(|(reserve_id, _)| reserve_id == id) {
+				Some(index) => {
+					reserves[index].1 = f(reserves[index].1);
+					if reserves[index].1.is_zero() {
+						reserves.remove(index);
+					}
+				}
+				None => {
+					let amount = f(Zero::zero());
+					if !amount.is_zero() {
+						reserves.push((*id, amount));
+					}
+				}
+			}
+			if !reserves.is_empty() {
+				*maybe_reserves = Some(reserves);
+			}
+		});
 	}
 }
 
@@ -872,6 +1190,40 @@ where
 	}
 }
 
+// Adapt `frame_support::traits::fungible::Inspect`
+impl<T, AccountId, Currency, Amount, Moment> fungible::Inspect<AccountId>
+	for BasicCurrencyAdapter<T, Currency, Amount, Moment>
+where
+	Currency: fungible::Inspect<AccountId>,
+	T: Config,
+{
+	type Balance = Currency::Balance;
+
+	fn total_issuance() -> Self::Balance {
+		Currency::total_issuance()
+	}
+
+	fn minimum_balance() -> Self::Balance {
+		Currency::minimum_balance()
+	}
+
+	fn balance(who: &AccountId) -> Self::Balance {
+		Currency::balance(who)
+	}
+
+	fn reducible_balance(who: &AccountId, keep_alive: bool) -> Self::Balance {
+		Currency::reducible_balance(who, keep_alive)
+	}
+
+	fn can_deposit(who: &AccountId, amount: Self::Balance) -> DepositConsequence {
+		Currency::can_deposit(who, amount)
+	}
+
+	fn can_withdraw(who: &AccountId, amount: Self::Balance) -> WithdrawConsequence<Self::Balance> {
+		Currency::can_withdraw(who, amount)
+	}
+}
+
 impl<T: Config> TransferAll<T::AccountId> for Pallet<T> {
 	#[transactional]
 	fn transfer_all(source: &T::AccountId, dest: &T::AccountId) -> DispatchResult {
@@ -883,11 +1235,18 @@ impl<T: Config> TransferAll<T::AccountId> for Pallet<T> {
 	}
 }
 
+/// The escrow address of the reserved Erc20 balance of `address`.
 fn reserve_address(address: EvmAddress) -> EvmAddress {
 	let payload = (b"erc20:", address);
 	EvmAddress::from_slice(&payload.using_encoded(blake2_256)[0..20])
 }
 
+/// The escrow address of the locked Erc20 balance of `address`.
+fn lock_address(address: EvmAddress) -> EvmAddress {
+	let payload = (b"erc20-lock:", address);
+	EvmAddress::from_slice(&payload.using_encoded(blake2_256)[0..20])
+}
+
 pub struct TransferDust<T, GetAccountId>(marker::PhantomData<(T, GetAccountId)>);
 impl<T: Config, GetAccountId> OnDust<T::AccountId, CurrencyIdOf<T>, BalanceOf<T>> for TransferDust<T, GetAccountId>
 where
@@ -904,3 +1263,124 @@ where
 		};
 	}
 }
+
+/// A `CurrencyOperationHook` which notifies `OnBalanceChanged` of the accounts whose balance
+/// changed after a transfer, deposit or withdrawal succeeded.
+pub struct NotifyBalanceChanged<OnBalanceChanged>(marker::PhantomData<OnBalanceChanged>);
+impl<AccountId, CurrencyId, Balance, OnBalanceChanged> CurrencyOperationHook<AccountId, CurrencyId, Balance>
+	for NotifyBalanceChanged<OnBalanceChanged>
+where
+	AccountId: Clone,
+	CurrencyId: Clone,
+	OnBalanceChanged: Happened<(AccountId, CurrencyId)>,
+{
+	fn post_transfer(currency_id: CurrencyId, from: &AccountId, to: &AccountId, _amount: Balance) {
+		OnBalanceChanged::happened(&(from.clone(), currency_id.clone()));
+		OnBalanceChanged::happened(&(to.clone(), currency_id));
+	}
+
+	fn post_deposit(currency_id: CurrencyId, who: &AccountId, _amount: Balance) {
+		OnBalanceChanged::happened(&(who.clone(), currency_id));
+	}
+
+	fn post_withdraw(currency_id: CurrencyId, who: &AccountId, _amount: Balance) {
+		OnBalanceChanged::happened(&(who.clone(), currency_id));
+	}
+}
+
+/// A `fungibles` adapter of `Assets` which notifies `OnBalanceChanged` of the accounts whose
+/// balance changed after a mint, burn or transfer succeeded. It's used by the modules which move
+/// tokens through the tokens module directly rather than through this module, e.g. StableAsset.
+pub struct NotifyBalanceChangedAssets<Assets, OnBalanceChanged>(marker::PhantomData<(Assets, OnBalanceChanged)>);
+
+impl<AccountId, Assets, OnBalanceChanged> fungibles::Inspect<AccountId>
+	for NotifyBalanceChangedAssets<Assets, OnBalanceChanged>
+where
+	Assets: fungibles::Inspect<AccountId>,
+{
+	type AssetId = Assets::AssetId;
+	type Balance = Assets::Balance;
+
+	fn total_issuance(asset: Self::AssetId) -> Self::Balance {
+		Assets::total_issuance(asset)
+	}
+
+	fn minimum_balance(asset: Self::AssetId) -> Self::Balance {
+		Assets::minimum_balance(asset)
+	}
+
+	fn balance(asset: Self::AssetId, who: &AccountId) -> Self::Balance {
+		Assets::balance(asset, who)
+	}
+
+	fn reducible_balance(asset: Self::AssetId, who: &AccountId, keep_alive: bool) -> Self::Balance {
+		Assets::reducible_balance(asset, who, keep_alive)
+	}
+
+	fn can_deposit(asset: Self::AssetId, who: &AccountId, amount: Self::Balance) -> DepositConsequence {
+		Assets::can_deposit(asset, who, amount)
+	}
+
+	fn can_withdraw(
+		asset: Self::AssetId,
+		who: &AccountId,
+		amount: Self::Balance,
+	) -> WithdrawConsequence<Self::Balance> {
+		Assets::can_withdraw(asset, who, amount)
+	}
+}
+
+impl<AccountId, Assets, OnBalanceChanged> fungibles::Mutate<AccountId>
+	for NotifyBalanceChangedAssets<Assets, OnBalanceChanged>
+where
+	AccountId: Clone,
+	Assets: fungibles::Mutate<AccountId>,
+	OnBalanceChanged: Happened<(AccountId, Assets::AssetId)>,
+{
+	fn mint_into(asset: Self::AssetId, who: &AccountId, amount: Self::Balance) -> DispatchResult {
+		Assets::mint_into(asset, who, amount)?;
+		OnBalanceChanged::happened(&(who.clone(), asset));
+		Ok(())
+	}
+
+	fn burn_from(
+		asset: Self::AssetId,
+		who: &AccountId,
+		amount: Self::Balance,
+	) -> result::Result<Self::Balance, DispatchError> {
+		let actual = Assets::burn_from(asset, who, amount)?;
+		OnBalanceChanged::happened(&(who.clone(), asset));
+		Ok(actual)
+	}
+
+	fn slash(
+		asset: Self::AssetId,
+		who: &AccountId,
+		amount: Self::Balance,
+	) -> result::Result<Self::Balance, DispatchError> {
+		let actual = Assets::slash(asset, who, amount)?;
+		OnBalanceChanged::happened(&(who.clone(), asset));
+		Ok(actual)
+	}
+}
+
+impl<AccountId, Assets, OnBalanceChanged> fungibles::Transfer<AccountId>
+	for NotifyBalanceChangedAssets<Assets, OnBalanceChanged>
+where
+	AccountId: Clone,
+	Assets: fungibles::Transfer<AccountId>,
+	OnBalanceChanged: Happened<(AccountId, Assets::AssetId)>,
+{
+	fn transfer(
+		asset: Self::AssetId,
+		source: &AccountId,
+		dest: &AccountId,
+		amount: Self::Balance,
+		keep_alive: bool,
+	) -> result::Result<Self::Balance, DispatchError> {
+		let actual = Assets::transfer(asset, source, dest, amount, keep_alive)?;
+		OnBalanceChanged::happened(&(source.clone(), asset));
+		OnBalanceChanged::happened(&(dest.clone(), asset));
+		Ok(actual)
+	}
+}
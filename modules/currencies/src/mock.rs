@@ -177,6 +177,26 @@ impl module_evm_bridge::Config for Runtime {
 	type EVM = EVM;
 }
 
+/// Reject all the operations of DOT involving eva.
+pub struct MockCurrencyHook;
+impl CurrencyOperationHook<AccountId, CurrencyId, Balance> for MockCurrencyHook {
+	fn pre_transfer(currency_id: CurrencyId, from: &AccountId, to: &AccountId, _amount: Balance) -> DispatchResult {
+		Self::pre_deposit(currency_id, from, Zero::zero())?;
+		Self::pre_deposit(currency_id, to, Zero::zero())
+	}
+
+	fn pre_deposit(currency_id: CurrencyId, who: &AccountId, _amount: Balance) -> DispatchResult {
+		if currency_id == DOT && *who == eva() {
+			return Err(DispatchError::Other("restricted account"));
+		}
+		Ok(())
+	}
+
+	fn pre_withdraw(currency_id: CurrencyId, who: &AccountId, amount: Balance) -> DispatchResult {
+		Self::pre_deposit(currency_id, who, amount)
+	}
+}
+
 impl Config for Runtime {
 	type Event = Event;
 	type MultiCurrency = Tokens;
@@ -187,6 +207,7 @@ impl Config for Runtime {
 	type EVMBridge = EVMBridge;
 	type SweepOrigin = EnsureSignedBy<CouncilAccount, AccountId>;
 	type OnDust = crate::TransferDust<Runtime, DustAccount>;
+	type CurrencyHook = MockCurrencyHook;
 }
 
 pub type NativeCurrency = Currency<Runtime, GetNativeCurrencyId>;
@@ -23,9 +23,9 @@
 use super::*;
 use frame_support::{assert_noop, assert_ok};
 use mock::{
-	alice, bob, deploy_contracts, erc20_address, eva, AccountId, AdaptedBasicCurrency, CouncilAccount, Currencies,
-	DustAccount, Event, ExtBuilder, NativeCurrency, Origin, PalletBalances, Runtime, System, Tokens, DOT, EVM, ID_1,
-	NATIVE_CURRENCY_ID, X_TOKEN_ID,
+	alice, alice_evm_addr, bob, bob_evm_addr, deploy_contracts, erc20_address, eva, AccountId, AdaptedBasicCurrency,
+	CouncilAccount, Currencies, DustAccount, Event, ExtBuilder, NativeCurrency, Origin, PalletBalances, Runtime,
+	System, Tokens, DOT, EVM, ID_1, NATIVE_CURRENCY_ID, X_TOKEN_ID,
 };
 use sp_core::H160;
 use sp_runtime::traits::BadOrigin;
@@ -319,6 +319,174 @@ fn call_event_should_work() {
 		});
 }
 
+#[test]
+fn transfer_batch_should_work() {
+	ExtBuilder::default()
+		.one_hundred_for_alice_n_bob()
+		.build()
+		.execute_with(|| {
+			assert_ok!(Currencies::transfer_batch(
+				Some(alice()).into(),
+				vec![(bob(), X_TOKEN_ID, 30), (eva(), NATIVE_CURRENCY_ID, 40)]
+			));
+			assert_eq!(Currencies::free_balance(X_TOKEN_ID, &alice()), 70);
+			assert_eq!(Currencies::free_balance(X_TOKEN_ID, &bob()), 130);
+			assert_eq!(NativeCurrency::free_balance(&alice()), 60);
+			assert_eq!(NativeCurrency::free_balance(&eva()), 40);
+			System::assert_last_event(Event::Currencies(crate::Event::Transferred(
+				NATIVE_CURRENCY_ID,
+				alice(),
+				eva(),
+				40,
+			)));
+
+			// all the transfers are reverted if any of them failed
+			assert_noop!(
+				Currencies::transfer_batch(
+					Some(alice()).into(),
+					vec![(bob(), X_TOKEN_ID, 30), (bob(), X_TOKEN_ID, 50)]
+				),
+				tokens::Error::<Runtime>::BalanceTooLow
+			);
+		});
+}
+
+#[test]
+fn transfer_all_should_work() {
+	ExtBuilder::default()
+		.one_hundred_for_alice_n_bob()
+		.build()
+		.execute_with(|| {
+			assert_ok!(Currencies::transfer_all(Some(alice()).into(), eva(), X_TOKEN_ID, false));
+			assert_eq!(Currencies::free_balance(X_TOKEN_ID, &alice()), 0);
+			assert_eq!(Currencies::free_balance(X_TOKEN_ID, &eva()), 100);
+
+			assert_ok!(Currencies::transfer_all(
+				Some(bob()).into(),
+				eva(),
+				NATIVE_CURRENCY_ID,
+				true
+			));
+			assert_eq!(NativeCurrency::free_balance(&bob()), 2);
+			assert_eq!(NativeCurrency::free_balance(&eva()), 98);
+		});
+}
+
+#[test]
+fn transfer_all_should_not_transfer_locked_balance() {
+	ExtBuilder::default()
+		.one_hundred_for_alice_n_bob()
+		.build()
+		.execute_with(|| {
+			assert_ok!(Currencies::set_lock(ID_1, X_TOKEN_ID, &alice(), 30));
+			assert_ok!(Currencies::transfer_all(Some(alice()).into(), eva(), X_TOKEN_ID, false));
+			assert_eq!(Currencies::free_balance(X_TOKEN_ID, &alice()), 30);
+			assert_eq!(Currencies::free_balance(X_TOKEN_ID, &eva()), 70);
+
+			assert_ok!(Currencies::set_lock(ID_1, NATIVE_CURRENCY_ID, &alice(), 40));
+			assert_ok!(Currencies::transfer_all(
+				Some(alice()).into(),
+				eva(),
+				NATIVE_CURRENCY_ID,
+				false
+			));
+			assert_eq!(NativeCurrency::free_balance(&alice()), 40);
+			assert_eq!(NativeCurrency::free_balance(&eva()), 60);
+		});
+}
+
+#[test]
+fn erc20_transfer_batch_and_transfer_all_should_work() {
+	ExtBuilder::default()
+		.balances(vec![
+			(alice(), NATIVE_CURRENCY_ID, 100000),
+			(bob(), NATIVE_CURRENCY_ID, 100000),
+		])
+		.build()
+		.execute_with(|| {
+			deploy_contracts();
+			<EVM as EVMTrait<AccountId>>::set_origin(alice());
+			let alice_balance = 10000;
+
+			assert_ok!(Currencies::transfer_batch(
+				Origin::signed(alice()),
+				vec![
+					(bob(), CurrencyId::Erc20(erc20_address()), 100),
+					(bob(), NATIVE_CURRENCY_ID, 100)
+				]
+			));
+			assert_eq!(
+				Currencies::free_balance(CurrencyId::Erc20(erc20_address()), &bob()),
+				100
+			);
+			assert_eq!(NativeCurrency::free_balance(&bob()), 100100);
+
+			assert_ok!(Currencies::set_lock(
+				ID_1,
+				CurrencyId::Erc20(erc20_address()),
+				&alice(),
+				1000
+			));
+			assert_ok!(Currencies::reserve(CurrencyId::Erc20(erc20_address()), &alice(), 500));
+			assert_ok!(Currencies::transfer_all(
+				Origin::signed(alice()),
+				bob(),
+				CurrencyId::Erc20(erc20_address()),
+				true
+			));
+			assert_eq!(
+				Currencies::free_balance(CurrencyId::Erc20(erc20_address()), &alice()),
+				1000
+			);
+			assert_eq!(
+				Currencies::free_balance(CurrencyId::Erc20(erc20_address()), &bob()),
+				100 + (alice_balance - 100 - 500 - 1000)
+			);
+		});
+}
+
+#[test]
+fn currency_hook_should_work() {
+	ExtBuilder::default()
+		.balances(vec![(alice(), DOT, 100), (eva(), DOT, 100)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Currencies::transfer(Some(alice()).into(), eva(), DOT, 10),
+				DispatchError::Other("restricted account")
+			);
+			assert_noop!(
+				Currencies::transfer(Some(eva()).into(), alice(), DOT, 10),
+				DispatchError::Other("restricted account")
+			);
+			assert_noop!(
+				<Currencies as MultiCurrency<AccountId>>::deposit(DOT, &eva(), 10),
+				DispatchError::Other("restricted account")
+			);
+			assert_noop!(
+				<Currencies as MultiCurrency<AccountId>>::withdraw(DOT, &eva(), 10),
+				DispatchError::Other("restricted account")
+			);
+			assert_noop!(
+				<Currencies as MultiCurrencyExtended<AccountId>>::update_balance(DOT, &eva(), 10),
+				DispatchError::Other("restricted account")
+			);
+			assert_noop!(
+				<Currencies as MultiCurrencyExtended<AccountId>>::update_balance(DOT, &eva(), -10),
+				DispatchError::Other("restricted account")
+			);
+
+			assert_ok!(Currencies::reserve(DOT, &alice(), 10));
+			assert_noop!(
+				Currencies::repatriate_reserved(DOT, &alice(), &eva(), 10, BalanceStatus::Free),
+				DispatchError::Other("restricted account")
+			);
+
+			assert_ok!(Currencies::transfer(Some(alice()).into(), bob(), DOT, 10));
+			assert_eq!(Currencies::free_balance(DOT, &bob()), 10);
+		});
+}
+
 #[test]
 fn erc20_total_issuance_should_work() {
 	ExtBuilder::default()
@@ -560,6 +728,54 @@ fn erc20_reserve_should_work() {
 				Currencies::free_balance(CurrencyId::Erc20(erc20_address()), &alice()),
 				alice_balance - 100
 			);
+			assert_eq!(
+				Currencies::total_balance(CurrencyId::Erc20(erc20_address()), &alice()),
+				alice_balance
+			);
+
+			// the reserved balance is escrowed, it can't be transferred by the Erc20 contract
+			assert!(<Runtime as Config>::EVMBridge::transfer(
+				InvokeContext {
+					contract: erc20_address(),
+					sender: alice_evm_addr(),
+					origin: alice_evm_addr(),
+				},
+				bob_evm_addr(),
+				alice_balance - 99
+			)
+			.is_err());
+		});
+}
+
+#[test]
+fn erc20_reserved_balance_at_reserve_address_should_work() {
+	ExtBuilder::default()
+		.balances(vec![(alice(), NATIVE_CURRENCY_ID, 100000)])
+		.build()
+		.execute_with(|| {
+			deploy_contracts();
+			// the balance already escrowed at the reserve address is the reserved balance
+			assert_ok!(<Runtime as Config>::EVMBridge::transfer(
+				InvokeContext {
+					contract: erc20_address(),
+					sender: alice_evm_addr(),
+					origin: alice_evm_addr(),
+				},
+				reserve_address(alice_evm_addr()),
+				100
+			));
+			assert_eq!(
+				Currencies::reserved_balance(CurrencyId::Erc20(erc20_address()), &alice()),
+				100
+			);
+			assert_eq!(
+				Currencies::unreserve(CurrencyId::Erc20(erc20_address()), &alice(), 100),
+				0
+			);
+			assert_eq!(
+				Currencies::free_balance(CurrencyId::Erc20(erc20_address()), &alice()),
+				10000
+			);
 		});
 }
 
@@ -637,23 +853,100 @@ fn erc20_should_not_slash() {
 }
 
 #[test]
-fn erc20_should_not_be_lockable() {
+fn erc20_lockable_should_work() {
 	ExtBuilder::default()
-		.balances(vec![(alice(), NATIVE_CURRENCY_ID, 100000)])
+		.balances(vec![
+			(alice(), NATIVE_CURRENCY_ID, 100000),
+			(bob(), NATIVE_CURRENCY_ID, 100000),
+		])
 		.build()
 		.execute_with(|| {
 			deploy_contracts();
+			<EVM as EVMTrait<AccountId>>::set_origin(alice());
+			let alice_balance = 10000;
+
+			assert_ok!(Currencies::set_lock(
+				ID_1,
+				CurrencyId::Erc20(erc20_address()),
+				&alice(),
+				0
+			));
+			assert_eq!(Currencies::erc20_locks(erc20_address(), alice()), vec![]);
+
+			assert_ok!(Currencies::set_lock(
+				ID_1,
+				CurrencyId::Erc20(erc20_address()),
+				&alice(),
+				alice_balance - 100
+			));
+			assert_eq!(
+				Currencies::erc20_locks(erc20_address(), alice()),
+				vec![(ID_1, alice_balance - 100)]
+			);
+			assert_eq!(
+				Currencies::free_balance(CurrencyId::Erc20(erc20_address()), &alice()),
+				alice_balance
+			);
+			// the locked balance is escrowed, it can't be transferred by the Erc20 contract
+			assert!(<Runtime as Config>::EVMBridge::transfer(
+				InvokeContext {
+					contract: erc20_address(),
+					sender: alice_evm_addr(),
+					origin: alice_evm_addr(),
+				},
+				bob_evm_addr(),
+				101
+			)
+			.is_err());
 			assert_noop!(
-				Currencies::set_lock(ID_1, CurrencyId::Erc20(erc20_address()), &alice(), 1),
-				Error::<Runtime>::Erc20InvalidOperation
+				Currencies::transfer(Origin::signed(alice()), bob(), CurrencyId::Erc20(erc20_address()), 101),
+				Error::<Runtime>::LiquidityRestrictions
 			);
 			assert_noop!(
-				Currencies::extend_lock(ID_1, CurrencyId::Erc20(erc20_address()), &alice(), 1),
-				Error::<Runtime>::Erc20InvalidOperation
+				Currencies::reserve(CurrencyId::Erc20(erc20_address()), &alice(), 101),
+				Error::<Runtime>::LiquidityRestrictions
+			);
+			assert_ok!(Currencies::transfer(
+				Origin::signed(alice()),
+				bob(),
+				CurrencyId::Erc20(erc20_address()),
+				100
+			));
+			assert_eq!(
+				Currencies::free_balance(CurrencyId::Erc20(erc20_address()), &bob()),
+				100
 			);
 			assert_noop!(
-				Currencies::remove_lock(ID_1, CurrencyId::Erc20(erc20_address()), &alice()),
-				Error::<Runtime>::Erc20InvalidOperation
+				Currencies::set_lock(ID_1, CurrencyId::Erc20(erc20_address()), &alice(), alice_balance),
+				Error::<Runtime>::BalanceTooLow
+			);
+
+			assert_ok!(Currencies::extend_lock(
+				ID_1,
+				CurrencyId::Erc20(erc20_address()),
+				&alice(),
+				10
+			));
+			assert_eq!(
+				Currencies::erc20_locks(erc20_address(), alice()),
+				vec![(ID_1, alice_balance - 100)]
+			);
+
+			assert_ok!(Currencies::remove_lock(
+				ID_1,
+				CurrencyId::Erc20(erc20_address()),
+				&alice()
+			));
+			assert!(!Erc20Locks::<Runtime>::contains_key(erc20_address(), alice()));
+			assert_ok!(Currencies::transfer(
+				Origin::signed(alice()),
+				bob(),
+				CurrencyId::Erc20(erc20_address()),
+				100
+			));
+			assert_eq!(
+				Currencies::free_balance(CurrencyId::Erc20(erc20_address()), &bob()),
+				200
 			);
 		});
 }
@@ -795,6 +1088,61 @@ fn erc20_repatriate_reserved_should_work() {
 		});
 }
 
+#[test]
+fn erc20_named_reserves_should_work() {
+	ExtBuilder::default()
+		.balances(vec![
+			(alice(), NATIVE_CURRENCY_ID, 100000),
+			(bob(), NATIVE_CURRENCY_ID, 100000),
+		])
+		.build()
+		.execute_with(|| {
+			deploy_contracts();
+			let erc20 = CurrencyId::Erc20(erc20_address());
+			let honzon = ReserveIdentifier::Honzon;
+			let nft = ReserveIdentifier::Nft;
+
+			assert_noop!(
+				Currencies::reserve_named(&honzon, X_TOKEN_ID, &alice(), 100),
+				Error::<Runtime>::NamedReserveNotSupported
+			);
+
+			assert_ok!(Currencies::reserve_named(&honzon, erc20, &alice(), 100));
+			assert_ok!(Currencies::reserve_named(&nft, erc20, &alice(), 50));
+			assert_eq!(Currencies::reserved_balance_named(&honzon, erc20, &alice()), 100);
+			assert_eq!(Currencies::reserved_balance_named(&nft, erc20, &alice()), 50);
+			assert_eq!(Currencies::reserved_balance(erc20, &alice()), 150);
+			assert_eq!(Currencies::free_balance(erc20, &alice()), 10000 - 150);
+
+			// only the amount reserved under the identifier is unreserved
+			assert_eq!(Currencies::unreserve_named(&honzon, erc20, &alice(), 200), 100);
+			assert_eq!(Currencies::reserved_balance_named(&honzon, erc20, &alice()), 0);
+			assert_eq!(Currencies::reserved_balance(erc20, &alice()), 50);
+			assert_eq!(
+				Currencies::erc20_named_reserves(erc20_address(), &alice()),
+				vec![(nft, 50)]
+			);
+
+			// repatriate to the reserve of the same identifier
+			assert_eq!(
+				Currencies::repatriate_reserved_named(&nft, erc20, &alice(), &bob(), 30, BalanceStatus::Reserved),
+				Ok(0)
+			);
+			assert_eq!(Currencies::reserved_balance_named(&nft, erc20, &alice()), 20);
+			assert_eq!(Currencies::reserved_balance_named(&nft, erc20, &bob()), 30);
+			assert_eq!(Currencies::reserved_balance(erc20, &bob()), 30);
+
+			// repatriate to free balance
+			assert_eq!(
+				Currencies::repatriate_reserved_named(&nft, erc20, &alice(), &bob(), 30, BalanceStatus::Free),
+				Ok(10)
+			);
+			assert_eq!(Currencies::reserved_balance_named(&nft, erc20, &alice()), 0);
+			assert_eq!(Currencies::free_balance(erc20, &bob()), 20);
+			assert_eq!(Currencies::erc20_named_reserves(erc20_address(), &alice()), vec![]);
+		});
+}
+
 #[test]
 fn erc20_invalid_operation() {
 	ExtBuilder::default()
@@ -53,6 +53,8 @@ pub trait WeightInfo {
 	fn update_balance_native_currency_creating() -> Weight;
 	fn update_balance_native_currency_killing() -> Weight;
 	fn sweep_dust(c: u32, ) -> Weight;
+	fn transfer_batch(c: u32, ) -> Weight;
+	fn transfer_all() -> Weight;
 }
 
 /// Weights for module_currencies using the Acala node and recommended hardware.
@@ -92,6 +94,18 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(c as Weight)))
 	}
+	fn transfer_batch(c: u32, ) -> Weight {
+		(3_624_000 as Weight)
+			// Standard Error: 41_000
+			.saturating_add((64_113_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads((5 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes((4 as Weight).saturating_mul(c as Weight)))
+	}
+	fn transfer_all() -> Weight {
+		(71_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
 }
 
 // For backwards compatibility and tests
@@ -130,4 +144,16 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(c as Weight)))
 	}
+	fn transfer_batch(c: u32, ) -> Weight {
+		(3_624_000 as Weight)
+			// Standard Error: 41_000
+			.saturating_add((64_113_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads((5 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((4 as Weight).saturating_mul(c as Weight)))
+	}
+	fn transfer_all() -> Weight {
+		(71_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
 }
@@ -0,0 +1,208 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Currency Filter Module
+//!
+//! ## Overview
+//!
+//! The governance-managed allow/deny lists of the accounts for the specific
+//! currencies. It implements `CurrencyOperationHook` to reject the transfers,
+//! deposits and withdrawals of the filtered currencies that involve a
+//! restricted account.
+//!
+//! - `AllowList`: only the listed accounts can hold and move the currency,
+//!   note that the module accounts (e.g. DEX, treasury) must be listed too.
+//! - `DenyList`: the listed accounts can not hold and move the currency.
+//!
+//! ## Limits
+//!
+//! Only the operations of `module_currencies` are filtered, including
+//! `repatriate_reserved`. The runtime should reject the transfer calls of
+//! `pallet_balances` while the native currency is filtered, see
+//! `is_filtered`. The filter can't cover:
+//!
+//! - the modules which use `pallet_balances` or `orml_tokens` directly
+//!   instead of `module_currencies`;
+//! - the native currency transferred as the value of EVM calls;
+//! - the `Erc20` currencies transferred inside the EVM, e.g. a direct call of
+//!   the contract.
+//!
+//! A slash can't be vetoed.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::{pallet_prelude::*, transactional};
+use frame_system::pallet_prelude::*;
+use primitives::CurrencyId;
+use sp_runtime::{DispatchResult, RuntimeDebug};
+use support::CurrencyOperationHook;
+
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+pub use weights::WeightInfo;
+
+/// The filter mode of a currency.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq)]
+pub enum FilterMode {
+	/// Only the listed accounts are allowed.
+	AllowList,
+	/// The listed accounts are denied.
+	DenyList,
+}
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The origin which may update the filters.
+		type UpdateOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The account is restricted for the currency
+		AccountRestricted,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(fn deposit_event)]
+	#[pallet::metadata(T::AccountId = "AccountId")]
+	pub enum Event<T: Config> {
+		/// The filter mode of the currency is updated. \[currency_id,
+		/// filter_mode\]
+		FilterModeUpdated(CurrencyId, Option<FilterMode>),
+		/// The account is added to or removed from the list of the currency.
+		/// \[currency_id, who, listed\]
+		ListedAccountUpdated(CurrencyId, T::AccountId, bool),
+	}
+
+	/// The filter mode of the currencies, the currency is not filtered if
+	/// not set.
+	///
+	/// FilterModes: map CurrencyId => Option<FilterMode>
+	#[pallet::storage]
+	#[pallet::getter(fn filter_modes)]
+	pub type FilterModes<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, FilterMode, OptionQuery>;
+
+	/// The listed accounts of the currencies.
+	///
+	/// ListedAccounts: double_map CurrencyId, AccountId => Option<()>
+	#[pallet::storage]
+	#[pallet::getter(fn listed_accounts)]
+	pub type ListedAccounts<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, CurrencyId, Twox64Concat, T::AccountId, (), OptionQuery>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set the filter mode of the currency, remove the filter if
+		/// `filter_mode` is `None`.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		#[pallet::weight(T::WeightInfo::set_filter_mode())]
+		#[transactional]
+		pub fn set_filter_mode(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			filter_mode: Option<FilterMode>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			FilterModes::<T>::set(currency_id, filter_mode);
+			Self::deposit_event(Event::FilterModeUpdated(currency_id, filter_mode));
+			Ok(())
+		}
+
+		/// Add the account to or remove it from the list of the currency.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		#[pallet::weight(T::WeightInfo::set_listed_account())]
+		#[transactional]
+		pub fn set_listed_account(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			who: T::AccountId,
+			listed: bool,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			if listed {
+				ListedAccounts::<T>::insert(currency_id, &who, ());
+			} else {
+				ListedAccounts::<T>::remove(currency_id, &who);
+			}
+			Self::deposit_event(Event::ListedAccountUpdated(currency_id, who, listed));
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Check whether `currency_id` is filtered.
+	pub fn is_filtered(currency_id: CurrencyId) -> bool {
+		FilterModes::<T>::contains_key(currency_id)
+	}
+
+	/// Check whether `who` is allowed to hold and move `currency_id`.
+	pub fn is_allowed(currency_id: CurrencyId, who: &T::AccountId) -> bool {
+		match Self::filter_modes(currency_id) {
+			Some(FilterMode::AllowList) => ListedAccounts::<T>::contains_key(currency_id, who),
+			Some(FilterMode::DenyList) => !ListedAccounts::<T>::contains_key(currency_id, who),
+			None => true,
+		}
+	}
+
+	fn ensure_allowed(currency_id: CurrencyId, who: &T::AccountId) -> DispatchResult {
+		ensure!(Self::is_allowed(currency_id, who), Error::<T>::AccountRestricted);
+		Ok(())
+	}
+}
+
+impl<T: Config, Balance> CurrencyOperationHook<T::AccountId, CurrencyId, Balance> for Pallet<T> {
+	fn pre_transfer(
+		currency_id: CurrencyId,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		_amount: Balance,
+	) -> DispatchResult {
+		Self::ensure_allowed(currency_id, from)?;
+		Self::ensure_allowed(currency_id, to)
+	}
+
+	fn pre_deposit(currency_id: CurrencyId, who: &T::AccountId, _amount: Balance) -> DispatchResult {
+		Self::ensure_allowed(currency_id, who)
+	}
+
+	fn pre_withdraw(currency_id: CurrencyId, who: &T::AccountId, _amount: Balance) -> DispatchResult {
+		Self::ensure_allowed(currency_id, who)
+	}
+}
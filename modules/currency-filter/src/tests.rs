@@ -0,0 +1,165 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the currency filter module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use mock::{Event, *};
+use sp_runtime::traits::BadOrigin;
+
+#[test]
+fn set_filter_mode_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			CurrencyFilter::set_filter_mode(Origin::signed(BOB), AUSD, Some(FilterMode::DenyList)),
+			BadOrigin
+		);
+
+		assert_eq!(CurrencyFilter::filter_modes(AUSD), None);
+		assert!(!CurrencyFilter::is_filtered(AUSD));
+		assert_ok!(CurrencyFilter::set_filter_mode(
+			Origin::signed(ALICE),
+			AUSD,
+			Some(FilterMode::DenyList)
+		));
+		System::assert_last_event(Event::CurrencyFilter(crate::Event::FilterModeUpdated(
+			AUSD,
+			Some(FilterMode::DenyList),
+		)));
+		assert_eq!(CurrencyFilter::filter_modes(AUSD), Some(FilterMode::DenyList));
+		assert!(CurrencyFilter::is_filtered(AUSD));
+
+		assert_ok!(CurrencyFilter::set_filter_mode(Origin::signed(ALICE), AUSD, None));
+		System::assert_last_event(Event::CurrencyFilter(crate::Event::FilterModeUpdated(AUSD, None)));
+		assert_eq!(CurrencyFilter::filter_modes(AUSD), None);
+	});
+}
+
+#[test]
+fn set_listed_account_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			CurrencyFilter::set_listed_account(Origin::signed(BOB), AUSD, BOB, true),
+			BadOrigin
+		);
+
+		assert_eq!(CurrencyFilter::listed_accounts(AUSD, BOB), None);
+		assert_ok!(CurrencyFilter::set_listed_account(
+			Origin::signed(ALICE),
+			AUSD,
+			BOB,
+			true
+		));
+		System::assert_last_event(Event::CurrencyFilter(crate::Event::ListedAccountUpdated(
+			AUSD, BOB, true,
+		)));
+		assert_eq!(CurrencyFilter::listed_accounts(AUSD, BOB), Some(()));
+
+		assert_ok!(CurrencyFilter::set_listed_account(
+			Origin::signed(ALICE),
+			AUSD,
+			BOB,
+			false
+		));
+		System::assert_last_event(Event::CurrencyFilter(crate::Event::ListedAccountUpdated(
+			AUSD, BOB, false,
+		)));
+		assert_eq!(CurrencyFilter::listed_accounts(AUSD, BOB), None);
+	});
+}
+
+#[test]
+fn deny_list_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(CurrencyFilter::set_listed_account(
+			Origin::signed(ALICE),
+			AUSD,
+			BOB,
+			true
+		));
+		// not filtered without the filter mode
+		assert_ok!(CurrencyFilter::pre_transfer(AUSD, &ALICE, &BOB, 10));
+
+		assert_ok!(CurrencyFilter::set_filter_mode(
+			Origin::signed(ALICE),
+			AUSD,
+			Some(FilterMode::DenyList)
+		));
+		assert!(!CurrencyFilter::is_allowed(AUSD, &BOB));
+		assert!(CurrencyFilter::is_allowed(AUSD, &CHARLIE));
+		assert!(CurrencyFilter::is_allowed(DOT, &BOB));
+
+		assert_noop!(
+			CurrencyFilter::pre_transfer(AUSD, &ALICE, &BOB, 10),
+			Error::<Runtime>::AccountRestricted
+		);
+		assert_noop!(
+			CurrencyFilter::pre_transfer(AUSD, &BOB, &ALICE, 10),
+			Error::<Runtime>::AccountRestricted
+		);
+		assert_noop!(
+			CurrencyFilter::pre_deposit(AUSD, &BOB, 10),
+			Error::<Runtime>::AccountRestricted
+		);
+		assert_noop!(
+			CurrencyFilter::pre_withdraw(AUSD, &BOB, 10),
+			Error::<Runtime>::AccountRestricted
+		);
+		assert_ok!(CurrencyFilter::pre_transfer(AUSD, &ALICE, &CHARLIE, 10));
+		assert_ok!(CurrencyFilter::pre_transfer(DOT, &ALICE, &BOB, 10));
+	});
+}
+
+#[test]
+fn allow_list_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(CurrencyFilter::set_filter_mode(
+			Origin::signed(ALICE),
+			AUSD,
+			Some(FilterMode::AllowList)
+		));
+		assert_ok!(CurrencyFilter::set_listed_account(
+			Origin::signed(ALICE),
+			AUSD,
+			ALICE,
+			true
+		));
+		assert_ok!(CurrencyFilter::set_listed_account(
+			Origin::signed(ALICE),
+			AUSD,
+			BOB,
+			true
+		));
+
+		assert_ok!(CurrencyFilter::pre_transfer(AUSD, &ALICE, &BOB, 10));
+		assert_ok!(CurrencyFilter::pre_deposit(AUSD, &BOB, 10));
+		assert_ok!(CurrencyFilter::pre_withdraw(AUSD, &BOB, 10));
+		assert_noop!(
+			CurrencyFilter::pre_transfer(AUSD, &ALICE, &CHARLIE, 10),
+			Error::<Runtime>::AccountRestricted
+		);
+		assert_noop!(
+			CurrencyFilter::pre_deposit(AUSD, &CHARLIE, 10),
+			Error::<Runtime>::AccountRestricted
+		);
+		assert_ok!(CurrencyFilter::pre_deposit(DOT, &CHARLIE, 10));
+	});
+}
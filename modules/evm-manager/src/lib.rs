@@ -22,12 +22,22 @@
 //!
 //! Evm manager module provides common support features for Evm, including:
 //! - A two way mapping between `u32` and `Erc20 address` so user can use Erc20 address as LP token.
+//! - A governance-managed registry to register, refresh the metadata of, and deregister the Erc20
+//!   mappings.
+//! - The metadata and `EvmAddress` of the foreign assets registered by `ForeignAssetIdMapping`.
+//!
+//! The `u32` of an Erc20 address is the first 4 non-zero bytes of it. If it has been taken by another
+//! Erc20 address, the next `u32` is tried until a free one is found, at most `MAX_CURRENCY_ID_PROBES`
+//! times. The `u32` which starts with 3 zero bytes is skipped, as it is reserved for tokens in
+//! `DexShare`. The `u32` of a deregistered Erc20 address is never assigned to another address, as
+//! the DEX shares and balances of it may still exist.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
 
-use frame_support::{ensure, pallet_prelude::*, require_transactional, traits::Currency};
-use module_support::{CurrencyIdMapping, EVMBridge, InvokeContext};
+use frame_support::{ensure, pallet_prelude::*, require_transactional, traits::Currency, transactional};
+use frame_system::pallet_prelude::*;
+use module_support::{CurrencyIdMapping, EVMBridge, ForeignAssetIdMapping, InvokeContext, StableAssetPoolInfo};
 use primitives::{
 	currency::TokenInfo,
 	evm::{Erc20Info, EvmAddress},
@@ -37,11 +47,17 @@ use sp_std::{
 	convert::{TryFrom, TryInto},
 	vec::Vec,
 };
+use xcm::latest::MultiLocation;
 
 mod mock;
 mod tests;
+pub mod weights;
 
 pub use module::*;
+pub use weights::WeightInfo;
+
+/// The max number of the `u32` tried to resolve the collision of an Erc20 address.
+pub const MAX_CURRENCY_ID_PROBES: u32 = 16;
 
 pub type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
@@ -51,8 +67,22 @@ pub mod module {
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 		type Currency: Currency<Self::AccountId>;
 		type EVMBridge: EVMBridge<Self::AccountId, BalanceOf<Self>>;
+
+		/// The foreign assets registered on-chain.
+		type ForeignAssetIdMapping: ForeignAssetIdMapping<ForeignAssetId, MultiLocation, AssetMetadata<BalanceOf<Self>>>;
+
+		/// The stable asset pools, which resolve the decimals of the pool tokens.
+		type StableAsset: StableAssetPoolInfo<CurrencyId>;
+
+		/// The origin which may register, refresh and deregister the Erc20
+		/// mappings.
+		type RegistryOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
 	}
 
 	/// Error for evm accounts module.
@@ -60,6 +90,20 @@ pub mod module {
 	pub enum Error<T> {
 		/// CurrencyId existed
 		CurrencyIdExisted,
+		/// The Erc20 address is not registered
+		Erc20NotRegistered,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The Erc20 address is registered. \[address, currency_id\]
+		Erc20Registered(EvmAddress, u32),
+		/// The metadata of the Erc20 address is refreshed. \[address,
+		/// currency_id\]
+		Erc20MetadataRefreshed(EvmAddress, u32),
+		/// The Erc20 address is deregistered. \[address, currency_id\]
+		Erc20Deregistered(EvmAddress, u32),
 	}
 
 	/// Mapping between u32 and Erc20 address.
@@ -71,6 +115,22 @@ pub mod module {
 	#[pallet::getter(fn currency_id_map)]
 	pub type CurrencyIdMap<T: Config> = StorageMap<_, Twox64Concat, u32, Erc20Info, OptionQuery>;
 
+	/// Mapping between Erc20 address and the u32 assigned to it.
+	/// The Erc20 addresses mapped before it's introduced are not included, whose
+	/// u32 is always taken from the address.
+	///
+	/// map EvmAddress => Option<u32>
+	#[pallet::storage]
+	#[pallet::getter(fn erc20_currency_ids)]
+	pub type Erc20CurrencyIds<T: Config> = StorageMap<_, Twox64Concat, EvmAddress, u32, OptionQuery>;
+
+	/// The u32 of the deregistered Erc20 addresses, which are kept for them.
+	///
+	/// map u32 => Option<EvmAddress>
+	#[pallet::storage]
+	#[pallet::getter(fn deregistered_currency_ids)]
+	pub type DeregisteredCurrencyIds<T: Config> = StorageMap<_, Twox64Concat, u32, EvmAddress, OptionQuery>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
@@ -78,42 +138,132 @@ pub mod module {
 	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
 
 	#[pallet::call]
-	impl<T: Config> Pallet<T> {}
+	impl<T: Config> Pallet<T> {
+		/// Register the mapping of the Erc20 address, read the metadata of it
+		/// by `EVMBridge`.
+		///
+		/// The dispatch origin of this call must be `RegistryOrigin`.
+		#[pallet::weight(T::WeightInfo::register_erc20())]
+		#[transactional]
+		pub fn register_erc20(origin: OriginFor<T>, address: EvmAddress) -> DispatchResult {
+			T::RegistryOrigin::ensure_origin(origin)?;
+			Self::do_register_erc20(address)?;
+			Ok(())
+		}
+
+		/// Re-read the name, symbol and decimals of the registered Erc20
+		/// address by `EVMBridge`.
+		///
+		/// The dispatch origin of this call must be `RegistryOrigin`.
+		#[pallet::weight(T::WeightInfo::refresh_erc20_metadata())]
+		#[transactional]
+		pub fn refresh_erc20_metadata(origin: OriginFor<T>, address: EvmAddress) -> DispatchResult {
+			T::RegistryOrigin::ensure_origin(origin)?;
+			let currency_id = Self::get_currency_id(address).ok_or(Error::<T>::Erc20NotRegistered)?;
+			CurrencyIdMap::<T>::insert(currency_id, Self::read_erc20_info(address)?);
+			Self::deposit_event(Event::Erc20MetadataRefreshed(address, currency_id));
+			Ok(())
+		}
+
+		/// Deregister the mapping of the Erc20 address.
+		///
+		/// Note the DEX share of the Erc20 address can't be encoded to or
+		/// decoded from `EvmAddress` after it's deregistered. The u32 is kept
+		/// for the Erc20 address, and is assigned to it again if it's
+		/// registered again.
+		///
+		/// The dispatch origin of this call must be `RegistryOrigin`.
+		#[pallet::weight(T::WeightInfo::deregister_erc20())]
+		#[transactional]
+		pub fn deregister_erc20(origin: OriginFor<T>, address: EvmAddress) -> DispatchResult {
+			T::RegistryOrigin::ensure_origin(origin)?;
+			let currency_id = Self::get_currency_id(address).ok_or(Error::<T>::Erc20NotRegistered)?;
+			CurrencyIdMap::<T>::remove(currency_id);
+			Erc20CurrencyIds::<T>::remove(address);
+			DeregisteredCurrencyIds::<T>::insert(currency_id, address);
+			Self::deposit_event(Event::Erc20Deregistered(address, currency_id));
+			Ok(())
+		}
+	}
 }
 
-impl<T: Config> Pallet<T> {}
+impl<T: Config> Pallet<T> {
+	/// The u32 assigned to the registered Erc20 address.
+	pub fn get_currency_id(address: EvmAddress) -> Option<u32> {
+		Erc20CurrencyIds::<T>::get(address).or_else(|| {
+			let currency_id = Into::<u32>::into(DexShare::Erc20(address));
+			CurrencyIdMap::<T>::get(currency_id)
+				.filter(|v| v.address == address)
+				.map(|_| currency_id)
+		})
+	}
+
+	/// The info of the registered Erc20 address.
+	pub fn get_erc20_info(address: EvmAddress) -> Option<Erc20Info> {
+		Self::get_currency_id(address).and_then(CurrencyIdMap::<T>::get)
+	}
+
+	/// All the registered Erc20 addresses and the u32 assigned to them.
+	pub fn registered_erc20s() -> Vec<(u32, Erc20Info)> {
+		CurrencyIdMap::<T>::iter().collect()
+	}
+
+	fn read_erc20_info(address: EvmAddress) -> Result<Erc20Info, DispatchError> {
+		let invoke_context = InvokeContext {
+			contract: address,
+			sender: Default::default(),
+			origin: Default::default(),
+		};
+
+		Ok(Erc20Info {
+			address,
+			name: T::EVMBridge::name(invoke_context)?,
+			symbol: T::EVMBridge::symbol(invoke_context)?,
+			decimals: T::EVMBridge::decimals(invoke_context)?,
+		})
+	}
+
+	/// Assign a free u32 to the Erc20 address, or return the u32 if it's
+	/// registered already.
+	#[require_transactional]
+	fn do_register_erc20(address: EvmAddress) -> Result<u32, DispatchError> {
+		if let Some(currency_id) = Self::get_currency_id(address) {
+			return Ok(currency_id);
+		}
+
+		let mut currency_id = Into::<u32>::into(DexShare::Erc20(address));
+		let mut probes = 0;
+		loop {
+			match DeregisteredCurrencyIds::<T>::get(currency_id) {
+				// registered again, take back the u32 kept for it
+				Some(deregistered) if deregistered == address => {
+					DeregisteredCurrencyIds::<T>::remove(currency_id);
+					break;
+				}
+				None if !CurrencyIdMap::<T>::contains_key(currency_id) && currency_id.leading_zeros() < 24 => break,
+				_ => {}
+			}
+			probes += 1;
+			ensure!(probes < MAX_CURRENCY_ID_PROBES, Error::<T>::CurrencyIdExisted);
+			currency_id = currency_id.wrapping_add(1);
+		}
+
+		CurrencyIdMap::<T>::insert(currency_id, Self::read_erc20_info(address)?);
+		Erc20CurrencyIds::<T>::insert(address, currency_id);
+		Self::deposit_event(Event::Erc20Registered(address, currency_id));
+		Ok(currency_id)
+	}
+}
 
 pub struct EvmCurrencyIdMapping<T>(sp_std::marker::PhantomData<T>);
 
 impl<T: Config> CurrencyIdMapping for EvmCurrencyIdMapping<T> {
 	// Use first 4 non-zero bytes as u32 to the mapping between u32 and evm address.
 	// Take the first 4 non-zero bytes, if it is less than 4, add 0 to the left.
+	// If the u32 has been taken by another address, resolve the collision by the next free u32.
 	#[require_transactional]
 	fn set_erc20_mapping(address: EvmAddress) -> DispatchResult {
-		CurrencyIdMap::<T>::mutate(
-			Into::<u32>::into(DexShare::Erc20(address)),
-			|maybe_erc20_info| -> DispatchResult {
-				if let Some(erc20_info) = maybe_erc20_info.as_mut() {
-					ensure!(erc20_info.address == address, Error::<T>::CurrencyIdExisted);
-				} else {
-					let invoke_context = InvokeContext {
-						contract: address,
-						sender: Default::default(),
-						origin: Default::default(),
-					};
-
-					let info = Erc20Info {
-						address,
-						name: T::EVMBridge::name(invoke_context)?,
-						symbol: T::EVMBridge::symbol(invoke_context)?,
-						decimals: T::EVMBridge::decimals(invoke_context)?,
-					};
-
-					*maybe_erc20_info = Some(info);
-				}
-				Ok(())
-			},
-		)
+		Pallet::<T>::do_register_erc20(address).map(|_| ())
 	}
 
 	// Returns the EvmAddress associated with a given u32.
@@ -130,15 +280,11 @@ impl<T: Config> CurrencyIdMapping for EvmCurrencyIdMapping<T> {
 			CurrencyId::DexShare(symbol_0, symbol_1) => {
 				let name_0 = match symbol_0 {
 					DexShare::Token(symbol) => CurrencyId::Token(symbol).name().map(|v| v.as_bytes().to_vec()),
-					DexShare::Erc20(address) => CurrencyIdMap::<T>::get(Into::<u32>::into(symbol_0))
-						.filter(|v| v.address == address)
-						.map(|v| v.name),
+					DexShare::Erc20(address) => Pallet::<T>::get_erc20_info(address).map(|v| v.name),
 				}?;
 				let name_1 = match symbol_1 {
 					DexShare::Token(symbol) => CurrencyId::Token(symbol).name().map(|v| v.as_bytes().to_vec()),
-					DexShare::Erc20(address) => CurrencyIdMap::<T>::get(Into::<u32>::into(symbol_1))
-						.filter(|v| v.address == address)
-						.map(|v| v.name),
+					DexShare::Erc20(address) => Pallet::<T>::get_erc20_info(address).map(|v| v.name),
 				}?;
 
 				let mut vec = Vec::new();
@@ -148,10 +294,11 @@ impl<T: Config> CurrencyIdMapping for EvmCurrencyIdMapping<T> {
 				vec.extend_from_slice(&name_1);
 				Some(vec)
 			}
-			CurrencyId::Erc20(address) => CurrencyIdMap::<T>::get(Into::<u32>::into(DexShare::Erc20(address)))
-				.filter(|v| v.address == address)
-				.map(|v| v.name),
+			CurrencyId::Erc20(address) => Pallet::<T>::get_erc20_info(address).map(|v| v.name),
 			CurrencyId::StableAssetPoolToken(_) => None,
+			CurrencyId::ForeignAsset(foreign_asset_id) => {
+				T::ForeignAssetIdMapping::get_asset_metadata(foreign_asset_id).map(|v| v.name)
+			}
 		}?;
 
 		// More than 32 bytes will be truncated.
@@ -171,15 +318,11 @@ impl<T: Config> CurrencyIdMapping for EvmCurrencyIdMapping<T> {
 			CurrencyId::DexShare(symbol_0, symbol_1) => {
 				let token_symbol_0 = match symbol_0 {
 					DexShare::Token(symbol) => CurrencyId::Token(symbol).symbol().map(|v| v.as_bytes().to_vec()),
-					DexShare::Erc20(address) => CurrencyIdMap::<T>::get(Into::<u32>::into(symbol_0))
-						.filter(|v| v.address == address)
-						.map(|v| v.symbol),
+					DexShare::Erc20(address) => Pallet::<T>::get_erc20_info(address).map(|v| v.symbol),
 				}?;
 				let token_symbol_1 = match symbol_1 {
 					DexShare::Token(symbol) => CurrencyId::Token(symbol).symbol().map(|v| v.as_bytes().to_vec()),
-					DexShare::Erc20(address) => CurrencyIdMap::<T>::get(Into::<u32>::into(symbol_1))
-						.filter(|v| v.address == address)
-						.map(|v| v.symbol),
+					DexShare::Erc20(address) => Pallet::<T>::get_erc20_info(address).map(|v| v.symbol),
 				}?;
 
 				let mut vec = Vec::new();
@@ -189,10 +332,11 @@ impl<T: Config> CurrencyIdMapping for EvmCurrencyIdMapping<T> {
 				vec.extend_from_slice(&token_symbol_1);
 				Some(vec)
 			}
-			CurrencyId::Erc20(address) => CurrencyIdMap::<T>::get(Into::<u32>::into(DexShare::Erc20(address)))
-				.filter(|v| v.address == address)
-				.map(|v| v.symbol),
+			CurrencyId::Erc20(address) => Pallet::<T>::get_erc20_info(address).map(|v| v.symbol),
 			CurrencyId::StableAssetPoolToken(_) => None,
+			CurrencyId::ForeignAsset(foreign_asset_id) => {
+				T::ForeignAssetIdMapping::get_asset_metadata(foreign_asset_id).map(|v| v.symbol)
+			}
 		}?;
 
 		// More than 32 bytes will be truncated.
@@ -214,15 +358,28 @@ impl<T: Config> CurrencyIdMapping for EvmCurrencyIdMapping<T> {
 				// use the decimals of currency_id_0 as the decimals of lp token.
 				match symbol_0 {
 					DexShare::Token(symbol) => CurrencyId::Token(symbol).decimals(),
-					DexShare::Erc20(address) => CurrencyIdMap::<T>::get(Into::<u32>::into(symbol_0))
-						.filter(|v| v.address == address)
-						.map(|v| v.decimals),
+					DexShare::Erc20(address) => Pallet::<T>::get_erc20_info(address).map(|v| v.decimals),
 				}
 			}
-			CurrencyId::Erc20(address) => CurrencyIdMap::<T>::get(Into::<u32>::into(DexShare::Erc20(address)))
-				.filter(|v| v.address == address)
-				.map(|v| v.decimals),
-			CurrencyId::StableAssetPoolToken(_) => None,
+			CurrencyId::Erc20(address) => Pallet::<T>::get_erc20_info(address).map(|v| v.decimals),
+			CurrencyId::StableAssetPoolToken(_) => {
+				// the pool token is minted in the normalized balances of the underlying currencies,
+				// the precision of an underlying currency must be a power of 10.
+				let (underlying_currency_id, mut precision) = T::StableAsset::get_pool_token_precision(currency_id)?;
+				let mut decimals = Self::decimals(underlying_currency_id)?;
+				while precision > 1 && precision % 10 == 0 {
+					precision /= 10;
+					decimals = decimals.checked_add(1)?;
+				}
+				if precision == 1 {
+					Some(decimals)
+				} else {
+					None
+				}
+			}
+			CurrencyId::ForeignAsset(foreign_asset_id) => {
+				T::ForeignAssetIdMapping::get_asset_metadata(foreign_asset_id).map(|v| v.decimals)
+			}
 		}
 	}
 
@@ -234,17 +391,11 @@ impl<T: Config> CurrencyIdMapping for EvmCurrencyIdMapping<T> {
 			CurrencyId::DexShare(left, right) => {
 				let symbol_0 = match left {
 					DexShare::Token(_) => Some(left.into()),
-					DexShare::Erc20(address) => {
-						let id: u32 = left.into();
-						CurrencyIdMap::<T>::get(id).filter(|v| v.address == address).map(|_| id)
-					}
+					DexShare::Erc20(address) => Pallet::<T>::get_currency_id(address),
 				}?;
 				let symbol_1 = match right {
 					DexShare::Token(_) => Some(right.into()),
-					DexShare::Erc20(address) => {
-						let id: u32 = right.into();
-						CurrencyIdMap::<T>::get(id).filter(|v| v.address == address).map(|_| id)
-					}
+					DexShare::Erc20(address) => Pallet::<T>::get_currency_id(address),
 				}?;
 
 				let mut prefix = EvmAddress::default();
@@ -252,6 +403,12 @@ impl<T: Config> CurrencyIdMapping for EvmCurrencyIdMapping<T> {
 				Some(prefix | EvmAddress::from_low_u64_be(u64::from(symbol_0) << 32 | u64::from(symbol_1)))
 			}
 
+			// ForeignAsset must have been registered
+			CurrencyId::ForeignAsset(foreign_asset_id) => {
+				T::ForeignAssetIdMapping::get_asset_metadata(foreign_asset_id)
+					.and_then(|_| EvmAddress::try_from(v).ok())
+			}
+
 			// Token or Erc20
 			_ => EvmAddress::try_from(v).ok(),
 		}
@@ -300,8 +457,14 @@ impl<T: Config> CurrencyIdMapping for EvmCurrencyIdMapping<T> {
 			return Some(CurrencyId::DexShare(left, right));
 		}
 
+		// ForeignAsset
+		if address.starts_with(&H160_PREFIX_FOREIGN_ASSET) {
+			let foreign_asset_id = ForeignAssetId::from_be_bytes(address[H160_POSITION_FOREIGN_ASSET].try_into().ok()?);
+			return T::ForeignAssetIdMapping::get_asset_metadata(foreign_asset_id)
+				.map(|_| CurrencyId::ForeignAsset(foreign_asset_id));
+		}
+
 		// Erc20
-		let id = Into::<u32>::into(DexShare::Erc20(addr));
-		CurrencyIdMap::<T>::get(id).map(|v| CurrencyId::Erc20(v.address))
+		Pallet::<T>::get_currency_id(addr).map(|_| CurrencyId::Erc20(addr))
 	}
 }
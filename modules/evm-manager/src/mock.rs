@@ -23,7 +23,7 @@
 use super::*;
 use frame_support::{assert_ok, construct_runtime, ord_parameter_types, parameter_types};
 use frame_system::EnsureSignedBy;
-use module_support::{mocks::MockAddressMapping, AddressMapping};
+use module_support::{mocks::MockAddressMapping, AddressMapping, Price, StableAssetPoolInfo};
 use orml_traits::parameter_type_with_key;
 use primitives::{Amount, Balance, CurrencyId, ReserveIdentifier, TokenSymbol};
 use sp_core::{bytes::from_hex, crypto::AccountId32, H160, H256};
@@ -166,9 +166,66 @@ impl module_evm_bridge::Config for Runtime {
 	type EVM = EVM;
 }
 
+pub const FOREIGN_ASSET_ID: ForeignAssetId = 0;
+
+pub struct MockForeignAssetIdMapping;
+impl ForeignAssetIdMapping<ForeignAssetId, MultiLocation, AssetMetadata<Balance>> for MockForeignAssetIdMapping {
+	fn get_asset_metadata(foreign_asset_id: ForeignAssetId) -> Option<AssetMetadata<Balance>> {
+		if foreign_asset_id == FOREIGN_ASSET_ID {
+			Some(AssetMetadata {
+				name: b"Foreign Asset".to_vec(),
+				symbol: b"FA".to_vec(),
+				decimals: 12,
+				minimal_balance: 1,
+			})
+		} else {
+			None
+		}
+	}
+
+	fn get_multi_location(foreign_asset_id: ForeignAssetId) -> Option<MultiLocation> {
+		if foreign_asset_id == FOREIGN_ASSET_ID {
+			Some(MultiLocation::parent())
+		} else {
+			None
+		}
+	}
+
+	fn get_currency_id(multi_location: MultiLocation) -> Option<CurrencyId> {
+		if multi_location == MultiLocation::parent() {
+			Some(CurrencyId::ForeignAsset(FOREIGN_ASSET_ID))
+		} else {
+			None
+		}
+	}
+}
+
+pub const STABLE_ASSET_POOL_TOKEN: CurrencyId = CurrencyId::StableAssetPoolToken(0);
+pub const STABLE_ASSET_POOL_TOKEN_INVALID_PRECISION: CurrencyId = CurrencyId::StableAssetPoolToken(1);
+
+pub struct MockStableAsset;
+impl StableAssetPoolInfo<CurrencyId> for MockStableAsset {
+	fn get_pool_info(_: CurrencyId) -> Option<(Vec<CurrencyId>, Price)> {
+		None
+	}
+
+	fn get_pool_token_precision(pool_token: CurrencyId) -> Option<(CurrencyId, u128)> {
+		match pool_token {
+			STABLE_ASSET_POOL_TOKEN => Some((CurrencyId::Token(TokenSymbol::DOT), 100_000_000)),
+			STABLE_ASSET_POOL_TOKEN_INVALID_PRECISION => Some((CurrencyId::Token(TokenSymbol::DOT), 5_000)),
+			_ => None,
+		}
+	}
+}
+
 impl Config for Runtime {
+	type Event = Event;
 	type Currency = Balances;
 	type EVMBridge = EVMBridge;
+	type ForeignAssetIdMapping = MockForeignAssetIdMapping;
+	type StableAsset = MockStableAsset;
+	type RegistryOrigin = EnsureSignedBy<CouncilAccount, AccountId32>;
+	type WeightInfo = ();
 }
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
@@ -198,7 +255,7 @@ construct_runtime!(
 	{
 		System: frame_system::{Pallet, Call, Storage, Config, Event<T>},
 		Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent},
-		EvmManager: evm_manager::{Pallet, Storage},
+		EvmManager: evm_manager::{Pallet, Call, Storage, Event<T>},
 		Tokens: orml_tokens::{Pallet, Storage, Event<T>, Config<T>},
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 		Currencies: orml_currencies::{Pallet, Call, Event<T>},
@@ -22,10 +22,14 @@
 
 use super::*;
 use frame_support::{assert_noop, assert_ok};
-use mock::{alice, deploy_contracts, erc20_address, erc20_address_not_exists, ExtBuilder, Runtime};
+use mock::{
+	alice, deploy_contracts, erc20_address, erc20_address_not_exists, CouncilAccount, Event, EvmManager, ExtBuilder,
+	Origin, Runtime, System, FOREIGN_ASSET_ID, STABLE_ASSET_POOL_TOKEN, STABLE_ASSET_POOL_TOKEN_INVALID_PRECISION,
+};
 use orml_utilities::with_transaction_result;
 use primitives::TokenSymbol;
 use sp_core::H160;
+use sp_runtime::traits::BadOrigin;
 use std::str::FromStr;
 
 #[test]
@@ -43,13 +47,14 @@ fn set_erc20_mapping_works() {
 				EvmCurrencyIdMapping::<Runtime>::set_erc20_mapping(erc20_address())
 			}));
 
+			// the colliding u32 is resolved, but they are not Erc20 contracts
 			assert_noop!(
 				with_transaction_result(|| -> DispatchResult {
 					EvmCurrencyIdMapping::<Runtime>::set_erc20_mapping(
 						EvmAddress::from_str("0000000000000000000000000000000200000000").unwrap(),
 					)
 				}),
-				Error::<Runtime>::CurrencyIdExisted,
+				module_evm_bridge::Error::<Runtime>::InvalidReturnValue,
 			);
 
 			assert_noop!(
@@ -58,7 +63,7 @@ fn set_erc20_mapping_works() {
 						EvmAddress::from_str("0000000000000000000000000000000200000001").unwrap(),
 					)
 				}),
-				Error::<Runtime>::CurrencyIdExisted,
+				module_evm_bridge::Error::<Runtime>::InvalidReturnValue,
 			);
 
 			assert_noop!(
@@ -70,6 +75,219 @@ fn set_erc20_mapping_works() {
 		});
 }
 
+#[test]
+fn register_erc20_works() {
+	ExtBuilder::default()
+		.balances(vec![(alice(), 1_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			deploy_contracts();
+			assert_noop!(
+				EvmManager::register_erc20(Origin::signed(alice()), erc20_address()),
+				BadOrigin
+			);
+
+			assert_ok!(EvmManager::register_erc20(
+				Origin::signed(CouncilAccount::get()),
+				erc20_address()
+			));
+			System::assert_last_event(Event::EvmManager(crate::Event::Erc20Registered(
+				erc20_address(),
+				0x02000000,
+			)));
+			assert_eq!(EvmManager::erc20_currency_ids(erc20_address()), Some(0x02000000));
+			let registered = EvmManager::registered_erc20s();
+			assert_eq!(registered.len(), 1);
+			assert_eq!(registered[0].0, 0x02000000);
+			assert_eq!(registered[0].1.address, erc20_address());
+			assert_eq!(registered[0].1.symbol, b"TestToken".to_vec());
+			assert_eq!(registered[0].1.decimals, 17);
+
+			// register again returns the assigned u32
+			assert_ok!(EvmManager::register_erc20(
+				Origin::signed(CouncilAccount::get()),
+				erc20_address()
+			));
+			assert_eq!(EvmManager::registered_erc20s().len(), 1);
+
+			assert_noop!(
+				EvmManager::register_erc20(Origin::signed(CouncilAccount::get()), erc20_address_not_exists()),
+				module_evm_bridge::Error::<Runtime>::InvalidReturnValue
+			);
+		});
+}
+
+#[test]
+fn register_erc20_resolves_collision() {
+	ExtBuilder::default()
+		.balances(vec![(alice(), 1_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			deploy_contracts();
+			let other = Erc20Info {
+				address: EvmAddress::from_str("0000000000000000000000000000000200000000").unwrap(),
+				name: b"Other".to_vec(),
+				symbol: b"OTHER".to_vec(),
+				decimals: 18,
+			};
+			CurrencyIdMap::<Runtime>::insert(0x02000000, other.clone());
+			CurrencyIdMap::<Runtime>::insert(0x02000001, other.clone());
+
+			assert_ok!(EvmManager::register_erc20(
+				Origin::signed(CouncilAccount::get()),
+				erc20_address()
+			));
+			assert_eq!(EvmManager::get_currency_id(erc20_address()), Some(0x02000002));
+			assert_eq!(
+				EvmCurrencyIdMapping::<Runtime>::get_evm_address(0x02000002),
+				Some(erc20_address())
+			);
+			assert_eq!(
+				EvmCurrencyIdMapping::<Runtime>::decode_evm_address(
+					EvmCurrencyIdMapping::<Runtime>::encode_evm_address(CurrencyId::Erc20(erc20_address())).unwrap()
+				),
+				Some(CurrencyId::Erc20(erc20_address()))
+			);
+		});
+}
+
+#[test]
+fn register_erc20_fails_when_probes_exhausted() {
+	ExtBuilder::default()
+		.balances(vec![(alice(), 1_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			deploy_contracts();
+			let other = Erc20Info {
+				address: EvmAddress::from_str("0000000000000000000000000000000200000000").unwrap(),
+				name: b"Other".to_vec(),
+				symbol: b"OTHER".to_vec(),
+				decimals: 18,
+			};
+			for i in 0..MAX_CURRENCY_ID_PROBES {
+				CurrencyIdMap::<Runtime>::insert(0x02000000 + i, other.clone());
+			}
+
+			assert_noop!(
+				EvmManager::register_erc20(Origin::signed(CouncilAccount::get()), erc20_address()),
+				Error::<Runtime>::CurrencyIdExisted
+			);
+		});
+}
+
+#[test]
+fn refresh_erc20_metadata_works() {
+	ExtBuilder::default()
+		.balances(vec![(alice(), 1_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			deploy_contracts();
+			assert_noop!(
+				EvmManager::refresh_erc20_metadata(Origin::signed(CouncilAccount::get()), erc20_address()),
+				Error::<Runtime>::Erc20NotRegistered
+			);
+
+			assert_ok!(EvmManager::register_erc20(
+				Origin::signed(CouncilAccount::get()),
+				erc20_address()
+			));
+			CurrencyIdMap::<Runtime>::mutate(0x02000000, |maybe_info| {
+				if let Some(info) = maybe_info {
+					info.decimals = 0;
+				}
+			});
+
+			assert_noop!(
+				EvmManager::refresh_erc20_metadata(Origin::signed(alice()), erc20_address()),
+				BadOrigin
+			);
+			assert_ok!(EvmManager::refresh_erc20_metadata(
+				Origin::signed(CouncilAccount::get()),
+				erc20_address()
+			));
+			System::assert_last_event(Event::EvmManager(crate::Event::Erc20MetadataRefreshed(
+				erc20_address(),
+				0x02000000,
+			)));
+			assert_eq!(
+				EvmCurrencyIdMapping::<Runtime>::decimals(CurrencyId::Erc20(erc20_address())),
+				Some(17)
+			);
+		});
+}
+
+#[test]
+fn deregister_erc20_works() {
+	ExtBuilder::default()
+		.balances(vec![(alice(), 1_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			deploy_contracts();
+			assert_noop!(
+				EvmManager::deregister_erc20(Origin::signed(CouncilAccount::get()), erc20_address()),
+				Error::<Runtime>::Erc20NotRegistered
+			);
+
+			assert_ok!(EvmManager::register_erc20(
+				Origin::signed(CouncilAccount::get()),
+				erc20_address()
+			));
+			assert_noop!(
+				EvmManager::deregister_erc20(Origin::signed(alice()), erc20_address()),
+				BadOrigin
+			);
+			assert_ok!(EvmManager::deregister_erc20(
+				Origin::signed(CouncilAccount::get()),
+				erc20_address()
+			));
+			System::assert_last_event(Event::EvmManager(crate::Event::Erc20Deregistered(
+				erc20_address(),
+				0x02000000,
+			)));
+			assert_eq!(EvmManager::get_currency_id(erc20_address()), None);
+			assert_eq!(EvmManager::registered_erc20s(), vec![]);
+			assert_eq!(
+				EvmCurrencyIdMapping::<Runtime>::decode_evm_address(
+					EvmCurrencyIdMapping::<Runtime>::encode_evm_address(CurrencyId::Erc20(erc20_address())).unwrap()
+				),
+				None
+			);
+			assert_eq!(EvmManager::deregistered_currency_ids(0x02000000), Some(erc20_address()));
+
+			// the u32 is assigned to the Erc20 address again
+			assert_ok!(EvmManager::register_erc20(
+				Origin::signed(CouncilAccount::get()),
+				erc20_address()
+			));
+			assert_eq!(EvmManager::get_currency_id(erc20_address()), Some(0x02000000));
+			assert_eq!(EvmManager::deregistered_currency_ids(0x02000000), None);
+		});
+}
+
+#[test]
+fn register_erc20_skips_deregistered_currency_id() {
+	ExtBuilder::default()
+		.balances(vec![(alice(), 1_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			deploy_contracts();
+			DeregisteredCurrencyIds::<Runtime>::insert(
+				0x02000000,
+				EvmAddress::from_str("0000000000000000000000000000000200000000").unwrap(),
+			);
+
+			assert_ok!(EvmManager::register_erc20(
+				Origin::signed(CouncilAccount::get()),
+				erc20_address()
+			));
+			assert_eq!(EvmManager::get_currency_id(erc20_address()), Some(0x02000001));
+			assert_eq!(
+				EvmManager::deregistered_currency_ids(0x02000000),
+				Some(EvmAddress::from_str("0000000000000000000000000000000200000000").unwrap())
+			);
+		});
+}
+
 #[test]
 fn get_evm_address_works() {
 	ExtBuilder::default()
@@ -261,6 +479,20 @@ fn decimals_works() {
 				)),
 				Some(17)
 			);
+
+			// the decimals of DOT scaled by the precision of the pool
+			assert_eq!(
+				EvmCurrencyIdMapping::<Runtime>::decimals(STABLE_ASSET_POOL_TOKEN),
+				Some(18)
+			);
+			assert_eq!(
+				EvmCurrencyIdMapping::<Runtime>::decimals(STABLE_ASSET_POOL_TOKEN_INVALID_PRECISION),
+				None
+			);
+			assert_eq!(
+				EvmCurrencyIdMapping::<Runtime>::decimals(CurrencyId::StableAssetPoolToken(2)),
+				None
+			);
 		});
 }
 
@@ -456,3 +688,46 @@ fn decode_evm_address_works() {
 			);
 		});
 }
+
+#[test]
+fn foreign_asset_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let foreign_asset = CurrencyId::ForeignAsset(FOREIGN_ASSET_ID);
+		let foreign_asset_not_exists = CurrencyId::ForeignAsset(1);
+
+		assert_eq!(
+			EvmCurrencyIdMapping::<Runtime>::name(foreign_asset),
+			Some(b"Foreign Asset".to_vec())
+		);
+		assert_eq!(
+			EvmCurrencyIdMapping::<Runtime>::symbol(foreign_asset),
+			Some(b"FA".to_vec())
+		);
+		assert_eq!(EvmCurrencyIdMapping::<Runtime>::decimals(foreign_asset), Some(12));
+		assert_eq!(EvmCurrencyIdMapping::<Runtime>::name(foreign_asset_not_exists), None);
+		assert_eq!(
+			EvmCurrencyIdMapping::<Runtime>::decimals(foreign_asset_not_exists),
+			None
+		);
+
+		let address = H160::from_str("0x0000000000000000000000000000000100000000").unwrap();
+		assert_eq!(
+			EvmCurrencyIdMapping::<Runtime>::encode_evm_address(foreign_asset),
+			Some(address)
+		);
+		assert_eq!(
+			EvmCurrencyIdMapping::<Runtime>::encode_evm_address(foreign_asset_not_exists),
+			None
+		);
+		assert_eq!(
+			EvmCurrencyIdMapping::<Runtime>::decode_evm_address(address),
+			Some(foreign_asset)
+		);
+		assert_eq!(
+			EvmCurrencyIdMapping::<Runtime>::decode_evm_address(
+				H160::from_str("0x0000000000000000000000000000000100000001").unwrap()
+			),
+			None
+		);
+	});
+}
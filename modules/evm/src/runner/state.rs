@@ -30,8 +30,8 @@ use frame_support::log;
 use primitive_types::{H160, H256, U256};
 pub use primitives::{
 	evm::{Account, EvmAddress, Log, Vicinity},
-	ReserveIdentifier, H160_PREFIX_DEXSHARE, H160_PREFIX_TOKEN, MIRRORED_NFT_ADDRESS_START, PREDEPLOY_ADDRESS_START,
-	SYSTEM_CONTRACT_ADDRESS_PREFIX,
+	ReserveIdentifier, H160_PREFIX_DEXSHARE, H160_PREFIX_FOREIGN_ASSET, H160_PREFIX_TOKEN, MIRRORED_NFT_ADDRESS_START,
+	PREDEPLOY_ADDRESS_START, SYSTEM_CONTRACT_ADDRESS_PREFIX,
 };
 use sha3::{Digest, Keccak256};
 use sp_std::{rc::Rc, vec::Vec};
@@ -427,7 +427,10 @@ impl<'config, S: StackState<'config>> StackExecutor<'config, S> {
 			return address;
 		}
 
-		if addr.starts_with(&H160_PREFIX_TOKEN) || addr.starts_with(&H160_PREFIX_DEXSHARE) {
+		if addr.starts_with(&H160_PREFIX_TOKEN)
+			|| addr.starts_with(&H160_PREFIX_DEXSHARE)
+			|| addr.starts_with(&H160_PREFIX_FOREIGN_ASSET)
+		{
 			// `Token` predeploy contract.
 			let token_address = H160::from_low_u64_be(PREDEPLOY_ADDRESS_START);
 			log::debug!(
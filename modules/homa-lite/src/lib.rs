@@ -33,8 +33,8 @@ use orml_traits::{
 };
 use primitives::{Balance, CurrencyId};
 use sp_runtime::{
-	traits::{BlockNumberProvider, Bounded, Saturating, Zero},
-	ArithmeticError, FixedPointNumber, Permill,
+	traits::{BlockNumberProvider, Bounded, Convert, Saturating, Zero},
+	ArithmeticError, DispatchError, FixedPointNumber, Permill,
 };
 use sp_std::{
 	cmp::{min, Ordering},
@@ -43,6 +43,10 @@ use sp_std::{
 	prelude::*,
 };
 use xcm::latest::prelude::*;
+use xcm_executor::{
+	traits::{Convert as XcmConvert, TransactAsset},
+	Assets,
+};
 
 pub use module::*;
 pub use weights::WeightInfo;
@@ -138,6 +142,10 @@ pub mod module {
 		/// Maximum number of scheduled unbonds allowed
 		#[pallet::constant]
 		type MaxScheduledUnbonds: Get<u32>;
+
+		/// The maximum number of the mints for XCM deposits processed in a block.
+		#[pallet::constant]
+		type MaxXcmDepositMintsPerBlock: Get<u32>;
 	}
 
 	#[pallet::error]
@@ -200,6 +208,10 @@ pub mod module {
 		/// The scheduled Unbond has been withdrew from the Relaychain.
 		///\[staking_amount_added\]
 		ScheduledUnbondWithdrew(Balance),
+
+		/// The mint for the Staking currency deposited via XCM has failed, the Staking currency
+		/// is kept in the account. \[who, staking_amount, error\]
+		XcmDepositMintFailed(T::AccountId, Balance, DispatchError),
 	}
 
 	/// The total amount of the staking currency on the relaychain.
@@ -240,11 +252,34 @@ pub mod module {
 	pub type ScheduledUnbond<T: Config> =
 		StorageValue<_, BoundedVec<(Balance, RelaychainBlockNumberOf<T>), T::MaxScheduledUnbonds>, ValueQuery>;
 
+	/// The Staking currency deposited via XCM that is waiting to be minted in `on_initialize`.
+	/// PendingXcmDepositMints: map AccountId => Balance
+	#[pallet::storage]
+	#[pallet::getter(fn pending_xcm_deposit_mints)]
+	pub type PendingXcmDepositMints<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, Balance, ValueQuery>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		fn on_initialize(_n: T::BlockNumber) -> Weight {
+			let pending_mints: Vec<(T::AccountId, Balance)> = PendingXcmDepositMints::<T>::iter()
+				.take(T::MaxXcmDepositMintsPerBlock::get() as usize)
+				.collect();
+			for (minter, amount) in pending_mints.iter() {
+				PendingXcmDepositMints::<T>::remove(minter);
+				if let Err(err) = Self::mint_for_xcm_deposit(minter, *amount) {
+					Self::deposit_event(Event::<T>::XcmDepositMintFailed(minter.clone(), *amount, err));
+				}
+			}
+
+			<T as Config>::WeightInfo::mint()
+				.saturating_add(T::DbWeight::get().writes(1))
+				.saturating_mul(pending_mints.len() as Weight)
+				.saturating_add(T::DbWeight::get().reads(1))
+		}
+
 		fn on_idle(_n: T::BlockNumber, remaining_weight: Weight) -> Weight {
 			let required_weight = <T as Config>::WeightInfo::on_idle();
 			let mut current_weight = 0;
@@ -768,6 +803,18 @@ pub mod module {
 			Ok(())
 		}
 
+		/// Mint some Liquid currency for the Staking currency that was deposited into the
+		/// minter's account via XCM, called in `on_initialize`. All changes are reverted if the
+		/// mint fails, leaving the deposited Staking currency in the minter's account.
+		///
+		/// Parameters:
+		/// - `minter`: The AccountId the Staking currency is deposited to.
+		/// - `amount`: The amount of Staking currency to be exchanged.
+		#[transactional]
+		pub fn mint_for_xcm_deposit(minter: &T::AccountId, amount: Balance) -> DispatchResult {
+			Self::do_mint_with_requests(minter, amount, vec![])
+		}
+
 		#[transactional]
 		fn process_scheduled_unbond(staking_amount: Balance) -> DispatchResult {
 			let msg = Self::construct_xcm_unreserve_message(T::ParachainAccount::get(), staking_amount);
@@ -868,3 +915,80 @@ pub mod module {
 		}
 	}
 }
+
+/// Asset transactor that mints Liquid currency for Staking currency deposited via XCM into the
+/// Homa-lite sub-account of an account, i.e. a beneficiary of the form
+/// `X2(AccountId32 { .. }, GeneralIndex(T::SubAccountIndex))`. This allows users to mint from
+/// the relaychain in one step with a reserve transfer.
+///
+/// The Staking currency is deposited into the account with `Transactor`, and the mint is queued
+/// to be processed in `on_initialize` of the next block, so its weight isn't charged to the XCM
+/// message. If the mint fails, the Staking currency is kept in the account and
+/// `XcmDepositMintFailed` is emitted. All other deposits and asset operations are handled by
+/// `Transactor`.
+pub struct MintFromXcmDeposit<T, AccountIdConvert, CurrencyIdConvert, Transactor>(
+	sp_std::marker::PhantomData<(T, AccountIdConvert, CurrencyIdConvert, Transactor)>,
+);
+
+impl<T, AccountIdConvert, CurrencyIdConvert, Transactor>
+	MintFromXcmDeposit<T, AccountIdConvert, CurrencyIdConvert, Transactor>
+where
+	T: Config,
+{
+	/// Returns the location of the minter if `who` is the Homa-lite sub-account of an account.
+	fn minter_location(who: &MultiLocation) -> Option<MultiLocation> {
+		match who {
+			MultiLocation {
+				parents: 0,
+				interior: X2(account @ AccountId32 { .. }, GeneralIndex(index)),
+			} if *index == T::SubAccountIndex::get() as u128 => Some(MultiLocation::new(0, X1(account.clone()))),
+			_ => None,
+		}
+	}
+}
+
+impl<T, AccountIdConvert, CurrencyIdConvert, Transactor> TransactAsset
+	for MintFromXcmDeposit<T, AccountIdConvert, CurrencyIdConvert, Transactor>
+where
+	T: Config,
+	AccountIdConvert: XcmConvert<MultiLocation, T::AccountId>,
+	CurrencyIdConvert: Convert<MultiAsset, Option<CurrencyId>>,
+	Transactor: TransactAsset,
+{
+	fn can_check_in(origin: &MultiLocation, what: &MultiAsset) -> XcmResult {
+		Transactor::can_check_in(origin, what)
+	}
+
+	fn check_in(origin: &MultiLocation, what: &MultiAsset) {
+		Transactor::check_in(origin, what)
+	}
+
+	fn check_out(dest: &MultiLocation, what: &MultiAsset) {
+		Transactor::check_out(dest, what)
+	}
+
+	fn deposit_asset(what: &MultiAsset, who: &MultiLocation) -> XcmResult {
+		match (Self::minter_location(who), &what.fun) {
+			(Some(minter_location), Fungible(amount))
+				if CurrencyIdConvert::convert(what.clone()) == Some(T::StakingCurrencyId::get()) =>
+			{
+				let minter = AccountIdConvert::convert_ref(&minter_location)
+					.map_err(|_| XcmError::FailedToTransactAsset("AccountIdConversionFailed"))?;
+
+				Transactor::deposit_asset(what, &minter_location)?;
+				PendingXcmDepositMints::<T>::mutate(&minter, |pending| *pending = pending.saturating_add(*amount));
+
+				Ok(())
+			}
+			_ => Transactor::deposit_asset(what, who),
+		}
+	}
+
+	fn withdraw_asset(what: &MultiAsset, who: &MultiLocation) -> Result<Assets, XcmError> {
+		Transactor::withdraw_asset(what, who)
+	}
+
+	fn transfer_asset(asset: &MultiAsset, from: &MultiLocation, to: &MultiLocation) -> Result<Assets, XcmError> {
+		Transactor::transfer_asset(asset, from, to)
+	}
+}
@@ -152,6 +152,59 @@ impl WeightBounds<Call> for MockWeigher {
 	}
 }
 
+pub struct MockAccountIdConvert;
+impl XcmConvert<MultiLocation, AccountId> for MockAccountIdConvert {
+	fn convert(location: MultiLocation) -> Result<AccountId, MultiLocation> {
+		match location {
+			MultiLocation {
+				parents: 0,
+				interior: X1(Junction::AccountId32 { id, .. }),
+			} => Ok(id.into()),
+			_ => Err(location),
+		}
+	}
+
+	fn reverse(who: AccountId) -> Result<MultiLocation, AccountId> {
+		Ok(X1(Junction::AccountId32 {
+			network: NetworkId::Any,
+			id: who.into(),
+		})
+		.into())
+	}
+}
+
+/// Only KSM from the relaychain is recognised.
+pub struct MockCurrencyIdConvert;
+impl Convert<MultiAsset, Option<CurrencyId>> for MockCurrencyIdConvert {
+	fn convert(asset: MultiAsset) -> Option<CurrencyId> {
+		match asset {
+			MultiAsset {
+				id: Concrete(location),
+				fun: Fungible(_),
+			} if location == MultiLocation::parent() => Some(KSM),
+			_ => None,
+		}
+	}
+}
+
+/// A mock asset transactor that deposits the currency into the local account.
+pub struct MockAssetTransactor;
+impl TransactAsset for MockAssetTransactor {
+	fn deposit_asset(what: &MultiAsset, who: &MultiLocation) -> XcmResult {
+		let currency_id = MockCurrencyIdConvert::convert(what.clone()).ok_or(XcmError::AssetNotFound)?;
+		let who = MockAccountIdConvert::convert_ref(who)
+			.map_err(|_| XcmError::FailedToTransactAsset("AccountIdConversionFailed"))?;
+		match what.fun {
+			Fungible(amount) => Currencies::deposit(currency_id, &who, amount)
+				.map_err(|_| XcmError::FailedToTransactAsset("DepositFailed")),
+			_ => Err(XcmError::AssetNotFound),
+		}
+	}
+}
+
+pub type HomaLiteAssetTransactor =
+	MintFromXcmDeposit<Runtime, MockAccountIdConvert, MockCurrencyIdConvert, MockAssetTransactor>;
+
 impl pallet_xcm::Config for Runtime {
 	type Event = Event;
 	type SendXcmOrigin = MockEnsureXcmOrigin;
@@ -241,6 +294,7 @@ impl module_currencies::Config for Runtime {
 	type EVMBridge = ();
 	type SweepOrigin = EnsureSignedBy<Root, AccountId>;
 	type OnDust = ();
+	type CurrencyHook = ();
 }
 
 parameter_types! {
@@ -260,6 +314,7 @@ parameter_types! {
 	pub static MockRelayBlockNumberProvider: u64 = 0;
 	pub const RelaychainUnbondingSlashingSpans: u32 = 5;
 	pub const MaxScheduledUnbonds: u32 = 14;
+	pub const MaxXcmDepositMintsPerBlock: u32 = 2;
 	pub const SubAccountIndex: u16 = 0;
 	pub ParachainId: ParaId = ParaId::from(PARACHAIN_ID);
 }
@@ -298,6 +353,7 @@ impl Config for Runtime {
 	type MaximumRedeemRequestMatchesForMint = MaximumRedeemRequestMatchesForMint;
 	type RelaychainUnbondingSlashingSpans = RelaychainUnbondingSlashingSpans;
 	type MaxScheduledUnbonds = MaxScheduledUnbonds;
+	type MaxXcmDepositMintsPerBlock = MaxXcmDepositMintsPerBlock;
 }
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
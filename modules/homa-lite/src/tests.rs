@@ -23,8 +23,8 @@
 use super::*;
 use frame_support::{assert_noop, assert_ok};
 use mock::{
-	dollar, Currencies, Event, ExtBuilder, HomaLite, MockRelayBlockNumberProvider, Origin, Runtime, System, ACALA,
-	ALICE, BOB, CHARLIE, INITIAL_BALANCE, INVALID_CALLER, KSM, LKSM, ROOT,
+	dollar, AccountId, Currencies, Event, ExtBuilder, HomaLite, HomaLiteAssetTransactor, MockRelayBlockNumberProvider,
+	Origin, Runtime, System, ACALA, ALICE, BOB, CHARLIE, INITIAL_BALANCE, INVALID_CALLER, KSM, LKSM, ROOT,
 };
 use sp_runtime::traits::BadOrigin;
 
@@ -799,3 +799,129 @@ fn staking_and_liquid_conversion_works() {
 		assert_eq!(HomaLite::convert_liquid_to_staking(5_000_000), Ok(1_000_000));
 	});
 }
+
+fn homa_lite_sub_account_of(who: AccountId, index: u128) -> MultiLocation {
+	MultiLocation::new(
+		0,
+		X2(
+			Junction::AccountId32 {
+				network: NetworkId::Any,
+				id: who.into(),
+			},
+			GeneralIndex(index),
+		),
+	)
+}
+
+#[test]
+fn xcm_deposit_to_sub_account_mints_liquid_currency() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(HomaLite::set_minting_cap(Origin::root(), 5 * dollar(INITIAL_BALANCE)));
+		let amount = dollar(1000);
+
+		assert_ok!(HomaLiteAssetTransactor::deposit_asset(
+			&(MultiLocation::parent(), amount).into(),
+			&homa_lite_sub_account_of(CHARLIE, 0),
+		));
+		assert_eq!(Currencies::free_balance(KSM, &CHARLIE), amount);
+		assert_eq!(HomaLite::pending_xcm_deposit_mints(CHARLIE), amount);
+
+		// The mint is processed in the next block.
+		HomaLite::on_initialize(1);
+		assert_eq!(HomaLite::pending_xcm_deposit_mints(CHARLIE), 0);
+
+		// liquid = (amount - MintFee) * 10 * (1 - MaxRewardPerEra)
+		//        = 0.99 * (1000 - 0.01)  * 10 = 9899.901
+		let liquid = 9_899_901_000_000_000;
+		assert_eq!(Currencies::free_balance(KSM, &CHARLIE), 0);
+		assert_eq!(Currencies::free_balance(LKSM, &CHARLIE), liquid);
+		assert_eq!(TotalStakingCurrency::<Runtime>::get(), amount);
+		System::assert_last_event(Event::HomaLite(crate::Event::Minted(CHARLIE, amount, liquid)));
+	});
+}
+
+#[test]
+fn xcm_deposit_keeps_staking_currency_if_mint_fails() {
+	ExtBuilder::default().build().execute_with(|| {
+		// The mint cap is not set, so minting fails.
+		assert_ok!(HomaLiteAssetTransactor::deposit_asset(
+			&(MultiLocation::parent(), dollar(1000)).into(),
+			&homa_lite_sub_account_of(CHARLIE, 0),
+		));
+		HomaLite::on_initialize(1);
+		System::assert_last_event(Event::HomaLite(crate::Event::XcmDepositMintFailed(
+			CHARLIE,
+			dollar(1000),
+			Error::<Runtime>::ExceededStakingCurrencyMintCap.into(),
+		)));
+		assert_eq!(HomaLite::pending_xcm_deposit_mints(CHARLIE), 0);
+
+		assert_eq!(Currencies::free_balance(KSM, &CHARLIE), dollar(1000));
+		assert_eq!(Currencies::free_balance(LKSM, &CHARLIE), 0);
+		assert_eq!(TotalStakingCurrency::<Runtime>::get(), 0);
+	});
+}
+
+#[test]
+fn xcm_deposit_to_other_locations_is_not_minted() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(HomaLite::set_minting_cap(Origin::root(), 5 * dollar(INITIAL_BALANCE)));
+
+		// Deposits to the account itself are not minted.
+		assert_ok!(HomaLiteAssetTransactor::deposit_asset(
+			&(MultiLocation::parent(), dollar(1000)).into(),
+			&MultiLocation::new(
+				0,
+				X1(Junction::AccountId32 {
+					network: NetworkId::Any,
+					id: CHARLIE.into(),
+				})
+			),
+		));
+		assert_eq!(HomaLite::pending_xcm_deposit_mints(CHARLIE), 0);
+		HomaLite::on_initialize(1);
+		assert_eq!(Currencies::free_balance(KSM, &CHARLIE), dollar(1000));
+		assert_eq!(Currencies::free_balance(LKSM, &CHARLIE), 0);
+
+		// Other sub-accounts are not recognised.
+		assert_noop!(
+			HomaLiteAssetTransactor::deposit_asset(
+				&(MultiLocation::parent(), dollar(1000)).into(),
+				&homa_lite_sub_account_of(CHARLIE, 1),
+			),
+			XcmError::FailedToTransactAsset("AccountIdConversionFailed")
+		);
+
+		// Only the Staking currency is minted.
+		assert_noop!(
+			HomaLiteAssetTransactor::deposit_asset(
+				&(MultiLocation::here(), dollar(1000)).into(),
+				&homa_lite_sub_account_of(CHARLIE, 0),
+			),
+			XcmError::AssetNotFound
+		);
+		assert_eq!(TotalStakingCurrency::<Runtime>::get(), 0);
+	});
+}
+
+#[test]
+fn xcm_deposit_mints_are_limited_per_block() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(HomaLite::set_minting_cap(Origin::root(), 5 * dollar(INITIAL_BALANCE)));
+
+		for who in [ALICE, BOB, CHARLIE].iter() {
+			assert_ok!(HomaLiteAssetTransactor::deposit_asset(
+				&(MultiLocation::parent(), dollar(1000)).into(),
+				&homa_lite_sub_account_of(who.clone(), 0),
+			));
+		}
+		assert_eq!(PendingXcmDepositMints::<Runtime>::iter().count(), 3);
+
+		// MaxXcmDepositMintsPerBlock = 2
+		HomaLite::on_initialize(1);
+		assert_eq!(PendingXcmDepositMints::<Runtime>::iter().count(), 1);
+		HomaLite::on_initialize(2);
+		assert_eq!(PendingXcmDepositMints::<Runtime>::iter().count(), 0);
+		assert_eq!(TotalStakingCurrency::<Runtime>::get(), dollar(3000));
+	});
+}
@@ -23,6 +23,11 @@
 //! This will require validators to lock some Liquid Token into insurance fund
 //! and if slash happened, HomaCouncil can burn those Liquid Token to compensate
 //! Liquid Token holders.
+//!
+//! Slashes can also be reported by a trusted origin (e.g. the relay chain via
+//! XCM `Transact`). Reported slashes are applied once per era and validator,
+//! and validators slashed more than `SlashFreezeThreshold` are frozen
+//! automatically.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
@@ -32,7 +37,7 @@ use codec::MaxEncodedLen;
 use frame_support::{pallet_prelude::*, traits::Contains, transactional};
 use frame_system::pallet_prelude::*;
 use orml_traits::{BasicCurrency, BasicLockableCurrency, Happened, LockIdentifier};
-use primitives::Balance;
+use primitives::{Balance, EraIndex};
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
 use sp_runtime::{
@@ -40,7 +45,7 @@ use sp_runtime::{
 	DispatchResult, FixedPointNumber, RuntimeDebug,
 };
 use sp_std::{fmt::Debug, vec::Vec};
-use support::{ExchangeRateProvider, Ratio};
+use support::{ExchangeRateProvider, HomaValidatorInsurance, Ratio};
 
 mod mock;
 mod tests;
@@ -57,6 +62,7 @@ pub trait WeightInfo {
 	fn freeze(u: u32) -> Weight;
 	fn thaw() -> Weight;
 	fn slash() -> Weight;
+	fn report_slashes(n: u32) -> Weight;
 }
 
 // TODO: do benchmarking test.
@@ -82,6 +88,9 @@ impl WeightInfo for () {
 	fn slash() -> Weight {
 		10_000
 	}
+	fn report_slashes(n: u32) -> Weight {
+		10_000 + 10_000 * n as Weight
+	}
 }
 
 /// Insurance for a validator from a single address
@@ -151,6 +160,15 @@ pub struct SlashInfo<Balance, RelaychainAccountId> {
 	relaychain_token_amount: Balance,
 }
 
+impl<Balance, RelaychainAccountId> SlashInfo<Balance, RelaychainAccountId> {
+	pub fn new(validator: RelaychainAccountId, relaychain_token_amount: Balance) -> Self {
+		Self {
+			validator,
+			relaychain_token_amount,
+		}
+	}
+}
+
 /// Validator insurance and frozen status
 #[derive(Encode, Decode, Clone, Copy, RuntimeDebug, Default, MaxEncodedLen)]
 pub struct ValidatorBacking {
@@ -184,6 +202,17 @@ pub mod module {
 		type FreezeOrigin: EnsureOrigin<Self::Origin>;
 		/// The AccountId that can perform a slash.
 		type SlashOrigin: EnsureOrigin<Self::Origin>;
+		/// The origin which is trusted to report relay chain slashes, e.g. the
+		/// relay chain itself through XCM.
+		type SlashReportOrigin: EnsureOrigin<Self::Origin>;
+		#[pallet::constant]
+		/// The reported slash amount of relay chain tokens above which the
+		/// validator will be frozen automatically.
+		type SlashFreezeThreshold: Get<Balance>;
+		#[pallet::constant]
+		/// The number of eras the reported slashes are kept for. Reports for
+		/// eras older than this are rejected.
+		type ReportedSlashesEraDepth: Get<EraIndex>;
 		/// Callback to be called when a slash occurs.
 		type OnSlash: Happened<Balance>;
 		/// Exchange rate between staked token and liquid token equivalent.
@@ -203,6 +232,7 @@ pub mod module {
 		BelowMinBondAmount,
 		UnbondingExists,
 		FrozenValidator,
+		SlashReportEraTooOld,
 	}
 
 	#[pallet::event]
@@ -215,6 +245,7 @@ pub mod module {
 		UnbondGuarantee(T::AccountId, T::RelaychainAccountId, Balance),
 		WithdrawnGuarantee(T::AccountId, T::RelaychainAccountId, Balance),
 		SlashGuarantee(T::AccountId, T::RelaychainAccountId, Balance),
+		SlashReported(EraIndex, T::RelaychainAccountId, Balance),
 	}
 
 	/// The slash guarantee deposits for relaychain validators.
@@ -247,6 +278,21 @@ pub mod module {
 	pub type ValidatorBackings<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::RelaychainAccountId, ValidatorBacking, OptionQuery>;
 
+	/// The relay chain slashes that have already been reported and applied.
+	///
+	/// ReportedSlashes: double_map EraIndex, RelaychainAccountId => Option<Balance>
+	#[pallet::storage]
+	#[pallet::getter(fn reported_slashes)]
+	pub type ReportedSlashes<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, EraIndex, Blake2_128Concat, T::RelaychainAccountId, Balance, OptionQuery>;
+
+	/// The latest era for which slashes have been reported.
+	///
+	/// LatestReportedEra: EraIndex
+	#[pallet::storage]
+	#[pallet::getter(fn latest_reported_era)]
+	pub type LatestReportedEra<T: Config> = StorageValue<_, EraIndex, ValueQuery>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
@@ -384,16 +430,7 @@ pub mod module {
 		#[transactional]
 		pub fn freeze(origin: OriginFor<T>, validators: Vec<T::RelaychainAccountId>) -> DispatchResult {
 			T::FreezeOrigin::ensure_origin(origin)?;
-			validators.iter().for_each(|validator| {
-				ValidatorBackings::<T>::mutate_exists(validator, |maybe_validator| {
-					let mut v = maybe_validator.take().unwrap_or_default();
-					if !v.is_frozen {
-						v.is_frozen = true;
-						Self::deposit_event(Event::FreezeValidator(validator.clone()));
-					}
-					*maybe_validator = Some(v);
-				});
-			});
+			validators.iter().for_each(Self::do_freeze);
 			Ok(())
 		}
 
@@ -428,48 +465,124 @@ pub mod module {
 		#[transactional]
 		pub fn slash(origin: OriginFor<T>, slashes: Vec<SlashInfo<Balance, T::RelaychainAccountId>>) -> DispatchResult {
 			T::SlashOrigin::ensure_origin(origin)?;
-			let liquid_staking_exchange_rate = T::LiquidStakingExchangeRateProvider::get_exchange_rate();
-			let staking_liquid_exchange_rate = liquid_staking_exchange_rate.reciprocal().unwrap_or_default();
-			let mut actual_total_slashing: Balance = Zero::zero();
-
-			for SlashInfo {
-				validator,
-				relaychain_token_amount,
-			} in slashes
-			{
-				let ValidatorBacking { total_insurance, .. } = Self::validator_backings(&validator).unwrap_or_default();
-				let insurance_loss = staking_liquid_exchange_rate
-					.saturating_mul_int(relaychain_token_amount)
-					.min(total_insurance);
-
-				for (guarantor, _) in Guarantees::<T>::iter_prefix(&validator) {
-					// NOTE: ignoring result because the closure will not throw err.
-					let res = Self::update_guarantee(&guarantor, &validator, |guarantee| -> DispatchResult {
-						let should_slashing = Ratio::checked_from_rational(guarantee.total, total_insurance)
-							.unwrap_or_else(Ratio::max_value)
-							.saturating_mul_int(insurance_loss);
-						let gap = T::LiquidTokenCurrency::slash(&guarantor, should_slashing);
-						let actual_slashing = should_slashing.saturating_sub(gap);
-						*guarantee = guarantee.slash(actual_slashing);
-						Self::deposit_event(Event::SlashGuarantee(
-							guarantor.clone(),
-							validator.clone(),
-							actual_slashing,
-						));
-						actual_total_slashing = actual_total_slashing.saturating_add(actual_slashing);
-						Ok(())
-					});
-					debug_assert!(res.is_ok());
+			Self::do_slash(slashes);
+			Ok(())
+		}
+
+		/// Report slashes of validators that happened on the relay chain.
+		/// Ensures the caller is trusted to report slashes.
+		///
+		/// Each validator is slashed at most once per era, the slash is
+		/// distributed to its guarantors proportionally to their guarantee, and
+		/// validators slashed more than `SlashFreezeThreshold` are frozen.
+		/// Only the reports of the latest `ReportedSlashesEraDepth` eras are
+		/// kept, reports for older eras are rejected.
+		///
+		/// - `era`: The relay chain era in which the slashes happened
+		/// - `slashes`: The SlashInfos of the validators to be slashed
+		#[pallet::weight(T::WeightInfo::report_slashes(slashes.len() as u32))]
+		#[transactional]
+		pub fn report_slashes(
+			origin: OriginFor<T>,
+			era: EraIndex,
+			slashes: Vec<SlashInfo<Balance, T::RelaychainAccountId>>,
+		) -> DispatchResult {
+			T::SlashReportOrigin::ensure_origin(origin)?;
+
+			let depth = T::ReportedSlashesEraDepth::get();
+			let latest_era = Self::latest_reported_era();
+			ensure!(era.saturating_add(depth) > latest_era, Error::<T>::SlashReportEraTooOld);
+			if era > latest_era {
+				// prune the eras that fall out of the window
+				let prune_start = latest_era.saturating_add(1).saturating_sub(depth);
+				let prune_end = era
+					.saturating_add(1)
+					.saturating_sub(depth)
+					.min(latest_era.saturating_add(1));
+				for old_era in prune_start..prune_end {
+					ReportedSlashes::<T>::remove_prefix(old_era, None);
+				}
+				LatestReportedEra::<T>::put(era);
+			}
+
+			// Check and record each slash in turn, so a validator listed more than once in the
+			// same report is only slashed once.
+			let mut new_slashes = Vec::new();
+			for slash in slashes {
+				let SlashInfo {
+					validator,
+					relaychain_token_amount,
+				} = &slash;
+				if ReportedSlashes::<T>::contains_key(era, validator) {
+					continue;
+				}
+
+				ReportedSlashes::<T>::insert(era, validator, relaychain_token_amount);
+				Self::deposit_event(Event::SlashReported(era, validator.clone(), *relaychain_token_amount));
+
+				if *relaychain_token_amount > T::SlashFreezeThreshold::get() {
+					Self::do_freeze(validator);
 				}
+				new_slashes.push(slash);
 			}
 
-			T::OnSlash::happened(&actual_total_slashing);
+			Self::do_slash(new_slashes);
 			Ok(())
 		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
+	fn do_freeze(validator: &T::RelaychainAccountId) {
+		ValidatorBackings::<T>::mutate_exists(validator, |maybe_validator| {
+			let mut v = maybe_validator.take().unwrap_or_default();
+			if !v.is_frozen {
+				v.is_frozen = true;
+				Self::deposit_event(Event::FreezeValidator(validator.clone()));
+			}
+			*maybe_validator = Some(v);
+		});
+	}
+
+	fn do_slash(slashes: Vec<SlashInfo<Balance, T::RelaychainAccountId>>) {
+		let liquid_staking_exchange_rate = T::LiquidStakingExchangeRateProvider::get_exchange_rate();
+		let staking_liquid_exchange_rate = liquid_staking_exchange_rate.reciprocal().unwrap_or_default();
+		let mut actual_total_slashing: Balance = Zero::zero();
+
+		for SlashInfo {
+			validator,
+			relaychain_token_amount,
+		} in slashes
+		{
+			let ValidatorBacking { total_insurance, .. } = Self::validator_backings(&validator).unwrap_or_default();
+			let insurance_loss = staking_liquid_exchange_rate
+				.saturating_mul_int(relaychain_token_amount)
+				.min(total_insurance);
+
+			for (guarantor, _) in Guarantees::<T>::iter_prefix(&validator) {
+				// NOTE: ignoring result because the closure will not throw err.
+				let res = Self::update_guarantee(&guarantor, &validator, |guarantee| -> DispatchResult {
+					let should_slashing = Ratio::checked_from_rational(guarantee.total, total_insurance)
+						.unwrap_or_else(Ratio::max_value)
+						.saturating_mul_int(insurance_loss);
+					let gap = T::LiquidTokenCurrency::slash(&guarantor, should_slashing);
+					let actual_slashing = should_slashing.saturating_sub(gap);
+					*guarantee = guarantee.slash(actual_slashing);
+					Self::deposit_event(Event::SlashGuarantee(
+						guarantor.clone(),
+						validator.clone(),
+						actual_slashing,
+					));
+					actual_total_slashing = actual_total_slashing.saturating_add(actual_slashing);
+					Ok(())
+				});
+				debug_assert!(res.is_ok());
+			}
+		}
+
+		T::OnSlash::happened(&actual_total_slashing);
+	}
+
 	fn update_guarantee(
 		guarantor: &T::AccountId,
 		validator: &T::RelaychainAccountId,
@@ -535,9 +648,17 @@ impl<T: Config> Pallet<T> {
 
 impl<T: Config> Contains<T::RelaychainAccountId> for Pallet<T> {
 	fn contains(relaychain_account_id: &T::RelaychainAccountId) -> bool {
-		Self::validator_backings(relaychain_account_id)
-			.unwrap_or_default()
-			.total_insurance
-			>= T::ValidatorInsuranceThreshold::get()
+		let validator_backing = Self::validator_backings(relaychain_account_id).unwrap_or_default();
+		!validator_backing.is_frozen && validator_backing.total_insurance >= T::ValidatorInsuranceThreshold::get()
+	}
+}
+
+impl<T: Config> HomaValidatorInsurance<T::RelaychainAccountId, Balance> for Pallet<T> {
+	fn total_insurance(validator: &T::RelaychainAccountId) -> Balance {
+		Self::validator_backings(validator).unwrap_or_default().total_insurance
+	}
+
+	fn is_frozen(validator: &T::RelaychainAccountId) -> bool {
+		Self::validator_backings(validator).unwrap_or_default().is_frozen
 	}
 }
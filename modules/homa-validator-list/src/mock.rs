@@ -24,7 +24,7 @@ use super::*;
 use frame_support::{construct_runtime, ord_parameter_types, parameter_types};
 use frame_system::EnsureSignedBy;
 use orml_traits::parameter_type_with_key;
-use primitives::{Amount, Balance, CurrencyId, TokenSymbol};
+use primitives::{Amount, Balance, CurrencyId, EraIndex, TokenSymbol};
 use sp_core::H256;
 use sp_runtime::{testing::Header, traits::IdentityLookup};
 use sp_std::cell::RefCell;
@@ -187,6 +187,8 @@ parameter_types! {
 	pub const MinBondAmount: Balance = 100;
 	pub const BondingDuration: BlockNumber = 100;
 	pub const ValidatorInsuranceThreshold: Balance = 200;
+	pub const SlashFreezeThreshold: Balance = 60;
+	pub const ReportedSlashesEraDepth: EraIndex = 4;
 	pub static MockBlockNumberProvider: u64 = 0;
 }
 
@@ -200,6 +202,7 @@ impl BlockNumberProvider for MockBlockNumberProvider {
 
 ord_parameter_types! {
 	pub const Admin: AccountId = 10;
+	pub const SlashReporter: AccountId = 11;
 }
 
 impl Config for Runtime {
@@ -211,6 +214,9 @@ impl Config for Runtime {
 	type ValidatorInsuranceThreshold = ValidatorInsuranceThreshold;
 	type FreezeOrigin = EnsureSignedBy<Admin, AccountId>;
 	type SlashOrigin = EnsureSignedBy<Admin, AccountId>;
+	type SlashReportOrigin = EnsureSignedBy<SlashReporter, AccountId>;
+	type SlashFreezeThreshold = SlashFreezeThreshold;
+	type ReportedSlashesEraDepth = ReportedSlashesEraDepth;
 	type OnSlash = MockOnSlash;
 	type LiquidStakingExchangeRateProvider = MockLiquidStakingExchangeProvider;
 	type WeightInfo = ();
@@ -760,6 +760,200 @@ fn slash_work() {
 	});
 }
 
+#[test]
+fn report_slashes_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(HomaValidatorListModule::bond(Origin::signed(ALICE), VALIDATOR_1, 100));
+		assert_ok!(HomaValidatorListModule::bond(Origin::signed(BOB), VALIDATOR_1, 200));
+		assert_ok!(HomaValidatorListModule::bond(Origin::signed(BOB), VALIDATOR_2, 300));
+
+		assert_noop!(
+			HomaValidatorListModule::report_slashes(
+				Origin::signed(10),
+				1,
+				vec![SlashInfo {
+					validator: VALIDATOR_1,
+					relaychain_token_amount: 90
+				}]
+			),
+			BadOrigin
+		);
+
+		assert_ok!(HomaValidatorListModule::report_slashes(
+			Origin::signed(11),
+			1,
+			vec![
+				SlashInfo {
+					validator: VALIDATOR_1,
+					relaychain_token_amount: 90
+				},
+				SlashInfo {
+					validator: VALIDATOR_2,
+					relaychain_token_amount: 50
+				},
+			]
+		));
+		System::assert_has_event(mock::Event::HomaValidatorListModule(crate::Event::SlashReported(
+			1,
+			VALIDATOR_1,
+			90,
+		)));
+		System::assert_has_event(mock::Event::HomaValidatorListModule(crate::Event::SlashReported(
+			1,
+			VALIDATOR_2,
+			50,
+		)));
+		System::assert_has_event(mock::Event::HomaValidatorListModule(crate::Event::SlashGuarantee(
+			ALICE,
+			VALIDATOR_1,
+			59,
+		)));
+		System::assert_has_event(mock::Event::HomaValidatorListModule(crate::Event::SlashGuarantee(
+			BOB,
+			VALIDATOR_1,
+			119,
+		)));
+		System::assert_has_event(mock::Event::HomaValidatorListModule(crate::Event::SlashGuarantee(
+			BOB,
+			VALIDATOR_2,
+			100,
+		)));
+		System::assert_has_event(mock::Event::HomaValidatorListModule(crate::Event::FreezeValidator(
+			VALIDATOR_1,
+		)));
+		assert_eq!(HomaValidatorListModule::reported_slashes(1, VALIDATOR_1), Some(90));
+		assert_eq!(HomaValidatorListModule::reported_slashes(1, VALIDATOR_2), Some(50));
+		assert!(
+			HomaValidatorListModule::validator_backings(VALIDATOR_1)
+				.unwrap_or_default()
+				.is_frozen
+		);
+		assert!(
+			!HomaValidatorListModule::validator_backings(VALIDATOR_2)
+				.unwrap_or_default()
+				.is_frozen
+		);
+		assert_eq!(
+			HomaValidatorListModule::validator_backings(VALIDATOR_1)
+				.unwrap_or_default()
+				.total_insurance,
+			122
+		);
+		assert_eq!(ACCUMULATED_SLASH.with(|v| *v.borrow()), 278);
+
+		// the same slash reported twice will not be applied again
+		assert_ok!(HomaValidatorListModule::report_slashes(
+			Origin::signed(11),
+			1,
+			vec![SlashInfo {
+				validator: VALIDATOR_1,
+				relaychain_token_amount: 90
+			}]
+		));
+		assert_eq!(
+			HomaValidatorListModule::validator_backings(VALIDATOR_1)
+				.unwrap_or_default()
+				.total_insurance,
+			122
+		);
+		assert_eq!(ACCUMULATED_SLASH.with(|v| *v.borrow()), 278);
+
+		// the same validator listed twice in one report is only slashed once
+		assert_ok!(HomaValidatorListModule::report_slashes(
+			Origin::signed(11),
+			3,
+			vec![
+				SlashInfo {
+					validator: VALIDATOR_2,
+					relaychain_token_amount: 10
+				},
+				SlashInfo {
+					validator: VALIDATOR_2,
+					relaychain_token_amount: 10
+				},
+			]
+		));
+		assert_eq!(HomaValidatorListModule::reported_slashes(3, VALIDATOR_2), Some(10));
+		assert_eq!(
+			HomaValidatorListModule::validator_backings(VALIDATOR_2)
+				.unwrap_or_default()
+				.total_insurance,
+			180
+		);
+
+		// slash of a later era will be applied
+		assert_ok!(HomaValidatorListModule::report_slashes(
+			Origin::signed(11),
+			2,
+			vec![SlashInfo {
+				validator: VALIDATOR_1,
+				relaychain_token_amount: 10
+			}]
+		));
+		assert_eq!(
+			HomaValidatorListModule::validator_backings(VALIDATOR_1)
+				.unwrap_or_default()
+				.total_insurance,
+			103
+		);
+	});
+}
+
+#[test]
+fn report_slashes_prune_old_eras() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(HomaValidatorListModule::bond(Origin::signed(ALICE), VALIDATOR_1, 100));
+
+		assert_ok!(HomaValidatorListModule::report_slashes(
+			Origin::signed(11),
+			1,
+			vec![SlashInfo {
+				validator: VALIDATOR_1,
+				relaychain_token_amount: 1
+			}]
+		));
+		assert_ok!(HomaValidatorListModule::report_slashes(
+			Origin::signed(11),
+			2,
+			vec![SlashInfo {
+				validator: VALIDATOR_1,
+				relaychain_token_amount: 1
+			}]
+		));
+		assert_eq!(HomaValidatorListModule::latest_reported_era(), 2);
+		assert_eq!(HomaValidatorListModule::reported_slashes(1, VALIDATOR_1), Some(1));
+		assert_eq!(HomaValidatorListModule::reported_slashes(2, VALIDATOR_1), Some(1));
+
+		// era 1 falls out of the window of the latest 4 eras
+		assert_ok!(HomaValidatorListModule::report_slashes(Origin::signed(11), 5, vec![]));
+		assert_eq!(HomaValidatorListModule::latest_reported_era(), 5);
+		assert_eq!(HomaValidatorListModule::reported_slashes(1, VALIDATOR_1), None);
+		assert_eq!(HomaValidatorListModule::reported_slashes(2, VALIDATOR_1), Some(1));
+
+		// reports for pruned eras are rejected so they can't be applied twice
+		assert_noop!(
+			HomaValidatorListModule::report_slashes(
+				Origin::signed(11),
+				1,
+				vec![SlashInfo {
+					validator: VALIDATOR_1,
+					relaychain_token_amount: 1
+				}]
+			),
+			Error::<Runtime>::SlashReportEraTooOld
+		);
+
+		// jumping ahead prunes every era that is out of the window
+		assert_ok!(HomaValidatorListModule::report_slashes(Origin::signed(11), 100, vec![]));
+		assert_eq!(HomaValidatorListModule::latest_reported_era(), 100);
+		assert_eq!(HomaValidatorListModule::reported_slashes(2, VALIDATOR_1), None);
+	});
+}
+
 #[test]
 fn contains_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -782,5 +976,8 @@ fn contains_work() {
 			200
 		);
 		assert!(HomaValidatorListModule::contains(&VALIDATOR_1));
+
+		assert_ok!(HomaValidatorListModule::freeze(Origin::signed(10), vec![VALIDATOR_1]));
+		assert!(!HomaValidatorListModule::contains(&VALIDATOR_1));
 	});
 }
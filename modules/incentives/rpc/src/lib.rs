@@ -0,0 +1,124 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! RPC interface for the incentives module.
+
+use codec::Codec;
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use module_incentives_rpc_runtime_api::{PendingRewardsInfo, RewardProgramInfo};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{
+	generic::BlockId,
+	traits::{Block as BlockT, MaybeDisplay, MaybeFromStr},
+};
+use std::sync::Arc;
+
+pub use self::gen_client::Client as IncentivesClient;
+pub use module_incentives_rpc_runtime_api::IncentivesApi as IncentivesRuntimeApi;
+
+#[rpc]
+pub trait IncentivesApi<BlockHash, AccountId, PendingRewardsResponseType, RewardProgramsResponseType> {
+	#[rpc(name = "incentives_getPendingRewards")]
+	fn get_pending_rewards(&self, account: AccountId, at: Option<BlockHash>) -> Result<PendingRewardsResponseType>;
+
+	#[rpc(name = "incentives_getActiveRewardPrograms")]
+	fn get_active_reward_programs(&self, at: Option<BlockHash>) -> Result<RewardProgramsResponseType>;
+}
+
+/// A struct that implements the [`IncentivesApi`].
+pub struct Incentives<C, B> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> Incentives<C, B> {
+	/// Create new `Incentives` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Incentives {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+pub enum Error {
+	RuntimeError,
+}
+
+impl From<Error> for i64 {
+	fn from(e: Error) -> i64 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+impl<C, Block, AccountId, PoolId, CurrencyId, BlockNumber, Balance>
+	IncentivesApi<
+		<Block as BlockT>::Hash,
+		AccountId,
+		Vec<PendingRewardsInfo<PoolId, CurrencyId, Balance>>,
+		Vec<RewardProgramInfo<PoolId, CurrencyId, BlockNumber, Balance>>,
+	> for Incentives<C, Block>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: IncentivesRuntimeApi<Block, AccountId, PoolId, CurrencyId, BlockNumber, Balance>,
+	AccountId: Codec,
+	PoolId: Codec,
+	CurrencyId: Codec,
+	BlockNumber: Codec,
+	Balance: Codec + MaybeDisplay + MaybeFromStr,
+{
+	fn get_pending_rewards(
+		&self,
+		account: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Vec<PendingRewardsInfo<PoolId, CurrencyId, Balance>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or(
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash,
+		));
+
+		api.pending_rewards(&at, account).map_err(|e| RpcError {
+			code: ErrorCode::ServerError(Error::RuntimeError.into()),
+			message: "Unable to get pending rewards.".into(),
+			data: Some(format!("{:?}", e).into()),
+		})
+	}
+
+	fn get_active_reward_programs(
+		&self,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Vec<RewardProgramInfo<PoolId, CurrencyId, BlockNumber, Balance>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or(
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash,
+		));
+
+		api.active_reward_programs(&at).map_err(|e| RpcError {
+			code: ErrorCode::ServerError(Error::RuntimeError.into()),
+			message: "Unable to get active reward programs.".into(),
+			data: Some(format!("{:?}", e).into()),
+		})
+	}
+}
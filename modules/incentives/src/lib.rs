@@ -29,6 +29,12 @@
 //! Pool types:
 //! 1. Loans: record the shares and rewards for users of Loans(Honzon protocol).
 //! 2. Dex: record the shares and rewards for DEX makers who staking LP token.
+//! 3. HomaValidatorAllowance: record the shares and rewards for guarantors of a relaychain
+//! validator in Homa validator list.
+//! 4. HomaIncentive: record the shares and rewards for Homa liquid currency holders, the shares
+//! follow the balance of liquid currency and are updated by the `OnBalanceChanged` hook.
+//! 5. StableAsset: record the shares and rewards for StableAsset LP providers, the shares follow
+//! the balance of LP token and are updated by the `OnBalanceChanged` hook.
 //!
 //! Rewards accumulation:
 //! 1. Incentives: periodicly(AccumulatePeriod), accumulate fixed amount according to Incentive.
@@ -37,22 +43,59 @@
 //! 2. DexSaving: periodicly(AccumulatePeriod), the reward currency is Stable(KUSD/AUSD),
 //! the accumulation amount is the multiplier of DexSavingRewardRates and the stable amount of
 //! corresponding liquidity pool. CDPTreasury will issue the stable currency to RewardsSource.
+//! 3. HomaValidatorAllowance: periodicly(AccumulatePeriod), the accumulation amount is the
+//! multiplier of HomaValidatorAllowanceRewardRates and the total insurance of the validator,
+//! frozen validators don't accumulate. Rewards come from RewardsSource.
+//!
+//! Reward programs:
+//! Governance can schedule reward programs for a pool with explicit start block, end block and
+//! total budget of a reward currency. The budget is transferred from RewardsSource when the
+//! program is added, spreaded evenly over the accumulation periods between start and end, and the
+//! program is removed once the budget is exhausted or it ends, the remaining budget is returned
+//! to RewardsSource. The programs of a pool without shares don't accumulate, but still end in time
+//! and return the remaining budget. At most MaxRewardProgramsPerPool programs can run concurrently
+//! for a pool.
+//!
+//! Claim and restake:
+//! DEX makers can claim the rewards of Pool::Dex and restake them in one call. The rewards are
+//! swapped into the two underlying tokens of the LP token, added as liquidity and the LP token
+//! increment is deposited back to the same pool.
+//!
+//! Time-locked boosts:
+//! Users can lock LP token for a chosen duration to boost their shares of Pool::Dex, or lock native
+//! token to boost their shares of all Pool::Loans. The longer the duration, the higher the shares
+//! multiplier, up to MaxBoostMultiplier at MaxBoostDuration. The boost is removed when the lock
+//! expires, and unlocking before expiry will be charged a penalty which goes to RewardsSource.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
 #![allow(clippy::upper_case_acronyms)]
 
-use frame_support::{log, pallet_prelude::*, transactional, PalletId};
+use frame_support::{
+	log,
+	pallet_prelude::*,
+	traits::{Contains, StorageVersion},
+	transactional, PalletId,
+};
 use frame_system::pallet_prelude::*;
 use orml_traits::{Happened, MultiCurrency, RewardHandler};
 use primitives::{Amount, Balance, CurrencyId};
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 use sp_runtime::{
-	traits::{AccountIdConversion, One, UniqueSaturatedInto, Zero},
-	DispatchResult, FixedPointNumber, RuntimeDebug,
+	helpers_128bit::multiply_by_rational,
+	traits::{
+		AccountIdConversion, MaybeDisplay, MaybeSerializeDeserialize, Member, One, Saturating, UniqueSaturatedInto,
+		Zero,
+	},
+	ArithmeticError, DispatchResult, FixedPointNumber, RuntimeDebug,
+};
+use sp_std::{collections::btree_map::BTreeMap, fmt::Debug, prelude::*};
+use support::{
+	CDPTreasury, DEXIncentives, DEXManager, EmergencyShutdown, HomaValidatorInsurance, PriceProvider, Rate, Ratio,
 };
-use sp_std::{collections::btree_map::BTreeMap, prelude::*};
-use support::{CDPTreasury, DEXIncentives, DEXManager, EmergencyShutdown, Rate};
 
+pub mod migrations;
 mod mock;
 mod tests;
 pub mod weights;
@@ -60,14 +103,73 @@ pub mod weights;
 pub use module::*;
 pub use weights::WeightInfo;
 
+/// The current storage version.
+const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 /// PoolId for various rewards pools
-#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
-pub enum PoolId {
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum PoolId<RelaychainAccountId> {
 	/// Rewards and shares pool for users who open CDP(CollateralCurrencyId)
 	Loans(CurrencyId),
 
 	/// Rewards and shares pool for DEX makers who stake LP token(LPCurrencyId)
 	Dex(CurrencyId),
+
+	/// Rewards and shares pool for guarantors of the relaychain
+	/// validator(RelaychainAccountId)
+	HomaValidatorAllowance(RelaychainAccountId),
+
+	/// Rewards and shares pool for Homa liquid currency holders who stake
+	/// liquid currency(LDOT/LKSM)
+	HomaIncentive,
+
+	/// Rewards and shares pool for StableAsset LP providers who stake LP
+	/// token(StableAssetPoolTokenCurrencyId)
+	StableAsset(CurrencyId),
+}
+
+/// The id of reward program
+pub type RewardProgramId = u32;
+
+/// The reward program of a pool
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct RewardProgram<BlockNumber> {
+	/// The reward currency
+	pub currency_id: CurrencyId,
+	/// The block number at which the program starts
+	pub start: BlockNumber,
+	/// The block number at which the program ends
+	pub end: BlockNumber,
+	/// The total budget of the program
+	pub total_budget: Balance,
+	/// The budget which has not been accumulated yet
+	pub remaining_budget: Balance,
+	/// The reward amount accumulated per period
+	pub amount_per_period: Balance,
+}
+
+/// The target of a time-locked boost
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum BoostTarget {
+	/// Lock LP token(LPCurrencyId) to boost the shares of Pool::Dex(LPCurrencyId)
+	Dex(CurrencyId),
+
+	/// Lock native token to boost the shares of all Pool::Loans
+	Loans,
+}
+
+/// The time-locked boost of an account
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct BoostLock<BlockNumber> {
+	/// The locked amount
+	pub amount: Balance,
+	/// The shares multiplier, reset to one once the lock expired
+	pub multiplier: Rate,
+	/// The lock duration
+	pub duration: BlockNumber,
+	/// The block number at which the lock expires
+	pub unlock_at: BlockNumber,
 }
 
 #[frame_support::pallet]
@@ -77,10 +179,18 @@ pub mod module {
 	#[pallet::config]
 	pub trait Config:
 		frame_system::Config
-		+ orml_rewards::Config<Share = Balance, Balance = Balance, PoolId = PoolId, CurrencyId = CurrencyId>
+		+ orml_rewards::Config<
+			Share = Balance,
+			Balance = Balance,
+			PoolId = PoolId<Self::RelaychainAccountId>,
+			CurrencyId = CurrencyId,
+		>
 	{
 		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 
+		/// The AccountId of a relaychain account.
+		type RelaychainAccountId: Parameter + Member + MaybeSerializeDeserialize + Debug + MaybeDisplay + Ord + Default;
+
 		/// The period to accumulate rewards
 		#[pallet::constant]
 		type AccumulatePeriod: Get<Self::BlockNumber>;
@@ -105,6 +215,14 @@ pub mod module {
 		/// DEX to supply liquidity info
 		type DEX: DEXManager<Self::AccountId, CurrencyId, Balance>;
 
+		/// The price source to limit the swaps of claim and restake
+		type PriceSource: PriceProvider<CurrencyId>;
+
+		/// When claim and restake swap with DEX, the acceptable max slippage for the price from
+		/// oracle.
+		#[pallet::constant]
+		type MaxSwapSlippageCompareToOracle: Get<Ratio>;
+
 		/// Emergency shutdown.
 		type EmergencyShutdown: EmergencyShutdown;
 
@@ -112,6 +230,49 @@ pub mod module {
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
 
+		/// The liquid currency id of Homa, the holders get shares of Pool::HomaIncentive
+		#[pallet::constant]
+		type LiquidCurrencyId: Get<CurrencyId>;
+
+		/// The accounts don't get shares of Pool::HomaIncentive and Pool::StableAsset for their
+		/// balances, e.g. module accounts.
+		type ExcludedShareHolders: Contains<Self::AccountId>;
+
+		/// The insurance of relaychain validators, the rewards of
+		/// Pool::HomaValidatorAllowance are derived from it
+		type ValidatorInsurance: HomaValidatorInsurance<Self::RelaychainAccountId, Balance>;
+
+		/// The native currency id, locked to boost the shares of Pool::Loans
+		#[pallet::constant]
+		type NativeCurrencyId: Get<CurrencyId>;
+
+		/// The maximum duration of a time-locked boost
+		#[pallet::constant]
+		type MaxBoostDuration: Get<Self::BlockNumber>;
+
+		/// The shares multiplier of a time-locked boost with MaxBoostDuration
+		#[pallet::constant]
+		type MaxBoostMultiplier: Get<Rate>;
+
+		/// The amount of native token to be locked for the full boost of Pool::Loans, the boost of
+		/// less amount is reduced proportionally
+		#[pallet::constant]
+		type LoansBoostFullAmount: Get<Balance>;
+
+		/// The penalty rate of the locked amount for unlocking right after locking, decreases
+		/// linearly to zero at expiry
+		#[pallet::constant]
+		type EarlyUnlockPenaltyRate: Get<Rate>;
+
+		/// The maximum number of boost expiries and scheduled blocks to be processed per block, the
+		/// rest are carried over to the next block
+		#[pallet::constant]
+		type MaxBoostExpiriesPerBlock: Get<u32>;
+
+		/// The max number of reward programs of a pool
+		#[pallet::constant]
+		type MaxRewardProgramsPerPool: Get<u32>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -126,25 +287,77 @@ pub mod module {
 		InvalidPoolId,
 		/// Invalid rate
 		InvalidRate,
+		/// There are no rewards to restake
+		NoRewardsToRestake,
+		/// Feed price is invalid
+		InvalidFeedPrice,
+		/// Invalid reward program
+		InvalidRewardProgram,
+		/// The reward programs of the pool reach the limit
+		TooManyRewardPrograms,
+		/// The reward program does not exist
+		RewardProgramNotFound,
+		/// Invalid boost duration
+		InvalidBoostDuration,
+		/// The boost lock does not exist
+		BoostLockNotFound,
+		/// Invalid amount
+		InvalidAmount,
 	}
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
-	#[pallet::metadata(T::AccountId = "AccountId", PoolId = "PoolId")]
+	#[pallet::metadata(
+		T::AccountId = "AccountId",
+		T::BlockNumber = "BlockNumber",
+		PoolId<T::RelaychainAccountId> = "PoolId"
+	)]
 	pub enum Event<T: Config> {
 		/// Deposit DEX share. \[who, dex_share_type, deposit_amount\]
 		DepositDexShare(T::AccountId, CurrencyId, Balance),
 		/// Withdraw DEX share. \[who, dex_share_type, withdraw_amount\]
 		WithdrawDexShare(T::AccountId, CurrencyId, Balance),
 		/// Claim rewards. \[who, pool_id, reward_currency_id, actual_amount, deduction_amount\]
-		ClaimRewards(T::AccountId, PoolId, CurrencyId, Balance, Balance),
+		ClaimRewards(
+			T::AccountId,
+			PoolId<T::RelaychainAccountId>,
+			CurrencyId,
+			Balance,
+			Balance,
+		),
+		/// Rewards claimed and restaked. \[who, dex_share_type, deposit_amount\]
+		ClaimAndRestake(T::AccountId, CurrencyId, Balance),
 		/// Incentive reward amount updated. \[pool_id, reward_currency_id,
 		/// reward_amount_per_period\]
-		IncentiveRewardAmountUpdated(PoolId, CurrencyId, Balance),
+		IncentiveRewardAmountUpdated(PoolId<T::RelaychainAccountId>, CurrencyId, Balance),
 		/// Saving reward rate updated. \[pool_id, reward_rate_per_period\]
-		SavingRewardRateUpdated(PoolId, Rate),
+		SavingRewardRateUpdated(PoolId<T::RelaychainAccountId>, Rate),
 		/// Payout deduction rate updated. \[pool_id, deduction_rate\]
-		ClaimRewardDeductionRateUpdated(PoolId, Rate),
+		ClaimRewardDeductionRateUpdated(PoolId<T::RelaychainAccountId>, Rate),
+		/// Homa validator allowance reward rate updated. \[reward_currency_id,
+		/// reward_rate_per_period\]
+		HomaValidatorAllowanceRewardRateUpdated(CurrencyId, Rate),
+		/// Reward program added. \[pool_id, program_id, reward_currency_id, start, end, total_budget\]
+		RewardProgramAdded(
+			PoolId<T::RelaychainAccountId>,
+			RewardProgramId,
+			CurrencyId,
+			T::BlockNumber,
+			T::BlockNumber,
+			Balance,
+		),
+		/// Reward program removed by governance. \[pool_id, program_id, remaining_budget\]
+		RewardProgramRemoved(PoolId<T::RelaychainAccountId>, RewardProgramId, Balance),
+		/// Reward program finished, the budget is exhausted or it ends. \[pool_id, program_id,
+		/// remaining_budget\]
+		RewardProgramFinished(PoolId<T::RelaychainAccountId>, RewardProgramId, Balance),
+		/// Time-locked boost locked. \[who, boost_target, total_locked_amount, unlock_at,
+		/// multiplier\]
+		BoostLocked(T::AccountId, BoostTarget, Balance, T::BlockNumber, Rate),
+		/// Time-locked boost expired. \[who, boost_target\]
+		BoostExpired(T::AccountId, BoostTarget),
+		/// Time-locked boost unlocked. \[who, boost_target, unlocked_amount, penalty_amount\]
+		BoostUnlocked(T::AccountId, BoostTarget, Balance, Balance),
 	}
 
 	/// Mapping from pool to its fixed incentive amounts of multi currencies per period.
@@ -152,22 +365,39 @@ pub mod module {
 	/// IncentiveRewardAmounts: double_map Pool, RewardCurrencyId => RewardAmountPerPeriod
 	#[pallet::storage]
 	#[pallet::getter(fn incentive_reward_amounts)]
-	pub type IncentiveRewardAmounts<T: Config> =
-		StorageDoubleMap<_, Twox64Concat, PoolId, Twox64Concat, CurrencyId, Balance, ValueQuery>;
+	pub type IncentiveRewardAmounts<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		PoolId<T::RelaychainAccountId>,
+		Twox64Concat,
+		CurrencyId,
+		Balance,
+		ValueQuery,
+	>;
 
 	/// Mapping from pool to its fixed reward rate per period.
 	///
 	/// DexSavingRewardRates: map Pool => SavingRatePerPeriod
 	#[pallet::storage]
 	#[pallet::getter(fn dex_saving_reward_rates)]
-	pub type DexSavingRewardRates<T: Config> = StorageMap<_, Twox64Concat, PoolId, Rate, ValueQuery>;
+	pub type DexSavingRewardRates<T: Config> =
+		StorageMap<_, Twox64Concat, PoolId<T::RelaychainAccountId>, Rate, ValueQuery>;
+
+	/// Mapping from reward currency to the reward rate per period of the insurance of a validator,
+	/// which is accumulated to Pool::HomaValidatorAllowance of every validator that isn't frozen.
+	///
+	/// HomaValidatorAllowanceRewardRates: map RewardCurrencyId => RewardRatePerPeriod
+	#[pallet::storage]
+	#[pallet::getter(fn homa_validator_allowance_reward_rates)]
+	pub type HomaValidatorAllowanceRewardRates<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, Rate, ValueQuery>;
 
 	/// Mapping from pool to its claim reward deduction rate.
 	///
 	/// ClaimRewardDeductionRates: map Pool => DeductionRate
 	#[pallet::storage]
 	#[pallet::getter(fn claim_reward_deduction_rates)]
-	pub type ClaimRewardDeductionRates<T: Config> = StorageMap<_, Twox64Concat, PoolId, Rate, ValueQuery>;
+	pub type ClaimRewardDeductionRates<T: Config> =
+		StorageMap<_, Twox64Concat, PoolId<T::RelaychainAccountId>, Rate, ValueQuery>;
 
 	/// The pending rewards amount, actual available rewards amount may be deducted
 	///
@@ -177,22 +407,88 @@ pub mod module {
 	pub type PendingMultiRewards<T: Config> = StorageDoubleMap<
 		_,
 		Twox64Concat,
-		PoolId,
+		PoolId<T::RelaychainAccountId>,
 		Twox64Concat,
 		T::AccountId,
 		BTreeMap<CurrencyId, Balance>,
 		ValueQuery,
 	>;
 
+	/// The reward programs of pools.
+	///
+	/// RewardPrograms: double_map PoolId, RewardProgramId => Option<RewardProgram>
+	#[pallet::storage]
+	#[pallet::getter(fn reward_programs)]
+	pub type RewardPrograms<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		PoolId<T::RelaychainAccountId>,
+		Twox64Concat,
+		RewardProgramId,
+		RewardProgram<T::BlockNumber>,
+		OptionQuery,
+	>;
+
+	/// The id of the next reward program.
+	///
+	/// NextRewardProgramId: RewardProgramId
+	#[pallet::storage]
+	#[pallet::getter(fn next_reward_program_id)]
+	pub type NextRewardProgramId<T: Config> = StorageValue<_, RewardProgramId, ValueQuery>;
+
+	/// The time-locked boosts of accounts.
+	///
+	/// BoostLocks: double_map BoostTarget, AccountId => Option<BoostLock>
+	#[pallet::storage]
+	#[pallet::getter(fn boost_locks)]
+	pub type BoostLocks<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		BoostTarget,
+		Twox64Concat,
+		T::AccountId,
+		BoostLock<T::BlockNumber>,
+		OptionQuery,
+	>;
+
+	/// The expiry schedule of the time-locked boosts.
+	///
+	/// BoostExpiries: double_map BlockNumber, (BoostTarget, AccountId) => ()
+	#[pallet::storage]
+	#[pallet::getter(fn boost_expiries)]
+	pub type BoostExpiries<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::BlockNumber, Twox64Concat, (BoostTarget, T::AccountId), (), OptionQuery>;
+
+	/// The scheduled block from which the boost expiries have not been fully processed, it only
+	/// exists if the expiries are carried over.
+	///
+	/// BoostExpiryCursor: BlockNumber
+	#[pallet::storage]
+	#[pallet::getter(fn boost_expiry_cursor)]
+	pub type BoostExpiryCursor<T: Config> = StorageValue<_, T::BlockNumber, OptionQuery>;
+
+	/// The base shares(without boost) of accounts in Pool::Loans(CollateralCurrencyId), the shares
+	/// of the pools are the base shares multiplied by the boost of Pool::Loans.
+	///
+	/// LoansPools: double_map AccountId, CollateralCurrencyId => Option<BaseShare>
+	#[pallet::storage]
+	#[pallet::getter(fn loans_pools)]
+	pub type LoansPools<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::AccountId, Twox64Concat, CurrencyId, Balance, OptionQuery>;
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
 		fn on_initialize(now: T::BlockNumber) -> Weight {
+			let expire_weight = Self::expire_boosts(now);
+
 			// accumulate reward periodically
-			if now % T::AccumulatePeriod::get() == Zero::zero() {
+			let accumulate_weight = if now % T::AccumulatePeriod::get() == Zero::zero() {
 				let mut count: u32 = 0;
+				let mut program_count: u32 = 0;
 				let shutdown = T::EmergencyShutdown::is_shutdown();
 
 				for (pool_id, pool_info) in orml_rewards::PoolInfos::<T>::iter() {
@@ -201,25 +497,50 @@ pub mod module {
 							// do not accumulate incentives for PoolId::Loans after shutdown
 							PoolId::Loans(_) if !shutdown => {
 								count += 1;
-								Self::accumulate_incentives(pool_id);
+								program_count = program_count.saturating_add(Self::accumulate_incentives(pool_id, now));
 							}
 							PoolId::Dex(lp_currency_id) => {
 								// do not accumulate dex saving any more after shutdown
 								if !shutdown {
-									Self::accumulate_dex_saving(lp_currency_id, pool_id);
+									Self::accumulate_dex_saving(lp_currency_id, &pool_id);
 								}
 								count += 1;
-								Self::accumulate_incentives(pool_id);
+								program_count = program_count.saturating_add(Self::accumulate_incentives(pool_id, now));
+							}
+							// do not accumulate incentives for PoolId::HomaValidatorAllowance,
+							// PoolId::HomaIncentive and PoolId::StableAsset after shutdown
+							PoolId::HomaValidatorAllowance(ref validator) if !shutdown => {
+								Self::accumulate_homa_validator_allowance(validator, &pool_id);
+								count += 1;
+								program_count = program_count.saturating_add(Self::accumulate_incentives(pool_id, now));
+							}
+							PoolId::HomaIncentive | PoolId::StableAsset(_) if !shutdown => {
+								count += 1;
+								program_count = program_count.saturating_add(Self::accumulate_incentives(pool_id, now));
 							}
 							_ => {}
 						}
 					}
 				}
 
+				// the reward programs of the pools without shares don't accumulate, but still end. The
+				// programs of a pool are iterated consecutively.
+				let mut empty_pools: Vec<PoolId<T::RelaychainAccountId>> = RewardPrograms::<T>::iter()
+					.map(|(pool_id, _, _)| pool_id)
+					.filter(|pool_id| orml_rewards::PoolInfos::<T>::get(pool_id).total_shares.is_zero())
+					.collect();
+				empty_pools.dedup();
+				for pool_id in empty_pools {
+					program_count = program_count.saturating_add(Self::expire_reward_programs(pool_id, now));
+				}
+
 				T::WeightInfo::on_initialize(count)
+					.saturating_add(T::WeightInfo::accumulate_reward_programs(program_count))
 			} else {
 				0
-			}
+			};
+
+			expire_weight.saturating_add(accumulate_weight)
 		}
 	}
 
@@ -261,6 +582,41 @@ pub mod module {
 			Ok(())
 		}
 
+		/// Lock LP token or native token for a duration to boost the shares. If the boost lock
+		/// exists, the amount will be added to it, and the duration will be extended if it expires
+		/// later.
+		///
+		/// The dispatch origin of this call must be `Signed` by the transactor.
+		///
+		/// - `target`: boost target, Dex(LPCurrencyId) locks LP token, Loans locks native token
+		/// - `amount`: amount to lock, must be greater than zero
+		/// - `duration`: lock duration, no more than MaxBoostDuration
+		#[pallet::weight(<T as Config>::WeightInfo::lock_boost())]
+		#[transactional]
+		pub fn lock_boost(
+			origin: OriginFor<T>,
+			target: BoostTarget,
+			#[pallet::compact] amount: Balance,
+			duration: T::BlockNumber,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_lock_boost(&who, target, amount, duration)?;
+			Ok(())
+		}
+
+		/// Unlock the boost lock. A penalty will be charged if it's unlocked before expiry.
+		///
+		/// The dispatch origin of this call must be `Signed` by the transactor.
+		///
+		/// - `target`: boost target
+		#[pallet::weight(<T as Config>::WeightInfo::unlock_boost())]
+		#[transactional]
+		pub fn unlock_boost(origin: OriginFor<T>, target: BoostTarget) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_unlock_boost(&who, target)?;
+			Ok(())
+		}
+
 		/// Claim all avalible multi currencies rewards for specific PoolId.
 		///
 		/// The dispatch origin of this call must be `Signed` by the transactor.
@@ -268,43 +624,30 @@ pub mod module {
 		/// - `pool_id`: pool type
 		#[pallet::weight(<T as Config>::WeightInfo::claim_rewards())]
 		#[transactional]
-		pub fn claim_rewards(origin: OriginFor<T>, pool_id: PoolId) -> DispatchResult {
+		pub fn claim_rewards(origin: OriginFor<T>, pool_id: PoolId<T::RelaychainAccountId>) -> DispatchResult {
 			let who = ensure_signed(origin)?;
+			Self::do_claim_rewards(&who, &pool_id)?;
+			Ok(())
+		}
 
-			// orml_rewards will claim rewards for all currencies rewards
-			<orml_rewards::Pallet<T>>::claim_rewards(&who, &pool_id);
-
-			let pending_multi_rewards: BTreeMap<CurrencyId, Balance> = PendingMultiRewards::<T>::take(&pool_id, &who);
-			let deduction_rate = Self::claim_reward_deduction_rates(&pool_id);
-
-			for (currency_id, pending_reward) in pending_multi_rewards {
-				if pending_reward.is_zero() {
-					continue;
-				}
-				// calculate actual rewards and deduction amount
-				let (actual_amount, deduction_amount) = {
-					let deduction_amount = deduction_rate.saturating_mul_int(pending_reward).min(pending_reward);
-					if !deduction_amount.is_zero() {
-						// re-accumulate deduction to rewards pool if deduction amount is not zero
-						<orml_rewards::Pallet<T>>::accumulate_reward(&pool_id, currency_id, deduction_amount)?;
-					}
-					(pending_reward.saturating_sub(deduction_amount), deduction_amount)
-				};
-
-				// transfer the actual reward(pending reward exclude deduction) to user from the pool. it should not
-				// affect the process, ignore the result to continue. if it fails, just the user will not
-				// be rewarded, there will not increase user balance.
-				T::Currency::transfer(currency_id, &Self::account_id(), &who, actual_amount)?;
-
-				Self::deposit_event(Event::ClaimRewards(
-					who.clone(),
-					pool_id,
-					currency_id,
-					actual_amount,
-					deduction_amount,
-				));
-			}
-
+		/// Claim all avalible multi currencies rewards of Pool::Dex, swap them into the two
+		/// underlying tokens of the LP token, add liquidity and deposit the LP token increment
+		/// back to the same pool.
+		///
+		/// The dispatch origin of this call must be `Signed` by the transactor.
+		///
+		/// - `lp_currency_id`: LP token type
+		/// - `min_share_increment`: the minimum acceptable LP token increment, the slippage bound of
+		///   the whole swap and add liquidity process
+		#[pallet::weight(<T as Config>::WeightInfo::claim_and_restake())]
+		#[transactional]
+		pub fn claim_and_restake(
+			origin: OriginFor<T>,
+			lp_currency_id: CurrencyId,
+			#[pallet::compact] min_share_increment: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_claim_and_restake(&who, lp_currency_id, min_share_increment)?;
 			Ok(())
 		}
 
@@ -319,20 +662,27 @@ pub mod module {
 		#[transactional]
 		pub fn update_incentive_rewards(
 			origin: OriginFor<T>,
-			updates: Vec<(PoolId, Vec<(CurrencyId, Balance)>)>,
+			updates: Vec<(PoolId<T::RelaychainAccountId>, Vec<(CurrencyId, Balance)>)>,
 		) -> DispatchResult {
 			T::UpdateOrigin::ensure_origin(origin)?;
 			for (pool_id, update_list) in updates {
-				if let PoolId::Dex(currency_id) = pool_id {
-					ensure!(currency_id.is_dex_share_currency_id(), Error::<T>::InvalidPoolId);
-				}
+				Self::ensure_valid_pool_id(&pool_id)?;
+				// the rewards of PoolId::HomaValidatorAllowance are derived from the insurance
+				ensure!(
+					!matches!(pool_id, PoolId::HomaValidatorAllowance(_)),
+					Error::<T>::InvalidPoolId
+				);
 
 				for (currency_id, amount) in update_list {
-					IncentiveRewardAmounts::<T>::mutate_exists(pool_id, currency_id, |maybe_amount| {
+					IncentiveRewardAmounts::<T>::mutate_exists(&pool_id, currency_id, |maybe_amount| {
 						let mut v = maybe_amount.unwrap_or_default();
 						if amount != v {
 							v = amount;
-							Self::deposit_event(Event::IncentiveRewardAmountUpdated(pool_id, currency_id, amount));
+							Self::deposit_event(Event::IncentiveRewardAmountUpdated(
+								pool_id.clone(),
+								currency_id,
+								amount,
+							));
 						}
 
 						if v.is_zero() {
@@ -346,6 +696,110 @@ pub mod module {
 			Ok(())
 		}
 
+		/// Add a reward program for specific PoolId, the budget is transferred from RewardsSource
+		/// when added and will be accumulated evenly per period from `start` until `end`.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `pool_id`: pool type
+		/// - `currency_id`: reward currency type
+		/// - `start`: the block number at which the program starts
+		/// - `end`: the block number at which the program ends
+		/// - `total_budget`: the total reward amount of the program
+		#[pallet::weight(<T as Config>::WeightInfo::add_reward_program())]
+		#[transactional]
+		pub fn add_reward_program(
+			origin: OriginFor<T>,
+			pool_id: PoolId<T::RelaychainAccountId>,
+			currency_id: CurrencyId,
+			start: T::BlockNumber,
+			end: T::BlockNumber,
+			#[pallet::compact] total_budget: Balance,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			Self::ensure_valid_pool_id(&pool_id)?;
+			ensure!(
+				start >= <frame_system::Pallet<T>>::block_number() && start < end && !total_budget.is_zero(),
+				Error::<T>::InvalidRewardProgram
+			);
+
+			// the number of accumulation periods in [start, end)
+			let period = T::AccumulatePeriod::get();
+			let periods: u128 = (end.saturating_add(period).saturating_sub(One::one()) / period)
+				.saturating_sub(start.saturating_add(period).saturating_sub(One::one()) / period)
+				.unique_saturated_into();
+			ensure!(!periods.is_zero(), Error::<T>::InvalidRewardProgram);
+			let amount_per_period = total_budget
+				.saturating_add(periods.saturating_sub(1))
+				.checked_div(periods)
+				.ok_or(Error::<T>::InvalidRewardProgram)?;
+			ensure!(
+				(RewardPrograms::<T>::iter_prefix(&pool_id).count() as u32) < T::MaxRewardProgramsPerPool::get(),
+				Error::<T>::TooManyRewardPrograms
+			);
+
+			// reserve the whole budget up front
+			T::Currency::transfer(currency_id, &T::RewardsSource::get(), &Self::account_id(), total_budget)?;
+
+			let program_id = NextRewardProgramId::<T>::try_mutate(|id| -> Result<RewardProgramId, DispatchError> {
+				let current_id = *id;
+				*id = id.checked_add(1).ok_or(ArithmeticError::Overflow)?;
+				Ok(current_id)
+			})?;
+			RewardPrograms::<T>::insert(
+				&pool_id,
+				program_id,
+				RewardProgram {
+					currency_id,
+					start,
+					end,
+					total_budget,
+					remaining_budget: total_budget,
+					amount_per_period,
+				},
+			);
+
+			Self::deposit_event(Event::RewardProgramAdded(
+				pool_id,
+				program_id,
+				currency_id,
+				start,
+				end,
+				total_budget,
+			));
+			Ok(())
+		}
+
+		/// Remove the reward program of specific PoolId, the remaining budget will be returned to
+		/// RewardsSource.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `pool_id`: pool type
+		/// - `program_id`: reward program id
+		#[pallet::weight(<T as Config>::WeightInfo::remove_reward_program())]
+		#[transactional]
+		pub fn remove_reward_program(
+			origin: OriginFor<T>,
+			pool_id: PoolId<T::RelaychainAccountId>,
+			program_id: RewardProgramId,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			let program = RewardPrograms::<T>::take(&pool_id, program_id).ok_or(Error::<T>::RewardProgramNotFound)?;
+			T::Currency::transfer(
+				program.currency_id,
+				&Self::account_id(),
+				&T::RewardsSource::get(),
+				program.remaining_budget,
+			)?;
+			Self::deposit_event(Event::RewardProgramRemoved(
+				pool_id,
+				program_id,
+				program.remaining_budget,
+			));
+			Ok(())
+		}
+
 		/// Update DEX saving reward rate for specific PoolId
 		///
 		/// The dispatch origin of this call must be `UpdateOrigin`.
@@ -353,7 +807,10 @@ pub mod module {
 		/// - `updates`: Vec<(PoolId, Rate)>
 		#[pallet::weight(<T as Config>::WeightInfo::update_dex_saving_rewards(updates.len() as u32))]
 		#[transactional]
-		pub fn update_dex_saving_rewards(origin: OriginFor<T>, updates: Vec<(PoolId, Rate)>) -> DispatchResult {
+		pub fn update_dex_saving_rewards(
+			origin: OriginFor<T>,
+			updates: Vec<(PoolId<T::RelaychainAccountId>, Rate)>,
+		) -> DispatchResult {
 			T::UpdateOrigin::ensure_origin(origin)?;
 			for (pool_id, rate) in updates {
 				match pool_id {
@@ -366,7 +823,39 @@ pub mod module {
 					let mut v = maybe_rate.unwrap_or_default();
 					if rate != v {
 						v = rate;
-						Self::deposit_event(Event::SavingRewardRateUpdated(pool_id, rate));
+						Self::deposit_event(Event::SavingRewardRateUpdated(pool_id.clone(), rate));
+					}
+
+					if v.is_zero() {
+						*maybe_rate = None;
+					} else {
+						*maybe_rate = Some(v);
+					}
+				});
+			}
+			Ok(())
+		}
+
+		/// Update the reward rates of the insurance of validators for PoolId::HomaValidatorAllowance
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `updates`: Vec<(RewardCurrencyId, Rate)>
+		#[pallet::weight(<T as Config>::WeightInfo::update_dex_saving_rewards(updates.len() as u32))]
+		#[transactional]
+		pub fn update_homa_validator_allowance_reward_rates(
+			origin: OriginFor<T>,
+			updates: Vec<(CurrencyId, Rate)>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			for (currency_id, rate) in updates {
+				ensure!(rate <= Rate::one(), Error::<T>::InvalidRate);
+
+				HomaValidatorAllowanceRewardRates::<T>::mutate_exists(currency_id, |maybe_rate| {
+					let mut v = maybe_rate.unwrap_or_default();
+					if rate != v {
+						v = rate;
+						Self::deposit_event(Event::HomaValidatorAllowanceRewardRateUpdated(currency_id, rate));
 					}
 
 					if v.is_zero() {
@@ -388,19 +877,17 @@ pub mod module {
 		#[transactional]
 		pub fn update_claim_reward_deduction_rates(
 			origin: OriginFor<T>,
-			updates: Vec<(PoolId, Rate)>,
+			updates: Vec<(PoolId<T::RelaychainAccountId>, Rate)>,
 		) -> DispatchResult {
 			T::UpdateOrigin::ensure_origin(origin)?;
 			for (pool_id, deduction_rate) in updates {
-				if let PoolId::Dex(currency_id) = pool_id {
-					ensure!(currency_id.is_dex_share_currency_id(), Error::<T>::InvalidPoolId);
-				}
+				Self::ensure_valid_pool_id(&pool_id)?;
 				ensure!(deduction_rate <= Rate::one(), Error::<T>::InvalidRate);
 				ClaimRewardDeductionRates::<T>::mutate_exists(&pool_id, |maybe_rate| {
 					let mut v = maybe_rate.unwrap_or_default();
 					if deduction_rate != v {
 						v = deduction_rate;
-						Self::deposit_event(Event::ClaimRewardDeductionRateUpdated(pool_id, deduction_rate));
+						Self::deposit_event(Event::ClaimRewardDeductionRateUpdated(pool_id.clone(), deduction_rate));
 					}
 
 					if v.is_zero() {
@@ -420,51 +907,573 @@ impl<T: Config> Pallet<T> {
 		T::PalletId::get().into_account()
 	}
 
-	// accumulate incentive rewards of multi currencies
-	fn accumulate_incentives(pool_id: PoolId) {
-		for (reward_currency_id, reward_amount) in IncentiveRewardAmounts::<T>::iter_prefix(pool_id) {
-			if reward_amount.is_zero() {
+	// claim the rewards of `pool_id` for `who`, return the actual amounts transferred to `who`
+	fn do_claim_rewards(
+		who: &T::AccountId,
+		pool_id: &PoolId<T::RelaychainAccountId>,
+	) -> Result<BTreeMap<CurrencyId, Balance>, DispatchError> {
+		// orml_rewards will claim rewards for all currencies rewards
+		<orml_rewards::Pallet<T>>::claim_rewards(who, pool_id);
+
+		let pending_multi_rewards: BTreeMap<CurrencyId, Balance> = PendingMultiRewards::<T>::take(pool_id, who);
+		let deduction_rate = Self::claim_reward_deduction_rates(pool_id);
+		let mut claimed_rewards: BTreeMap<CurrencyId, Balance> = BTreeMap::new();
+
+		for (currency_id, pending_reward) in pending_multi_rewards {
+			if pending_reward.is_zero() {
 				continue;
 			}
+			// calculate actual rewards and deduction amount
+			let (actual_amount, deduction_amount) = {
+				let deduction_amount = deduction_rate.saturating_mul_int(pending_reward).min(pending_reward);
+				if !deduction_amount.is_zero() {
+					// re-accumulate deduction to rewards pool if deduction amount is not zero
+					<orml_rewards::Pallet<T>>::accumulate_reward(pool_id, currency_id, deduction_amount)?;
+				}
+				(pending_reward.saturating_sub(deduction_amount), deduction_amount)
+			};
+
+			// transfer the actual reward(pending reward exclude deduction) to user from the pool. it should not
+			// affect the process, ignore the result to continue. if it fails, just the user will not
+			// be rewarded, there will not increase user balance.
+			T::Currency::transfer(currency_id, &Self::account_id(), who, actual_amount)?;
+			claimed_rewards.insert(currency_id, actual_amount);
+
+			Self::deposit_event(Event::ClaimRewards(
+				who.clone(),
+				pool_id.clone(),
+				currency_id,
+				actual_amount,
+				deduction_amount,
+			));
+		}
 
-			let res = T::Currency::transfer(
-				reward_currency_id,
-				&T::RewardsSource::get(),
-				&Self::account_id(),
-				reward_amount,
-			);
+		Ok(claimed_rewards)
+	}
+
+	fn do_claim_and_restake(
+		who: &T::AccountId,
+		lp_currency_id: CurrencyId,
+		min_share_increment: Balance,
+	) -> DispatchResult {
+		let (currency_id_a, currency_id_b) = lp_currency_id
+			.split_dex_share_currency_id()
+			.ok_or(Error::<T>::InvalidCurrencyId)?;
+		let claimed_rewards = Self::do_claim_rewards(who, &PoolId::Dex(lp_currency_id))?;
+
+		// swap the rewards of other currencies into the underlying tokens, the slippage of each
+		// swap is bounded by the price from oracle, and the whole process by `min_share_increment`
+		let (mut amount_a, mut amount_b): (Balance, Balance) = (Zero::zero(), Zero::zero());
+		for (currency_id, amount) in claimed_rewards {
+			if currency_id == currency_id_a {
+				amount_a = amount_a.saturating_add(amount);
+			} else if currency_id == currency_id_b {
+				amount_b = amount_b.saturating_add(amount);
+			} else if T::DEX::get_swap_target_amount(&[currency_id, currency_id_a], amount).is_some() {
+				let target_amount = Self::swap_with_oracle_limit(who, currency_id, currency_id_a, amount)?;
+				amount_a = amount_a.saturating_add(target_amount);
+			} else {
+				let target_amount = Self::swap_with_oracle_limit(who, currency_id, currency_id_b, amount)?;
+				amount_b = amount_b.saturating_add(target_amount);
+			}
+		}
+		ensure!(
+			!amount_a.is_zero() || !amount_b.is_zero(),
+			Error::<T>::NoRewardsToRestake
+		);
+
+		// swap half of the excess token to balance the amounts with the liquidity pool
+		let (pool_a, pool_b) = T::DEX::get_liquidity_pool(currency_id_a, currency_id_b);
+		let matched_amount_a = Rate::checked_from_rational(pool_a, pool_b)
+			.unwrap_or_default()
+			.saturating_mul_int(amount_b);
+		if amount_a > matched_amount_a {
+			let supply_amount = amount_a.saturating_sub(matched_amount_a) / 2;
+			if !supply_amount.is_zero() {
+				let target_amount = Self::swap_with_oracle_limit(who, currency_id_a, currency_id_b, supply_amount)?;
+				amount_a = amount_a.saturating_sub(supply_amount);
+				amount_b = amount_b.saturating_add(target_amount);
+			}
+		} else {
+			let matched_amount_b = Rate::checked_from_rational(pool_b, pool_a)
+				.unwrap_or_default()
+				.saturating_mul_int(amount_a);
+			let supply_amount = amount_b.saturating_sub(matched_amount_b) / 2;
+			if !supply_amount.is_zero() {
+				let target_amount = Self::swap_with_oracle_limit(who, currency_id_b, currency_id_a, supply_amount)?;
+				amount_b = amount_b.saturating_sub(supply_amount);
+				amount_a = amount_a.saturating_add(target_amount);
+			}
+		}
+
+		let share_before = T::Currency::free_balance(lp_currency_id, who);
+		T::DEX::add_liquidity(
+			who,
+			currency_id_a,
+			currency_id_b,
+			amount_a,
+			amount_b,
+			min_share_increment,
+			false,
+		)?;
+		let share_increment = T::Currency::free_balance(lp_currency_id, who).saturating_sub(share_before);
+		Self::do_deposit_dex_share(who, lp_currency_id, share_increment)?;
+
+		Self::deposit_event(Event::ClaimAndRestake(who.clone(), lp_currency_id, share_increment));
+		Ok(())
+	}
 
-			match res {
-				Ok(_) => {
-					let _ = <orml_rewards::Pallet<T>>::accumulate_reward(
-						&pool_id,
-						reward_currency_id,
-						reward_amount,
+	// swap `supply_amount` of `supply_currency_id` to `target_currency_id`, the min target amount is
+	// limited by the relative price from oracle and MaxSwapSlippageCompareToOracle
+	fn swap_with_oracle_limit(
+		who: &T::AccountId,
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		supply_amount: Balance,
+	) -> Result<Balance, DispatchError> {
+		let relative_price = T::PriceSource::get_relative_price(supply_currency_id, target_currency_id)
+			.ok_or(Error::<T>::InvalidFeedPrice)?;
+		let min_target_amount = Ratio::one()
+			.saturating_sub(T::MaxSwapSlippageCompareToOracle::get())
+			.saturating_mul_int(relative_price.saturating_mul_int(supply_amount));
+
+		T::DEX::swap_with_exact_supply(
+			who,
+			&[supply_currency_id, target_currency_id],
+			supply_amount,
+			min_target_amount,
+		)
+	}
+
+	fn ensure_valid_pool_id(pool_id: &PoolId<T::RelaychainAccountId>) -> DispatchResult {
+		match pool_id {
+			PoolId::Dex(currency_id) => ensure!(currency_id.is_dex_share_currency_id(), Error::<T>::InvalidPoolId),
+			PoolId::StableAsset(currency_id) => ensure!(
+				currency_id.is_stable_asset_pool_token_currency_id(),
+				Error::<T>::InvalidPoolId
+			),
+			_ => {}
+		}
+		Ok(())
+	}
+
+	fn boost_currency_id(target: BoostTarget) -> Result<CurrencyId, DispatchError> {
+		match target {
+			BoostTarget::Dex(lp_currency_id) => {
+				ensure!(lp_currency_id.is_dex_share_currency_id(), Error::<T>::InvalidCurrencyId);
+				Ok(lp_currency_id)
+			}
+			BoostTarget::Loans => Ok(T::NativeCurrencyId::get()),
+		}
+	}
+
+	// the shares multiplier of the boost lock with `amount` and `duration`
+	fn boost_multiplier(target: BoostTarget, amount: Balance, duration: T::BlockNumber) -> Rate {
+		let duration: u128 = duration.unique_saturated_into();
+		let max_duration: u128 = T::MaxBoostDuration::get().unique_saturated_into();
+		let duration_ratio = Rate::saturating_from_rational(duration, max_duration).min(Rate::one());
+		let amount_ratio = match target {
+			BoostTarget::Dex(_) => Rate::one(),
+			BoostTarget::Loans => {
+				Rate::saturating_from_rational(amount, T::LoansBoostFullAmount::get()).min(Rate::one())
+			}
+		};
+
+		Rate::one().saturating_add(
+			T::MaxBoostMultiplier::get()
+				.saturating_sub(Rate::one())
+				.saturating_mul(duration_ratio)
+				.saturating_mul(amount_ratio),
+		)
+	}
+
+	// the shares multiplier of Pool::Loans for `who`
+	fn loans_boost_multiplier(who: &T::AccountId) -> Rate {
+		Self::boost_locks(BoostTarget::Loans, who).map_or_else(Rate::one, |lock| lock.multiplier)
+	}
+
+	// update the shares of the boosted pools from the old boost to the new boost
+	fn update_boosted_shares(who: &T::AccountId, target: BoostTarget, old: (Balance, Rate), new: (Balance, Rate)) {
+		match target {
+			BoostTarget::Dex(lp_currency_id) => {
+				let pool_id = PoolId::Dex(lp_currency_id);
+				<orml_rewards::Pallet<T>>::remove_share(who, &pool_id, old.1.saturating_mul_int(old.0));
+				<orml_rewards::Pallet<T>>::add_share(who, &pool_id, new.1.saturating_mul_int(new.0));
+			}
+			BoostTarget::Loans => {
+				if old.1 == new.1 {
+					return;
+				}
+				for (currency_id, base_share) in LoansPools::<T>::iter_prefix(who) {
+					<orml_rewards::Pallet<T>>::set_share(
+						who,
+						&PoolId::Loans(currency_id),
+						new.1.saturating_mul_int(base_share),
+					);
+				}
+			}
+		}
+	}
+
+	fn do_lock_boost(
+		who: &T::AccountId,
+		target: BoostTarget,
+		amount: Balance,
+		duration: T::BlockNumber,
+	) -> DispatchResult {
+		ensure!(!amount.is_zero(), Error::<T>::InvalidAmount);
+		ensure!(
+			!duration.is_zero() && duration <= T::MaxBoostDuration::get(),
+			Error::<T>::InvalidBoostDuration
+		);
+		let currency_id = Self::boost_currency_id(target)?;
+		let now = <frame_system::Pallet<T>>::block_number();
+
+		T::Currency::transfer(currency_id, who, &Self::account_id(), amount)?;
+		BoostLocks::<T>::try_mutate(target, who, |maybe_lock| -> DispatchResult {
+			let (old_amount, old_multiplier, unlock_at) = match maybe_lock.take() {
+				Some(lock) => {
+					BoostExpiries::<T>::remove(lock.unlock_at, (target, who.clone()));
+					(
+						lock.amount,
+						lock.multiplier,
+						lock.unlock_at.max(now.saturating_add(duration)),
 					)
-					.map_err(|e| {
-						log::error!(
-							target: "incentives",
-							"accumulate_reward: failed to accumulate reward to non-existen pool {:?}, reward_currency_id {:?}, reward_amount {:?}: {:?}",
-							pool_id, reward_currency_id, reward_amount, e
+				}
+				None => (Zero::zero(), Rate::one(), now.saturating_add(duration)),
+			};
+
+			let new_amount = old_amount.checked_add(amount).ok_or(ArithmeticError::Overflow)?;
+			let remaining_duration = unlock_at.saturating_sub(now);
+			let new_multiplier = Self::boost_multiplier(target, new_amount, remaining_duration);
+
+			Self::update_boosted_shares(who, target, (old_amount, old_multiplier), (new_amount, new_multiplier));
+			BoostExpiries::<T>::insert(unlock_at, (target, who.clone()), ());
+			*maybe_lock = Some(BoostLock {
+				amount: new_amount,
+				multiplier: new_multiplier,
+				duration: remaining_duration,
+				unlock_at,
+			});
+
+			Self::deposit_event(Event::BoostLocked(
+				who.clone(),
+				target,
+				new_amount,
+				unlock_at,
+				new_multiplier,
+			));
+			Ok(())
+		})
+	}
+
+	fn do_unlock_boost(who: &T::AccountId, target: BoostTarget) -> DispatchResult {
+		let currency_id = Self::boost_currency_id(target)?;
+		let lock = BoostLocks::<T>::take(target, who).ok_or(Error::<T>::BoostLockNotFound)?;
+		let now = <frame_system::Pallet<T>>::block_number();
+		BoostExpiries::<T>::remove(lock.unlock_at, (target, who.clone()));
+
+		// charge penalty for the remaining duration if unlock before expiry
+		let penalty_amount = if now < lock.unlock_at {
+			let remaining_duration: u128 = lock.unlock_at.saturating_sub(now).unique_saturated_into();
+			let duration: u128 = lock.duration.unique_saturated_into();
+			let remaining_ratio = Rate::saturating_from_rational(remaining_duration, duration).min(Rate::one());
+			T::EarlyUnlockPenaltyRate::get()
+				.saturating_mul(remaining_ratio)
+				.saturating_mul_int(lock.amount)
+				.min(lock.amount)
+		} else {
+			Zero::zero()
+		};
+		let unlocked_amount = lock.amount.saturating_sub(penalty_amount);
+
+		Self::update_boosted_shares(who, target, (lock.amount, lock.multiplier), (Zero::zero(), Rate::one()));
+		T::Currency::transfer(currency_id, &Self::account_id(), who, unlocked_amount)?;
+		if !penalty_amount.is_zero() {
+			T::Currency::transfer(
+				currency_id,
+				&Self::account_id(),
+				&T::RewardsSource::get(),
+				penalty_amount,
+			)?;
+		}
+
+		Self::deposit_event(Event::BoostUnlocked(
+			who.clone(),
+			target,
+			unlocked_amount,
+			penalty_amount,
+		));
+		Ok(())
+	}
+
+	// remove the boost of the locks which expire before or at `now`, the locked amount is kept
+	// until unlocked. No more than MaxBoostExpiriesPerBlock expiries and scheduled blocks are
+	// processed, the rest are carried over to the next block by BoostExpiryCursor.
+	fn expire_boosts(now: T::BlockNumber) -> Weight {
+		let mut remaining = T::MaxBoostExpiriesPerBlock::get();
+		let mut block = Self::boost_expiry_cursor().unwrap_or(now);
+		let mut scanned_count: u32 = 0;
+		let mut expired_count: u32 = 0;
+
+		while block <= now && !remaining.is_zero() {
+			remaining -= 1;
+			scanned_count += 1;
+
+			// take one more to know whether the expiries of `block` can be finished
+			let expiries = BoostExpiries::<T>::iter_prefix(block)
+				.take((remaining as usize).saturating_add(1))
+				.map(|(key, _)| key)
+				.collect::<Vec<_>>();
+			let finished = expiries.len() <= remaining as usize;
+
+			for (target, who) in expiries.into_iter().take(remaining as usize) {
+				BoostExpiries::<T>::remove(block, (target, who.clone()));
+				BoostLocks::<T>::mutate(target, &who, |maybe_lock| {
+					if let Some(lock) = maybe_lock {
+						Self::update_boosted_shares(
+							&who,
+							target,
+							(lock.amount, lock.multiplier),
+							(lock.amount, Rate::one()),
 						);
-					});
+						lock.multiplier = Rate::one();
+					}
+				});
+				Self::deposit_event(Event::BoostExpired(who, target));
+				remaining -= 1;
+				expired_count += 1;
+			}
+
+			if !finished {
+				break;
+			}
+			block = block.saturating_add(One::one());
+		}
+
+		if block > now {
+			BoostExpiryCursor::<T>::kill();
+		} else {
+			BoostExpiryCursor::<T>::put(block);
+		}
+
+		let expire_weight = if expired_count.is_zero() {
+			0
+		} else {
+			T::WeightInfo::expire_boosts(expired_count)
+		};
+		expire_weight.saturating_add(T::DbWeight::get().reads_writes(scanned_count.saturating_add(1).into(), 1))
+	}
+
+	/// The staked shares and the pending rewards of all pools for `who`. The pending rewards
+	/// include the unclaimed rewards of orml_rewards and PendingMultiRewards, each item is
+	/// `(reward_currency_id, pending_amount, actual_amount_after_deduction)`.
+	#[allow(clippy::type_complexity)]
+	pub fn pending_rewards(
+		who: &T::AccountId,
+	) -> Vec<(
+		PoolId<T::RelaychainAccountId>,
+		Balance,
+		Vec<(CurrencyId, Balance, Balance)>,
+	)> {
+		orml_rewards::PoolInfos::<T>::iter()
+			.filter_map(|(pool_id, pool_info)| {
+				let (share, withdrawn_rewards) = <orml_rewards::Pallet<T>>::shares_and_withdrawn_rewards(&pool_id, who);
+				let mut pending_multi_rewards = Self::pending_multi_rewards(&pool_id, who);
+
+				// the same as the calculation of orml_rewards::claim_rewards
+				if !share.is_zero() {
+					for (currency_id, (total_reward, total_withdrawn_reward)) in pool_info.rewards {
+						let withdrawn_reward = withdrawn_rewards.get(&currency_id).copied().unwrap_or_default();
+						let reward_to_withdraw = multiply_by_rational(share, total_reward, pool_info.total_shares)
+							.unwrap_or_default()
+							.saturating_sub(withdrawn_reward)
+							.min(total_reward.saturating_sub(total_withdrawn_reward));
+						if !reward_to_withdraw.is_zero() {
+							pending_multi_rewards
+								.entry(currency_id)
+								.and_modify(|current| *current = current.saturating_add(reward_to_withdraw))
+								.or_insert(reward_to_withdraw);
+						}
+					}
+				}
+
+				if share.is_zero() && pending_multi_rewards.is_empty() {
+					return None;
 				}
-				Err(e) => {
-					log::warn!(
+
+				let deduction_rate = Self::claim_reward_deduction_rates(&pool_id);
+				let rewards = pending_multi_rewards
+					.into_iter()
+					.map(|(currency_id, pending_amount)| {
+						let deduction_amount = deduction_rate.saturating_mul_int(pending_amount).min(pending_amount);
+						(
+							currency_id,
+							pending_amount,
+							pending_amount.saturating_sub(deduction_amount),
+						)
+					})
+					.collect();
+				Some((pool_id, share, rewards))
+			})
+			.collect()
+	}
+
+	/// The reward programs which are not finished at `now`, including the ones not started yet.
+	pub fn active_reward_programs(
+		now: T::BlockNumber,
+	) -> Vec<(
+		PoolId<T::RelaychainAccountId>,
+		RewardProgramId,
+		RewardProgram<T::BlockNumber>,
+	)> {
+		RewardPrograms::<T>::iter()
+			.filter(|(_, _, program)| now < program.end && !program.remaining_budget.is_zero())
+			.collect()
+	}
+
+	// accumulate the rewards of the validator proportionally to its insurance, frozen validators
+	// don't get rewards
+	fn accumulate_homa_validator_allowance(
+		validator: &T::RelaychainAccountId,
+		pool_id: &PoolId<T::RelaychainAccountId>,
+	) {
+		if T::ValidatorInsurance::is_frozen(validator) {
+			return;
+		}
+
+		let total_insurance = T::ValidatorInsurance::total_insurance(validator);
+		for (reward_currency_id, rate) in HomaValidatorAllowanceRewardRates::<T>::iter() {
+			let reward_amount = rate.saturating_mul_int(total_insurance);
+			if reward_amount.is_zero() {
+				continue;
+			}
+			Self::transfer_and_accumulate_reward(pool_id, reward_currency_id, reward_amount);
+		}
+	}
+
+	// accumulate incentive rewards of multi currencies and reward programs, return the number of
+	// reward programs of the pool
+	fn accumulate_incentives(pool_id: PoolId<T::RelaychainAccountId>, now: T::BlockNumber) -> u32 {
+		for (reward_currency_id, reward_amount) in IncentiveRewardAmounts::<T>::iter_prefix(&pool_id) {
+			if reward_amount.is_zero() {
+				continue;
+			}
+			Self::transfer_and_accumulate_reward(&pool_id, reward_currency_id, reward_amount);
+		}
+
+		let programs: Vec<(RewardProgramId, RewardProgram<T::BlockNumber>)> =
+			RewardPrograms::<T>::iter_prefix(&pool_id).collect();
+		let program_count = programs.len() as u32;
+		for (program_id, mut program) in programs {
+			if now < program.start {
+				continue;
+			}
+
+			// the budget has been reserved in the module account
+			if now < program.end {
+				let reward_amount = program.amount_per_period.min(program.remaining_budget);
+				match <orml_rewards::Pallet<T>>::accumulate_reward(&pool_id, program.currency_id, reward_amount) {
+					Ok(_) => program.remaining_budget = program.remaining_budget.saturating_sub(reward_amount),
+					Err(e) => log::error!(
 						target: "incentives",
-						"transfer: failed to transfer {:?} {:?} from {:?} to {:?}: {:?}. \
-						This is unexpected but should be safe",
-						reward_amount, reward_currency_id, T::RewardsSource::get(), Self::account_id(), e
-					);
+						"accumulate_reward: failed to accumulate reward program {:?} to pool {:?}: {:?}",
+						program_id, pool_id, e
+					),
 				}
 			}
+
+			if program.remaining_budget.is_zero() || now.saturating_add(T::AccumulatePeriod::get()) >= program.end {
+				Self::finish_reward_program(&pool_id, program_id, program);
+			} else {
+				RewardPrograms::<T>::insert(&pool_id, program_id, program);
+			}
+		}
+
+		program_count
+	}
+
+	// finish the reward programs of the pool which end before the next accumulation without
+	// accumulating them, return the number of reward programs of the pool
+	fn expire_reward_programs(pool_id: PoolId<T::RelaychainAccountId>, now: T::BlockNumber) -> u32 {
+		let programs: Vec<(RewardProgramId, RewardProgram<T::BlockNumber>)> =
+			RewardPrograms::<T>::iter_prefix(&pool_id).collect();
+		let program_count = programs.len() as u32;
+		for (program_id, program) in programs {
+			if now.saturating_add(T::AccumulatePeriod::get()) >= program.end {
+				Self::finish_reward_program(&pool_id, program_id, program);
+			}
+		}
+
+		program_count
+	}
+
+	// remove the reward program and return its remaining budget to RewardsSource
+	fn finish_reward_program(
+		pool_id: &PoolId<T::RelaychainAccountId>,
+		program_id: RewardProgramId,
+		program: RewardProgram<T::BlockNumber>,
+	) {
+		if let Err(e) = T::Currency::transfer(
+			program.currency_id,
+			&Self::account_id(),
+			&T::RewardsSource::get(),
+			program.remaining_budget,
+		) {
+			log::error!(
+				target: "incentives",
+				"transfer: failed to return the remaining budget {:?} of reward program {:?}: {:?}",
+				program.remaining_budget, program_id, e
+			);
+		}
+		RewardPrograms::<T>::remove(pool_id, program_id);
+		Self::deposit_event(Event::RewardProgramFinished(
+			pool_id.clone(),
+			program_id,
+			program.remaining_budget,
+		));
+	}
+
+	// transfer reward from RewardsSource and accumulate it to the pool, return true if succeeded
+	fn transfer_and_accumulate_reward(
+		pool_id: &PoolId<T::RelaychainAccountId>,
+		reward_currency_id: CurrencyId,
+		reward_amount: Balance,
+	) -> bool {
+		let res = T::Currency::transfer(
+			reward_currency_id,
+			&T::RewardsSource::get(),
+			&Self::account_id(),
+			reward_amount,
+		);
+
+		match res {
+			Ok(_) => {
+				let _ = <orml_rewards::Pallet<T>>::accumulate_reward(pool_id, reward_currency_id, reward_amount).map_err(|e| {
+					log::error!(
+						target: "incentives",
+						"accumulate_reward: failed to accumulate reward to non-existen pool {:?}, reward_currency_id {:?}, reward_amount {:?}: {:?}",
+						pool_id, reward_currency_id, reward_amount, e
+					);
+				});
+				true
+			}
+			Err(e) => {
+				log::warn!(
+					target: "incentives",
+					"transfer: failed to transfer {:?} {:?} from {:?} to {:?}: {:?}. \
+					This is unexpected but should be safe",
+					reward_amount, reward_currency_id, T::RewardsSource::get(), Self::account_id(), e
+				);
+				false
+			}
 		}
 	}
 
 	// accumulate DEX saving reward(stable currency) for Dex Pool
-	fn accumulate_dex_saving(lp_currency_id: CurrencyId, pool_id: PoolId) {
+	fn accumulate_dex_saving(lp_currency_id: CurrencyId, pool_id: &PoolId<T::RelaychainAccountId>) {
 		let stable_currency_id = T::StableCurrencyId::get();
-		let dex_saving_reward_rate = Self::dex_saving_reward_rates(&pool_id);
+		let dex_saving_reward_rate = Self::dex_saving_reward_rates(pool_id);
 
 		if !dex_saving_reward_rate.is_zero() {
 			if let Some((currency_id_a, currency_id_b)) = lp_currency_id.split_dex_share_currency_id() {
@@ -484,7 +1493,7 @@ impl<T: Config> Pallet<T> {
 					match res {
 						Ok(_) => {
 							let _ = <orml_rewards::Pallet<T>>::accumulate_reward(
-								&pool_id,
+								pool_id,
 								stable_currency_id,
 								dex_saving_reward_amount,
 							)
@@ -524,8 +1533,14 @@ impl<T: Config> DEXIncentives<T::AccountId, CurrencyId, Balance> for Pallet<T> {
 
 	fn do_withdraw_dex_share(who: &T::AccountId, lp_currency_id: CurrencyId, amount: Balance) -> DispatchResult {
 		ensure!(lp_currency_id.is_dex_share_currency_id(), Error::<T>::InvalidCurrencyId);
+		// the boosted shares of the locked LP token can not be withdrawn
+		let locked_shares = Self::boost_locks(BoostTarget::Dex(lp_currency_id), who)
+			.map_or(Zero::zero(), |lock| lock.multiplier.saturating_mul_int(lock.amount));
 		ensure!(
-			<orml_rewards::Pallet<T>>::shares_and_withdrawn_rewards(&PoolId::Dex(lp_currency_id), &who).0 >= amount,
+			<orml_rewards::Pallet<T>>::shares_and_withdrawn_rewards(&PoolId::Dex(lp_currency_id), &who)
+				.0
+				.saturating_sub(locked_shares)
+				>= amount,
 			Error::<T>::NotEnough,
 		);
 
@@ -550,13 +1565,62 @@ impl<T: Config> Happened<(T::AccountId, CurrencyId, Amount, Balance)> for OnUpda
 			previous_amount.saturating_sub(adjustment_abs)
 		};
 
-		<orml_rewards::Pallet<T>>::set_share(who, &PoolId::Loans(*currency_id), new_share_amount);
+		<orml_rewards::Pallet<T>>::set_share(
+			who,
+			&PoolId::Loans(*currency_id),
+			Pallet::<T>::loans_boost_multiplier(who).saturating_mul_int(new_share_amount),
+		);
+		if new_share_amount.is_zero() {
+			LoansPools::<T>::remove(who, currency_id);
+		} else {
+			LoansPools::<T>::insert(who, currency_id, new_share_amount);
+		}
+	}
+}
+
+/// Set the shares of Pool::HomaIncentive and Pool::StableAsset to the total balance of liquid
+/// currency and StableAsset LP token, called after the balance of `who` changed.
+///
+/// Note: the modules which move these currencies through the tokens module directly, e.g.
+/// StableAsset, must notify this hook too, e.g. by `module_currencies::NotifyBalanceChangedAssets`.
+pub struct OnBalanceChanged<T>(sp_std::marker::PhantomData<T>);
+impl<T: Config> Happened<(T::AccountId, CurrencyId)> for OnBalanceChanged<T> {
+	fn happened(info: &(T::AccountId, CurrencyId)) {
+		let (who, currency_id) = info;
+		let pool_id = if *currency_id == T::LiquidCurrencyId::get() {
+			PoolId::HomaIncentive
+		} else if currency_id.is_stable_asset_pool_token_currency_id() {
+			PoolId::StableAsset(*currency_id)
+		} else {
+			return;
+		};
+		if T::ExcludedShareHolders::contains(who) {
+			return;
+		}
+
+		<orml_rewards::Pallet<T>>::set_share(who, &pool_id, T::Currency::total_balance(*currency_id, who));
+	}
+}
+
+pub struct OnIncreaseGuarantee<T>(sp_std::marker::PhantomData<T>);
+impl<T: Config> Happened<(T::AccountId, T::RelaychainAccountId, Balance)> for OnIncreaseGuarantee<T> {
+	fn happened(info: &(T::AccountId, T::RelaychainAccountId, Balance)) {
+		let (who, validator, increment) = info;
+		<orml_rewards::Pallet<T>>::add_share(who, &PoolId::HomaValidatorAllowance(validator.clone()), *increment);
+	}
+}
+
+pub struct OnDecreaseGuarantee<T>(sp_std::marker::PhantomData<T>);
+impl<T: Config> Happened<(T::AccountId, T::RelaychainAccountId, Balance)> for OnDecreaseGuarantee<T> {
+	fn happened(info: &(T::AccountId, T::RelaychainAccountId, Balance)) {
+		let (who, validator, decrement) = info;
+		<orml_rewards::Pallet<T>>::remove_share(who, &PoolId::HomaValidatorAllowance(validator.clone()), *decrement);
 	}
 }
 
 impl<T: Config> RewardHandler<T::AccountId, CurrencyId> for Pallet<T> {
 	type Balance = Balance;
-	type PoolId = PoolId;
+	type PoolId = PoolId<T::RelaychainAccountId>;
 
 	fn payout(who: &T::AccountId, pool_id: &Self::PoolId, currency_id: CurrencyId, payout_amount: Self::Balance) {
 		if payout_amount.is_zero() {
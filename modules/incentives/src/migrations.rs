@@ -0,0 +1,64 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Config, LoansPools, Pallet, PoolId};
+use frame_support::{
+	log,
+	traits::{Get, StorageVersion},
+	weights::Weight,
+};
+use sp_runtime::traits::Zero;
+
+pub mod v1 {
+	use super::*;
+
+	/// Record the base shares of the accounts in Pool::Loans, so that the boost of Pool::Loans
+	/// only updates the pools of the account. There is no boost before v1, the base shares are
+	/// the shares. It only runs once, if the storage version of the pallet is below v1.
+	pub fn migrate<T: Config>() -> Weight {
+		if StorageVersion::get::<Pallet<T>>() >= 1 {
+			return T::DbWeight::get().reads(1);
+		}
+
+		log::info!(target: "incentives", "Migrating incentives v1");
+
+		// the storage version
+		let mut reads: u64 = 1;
+		let mut writes: u64 = 0;
+
+		for (pool_id, _) in orml_rewards::PoolInfos::<T>::iter() {
+			reads += 1;
+			if let PoolId::Loans(currency_id) = pool_id {
+				for (who, (share, _)) in orml_rewards::ShareAndWithdrawnReward::<T>::iter_prefix(&pool_id) {
+					reads += 1;
+					if !share.is_zero() {
+						LoansPools::<T>::insert(who, currency_id, share);
+						writes += 1;
+					}
+				}
+			}
+		}
+
+		StorageVersion::new(1).put::<Pallet<T>>();
+		writes += 1;
+
+		log::info!(target: "incentives", "Completed incentives migration to v1");
+
+		T::DbWeight::get().reads_writes(reads, writes)
+	}
+}
@@ -37,6 +37,7 @@ pub use support::{CDPTreasury, DEXManager, Price, Ratio};
 
 pub type AccountId = AccountId32;
 pub type BlockNumber = u64;
+pub type PoolId = crate::PoolId<AccountId>;
 
 pub const ACA: CurrencyId = CurrencyId::Token(TokenSymbol::ACA);
 pub const AUSD: CurrencyId = CurrencyId::Token(TokenSymbol::AUSD);
@@ -47,6 +48,7 @@ pub const BTC_AUSD_LP: CurrencyId =
 	CurrencyId::DexShare(DexShare::Token(TokenSymbol::RENBTC), DexShare::Token(TokenSymbol::AUSD));
 pub const DOT_AUSD_LP: CurrencyId =
 	CurrencyId::DexShare(DexShare::Token(TokenSymbol::DOT), DexShare::Token(TokenSymbol::AUSD));
+pub const STABLE_ASSET_LP: CurrencyId = CurrencyId::StableAssetPoolToken(0);
 
 mod incentives {
 	pub use super::super::*;
@@ -58,6 +60,7 @@ ord_parameter_types! {
 	pub const VAULT: AccountId = IncentivesModule::account_id();
 	pub const RewardsSource: AccountId = AccountId::from([3u8; 32]);
 	pub const ROOT: AccountId = AccountId32::new([255u8; 32]);
+	pub const VALIDATOR: AccountId = AccountId::from([4u8; 32]);
 }
 
 parameter_types! {
@@ -166,6 +169,8 @@ impl DEXManager<AccountId, CurrencyId, Balance> for MockDEX {
 			(AUSD, DOT) => (400, 100),
 			(BTC, AUSD) => (100, 500),
 			(DOT, AUSD) => (100, 400),
+			(ACA, AUSD) => (1000, 100),
+			(AUSD, ACA) => (100, 1000),
 			_ => (0, 0),
 		}
 	}
@@ -174,8 +179,12 @@ impl DEXManager<AccountId, CurrencyId, Balance> for MockDEX {
 		unimplemented!()
 	}
 
-	fn get_swap_target_amount(_: &[CurrencyId], _: Balance) -> Option<Balance> {
-		unimplemented!()
+	fn get_swap_target_amount(path: &[CurrencyId], supply_amount: Balance) -> Option<Balance> {
+		// swap at the price of the liquidity pool, ignore the fee and price impact
+		match Self::get_liquidity_pool(path[0], path[1]) {
+			(0, _) | (_, 0) => None,
+			(supply_pool, target_pool) => Some(supply_amount * target_pool / supply_pool),
+		}
 	}
 
 	fn get_swap_supply_amount(_: &[CurrencyId], _: Balance) -> Option<Balance> {
@@ -183,12 +192,16 @@ impl DEXManager<AccountId, CurrencyId, Balance> for MockDEX {
 	}
 
 	fn swap_with_exact_supply(
-		_: &AccountId,
-		_: &[CurrencyId],
-		_: Balance,
-		_: Balance,
+		who: &AccountId,
+		path: &[CurrencyId],
+		supply_amount: Balance,
+		min_target_amount: Balance,
 	) -> sp_std::result::Result<Balance, DispatchError> {
-		unimplemented!()
+		let target_amount = Self::get_swap_target_amount(path, supply_amount).ok_or("invalid swap path")?;
+		ensure!(target_amount >= min_target_amount, "insufficient target amount");
+		TokensModule::withdraw(path[0], who, supply_amount)?;
+		TokensModule::deposit(path[path.len() - 1], who, target_amount)?;
+		Ok(target_amount)
 	}
 
 	fn swap_with_exact_target(
@@ -201,15 +214,27 @@ impl DEXManager<AccountId, CurrencyId, Balance> for MockDEX {
 	}
 
 	fn add_liquidity(
-		_: &AccountId,
-		_: CurrencyId,
-		_: CurrencyId,
-		_: Balance,
-		_: Balance,
-		_: Balance,
+		who: &AccountId,
+		currency_id_a: CurrencyId,
+		currency_id_b: CurrencyId,
+		max_amount_a: Balance,
+		max_amount_b: Balance,
+		min_share_increment: Balance,
 		_: bool,
 	) -> DispatchResult {
-		unimplemented!()
+		// the share increment is the amount of currency_id_a added
+		let (pool_a, pool_b) = Self::get_liquidity_pool(currency_id_a, currency_id_b);
+		let (amount_a, amount_b) = if max_amount_a * pool_b <= max_amount_b * pool_a {
+			(max_amount_a, max_amount_a * pool_b / pool_a)
+		} else {
+			(max_amount_b * pool_a / pool_b, max_amount_b)
+		};
+		ensure!(amount_a >= min_share_increment, "unacceptable share increment");
+		let lp_currency_id = CurrencyId::join_dex_share_currency_id(currency_id_a, currency_id_b).unwrap();
+		TokensModule::withdraw(currency_id_a, who, amount_a)?;
+		TokensModule::withdraw(currency_id_b, who, amount_b)?;
+		TokensModule::deposit(lp_currency_id, who, amount_a)?;
+		Ok(())
 	}
 
 	fn remove_liquidity(
@@ -227,6 +252,25 @@ impl DEXManager<AccountId, CurrencyId, Balance> for MockDEX {
 
 thread_local! {
 	static IS_SHUTDOWN: RefCell<bool> = RefCell::new(false);
+	static ACA_PRICE: RefCell<Option<Price>> = RefCell::new(Some(Price::saturating_from_rational(1, 10)));
+	static IS_VALIDATOR_FROZEN: RefCell<bool> = RefCell::new(false);
+}
+
+pub fn mock_aca_price(price: Option<Price>) {
+	ACA_PRICE.with(|v| *v.borrow_mut() = price);
+}
+
+pub struct MockPriceSource;
+impl PriceProvider<CurrencyId> for MockPriceSource {
+	fn get_price(currency_id: CurrencyId) -> Option<Price> {
+		match currency_id {
+			ACA => ACA_PRICE.with(|v| *v.borrow()),
+			AUSD => Some(Price::saturating_from_integer(1)),
+			BTC => Some(Price::saturating_from_integer(5)),
+			DOT => Some(Price::saturating_from_integer(4)),
+			_ => None,
+		}
+	}
 }
 
 pub fn mock_shutdown() {
@@ -240,6 +284,21 @@ impl EmergencyShutdown for MockEmergencyShutdown {
 	}
 }
 
+pub fn mock_freeze_validator() {
+	IS_VALIDATOR_FROZEN.with(|v| *v.borrow_mut() = true)
+}
+
+pub struct MockValidatorInsurance;
+impl HomaValidatorInsurance<AccountId, Balance> for MockValidatorInsurance {
+	fn total_insurance(validator: &AccountId) -> Balance {
+		RewardsModule::pool_infos(PoolId::HomaValidatorAllowance(validator.clone())).total_shares
+	}
+
+	fn is_frozen(_validator: &AccountId) -> bool {
+		IS_VALIDATOR_FROZEN.with(|v| *v.borrow_mut())
+	}
+}
+
 impl orml_rewards::Config for Runtime {
 	type Share = Balance;
 	type Balance = Balance;
@@ -252,14 +311,31 @@ parameter_types! {
 	pub const AccumulatePeriod: BlockNumber = 10;
 	pub const StableCurrencyId: CurrencyId = AUSD;
 	pub const IncentivesPalletId: PalletId = PalletId(*b"aca/inct");
+	pub const NativeCurrencyId: CurrencyId = ACA;
+	pub const LiquidCurrencyId: CurrencyId = LDOT;
+	pub const MaxBoostDuration: BlockNumber = 100;
+	pub MaxBoostMultiplier: Rate = Rate::saturating_from_integer(3);
+	pub const LoansBoostFullAmount: Balance = 1000;
+	pub EarlyUnlockPenaltyRate: Rate = Rate::saturating_from_rational(1, 2);
+	pub const MaxBoostExpiriesPerBlock: u32 = 3;
+	pub const MaxRewardProgramsPerPool: u32 = 2;
+	pub MaxSwapSlippageCompareToOracle: Ratio = Ratio::saturating_from_rational(10, 100);
 }
 
 ord_parameter_types! {
 	pub const Root: AccountId = ROOT::get();
 }
 
+pub struct MockExcludedShareHolders;
+impl Contains<AccountId> for MockExcludedShareHolders {
+	fn contains(a: &AccountId) -> bool {
+		*a == VAULT::get()
+	}
+}
+
 impl Config for Runtime {
 	type Event = Event;
+	type RelaychainAccountId = AccountId;
 	type RewardsSource = RewardsSource;
 	type AccumulatePeriod = AccumulatePeriod;
 	type StableCurrencyId = StableCurrencyId;
@@ -267,8 +343,20 @@ impl Config for Runtime {
 	type CDPTreasury = MockCDPTreasury;
 	type Currency = TokensModule;
 	type DEX = MockDEX;
+	type PriceSource = MockPriceSource;
+	type MaxSwapSlippageCompareToOracle = MaxSwapSlippageCompareToOracle;
 	type EmergencyShutdown = MockEmergencyShutdown;
 	type PalletId = IncentivesPalletId;
+	type LiquidCurrencyId = LiquidCurrencyId;
+	type ExcludedShareHolders = MockExcludedShareHolders;
+	type ValidatorInsurance = MockValidatorInsurance;
+	type NativeCurrencyId = NativeCurrencyId;
+	type MaxBoostDuration = MaxBoostDuration;
+	type MaxBoostMultiplier = MaxBoostMultiplier;
+	type LoansBoostFullAmount = LoansBoostFullAmount;
+	type EarlyUnlockPenaltyRate = EarlyUnlockPenaltyRate;
+	type MaxBoostExpiriesPerBlock = MaxBoostExpiriesPerBlock;
+	type MaxRewardProgramsPerPool = MaxRewardProgramsPerPool;
 	type WeightInfo = ();
 }
 
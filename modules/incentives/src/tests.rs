@@ -21,8 +21,11 @@
 #![cfg(test)]
 
 use super::*;
-use frame_support::{assert_noop, assert_ok};
-use mock::{Event, *};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{tokens::fungibles, StorageVersion},
+};
+use mock::{Event, PoolId, *};
 use orml_rewards::PoolInfo;
 use orml_traits::MultiCurrency;
 use sp_runtime::{traits::BadOrigin, FixedPointNumber};
@@ -135,6 +138,118 @@ fn withdraw_dex_share_works() {
 	});
 }
 
+#[test]
+fn on_balance_changed_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(TokensModule::deposit(LDOT, &ALICE::get(), 10000));
+		OnBalanceChanged::<Runtime>::happened(&(ALICE::get(), LDOT));
+		assert_eq!(
+			RewardsModule::shares_and_withdrawn_rewards(PoolId::HomaIncentive, ALICE::get()),
+			(10000, Default::default())
+		);
+
+		assert_ok!(TokensModule::transfer(LDOT, &ALICE::get(), &BOB::get(), 8000));
+		OnBalanceChanged::<Runtime>::happened(&(ALICE::get(), LDOT));
+		OnBalanceChanged::<Runtime>::happened(&(BOB::get(), LDOT));
+		assert_eq!(
+			RewardsModule::shares_and_withdrawn_rewards(PoolId::HomaIncentive, ALICE::get()),
+			(2000, Default::default())
+		);
+		assert_eq!(
+			RewardsModule::shares_and_withdrawn_rewards(PoolId::HomaIncentive, BOB::get()),
+			(8000, Default::default())
+		);
+
+		// StableAsset LP token
+		assert_ok!(TokensModule::deposit(STABLE_ASSET_LP, &BOB::get(), 10000));
+		OnBalanceChanged::<Runtime>::happened(&(BOB::get(), STABLE_ASSET_LP));
+		assert_eq!(
+			RewardsModule::pool_infos(PoolId::StableAsset(STABLE_ASSET_LP)),
+			PoolInfo {
+				total_shares: 10000,
+				..Default::default()
+			}
+		);
+
+		// other currencies don't get shares
+		assert_ok!(TokensModule::deposit(DOT, &ALICE::get(), 10000));
+		OnBalanceChanged::<Runtime>::happened(&(ALICE::get(), DOT));
+		assert_eq!(
+			RewardsModule::shares_and_withdrawn_rewards(PoolId::StableAsset(DOT), ALICE::get()),
+			(0, Default::default())
+		);
+
+		// excluded holders don't get shares
+		assert_ok!(TokensModule::deposit(LDOT, &VAULT::get(), 10000));
+		OnBalanceChanged::<Runtime>::happened(&(VAULT::get(), LDOT));
+		assert_eq!(
+			RewardsModule::shares_and_withdrawn_rewards(PoolId::HomaIncentive, VAULT::get()),
+			(0, Default::default())
+		);
+		assert_eq!(
+			RewardsModule::pool_infos(PoolId::HomaIncentive),
+			PoolInfo {
+				total_shares: 10000,
+				..Default::default()
+			}
+		);
+	});
+}
+
+#[test]
+fn stable_asset_shares_follow_mint_and_redeem() {
+	ExtBuilder::default().build().execute_with(|| {
+		// the adapter StableAsset moves the pool tokens through
+		type Assets = module_currencies::NotifyBalanceChangedAssets<TokensModule, OnBalanceChanged<Runtime>>;
+
+		// mint
+		assert_ok!(<Assets as fungibles::Mutate<_>>::mint_into(
+			STABLE_ASSET_LP,
+			&ALICE::get(),
+			10000
+		));
+		assert_eq!(
+			RewardsModule::shares_and_withdrawn_rewards(PoolId::StableAsset(STABLE_ASSET_LP), ALICE::get()),
+			(10000, Default::default())
+		);
+
+		// redeem
+		assert_ok!(<Assets as fungibles::Mutate<_>>::burn_from(
+			STABLE_ASSET_LP,
+			&ALICE::get(),
+			4000
+		));
+		assert_eq!(
+			RewardsModule::shares_and_withdrawn_rewards(PoolId::StableAsset(STABLE_ASSET_LP), ALICE::get()),
+			(6000, Default::default())
+		);
+
+		// transfer
+		assert_ok!(<Assets as fungibles::Transfer<_>>::transfer(
+			STABLE_ASSET_LP,
+			&ALICE::get(),
+			&BOB::get(),
+			1000,
+			false
+		));
+		assert_eq!(
+			RewardsModule::shares_and_withdrawn_rewards(PoolId::StableAsset(STABLE_ASSET_LP), ALICE::get()),
+			(5000, Default::default())
+		);
+		assert_eq!(
+			RewardsModule::shares_and_withdrawn_rewards(PoolId::StableAsset(STABLE_ASSET_LP), BOB::get()),
+			(1000, Default::default())
+		);
+		assert_eq!(
+			RewardsModule::pool_infos(PoolId::StableAsset(STABLE_ASSET_LP)),
+			PoolInfo {
+				total_shares: 6000,
+				..Default::default()
+			}
+		);
+	});
+}
+
 #[test]
 fn update_incentive_rewards_works() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -223,6 +338,225 @@ fn update_incentive_rewards_works() {
 	});
 }
 
+#[test]
+fn add_and_remove_reward_program_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(5);
+		let pool_id = PoolId::Loans(BTC);
+
+		assert_noop!(
+			IncentivesModule::add_reward_program(Origin::signed(ALICE::get()), pool_id.clone(), ACA, 10, 40, 100),
+			BadOrigin
+		);
+		// the budget is not enough
+		assert_noop!(
+			IncentivesModule::add_reward_program(Origin::signed(ROOT::get()), pool_id.clone(), ACA, 10, 40, 100),
+			orml_tokens::Error::<Runtime>::BalanceTooLow
+		);
+		assert_ok!(TokensModule::deposit(ACA, &RewardsSource::get(), 10000));
+		assert_noop!(
+			IncentivesModule::add_reward_program(Origin::signed(ROOT::get()), PoolId::Dex(BTC), ACA, 10, 40, 100),
+			Error::<Runtime>::InvalidPoolId
+		);
+		assert_noop!(
+			IncentivesModule::add_reward_program(Origin::signed(ROOT::get()), pool_id.clone(), ACA, 40, 40, 100),
+			Error::<Runtime>::InvalidRewardProgram
+		);
+		assert_noop!(
+			IncentivesModule::add_reward_program(Origin::signed(ROOT::get()), pool_id.clone(), ACA, 1, 40, 100),
+			Error::<Runtime>::InvalidRewardProgram
+		);
+		assert_noop!(
+			IncentivesModule::add_reward_program(Origin::signed(ROOT::get()), pool_id.clone(), ACA, 10, 40, 0),
+			Error::<Runtime>::InvalidRewardProgram
+		);
+		// no accumulation period in [11, 19)
+		assert_noop!(
+			IncentivesModule::add_reward_program(Origin::signed(ROOT::get()), pool_id.clone(), ACA, 11, 19, 100),
+			Error::<Runtime>::InvalidRewardProgram
+		);
+
+		assert_ok!(IncentivesModule::add_reward_program(
+			Origin::signed(ROOT::get()),
+			pool_id.clone(),
+			ACA,
+			10,
+			40,
+			100
+		));
+		System::assert_last_event(Event::IncentivesModule(crate::Event::RewardProgramAdded(
+			pool_id.clone(),
+			0,
+			ACA,
+			10,
+			40,
+			100,
+		)));
+		// accumulate at block 10, 20 and 30
+		assert_eq!(
+			IncentivesModule::reward_programs(&pool_id, 0),
+			Some(RewardProgram {
+				currency_id: ACA,
+				start: 10,
+				end: 40,
+				total_budget: 100,
+				remaining_budget: 100,
+				amount_per_period: 34,
+			})
+		);
+		assert_eq!(IncentivesModule::next_reward_program_id(), 1);
+		assert_eq!(IncentivesModule::active_reward_programs(5).len(), 1);
+		assert_eq!(IncentivesModule::active_reward_programs(40).len(), 0);
+		// the budget is reserved
+		assert_eq!(TokensModule::free_balance(ACA, &RewardsSource::get()), 9900);
+		assert_eq!(TokensModule::free_balance(ACA, &VAULT::get()), 100);
+
+		assert_ok!(IncentivesModule::add_reward_program(
+			Origin::signed(ROOT::get()),
+			pool_id.clone(),
+			ACA,
+			10,
+			40,
+			200
+		));
+		assert_noop!(
+			IncentivesModule::add_reward_program(Origin::signed(ROOT::get()), pool_id.clone(), ACA, 10, 40, 100),
+			Error::<Runtime>::TooManyRewardPrograms
+		);
+
+		assert_noop!(
+			IncentivesModule::remove_reward_program(Origin::signed(ROOT::get()), pool_id.clone(), 2),
+			Error::<Runtime>::RewardProgramNotFound
+		);
+		assert_ok!(IncentivesModule::remove_reward_program(
+			Origin::signed(ROOT::get()),
+			pool_id.clone(),
+			0
+		));
+		System::assert_last_event(Event::IncentivesModule(crate::Event::RewardProgramRemoved(
+			pool_id.clone(),
+			0,
+			100,
+		)));
+		assert_eq!(IncentivesModule::reward_programs(&pool_id, 0), None);
+		// the remaining budget is returned
+		assert_eq!(TokensModule::free_balance(ACA, &RewardsSource::get()), 9800);
+		assert_eq!(TokensModule::free_balance(ACA, &VAULT::get()), 200);
+	});
+}
+
+#[test]
+fn accumulate_reward_programs_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let pool_id = PoolId::Loans(BTC);
+		assert_ok!(TokensModule::deposit(ACA, &RewardsSource::get(), 10000));
+		RewardsModule::add_share(&ALICE::get(), &pool_id, 100);
+
+		assert_ok!(IncentivesModule::add_reward_program(
+			Origin::signed(ROOT::get()),
+			pool_id.clone(),
+			ACA,
+			10,
+			40,
+			100
+		));
+		assert_ok!(IncentivesModule::add_reward_program(
+			Origin::signed(ROOT::get()),
+			pool_id.clone(),
+			ACA,
+			20,
+			30,
+			50
+		));
+		assert_eq!(TokensModule::free_balance(ACA, &VAULT::get()), 150);
+		assert_eq!(TokensModule::free_balance(ACA, &RewardsSource::get()), 9850);
+
+		IncentivesModule::on_initialize(10);
+		assert_eq!(
+			RewardsModule::pool_infos(pool_id.clone()).rewards.get(&ACA),
+			Some(&(34, 0))
+		);
+		assert_eq!(
+			IncentivesModule::reward_programs(&pool_id, 0).map(|p| p.remaining_budget),
+			Some(66)
+		);
+		assert_eq!(
+			IncentivesModule::reward_programs(&pool_id, 1).map(|p| p.remaining_budget),
+			Some(50)
+		);
+
+		IncentivesModule::on_initialize(20);
+		assert_eq!(
+			RewardsModule::pool_infos(pool_id.clone()).rewards.get(&ACA),
+			Some(&(118, 0))
+		);
+		System::assert_has_event(Event::IncentivesModule(crate::Event::RewardProgramFinished(
+			pool_id.clone(),
+			1,
+			0,
+		)));
+		assert_eq!(IncentivesModule::reward_programs(&pool_id, 1), None);
+
+		// the last period accumulates the remaining budget only
+		IncentivesModule::on_initialize(30);
+		System::assert_last_event(Event::IncentivesModule(crate::Event::RewardProgramFinished(
+			pool_id.clone(),
+			0,
+			0,
+		)));
+		assert_eq!(IncentivesModule::reward_programs(&pool_id, 0), None);
+		assert_eq!(
+			RewardsModule::pool_infos(pool_id.clone()),
+			PoolInfo {
+				total_shares: 100,
+				rewards: vec![(ACA, (150, 0))].into_iter().collect(),
+			}
+		);
+
+		IncentivesModule::on_initialize(40);
+		assert_eq!(TokensModule::free_balance(ACA, &VAULT::get()), 150);
+		assert_eq!(TokensModule::free_balance(ACA, &RewardsSource::get()), 9850);
+	});
+}
+
+#[test]
+fn reward_programs_of_empty_pool_expire() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let pool_id = PoolId::Loans(DOT);
+		assert_ok!(TokensModule::deposit(ACA, &RewardsSource::get(), 10000));
+		assert_ok!(IncentivesModule::add_reward_program(
+			Origin::signed(ROOT::get()),
+			pool_id.clone(),
+			ACA,
+			10,
+			30,
+			100
+		));
+		assert_eq!(TokensModule::free_balance(ACA, &RewardsSource::get()), 9900);
+
+		// no shares, nothing is accumulated
+		IncentivesModule::on_initialize(10);
+		assert_eq!(RewardsModule::pool_infos(pool_id.clone()), PoolInfo::default());
+		assert_eq!(
+			IncentivesModule::reward_programs(&pool_id, 0).map(|p| p.remaining_budget),
+			Some(100)
+		);
+
+		// the program still ends and returns the whole budget
+		IncentivesModule::on_initialize(20);
+		System::assert_last_event(Event::IncentivesModule(crate::Event::RewardProgramFinished(
+			pool_id.clone(),
+			0,
+			100,
+		)));
+		assert_eq!(IncentivesModule::reward_programs(&pool_id, 0), None);
+		assert_eq!(TokensModule::free_balance(ACA, &VAULT::get()), 0);
+		assert_eq!(TokensModule::free_balance(ACA, &RewardsSource::get()), 10000);
+	});
+}
+
 #[test]
 fn update_dex_saving_rewards_works() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -492,39 +826,568 @@ fn on_update_loan_works() {
 }
 
 #[test]
-fn payout_works() {
+fn lock_boost_for_dex_works() {
 	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TokensModule::deposit(BTC_AUSD_LP, &ALICE::get(), 10000));
+
+		assert_noop!(
+			IncentivesModule::lock_boost(Origin::signed(ALICE::get()), BoostTarget::Dex(BTC_AUSD_LP), 0, 50),
+			Error::<Runtime>::InvalidAmount,
+		);
+		assert_noop!(
+			IncentivesModule::lock_boost(Origin::signed(ALICE::get()), BoostTarget::Dex(BTC_AUSD_LP), 100, 0),
+			Error::<Runtime>::InvalidBoostDuration,
+		);
+		assert_noop!(
+			IncentivesModule::lock_boost(Origin::signed(ALICE::get()), BoostTarget::Dex(BTC_AUSD_LP), 100, 101),
+			Error::<Runtime>::InvalidBoostDuration,
+		);
+		assert_noop!(
+			IncentivesModule::lock_boost(Origin::signed(ALICE::get()), BoostTarget::Dex(BTC), 100, 50),
+			Error::<Runtime>::InvalidCurrencyId,
+		);
+
+		assert_ok!(IncentivesModule::lock_boost(
+			Origin::signed(ALICE::get()),
+			BoostTarget::Dex(BTC_AUSD_LP),
+			100,
+			50
+		));
+		System::assert_last_event(Event::IncentivesModule(crate::Event::BoostLocked(
+			ALICE::get(),
+			BoostTarget::Dex(BTC_AUSD_LP),
+			100,
+			51,
+			Rate::saturating_from_integer(2),
+		)));
+		assert_eq!(TokensModule::free_balance(BTC_AUSD_LP, &ALICE::get()), 9900);
 		assert_eq!(
-			IncentivesModule::pending_multi_rewards(PoolId::Loans(BTC), ALICE::get()),
-			BTreeMap::default()
+			TokensModule::free_balance(BTC_AUSD_LP, &IncentivesModule::account_id()),
+			100
+		);
+		assert_eq!(
+			RewardsModule::shares_and_withdrawn_rewards(PoolId::Dex(BTC_AUSD_LP), ALICE::get()),
+			(200, Default::default())
 		);
 
-		IncentivesModule::payout(&ALICE::get(), &PoolId::Loans(BTC), ACA, 1000);
+		// lock more with shorter duration will not shorten the lock
+		assert_ok!(IncentivesModule::lock_boost(
+			Origin::signed(ALICE::get()),
+			BoostTarget::Dex(BTC_AUSD_LP),
+			100,
+			10
+		));
 		assert_eq!(
-			IncentivesModule::pending_multi_rewards(PoolId::Loans(BTC), ALICE::get()),
-			vec![(ACA, 1000)].into_iter().collect()
+			IncentivesModule::boost_locks(BoostTarget::Dex(BTC_AUSD_LP), ALICE::get()),
+			Some(BoostLock {
+				amount: 200,
+				multiplier: Rate::saturating_from_integer(2),
+				duration: 50,
+				unlock_at: 51,
+			})
+		);
+		assert_eq!(
+			IncentivesModule::boost_expiries(51, (BoostTarget::Dex(BTC_AUSD_LP), ALICE::get())),
+			Some(())
+		);
+		assert_eq!(
+			RewardsModule::shares_and_withdrawn_rewards(PoolId::Dex(BTC_AUSD_LP), ALICE::get()),
+			(400, Default::default())
 		);
 
-		IncentivesModule::payout(&ALICE::get(), &PoolId::Loans(BTC), ACA, 1000);
+		// the boosted shares of locked LP can not be withdrawn
+		assert_ok!(IncentivesModule::deposit_dex_share(
+			Origin::signed(ALICE::get()),
+			BTC_AUSD_LP,
+			100
+		));
+		assert_noop!(
+			IncentivesModule::withdraw_dex_share(Origin::signed(ALICE::get()), BTC_AUSD_LP, 101),
+			Error::<Runtime>::NotEnough,
+		);
+		assert_ok!(IncentivesModule::withdraw_dex_share(
+			Origin::signed(ALICE::get()),
+			BTC_AUSD_LP,
+			100
+		));
+
+		// the boost is removed after expiry, but LP is still locked
+		IncentivesModule::on_initialize(51);
+		System::assert_last_event(Event::IncentivesModule(crate::Event::BoostExpired(
+			ALICE::get(),
+			BoostTarget::Dex(BTC_AUSD_LP),
+		)));
 		assert_eq!(
-			IncentivesModule::pending_multi_rewards(PoolId::Loans(BTC), ALICE::get()),
-			vec![(ACA, 2000)].into_iter().collect()
+			RewardsModule::shares_and_withdrawn_rewards(PoolId::Dex(BTC_AUSD_LP), ALICE::get()),
+			(200, Default::default())
+		);
+		assert_eq!(
+			IncentivesModule::boost_expiries(51, (BoostTarget::Dex(BTC_AUSD_LP), ALICE::get())),
+			None
+		);
+
+		// unlock after expiry without penalty
+		System::set_block_number(52);
+		assert_ok!(IncentivesModule::unlock_boost(
+			Origin::signed(ALICE::get()),
+			BoostTarget::Dex(BTC_AUSD_LP)
+		));
+		System::assert_last_event(Event::IncentivesModule(crate::Event::BoostUnlocked(
+			ALICE::get(),
+			BoostTarget::Dex(BTC_AUSD_LP),
+			200,
+			0,
+		)));
+		assert_eq!(TokensModule::free_balance(BTC_AUSD_LP, &ALICE::get()), 10000);
+		assert_eq!(
+			RewardsModule::shares_and_withdrawn_rewards(PoolId::Dex(BTC_AUSD_LP), ALICE::get()),
+			Default::default(),
+		);
+		assert_eq!(
+			IncentivesModule::boost_locks(BoostTarget::Dex(BTC_AUSD_LP), ALICE::get()),
+			None
 		);
 	});
 }
 
 #[test]
-fn claim_rewards_works() {
+fn unlock_boost_before_expiry_works() {
 	ExtBuilder::default().build().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(TokensModule::deposit(ACA, &VAULT::get(), 10000));
-		assert_ok!(TokensModule::deposit(AUSD, &VAULT::get(), 10000));
-		assert_ok!(TokensModule::deposit(LDOT, &VAULT::get(), 10000));
-		assert_ok!(IncentivesModule::update_claim_reward_deduction_rates(
-			Origin::signed(ROOT::get()),
-			vec![
-				(PoolId::Dex(BTC_AUSD_LP), Rate::saturating_from_rational(50, 100)),
-				(PoolId::Loans(BTC), Rate::saturating_from_rational(90, 100)),
+		assert_ok!(TokensModule::deposit(BTC_AUSD_LP, &ALICE::get(), 10000));
+
+		assert_noop!(
+			IncentivesModule::unlock_boost(Origin::signed(ALICE::get()), BoostTarget::Dex(BTC_AUSD_LP)),
+			Error::<Runtime>::BoostLockNotFound,
+		);
+
+		assert_ok!(IncentivesModule::lock_boost(
+			Origin::signed(ALICE::get()),
+			BoostTarget::Dex(BTC_AUSD_LP),
+			100,
+			100
+		));
+		assert_eq!(
+			RewardsModule::shares_and_withdrawn_rewards(PoolId::Dex(BTC_AUSD_LP), ALICE::get()),
+			(300, Default::default())
+		);
+
+		// penalty = 100 * 50% * (101 - 51) / 100
+		System::set_block_number(51);
+		assert_ok!(IncentivesModule::unlock_boost(
+			Origin::signed(ALICE::get()),
+			BoostTarget::Dex(BTC_AUSD_LP)
+		));
+		System::assert_last_event(Event::IncentivesModule(crate::Event::BoostUnlocked(
+			ALICE::get(),
+			BoostTarget::Dex(BTC_AUSD_LP),
+			75,
+			25,
+		)));
+		assert_eq!(TokensModule::free_balance(BTC_AUSD_LP, &ALICE::get()), 9975);
+		assert_eq!(TokensModule::free_balance(BTC_AUSD_LP, &RewardsSource::get()), 25);
+		assert_eq!(
+			RewardsModule::shares_and_withdrawn_rewards(PoolId::Dex(BTC_AUSD_LP), ALICE::get()),
+			Default::default(),
+		);
+		assert_eq!(
+			IncentivesModule::boost_expiries(101, (BoostTarget::Dex(BTC_AUSD_LP), ALICE::get())),
+			None
+		);
+	});
+}
+
+#[test]
+fn lock_boost_for_loans_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TokensModule::deposit(ACA, &ALICE::get(), 10000));
+		OnUpdateLoan::<Runtime>::happened(&(ALICE::get(), BTC, 1000, 0));
+		OnUpdateLoan::<Runtime>::happened(&(ALICE::get(), DOT, 100, 0));
+
+		// half of LoansBoostFullAmount gets half of the boost
+		assert_ok!(IncentivesModule::lock_boost(
+			Origin::signed(ALICE::get()),
+			BoostTarget::Loans,
+			500,
+			100
+		));
+		System::assert_last_event(Event::IncentivesModule(crate::Event::BoostLocked(
+			ALICE::get(),
+			BoostTarget::Loans,
+			500,
+			101,
+			Rate::saturating_from_integer(2),
+		)));
+		assert_eq!(TokensModule::free_balance(ACA, &ALICE::get()), 9500);
+		assert_eq!(
+			RewardsModule::shares_and_withdrawn_rewards(PoolId::Loans(BTC), ALICE::get()),
+			(2000, Default::default())
+		);
+		assert_eq!(
+			RewardsModule::shares_and_withdrawn_rewards(PoolId::Loans(DOT), ALICE::get()),
+			(200, Default::default())
+		);
+
+		OnUpdateLoan::<Runtime>::happened(&(ALICE::get(), BTC, 500, 1000));
+		assert_eq!(
+			RewardsModule::shares_and_withdrawn_rewards(PoolId::Loans(BTC), ALICE::get()),
+			(3000, Default::default())
+		);
+
+		IncentivesModule::on_initialize(101);
+		assert_eq!(
+			RewardsModule::shares_and_withdrawn_rewards(PoolId::Loans(BTC), ALICE::get()),
+			(1500, Default::default())
+		);
+		assert_eq!(
+			RewardsModule::shares_and_withdrawn_rewards(PoolId::Loans(DOT), ALICE::get()),
+			(100, Default::default())
+		);
+	});
+}
+
+#[test]
+fn expire_boosts_carry_over_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let accounts = (10u8..15).map(|i| AccountId::from([i; 32])).collect::<Vec<_>>();
+		for who in accounts.iter() {
+			assert_ok!(TokensModule::deposit(BTC_AUSD_LP, who, 100));
+			assert_ok!(IncentivesModule::lock_boost(
+				Origin::signed(who.clone()),
+				BoostTarget::Dex(BTC_AUSD_LP),
+				100,
+				10
+			));
+		}
+		let expired_count = || {
+			accounts
+				.iter()
+				.filter(|who| {
+					IncentivesModule::boost_locks(BoostTarget::Dex(BTC_AUSD_LP), who)
+						.map_or(false, |lock| lock.multiplier == Rate::one())
+				})
+				.count()
+		};
+		assert_eq!(
+			RewardsModule::pool_infos(PoolId::Dex(BTC_AUSD_LP)).total_shares,
+			5 * 120
+		);
+
+		// MaxBoostExpiriesPerBlock is 3, scanning a block counts as one
+		IncentivesModule::on_initialize(10);
+		assert_eq!(expired_count(), 0);
+		assert_eq!(IncentivesModule::boost_expiry_cursor(), None);
+
+		IncentivesModule::on_initialize(11);
+		assert_eq!(expired_count(), 2);
+		assert_eq!(IncentivesModule::boost_expiry_cursor(), Some(11));
+
+		IncentivesModule::on_initialize(12);
+		assert_eq!(expired_count(), 4);
+		assert_eq!(IncentivesModule::boost_expiry_cursor(), Some(11));
+
+		IncentivesModule::on_initialize(13);
+		assert_eq!(expired_count(), 5);
+		assert_eq!(IncentivesModule::boost_expiry_cursor(), Some(13));
+		assert_eq!(BoostExpiries::<Runtime>::iter_prefix(11).count(), 0);
+		assert_eq!(
+			RewardsModule::pool_infos(PoolId::Dex(BTC_AUSD_LP)).total_shares,
+			5 * 100
+		);
+
+		IncentivesModule::on_initialize(14);
+		assert_eq!(IncentivesModule::boost_expiry_cursor(), None);
+	});
+}
+
+#[test]
+fn loans_pools_tracking_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TokensModule::deposit(ACA, &ALICE::get(), 10000));
+		OnUpdateLoan::<Runtime>::happened(&(ALICE::get(), BTC, 1000, 0));
+		OnUpdateLoan::<Runtime>::happened(&(ALICE::get(), DOT, 100, 0));
+		assert_eq!(IncentivesModule::loans_pools(ALICE::get(), BTC), Some(1000));
+		assert_eq!(IncentivesModule::loans_pools(ALICE::get(), DOT), Some(100));
+
+		OnUpdateLoan::<Runtime>::happened(&(ALICE::get(), DOT, -100, 100));
+		assert_eq!(IncentivesModule::loans_pools(ALICE::get(), DOT), None);
+
+		// only the tracked Pool::Loans are boosted
+		RewardsModule::add_share(&ALICE::get(), &PoolId::Loans(LDOT), 100);
+		assert_ok!(IncentivesModule::lock_boost(
+			Origin::signed(ALICE::get()),
+			BoostTarget::Loans,
+			1000,
+			100
+		));
+		assert_eq!(
+			RewardsModule::shares_and_withdrawn_rewards(PoolId::Loans(BTC), ALICE::get()),
+			(3000, Default::default())
+		);
+		assert_eq!(
+			RewardsModule::shares_and_withdrawn_rewards(PoolId::Loans(LDOT), ALICE::get()),
+			(100, Default::default())
+		);
+
+		// the boosted shares are derived from the base shares, the boost is removed without drift
+		assert_eq!(IncentivesModule::loans_pools(ALICE::get(), BTC), Some(1000));
+		assert_ok!(IncentivesModule::unlock_boost(
+			Origin::signed(ALICE::get()),
+			BoostTarget::Loans
+		));
+		assert_eq!(
+			RewardsModule::shares_and_withdrawn_rewards(PoolId::Loans(BTC), ALICE::get()),
+			(1000, Default::default())
+		);
+	});
+}
+
+#[test]
+fn migrate_to_v1_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		RewardsModule::add_share(&ALICE::get(), &PoolId::Loans(BTC), 100);
+		RewardsModule::add_share(&BOB::get(), &PoolId::Loans(DOT), 100);
+		RewardsModule::add_share(&ALICE::get(), &PoolId::Dex(BTC_AUSD_LP), 100);
+
+		assert_eq!(StorageVersion::get::<IncentivesModule>(), 0);
+		migrations::v1::migrate::<Runtime>();
+		assert_eq!(StorageVersion::get::<IncentivesModule>(), 1);
+		assert_eq!(IncentivesModule::loans_pools(ALICE::get(), BTC), Some(100));
+		assert_eq!(IncentivesModule::loans_pools(BOB::get(), DOT), Some(100));
+		assert_eq!(IncentivesModule::loans_pools(ALICE::get(), DOT), None);
+		assert_eq!(LoansPools::<Runtime>::iter().count(), 2);
+
+		// the migration only runs once
+		RewardsModule::add_share(&BOB::get(), &PoolId::Loans(BTC), 100);
+		migrations::v1::migrate::<Runtime>();
+		assert_eq!(IncentivesModule::loans_pools(BOB::get(), BTC), None);
+	});
+}
+
+#[test]
+fn on_guarantee_change_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let pool_id = PoolId::HomaValidatorAllowance(VALIDATOR::get());
+		assert_eq!(RewardsModule::pool_infos(pool_id.clone()), PoolInfo::default(),);
+
+		OnIncreaseGuarantee::<Runtime>::happened(&(ALICE::get(), VALIDATOR::get(), 100));
+		OnIncreaseGuarantee::<Runtime>::happened(&(BOB::get(), VALIDATOR::get(), 300));
+		assert_eq!(
+			RewardsModule::pool_infos(pool_id.clone()),
+			PoolInfo {
+				total_shares: 400,
+				..Default::default()
+			}
+		);
+		assert_eq!(
+			RewardsModule::shares_and_withdrawn_rewards(pool_id.clone(), ALICE::get()),
+			(100, Default::default())
+		);
+		assert_eq!(
+			RewardsModule::shares_and_withdrawn_rewards(pool_id.clone(), BOB::get()),
+			(300, Default::default())
+		);
+
+		OnDecreaseGuarantee::<Runtime>::happened(&(BOB::get(), VALIDATOR::get(), 100));
+		assert_eq!(
+			RewardsModule::pool_infos(pool_id.clone()),
+			PoolInfo {
+				total_shares: 300,
+				..Default::default()
+			}
+		);
+		assert_eq!(
+			RewardsModule::shares_and_withdrawn_rewards(pool_id.clone(), BOB::get()),
+			(200, Default::default())
+		);
+
+		OnDecreaseGuarantee::<Runtime>::happened(&(ALICE::get(), VALIDATOR::get(), 100));
+		assert_eq!(
+			RewardsModule::pool_infos(pool_id.clone()),
+			PoolInfo {
+				total_shares: 200,
+				..Default::default()
+			}
+		);
+		assert_eq!(
+			RewardsModule::shares_and_withdrawn_rewards(pool_id, ALICE::get()),
+			Default::default(),
+		);
+	});
+}
+
+#[test]
+fn accumulate_homa_validator_allowance_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let pool_id = PoolId::HomaValidatorAllowance(VALIDATOR::get());
+		assert_ok!(TokensModule::deposit(ACA, &RewardsSource::get(), 10000));
+		assert_noop!(
+			IncentivesModule::update_incentive_rewards(
+				Origin::signed(ROOT::get()),
+				vec![(pool_id.clone(), vec![(ACA, 100)])],
+			),
+			Error::<Runtime>::InvalidPoolId
+		);
+		assert_noop!(
+			IncentivesModule::update_homa_validator_allowance_reward_rates(
+				Origin::signed(ALICE::get()),
+				vec![(ACA, Rate::saturating_from_rational(1, 4))],
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			IncentivesModule::update_homa_validator_allowance_reward_rates(
+				Origin::signed(ROOT::get()),
+				vec![(ACA, Rate::saturating_from_rational(2, 1))],
+			),
+			Error::<Runtime>::InvalidRate
+		);
+		assert_ok!(IncentivesModule::update_homa_validator_allowance_reward_rates(
+			Origin::signed(ROOT::get()),
+			vec![(ACA, Rate::saturating_from_rational(1, 4))],
+		));
+		System::assert_last_event(Event::IncentivesModule(
+			crate::Event::HomaValidatorAllowanceRewardRateUpdated(ACA, Rate::saturating_from_rational(1, 4)),
+		));
+		assert_eq!(
+			IncentivesModule::homa_validator_allowance_reward_rates(ACA),
+			Rate::saturating_from_rational(1, 4)
+		);
+
+		OnIncreaseGuarantee::<Runtime>::happened(&(ALICE::get(), VALIDATOR::get(), 100));
+		OnIncreaseGuarantee::<Runtime>::happened(&(BOB::get(), VALIDATOR::get(), 300));
+
+		IncentivesModule::on_initialize(10);
+		assert_eq!(TokensModule::free_balance(ACA, &VAULT::get()), 100);
+		assert_eq!(
+			RewardsModule::pool_infos(pool_id.clone()),
+			PoolInfo {
+				total_shares: 400,
+				rewards: vec![(ACA, (100, 0))].into_iter().collect(),
+			}
+		);
+
+		assert_ok!(IncentivesModule::claim_rewards(
+			Origin::signed(ALICE::get()),
+			pool_id.clone()
+		));
+		assert_ok!(IncentivesModule::claim_rewards(Origin::signed(BOB::get()), pool_id));
+		assert_eq!(TokensModule::free_balance(ACA, &ALICE::get()), 25);
+		assert_eq!(TokensModule::free_balance(ACA, &BOB::get()), 75);
+
+		// the rewards follow the insurance of the validator
+		OnDecreaseGuarantee::<Runtime>::happened(&(BOB::get(), VALIDATOR::get(), 200));
+		IncentivesModule::on_initialize(20);
+		assert_eq!(TokensModule::free_balance(ACA, &RewardsSource::get()), 9850);
+
+		// frozen validator will not accumulate rewards
+		mock_freeze_validator();
+		IncentivesModule::on_initialize(30);
+		assert_eq!(TokensModule::free_balance(ACA, &RewardsSource::get()), 9850);
+
+		// PoolId::HomaValidatorAllowance will not accumulate incentive rewards after shutdown
+		mock_shutdown();
+		IncentivesModule::on_initialize(40);
+		assert_eq!(TokensModule::free_balance(ACA, &RewardsSource::get()), 9850);
+	});
+}
+
+#[test]
+fn accumulate_homa_incentive_and_stable_asset_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(TokensModule::deposit(ACA, &RewardsSource::get(), 10000));
+		assert_noop!(
+			IncentivesModule::update_incentive_rewards(
+				Origin::signed(ROOT::get()),
+				vec![(PoolId::StableAsset(BTC_AUSD_LP), vec![(ACA, 100)])],
+			),
+			Error::<Runtime>::InvalidPoolId
+		);
+		assert_ok!(IncentivesModule::update_incentive_rewards(
+			Origin::signed(ROOT::get()),
+			vec![
+				(PoolId::HomaIncentive, vec![(ACA, 100)]),
+				(PoolId::StableAsset(STABLE_ASSET_LP), vec![(ACA, 200)]),
+			],
+		));
+
+		RewardsModule::add_share(&ALICE::get(), &PoolId::HomaIncentive, 100);
+		RewardsModule::add_share(&BOB::get(), &PoolId::StableAsset(STABLE_ASSET_LP), 100);
+
+		IncentivesModule::on_initialize(10);
+		assert_eq!(TokensModule::free_balance(ACA, &VAULT::get()), 300);
+		assert_eq!(
+			RewardsModule::pool_infos(PoolId::HomaIncentive),
+			PoolInfo {
+				total_shares: 100,
+				rewards: vec![(ACA, (100, 0))].into_iter().collect(),
+			}
+		);
+		assert_eq!(
+			RewardsModule::pool_infos(PoolId::StableAsset(STABLE_ASSET_LP)),
+			PoolInfo {
+				total_shares: 100,
+				rewards: vec![(ACA, (200, 0))].into_iter().collect(),
+			}
+		);
+
+		assert_ok!(IncentivesModule::claim_rewards(
+			Origin::signed(ALICE::get()),
+			PoolId::HomaIncentive
+		));
+		assert_ok!(IncentivesModule::claim_rewards(
+			Origin::signed(BOB::get()),
+			PoolId::StableAsset(STABLE_ASSET_LP)
+		));
+		assert_eq!(TokensModule::free_balance(ACA, &ALICE::get()), 100);
+		assert_eq!(TokensModule::free_balance(ACA, &BOB::get()), 200);
+
+		// PoolId::HomaIncentive and PoolId::StableAsset will not accumulate incentive rewards after shutdown
+		mock_shutdown();
+		IncentivesModule::on_initialize(20);
+		assert_eq!(TokensModule::free_balance(ACA, &RewardsSource::get()), 9700);
+	});
+}
+
+#[test]
+fn payout_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(
+			IncentivesModule::pending_multi_rewards(PoolId::Loans(BTC), ALICE::get()),
+			BTreeMap::default()
+		);
+
+		IncentivesModule::payout(&ALICE::get(), &PoolId::Loans(BTC), ACA, 1000);
+		assert_eq!(
+			IncentivesModule::pending_multi_rewards(PoolId::Loans(BTC), ALICE::get()),
+			vec![(ACA, 1000)].into_iter().collect()
+		);
+
+		IncentivesModule::payout(&ALICE::get(), &PoolId::Loans(BTC), ACA, 1000);
+		assert_eq!(
+			IncentivesModule::pending_multi_rewards(PoolId::Loans(BTC), ALICE::get()),
+			vec![(ACA, 2000)].into_iter().collect()
+		);
+	});
+}
+
+#[test]
+fn claim_rewards_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TokensModule::deposit(ACA, &VAULT::get(), 10000));
+		assert_ok!(TokensModule::deposit(AUSD, &VAULT::get(), 10000));
+		assert_ok!(TokensModule::deposit(LDOT, &VAULT::get(), 10000));
+		assert_ok!(IncentivesModule::update_claim_reward_deduction_rates(
+			Origin::signed(ROOT::get()),
+			vec![
+				(PoolId::Dex(BTC_AUSD_LP), Rate::saturating_from_rational(50, 100)),
+				(PoolId::Loans(BTC), Rate::saturating_from_rational(90, 100)),
 			]
 		));
 
@@ -719,6 +1582,117 @@ fn claim_rewards_works() {
 	});
 }
 
+#[test]
+fn claim_and_restake_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let pool_id = PoolId::Dex(BTC_AUSD_LP);
+		assert_ok!(TokensModule::deposit(BTC_AUSD_LP, &ALICE::get(), 100));
+		assert_ok!(IncentivesModule::deposit_dex_share(
+			Origin::signed(ALICE::get()),
+			BTC_AUSD_LP,
+			100
+		));
+
+		assert_noop!(
+			IncentivesModule::claim_and_restake(Origin::signed(ALICE::get()), BTC, 0),
+			Error::<Runtime>::InvalidCurrencyId
+		);
+		assert_noop!(
+			IncentivesModule::claim_and_restake(Origin::signed(ALICE::get()), BTC_AUSD_LP, 0),
+			Error::<Runtime>::NoRewardsToRestake
+		);
+
+		assert_ok!(TokensModule::deposit(ACA, &VAULT::get(), 100));
+		assert_ok!(TokensModule::deposit(AUSD, &VAULT::get(), 200));
+		assert_ok!(RewardsModule::accumulate_reward(&pool_id, ACA, 100));
+		assert_ok!(RewardsModule::accumulate_reward(&pool_id, AUSD, 200));
+
+		// the swap of 100 ACA => 10 AUSD is below the oracle price, or the oracle price is unavailable
+		mock_aca_price(Some(Price::saturating_from_rational(2, 10)));
+		assert_noop!(
+			IncentivesModule::claim_and_restake(Origin::signed(ALICE::get()), BTC_AUSD_LP, 0),
+			"insufficient target amount"
+		);
+		mock_aca_price(None);
+		assert_noop!(
+			IncentivesModule::claim_and_restake(Origin::signed(ALICE::get()), BTC_AUSD_LP, 0),
+			Error::<Runtime>::InvalidFeedPrice
+		);
+		mock_aca_price(Some(Price::saturating_from_rational(1, 10)));
+
+		// 100 ACA => 10 AUSD, half of 210 AUSD => 21 BTC, add liquidity with 21 BTC and 105 AUSD
+		assert_noop!(
+			IncentivesModule::claim_and_restake(Origin::signed(ALICE::get()), BTC_AUSD_LP, 22),
+			"unacceptable share increment"
+		);
+		assert_ok!(IncentivesModule::claim_and_restake(
+			Origin::signed(ALICE::get()),
+			BTC_AUSD_LP,
+			21
+		));
+		System::assert_last_event(Event::IncentivesModule(crate::Event::ClaimAndRestake(
+			ALICE::get(),
+			BTC_AUSD_LP,
+			21,
+		)));
+		assert_eq!(TokensModule::free_balance(ACA, &ALICE::get()), 0);
+		assert_eq!(TokensModule::free_balance(AUSD, &ALICE::get()), 0);
+		assert_eq!(TokensModule::free_balance(BTC, &ALICE::get()), 0);
+		assert_eq!(TokensModule::free_balance(BTC_AUSD_LP, &ALICE::get()), 0);
+		assert_eq!(TokensModule::free_balance(BTC_AUSD_LP, &VAULT::get()), 121);
+		assert_eq!(
+			RewardsModule::shares_and_withdrawn_rewards(pool_id, ALICE::get()).0,
+			121
+		);
+	});
+}
+
+#[test]
+fn pending_rewards_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let pool_id = PoolId::Loans(BTC);
+		assert_eq!(IncentivesModule::pending_rewards(&ALICE::get()), vec![]);
+
+		RewardsModule::add_share(&ALICE::get(), &pool_id, 100);
+		RewardsModule::add_share(&BOB::get(), &pool_id, 300);
+		assert_ok!(TokensModule::deposit(ACA, &VAULT::get(), 400));
+		assert_ok!(RewardsModule::accumulate_reward(&pool_id, ACA, 400));
+		assert_ok!(IncentivesModule::update_claim_reward_deduction_rates(
+			Origin::signed(ROOT::get()),
+			vec![(pool_id.clone(), Rate::saturating_from_rational(10, 100))]
+		));
+		assert_eq!(
+			IncentivesModule::pending_rewards(&ALICE::get()),
+			vec![(pool_id.clone(), 100, vec![(ACA, 100, 90)])]
+		);
+
+		// the deduction is re-accumulated to the pool
+		assert_ok!(IncentivesModule::claim_rewards(
+			Origin::signed(ALICE::get()),
+			pool_id.clone()
+		));
+		assert_eq!(TokensModule::free_balance(ACA, &ALICE::get()), 90);
+		assert_eq!(
+			IncentivesModule::pending_rewards(&ALICE::get()),
+			vec![(pool_id.clone(), 100, vec![(ACA, 2, 2)])]
+		);
+		assert_eq!(
+			IncentivesModule::pending_rewards(&BOB::get()),
+			vec![(pool_id.clone(), 300, vec![(ACA, 307, 277)])]
+		);
+
+		// the pending multi rewards are included
+		PendingMultiRewards::<Runtime>::mutate(&pool_id, BOB::get(), |rewards| {
+			rewards.insert(AUSD, 50);
+		});
+		assert_eq!(
+			IncentivesModule::pending_rewards(&BOB::get()),
+			vec![(pool_id, 300, vec![(ACA, 307, 277), (AUSD, 50, 45)])]
+		);
+	});
+}
+
 #[test]
 fn on_initialize_should_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -52,9 +52,16 @@ pub trait WeightInfo {
 	fn deposit_dex_share() -> Weight;
 	fn withdraw_dex_share() -> Weight;
 	fn claim_rewards() -> Weight;
+	fn claim_and_restake() -> Weight;
 	fn update_incentive_rewards(c: u32, ) -> Weight;
+	fn add_reward_program() -> Weight;
+	fn remove_reward_program() -> Weight;
+	fn accumulate_reward_programs(c: u32, ) -> Weight;
 	fn update_dex_saving_rewards(c: u32, ) -> Weight;
 	fn update_claim_reward_deduction_rates(c: u32, ) -> Weight;
+	fn lock_boost() -> Weight;
+	fn unlock_boost() -> Weight;
+	fn expire_boosts(c: u32, ) -> Weight;
 }
 
 /// Weights for module_incentives using the Acala node and recommended hardware.
@@ -81,12 +88,33 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(3 as Weight))
 			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 	}
+	fn claim_and_restake() -> Weight {
+		(190_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(18 as Weight))
+			.saturating_add(T::DbWeight::get().writes(15 as Weight))
+	}
 	fn update_incentive_rewards(c: u32, ) -> Weight {
 		(479_000 as Weight)
 			// Standard Error: 29_000
 			.saturating_add((1_893_000 as Weight).saturating_mul(c as Weight))
 			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
 	}
+	fn add_reward_program() -> Weight {
+		(63_714_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(15 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	fn remove_reward_program() -> Weight {
+		(58_210_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	fn accumulate_reward_programs(c: u32, ) -> Weight {
+		(0 as Weight)
+			.saturating_add((46_372_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes((4 as Weight).saturating_mul(c as Weight)))
+	}
 	fn update_dex_saving_rewards(c: u32, ) -> Weight {
 		(914_000 as Weight)
 			// Standard Error: 21_000
@@ -99,6 +127,23 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add((1_829_000 as Weight).saturating_mul(c as Weight))
 			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
 	}
+	fn lock_boost() -> Weight {
+		(231_328_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(15 as Weight))
+			.saturating_add(T::DbWeight::get().writes(12 as Weight))
+	}
+	fn unlock_boost() -> Weight {
+		(239_402_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(15 as Weight))
+			.saturating_add(T::DbWeight::get().writes(13 as Weight))
+	}
+	fn expire_boosts(c: u32, ) -> Weight {
+		(13_303_000 as Weight)
+			.saturating_add((91_909_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((9 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes((6 as Weight).saturating_mul(c as Weight)))
+	}
 }
 
 // For backwards compatibility and tests
@@ -124,12 +169,33 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
 	}
+	fn claim_and_restake() -> Weight {
+		(190_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(18 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(15 as Weight))
+	}
 	fn update_incentive_rewards(c: u32, ) -> Weight {
 		(479_000 as Weight)
 			// Standard Error: 29_000
 			.saturating_add((1_893_000 as Weight).saturating_mul(c as Weight))
 			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
 	}
+	fn add_reward_program() -> Weight {
+		(63_714_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(15 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	fn remove_reward_program() -> Weight {
+		(58_210_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn accumulate_reward_programs(c: u32, ) -> Weight {
+		(0 as Weight)
+			.saturating_add((46_372_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((4 as Weight).saturating_mul(c as Weight)))
+	}
 	fn update_dex_saving_rewards(c: u32, ) -> Weight {
 		(914_000 as Weight)
 			// Standard Error: 21_000
@@ -142,4 +208,21 @@ impl WeightInfo for () {
 			.saturating_add((1_829_000 as Weight).saturating_mul(c as Weight))
 			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
 	}
+	fn lock_boost() -> Weight {
+		(231_328_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(15 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(12 as Weight))
+	}
+	fn unlock_boost() -> Weight {
+		(239_402_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(15 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(13 as Weight))
+	}
+	fn expire_boosts(c: u32, ) -> Weight {
+		(13_303_000 as Weight)
+			.saturating_add((91_909_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((9 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((6 as Weight).saturating_mul(c as Weight)))
+	}
 }
@@ -185,6 +185,7 @@ impl module_currencies::Config for Runtime {
 	type EVMBridge = ();
 	type SweepOrigin = EnsureSignedBy<One, AccountId>;
 	type OnDust = ();
+	type CurrencyHook = ();
 }
 
 parameter_types! {
@@ -23,7 +23,7 @@ use codec::{Encode, MaxEncodedLen};
 use frame_support::{
 	log,
 	pallet_prelude::*,
-	traits::{Contains, Get, LockIdentifier},
+	traits::{Contains, Get, LockIdentifier, StorageVersion},
 	transactional, BoundedVec,
 };
 use frame_system::pallet_prelude::*;
@@ -33,9 +33,10 @@ use sp_runtime::{
 	traits::{MaybeDisplay, MaybeSerializeDeserialize, Member, Zero},
 	RuntimeDebug, SaturatedConversion,
 };
-use sp_std::{convert::TryInto, fmt::Debug, prelude::*};
+use sp_std::{collections::btree_map::BTreeMap, convert::TryInto, fmt::Debug, prelude::*};
 use support::{NomineesProvider, OnNewEra};
 
+pub mod migrations;
 mod mock;
 mod tests;
 pub mod weights;
@@ -43,6 +44,9 @@ pub mod weights;
 pub use module::*;
 pub use weights::WeightInfo;
 
+/// The current storage version.
+const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 /// Just a Balance/BlockNumber tuple to encode when a chunk of funds will be
 /// unlocked.
 #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, MaxEncodedLen)]
@@ -131,6 +135,39 @@ where
 	}
 }
 
+/// The votes of a nominee. The votes of an active bond grow linearly with the
+/// eras it has been kept, up to double of the active bond after
+/// `MaxVoteWeightEras`.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, Default, MaxEncodedLen)]
+pub struct VoteTally {
+	/// The sum of the active bonds that have not reached the maximum vote
+	/// weight yet.
+	pub growing: Balance,
+	/// The sum of `active * bonded_since` of the growing bonds.
+	pub growing_since: Balance,
+	/// The sum of the active bonds that have reached the maximum vote weight.
+	pub matured: Balance,
+}
+
+impl VoteTally {
+	/// The weighted votes at `current_era`.
+	fn votes(&self, current_era: EraIndex, max_eras: EraIndex) -> Balance {
+		let extra = if max_eras.is_zero() {
+			Zero::zero()
+		} else {
+			self.growing
+				.saturating_mul(current_era.into())
+				.saturating_sub(self.growing_since)
+				/ Balance::from(max_eras)
+		};
+
+		self.matured
+			.saturating_mul(2)
+			.saturating_add(self.growing)
+			.saturating_add(extra)
+	}
+}
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
@@ -150,6 +187,12 @@ pub mod module {
 		type NominateesCount: Get<u32>;
 		#[pallet::constant]
 		type MaxUnlockingChunks: Get<u32>;
+		/// The number of eras the bond needs to be kept to reach the maximum
+		/// vote weight, which is double of the active bond.
+		#[pallet::constant]
+		type MaxVoteWeightEras: Get<EraIndex>;
+		/// Filter the nominees that can be nominated and elected, e.g. the
+		/// validators that are not frozen.
 		type NomineeFilter: Contains<Self::NomineeId>;
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
@@ -195,13 +238,29 @@ pub mod module {
 	pub type Ledger<T: Config<I>, I: 'static = ()> =
 		StorageMap<_, Twox64Concat, T::AccountId, BondingLedger<T::MaxUnlockingChunks>, ValueQuery>;
 
-	/// The total voting value for nominees.
+	/// The era since which the active bond of the nominator has been kept.
 	///
-	/// Votes: map NomineeId => Balance
+	/// BondedSince: map AccountId => Option<EraIndex>
 	#[pallet::storage]
-	#[pallet::getter(fn votes)]
+	#[pallet::getter(fn bonded_since)]
+	pub type BondedSince<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, T::AccountId, EraIndex, OptionQuery>;
+
+	/// The vote tally of nominees.
+	///
+	/// Votes: map NomineeId => VoteTally
+	#[pallet::storage]
+	#[pallet::getter(fn vote_tally)]
 	pub type Votes<T: Config<I>, I: 'static = ()> =
-		StorageMap<_, Twox64Concat, <T as Config<I>>::NomineeId, Balance, ValueQuery>;
+		StorageMap<_, Twox64Concat, <T as Config<I>>::NomineeId, VoteTally, ValueQuery>;
+
+	/// The active bonds that reach the maximum vote weight at an era.
+	///
+	/// MaturingVotes: double_map EraIndex, NomineeId => Balance
+	#[pallet::storage]
+	#[pallet::getter(fn maturing_votes)]
+	pub type MaturingVotes<T: Config<I>, I: 'static = ()> =
+		StorageDoubleMap<_, Twox64Concat, EraIndex, Twox64Concat, <T as Config<I>>::NomineeId, Balance, ValueQuery>;
 
 	/// The elected nominees.
 	///
@@ -219,6 +278,7 @@ pub mod module {
 	pub type CurrentEra<T: Config<I>, I: 'static = ()> = StorageValue<_, EraIndex, ValueQuery>;
 
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T, I = ()>(PhantomData<(T, I)>);
 
 	#[pallet::hooks]
@@ -244,7 +304,7 @@ pub mod module {
 				ledger.total += extra;
 				let old_nominations = Self::nominations(&who);
 
-				Self::update_votes(old_active, &old_nominations, ledger.active, &old_nominations);
+				Self::update_votes(&who, old_active, &old_nominations, ledger.active, &old_nominations);
 				Self::update_ledger(&who, &ledger);
 			}
 			Ok(())
@@ -276,7 +336,7 @@ pub mod module {
 					.map_err(|_| Error::<T, I>::MaxUnlockChunksExceeded)?;
 				let old_nominations = Self::nominations(&who);
 
-				Self::update_votes(old_active, &old_nominations, ledger.active, &old_nominations);
+				Self::update_votes(&who, old_active, &old_nominations, ledger.active, &old_nominations);
 				Self::update_ledger(&who, &ledger);
 			}
 			Ok(())
@@ -293,7 +353,7 @@ pub mod module {
 			let old_nominations = Self::nominations(&who);
 			let ledger = ledger.rebond(amount);
 
-			Self::update_votes(old_active, &old_nominations, ledger.active, &old_nominations);
+			Self::update_votes(&who, old_active, &old_nominations, ledger.active, &old_nominations);
 			Self::update_ledger(&who, &ledger);
 			Self::deposit_event(Event::Rebond(who, amount));
 			let removed_len = old_ledger_unlocking - ledger.unlocking.len();
@@ -349,7 +409,7 @@ pub mod module {
 			let old_nominations = Self::nominations(&who);
 			let old_active = Self::ledger(&who).active;
 
-			Self::update_votes(old_active, &old_nominations, old_active, &bounded_targets);
+			Self::update_votes(&who, old_active, &old_nominations, old_active, &bounded_targets);
 			Nominations::<T, I>::insert(&who, &bounded_targets);
 			Ok(())
 		}
@@ -362,7 +422,7 @@ pub mod module {
 			let old_nominations = Self::nominations(&who);
 			let old_active = Self::ledger(&who).active;
 
-			Self::update_votes(old_active, &old_nominations, Zero::zero(), &[]);
+			Self::update_votes(&who, old_active, &old_nominations, old_active, &[]);
 			Nominations::<T, I>::remove(&who);
 			Ok(Some(T::WeightInfo::chill(old_nominations.len() as u32)).into())
 		}
@@ -399,31 +459,145 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 		Ledger::<T, I>::remove(who);
 		Nominations::<T, I>::remove(who);
+		BondedSince::<T, I>::remove(who);
 	}
 
+	/// Move the votes of `who` from the old active bond and nominations to the
+	/// new ones, and update the era since which the active bond is kept.
 	fn update_votes(
+		who: &T::AccountId,
 		old_active: Balance,
 		old_nominations: &[T::NomineeId],
 		new_active: Balance,
 		new_nominations: &[T::NomineeId],
 	) {
-		if !old_active.is_zero() && !old_nominations.is_empty() {
-			for account in old_nominations {
-				Votes::<T, I>::mutate(account, |balance| *balance = balance.saturating_sub(old_active));
+		let old_bonded_since = Self::bonded_since(who);
+		let new_bonded_since = if new_active.is_zero() {
+			None
+		} else {
+			Some(Self::averaged_bonded_since(old_active, old_bonded_since, new_active))
+		};
+
+		Self::apply_votes(old_active, old_bonded_since, old_nominations, false);
+		Self::apply_votes(new_active, new_bonded_since, new_nominations, true);
+
+		match new_bonded_since {
+			Some(era) => BondedSince::<T, I>::insert(who, era),
+			None => BondedSince::<T, I>::remove(who),
+		}
+	}
+
+	/// The era since which the new active bond is considered kept. The
+	/// increment of the active bond is kept since the current era, and the
+	/// eras are averaged by the amount.
+	fn averaged_bonded_since(old_active: Balance, old_bonded_since: Option<EraIndex>, new_active: Balance) -> EraIndex {
+		let current_era = Self::current_era();
+		let old_bonded_since = match old_bonded_since {
+			Some(era) if !old_active.is_zero() => era,
+			_ => return current_era,
+		};
+		if new_active <= old_active {
+			return old_bonded_since;
+		}
+
+		// the bond kept for more than `MaxVoteWeightEras` has no more weight.
+		let old_bonded_since = old_bonded_since.max(current_era.saturating_sub(T::MaxVoteWeightEras::get()));
+		let increment = new_active.saturating_sub(old_active);
+		let sum = old_active
+			.saturating_mul(old_bonded_since.into())
+			.saturating_add(increment.saturating_mul(current_era.into()));
+
+		// round up to not overestimate the eras kept.
+		let era = sum.saturating_add(new_active.saturating_sub(1)) / new_active;
+		era.saturated_into()
+	}
+
+	/// Add or remove the votes of the active bond kept since `bonded_since` to
+	/// `nominations`. Growing bonds are scheduled in `MaturingVotes` to reach
+	/// the maximum vote weight.
+	fn apply_votes(active: Balance, bonded_since: Option<EraIndex>, nominations: &[T::NomineeId], add: bool) {
+		let bonded_since = match bonded_since {
+			Some(era) if !active.is_zero() => era,
+			_ => return,
+		};
+		let update = |balance: Balance, amount: Balance| {
+			if add {
+				balance.saturating_add(amount)
+			} else {
+				balance.saturating_sub(amount)
+			}
+		};
+
+		let max_eras = T::MaxVoteWeightEras::get();
+		let maturing_era = bonded_since.saturating_add(max_eras);
+		let matured = !max_eras.is_zero() && maturing_era <= Self::current_era();
+
+		for nominee in nominations {
+			Votes::<T, I>::mutate_exists(nominee, |maybe_tally| {
+				let mut tally = maybe_tally.take().unwrap_or_default();
+				if matured {
+					tally.matured = update(tally.matured, active);
+				} else {
+					tally.growing = update(tally.growing, active);
+					tally.growing_since =
+						update(tally.growing_since, active.saturating_mul(Balance::from(bonded_since)));
+				}
+				*maybe_tally = Some(tally).filter(|tally| *tally != VoteTally::default());
+			});
+
+			if !matured && !max_eras.is_zero() {
+				MaturingVotes::<T, I>::mutate_exists(maturing_era, nominee, |maybe_balance| {
+					let balance = update(maybe_balance.unwrap_or_default(), active);
+					*maybe_balance = Some(balance).filter(|balance| !balance.is_zero());
+				});
 			}
 		}
+	}
 
-		if !new_active.is_zero() && !new_nominations.is_empty() {
-			for account in new_nominations {
-				Votes::<T, I>::mutate(account, |balance| *balance = balance.saturating_add(new_active));
+	/// Move the bonds that reach the maximum vote weight in `(from_era,
+	/// to_era]` to matured, returns the number of entries moved.
+	///
+	/// Bonds are scheduled at most `MaxVoteWeightEras` after the current era,
+	/// so at most `MaxVoteWeightEras` eras are processed.
+	fn mature_votes(from_era: EraIndex, to_era: EraIndex) -> u32 {
+		let max_eras = T::MaxVoteWeightEras::get();
+		let to_era = to_era.min(from_era.saturating_add(max_eras));
+		let mut count: u32 = 0;
+
+		for era in from_era.saturating_add(1)..=to_era {
+			let bonded_since = Balance::from(era.saturating_sub(max_eras));
+			for (nominee, active) in MaturingVotes::<T, I>::drain_prefix(era) {
+				Votes::<T, I>::mutate(&nominee, |tally| {
+					tally.growing = tally.growing.saturating_sub(active);
+					tally.growing_since = tally.growing_since.saturating_sub(active.saturating_mul(bonded_since));
+					tally.matured = tally.matured.saturating_add(active);
+				});
+				count = count.saturating_add(1);
 			}
 		}
+
+		count
 	}
 
-	fn rebalance() {
-		let mut voters = Votes::<T, I>::iter().collect::<Vec<(T::NomineeId, Balance)>>();
+	/// The weighted votes of `nominee` at the current era.
+	pub fn votes(nominee: &T::NomineeId) -> Balance {
+		Self::vote_tally(nominee).votes(Self::current_era(), T::MaxVoteWeightEras::get())
+	}
+
+	/// Elect the nominees with the most weighted votes, returns the number of
+	/// nominees with votes.
+	fn rebalance() -> u32 {
+		let current_era = Self::current_era();
+		let max_eras = T::MaxVoteWeightEras::get();
+		let mut count: u32 = 0;
 
-		voters.sort_by(|a, b| b.1.cmp(&a.1));
+		let mut voters = Votes::<T, I>::iter()
+			.inspect(|_| count = count.saturating_add(1))
+			.filter(|(nominee, _)| T::NomineeFilter::contains(nominee))
+			.map(|(nominee, tally)| (nominee, tally.votes(current_era, max_eras)))
+			.collect::<Vec<(T::NomineeId, Balance)>>();
+
+		voters.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
 
 		let new_nominees: BoundedVec<<T as Config<I>>::NomineeId, <T as Config<I>>::NominateesCount> = voters
 			.into_iter()
@@ -434,6 +608,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			.expect("Only took from voters");
 
 		Nominees::<T, I>::put(new_nominees);
+		count
 	}
 }
 
@@ -444,8 +619,24 @@ impl<T: Config<I>, I: 'static> NomineesProvider<T::NomineeId> for Pallet<T, I> {
 }
 
 impl<T: Config<I>, I: 'static> OnNewEra<EraIndex> for Pallet<T, I> {
-	fn on_new_era(era: EraIndex) {
+	fn on_new_era(era: EraIndex) -> Weight {
+		let previous_era = Self::current_era();
 		CurrentEra::<T, I>::put(era);
-		Self::rebalance();
+
+		let eras = era
+			.min(previous_era.saturating_add(T::MaxVoteWeightEras::get()))
+			.saturating_sub(previous_era);
+		let matured = Self::mature_votes(previous_era, era);
+		let nominees = Self::rebalance();
+
+		// reads: the eras to mature, the matured tallies, and the tallies and the
+		// filter of the nominees.
+		// writes: the current era, the matured entries and tallies, and the nominees.
+		T::DbWeight::get().reads_writes(
+			1u64.saturating_add(eras.into())
+				.saturating_add(matured.into())
+				.saturating_add(u64::from(nominees).saturating_mul(2)),
+			2u64.saturating_add(u64::from(matured).saturating_mul(2)),
+		)
 	}
 }
@@ -0,0 +1,81 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{BondedSince, Config, CurrentEra, Ledger, MaturingVotes, Nominations, Pallet, Votes};
+use frame_support::{
+	log,
+	traits::{Get, StorageVersion},
+	weights::Weight,
+};
+use primitives::Balance;
+use sp_runtime::traits::Zero;
+
+pub mod v1 {
+	use super::*;
+
+	/// Start the bond duration of the existing bonds from the current era, and
+	/// rebuild the vote tallies with the weighted votes. It only runs once, if
+	/// the storage version of the pallet is below v1.
+	pub fn migrate<T: Config<I>, I: 'static>() -> Weight {
+		if StorageVersion::get::<Pallet<T, I>>() >= 1 {
+			return T::DbWeight::get().reads(1);
+		}
+
+		log::info!(target: "nominees-election", "Migrating nominees-election v1");
+
+		let current_era = CurrentEra::<T, I>::get();
+		// the storage version and the current era
+		let mut reads: u64 = 2;
+		let mut writes: u64 = 0;
+
+		// the votes were tallied without weights, clear and rebuild them.
+		Votes::<T, I>::translate::<Balance, _>(|_, _| {
+			reads += 1;
+			writes += 1;
+			None
+		});
+		MaturingVotes::<T, I>::translate::<Balance, _>(|_, _, _| {
+			reads += 1;
+			writes += 1;
+			None
+		});
+
+		for (who, ledger) in Ledger::<T, I>::iter() {
+			reads += 2;
+			if !ledger.active.is_zero() && !BondedSince::<T, I>::contains_key(&who) {
+				BondedSince::<T, I>::insert(&who, current_era);
+				writes += 1;
+			}
+		}
+
+		for (who, nominations) in Nominations::<T, I>::iter() {
+			let active = Ledger::<T, I>::get(&who).active;
+			Pallet::<T, I>::apply_votes(active, BondedSince::<T, I>::get(&who), &nominations, true);
+			let count = nominations.len() as u64;
+			reads += 4u64.saturating_add(count.saturating_mul(2));
+			writes += count.saturating_mul(2);
+		}
+
+		StorageVersion::new(1).put::<Pallet<T, I>>();
+		writes += 1;
+
+		log::info!(target: "nominees-election", "Completed nominees-election migration to v1");
+
+		T::DbWeight::get().reads_writes(reads, writes)
+	}
+}
@@ -130,13 +130,15 @@ parameter_types! {
 	pub const BondingDuration: EraIndex = 4;
 	pub const NominateesCount: u32 = 5;
 	pub const MaxUnlockingChunks: u32 = 3;
+	pub const MaxVoteWeightEras: EraIndex = 4;
 	pub const PalletId: LockIdentifier = *b"1       ";
+	pub static FrozenNominee: Option<AccountId> = None;
 }
 
 pub struct MockNomineeFilter;
 impl Contains<AccountId> for MockNomineeFilter {
 	fn contains(a: &AccountId) -> bool {
-		(0..=6).contains(a)
+		(0..=6).contains(a) && FrozenNominee::get() != Some(*a)
 	}
 }
 
@@ -149,6 +151,7 @@ impl Config for Runtime {
 	type BondingDuration = BondingDuration;
 	type NominateesCount = NominateesCount;
 	type MaxUnlockingChunks = MaxUnlockingChunks;
+	type MaxVoteWeightEras = MaxVoteWeightEras;
 	type NomineeFilter = MockNomineeFilter;
 	type WeightInfo = ();
 }
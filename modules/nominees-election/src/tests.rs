@@ -21,8 +21,24 @@
 #![cfg(test)]
 
 use super::*;
+use frame_support::traits::StorageVersion;
 use frame_support::{assert_noop, assert_ok};
 use mock::*;
+use sp_runtime::FixedPointNumber;
+use support::Ratio;
+
+/// The vote weight of the active bond of `who`. It grows linearly with the
+/// eras the bond has been kept, up to double of the active bond after
+/// `MaxVoteWeightEras`.
+fn vote_weight(who: &AccountId, active: Balance) -> Balance {
+	let max_eras = MaxVoteWeightEras::get();
+	let current_era = NomineesElectionModule::current_era();
+	let bonded_eras = current_era
+		.saturating_sub(NomineesElectionModule::bonded_since(who).unwrap_or(current_era))
+		.min(max_eras);
+	let extra = Ratio::saturating_from_rational(bonded_eras, max_eras).saturating_mul_int(active);
+	active.saturating_add(extra)
+}
 
 #[test]
 fn bond_below_min_bond_threshold() {
@@ -177,21 +193,21 @@ fn nominate_work() {
 		);
 		assert_ok!(NomineesElectionModule::bond(Origin::signed(ALICE), 500));
 		assert_eq!(NomineesElectionModule::nominations(&ALICE), vec![]);
-		assert_eq!(NomineesElectionModule::votes(1), 0);
+		assert_eq!(NomineesElectionModule::votes(&1), 0);
 		assert_ok!(NomineesElectionModule::nominate(
 			Origin::signed(ALICE),
 			vec![1, 2, 3, 4, 5]
 		));
 		assert_eq!(NomineesElectionModule::nominations(&ALICE), vec![1, 2, 3, 4, 5]);
-		assert_eq!(NomineesElectionModule::votes(1), 500);
-		assert_eq!(NomineesElectionModule::votes(2), 500);
+		assert_eq!(NomineesElectionModule::votes(&1), 500);
+		assert_eq!(NomineesElectionModule::votes(&2), 500);
 		assert_ok!(NomineesElectionModule::nominate(
 			Origin::signed(ALICE),
 			vec![2, 3, 4, 5, 6]
 		));
 		assert_eq!(NomineesElectionModule::nominations(&ALICE), vec![2, 3, 4, 5, 6]);
-		assert_eq!(NomineesElectionModule::votes(1), 0);
-		assert_eq!(NomineesElectionModule::votes(2), 500);
+		assert_eq!(NomineesElectionModule::votes(&1), 0);
+		assert_eq!(NomineesElectionModule::votes(&2), 500);
 	});
 }
 
@@ -204,12 +220,12 @@ fn chill_work() {
 			vec![1, 2, 3, 4, 5]
 		));
 		assert_eq!(NomineesElectionModule::nominations(&ALICE), vec![1, 2, 3, 4, 5]);
-		assert_eq!(NomineesElectionModule::votes(1), 500);
-		assert_eq!(NomineesElectionModule::votes(2), 500);
+		assert_eq!(NomineesElectionModule::votes(&1), 500);
+		assert_eq!(NomineesElectionModule::votes(&2), 500);
 		assert_ok!(NomineesElectionModule::chill(Origin::signed(ALICE)));
 		assert_eq!(NomineesElectionModule::nominations(&ALICE), vec![]);
-		assert_eq!(NomineesElectionModule::votes(1), 0);
-		assert_eq!(NomineesElectionModule::votes(2), 0);
+		assert_eq!(NomineesElectionModule::votes(&1), 0);
+		assert_eq!(NomineesElectionModule::votes(&2), 0);
 	});
 }
 
@@ -237,23 +253,173 @@ fn rebalance_work() {
 	});
 }
 
+#[test]
+fn vote_weight_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(NomineesElectionModule::bonded_since(&ALICE), None);
+		assert_ok!(NomineesElectionModule::bond(Origin::signed(ALICE), 500));
+		assert_eq!(NomineesElectionModule::bonded_since(&ALICE), Some(0));
+		assert_eq!(vote_weight(&ALICE, 500), 500);
+
+		NomineesElectionModule::on_new_era(1);
+		assert_eq!(vote_weight(&ALICE, 500), 625);
+
+		// bond extra will not reset the bonded era, the increment is averaged in
+		assert_ok!(NomineesElectionModule::bond(Origin::signed(ALICE), 100));
+		assert_eq!(NomineesElectionModule::bonded_since(&ALICE), Some(1));
+
+		NomineesElectionModule::on_new_era(10);
+		assert_eq!(vote_weight(&ALICE, 600), 1200);
+
+		assert_ok!(NomineesElectionModule::unbond(Origin::signed(ALICE), 600));
+		assert_eq!(NomineesElectionModule::bonded_since(&ALICE), None);
+		assert_ok!(NomineesElectionModule::rebond(Origin::signed(ALICE), 100));
+		assert_eq!(NomineesElectionModule::bonded_since(&ALICE), Some(10));
+	});
+}
+
+#[test]
+fn bonded_era_averaged_by_amount_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(NomineesElectionModule::bond(Origin::signed(ALICE), 300));
+		assert_ok!(NomineesElectionModule::nominate(Origin::signed(ALICE), vec![1]));
+
+		// (300 * 0 + 100 * 2) / 400, rounded up
+		NomineesElectionModule::on_new_era(2);
+		assert_ok!(NomineesElectionModule::bond(Origin::signed(ALICE), 100));
+		assert_eq!(NomineesElectionModule::bonded_since(&ALICE), Some(1));
+		assert_eq!(vote_weight(&ALICE, 400), 500);
+		assert_eq!(NomineesElectionModule::votes(&1), 500);
+
+		// the matured bond is kept since at most MaxVoteWeightEras ago,
+		// (400 * 6 + 400 * 10) / 800
+		NomineesElectionModule::on_new_era(10);
+		assert_ok!(NomineesElectionModule::bond(Origin::signed(ALICE), 400));
+		assert_eq!(NomineesElectionModule::bonded_since(&ALICE), Some(8));
+		assert_eq!(vote_weight(&ALICE, 800), 1200);
+		assert_eq!(NomineesElectionModule::votes(&1), 1200);
+
+		// unbond keeps the bonded era
+		assert_ok!(NomineesElectionModule::unbond(Origin::signed(ALICE), 400));
+		assert_eq!(NomineesElectionModule::bonded_since(&ALICE), Some(8));
+
+		// rebond averages the bonded era as well, (400 * 8 + 400 * 12) / 800
+		NomineesElectionModule::on_new_era(12);
+		assert_ok!(NomineesElectionModule::rebond(Origin::signed(ALICE), 400));
+		assert_eq!(NomineesElectionModule::bonded_since(&ALICE), Some(10));
+		assert_eq!(vote_weight(&ALICE, 800), 1200);
+		assert_eq!(NomineesElectionModule::votes(&1), 1200);
+	});
+}
+
+#[test]
+fn rebalance_by_weighted_votes_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(NomineesElectionModule::bond(Origin::signed(ALICE), 500));
+		assert_ok!(NomineesElectionModule::nominate(Origin::signed(ALICE), vec![1, 2]));
+
+		NomineesElectionModule::on_new_era(4);
+		assert_eq!(NomineesElectionModule::nominees(), vec![1, 2]);
+
+		assert_ok!(NomineesElectionModule::bond(Origin::signed(BOB), 600));
+		assert_ok!(NomineesElectionModule::nominate(Origin::signed(BOB), vec![3]));
+		assert_eq!(NomineesElectionModule::votes(&1), 1000);
+		assert_eq!(NomineesElectionModule::votes(&3), 600);
+
+		// ALICE has bonded for 5 eras, the weighted votes is 1000,
+		// BOB has bonded for 1 era, the weighted votes is 750.
+		NomineesElectionModule::on_new_era(5);
+		assert_eq!(NomineesElectionModule::nominees(), vec![1, 2, 3]);
+
+		// frozen nominee will not be elected.
+		FrozenNominee::set(Some(1));
+		NomineesElectionModule::on_new_era(6);
+		assert_eq!(NomineesElectionModule::nominees(), vec![2, 3]);
+	});
+}
+
 #[test]
 fn update_votes_work() {
 	ExtBuilder::default().build().execute_with(|| {
-		<Votes<Runtime>>::insert(1, 50);
-		<Votes<Runtime>>::insert(2, 100);
-		NomineesElectionModule::update_votes(30, &[1, 2], 50, &[1, 2]);
-		assert_eq!(NomineesElectionModule::votes(1), 70);
-		assert_eq!(NomineesElectionModule::votes(2), 120);
-		NomineesElectionModule::update_votes(0, &[1, 2], 50, &[3, 4]);
-		assert_eq!(NomineesElectionModule::votes(1), 70);
-		assert_eq!(NomineesElectionModule::votes(2), 120);
-		assert_eq!(NomineesElectionModule::votes(3), 50);
-		assert_eq!(NomineesElectionModule::votes(4), 50);
-		NomineesElectionModule::update_votes(200, &[1, 2, 3, 4], 10, &[3, 4]);
-		assert_eq!(NomineesElectionModule::votes(1), 0);
-		assert_eq!(NomineesElectionModule::votes(2), 0);
-		assert_eq!(NomineesElectionModule::votes(3), 10);
-		assert_eq!(NomineesElectionModule::votes(4), 10);
+		NomineesElectionModule::update_votes(&ALICE, 0, &[], 50, &[1, 2]);
+		assert_eq!(NomineesElectionModule::bonded_since(&ALICE), Some(0));
+		assert_eq!(NomineesElectionModule::votes(&1), 50);
+		assert_eq!(NomineesElectionModule::votes(&2), 50);
+		assert_eq!(NomineesElectionModule::maturing_votes(4, 1), 50);
+		NomineesElectionModule::update_votes(&ALICE, 50, &[1, 2], 30, &[2, 3]);
+		assert_eq!(NomineesElectionModule::votes(&1), 0);
+		assert_eq!(NomineesElectionModule::votes(&2), 30);
+		assert_eq!(NomineesElectionModule::votes(&3), 30);
+		assert!(!Votes::<Runtime>::contains_key(1));
+		assert!(!MaturingVotes::<Runtime>::contains_key(4, 1));
+		assert_eq!(NomineesElectionModule::maturing_votes(4, 2), 30);
+		NomineesElectionModule::update_votes(&ALICE, 30, &[2, 3], 0, &[2, 3]);
+		assert_eq!(NomineesElectionModule::bonded_since(&ALICE), None);
+		assert_eq!(NomineesElectionModule::votes(&2), 0);
+		assert_eq!(NomineesElectionModule::votes(&3), 0);
+		assert_eq!(MaturingVotes::<Runtime>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn vote_tally_matches_vote_weight() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(NomineesElectionModule::bond(Origin::signed(ALICE), 500));
+		assert_ok!(NomineesElectionModule::nominate(Origin::signed(ALICE), vec![1, 2]));
+		NomineesElectionModule::on_new_era(2);
+		assert_ok!(NomineesElectionModule::bond(Origin::signed(BOB), 400));
+		assert_ok!(NomineesElectionModule::nominate(Origin::signed(BOB), vec![1]));
+
+		NomineesElectionModule::on_new_era(3);
+		// ALICE: 500 + 500 * 3 / 4 = 875, BOB: 400 + 400 * 1 / 4 = 500
+		assert_eq!(NomineesElectionModule::votes(&1), 1375);
+		assert_eq!(NomineesElectionModule::votes(&2), 875);
+
+		// ALICE's bond reaches the maximum vote weight.
+		NomineesElectionModule::on_new_era(4);
+		assert_eq!(NomineesElectionModule::vote_tally(&1).matured, 500);
+		assert!(!MaturingVotes::<Runtime>::contains_key(4, 1));
+		assert_eq!(NomineesElectionModule::votes(&1), 1000 + 600);
+
+		// matured bond can be unbonded.
+		assert_ok!(NomineesElectionModule::unbond(Origin::signed(ALICE), 100));
+		assert_eq!(NomineesElectionModule::votes(&1), 800 + 600);
+		assert_eq!(NomineesElectionModule::votes(&2), 800);
+
+		// eras skipped are matured.
+		NomineesElectionModule::on_new_era(100);
+		assert_eq!(NomineesElectionModule::vote_tally(&1).matured, 800);
+		assert_eq!(NomineesElectionModule::vote_tally(&1).growing, 0);
+		assert_eq!(NomineesElectionModule::votes(&1), 800 + 800);
+		assert_eq!(MaturingVotes::<Runtime>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn migrate_to_v1_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		CurrentEra::<Runtime>::put(5);
+		assert_ok!(NomineesElectionModule::bond(Origin::signed(ALICE), 500));
+		assert_ok!(NomineesElectionModule::nominate(Origin::signed(ALICE), vec![1, 2]));
+
+		// bonds before the upgrade have no bonded era.
+		BondedSince::<Runtime>::remove(&ALICE);
+		CurrentEra::<Runtime>::put(8);
+
+		assert_eq!(StorageVersion::get::<NomineesElectionModule>(), 0);
+		migrations::v1::migrate::<Runtime, ()>();
+		assert_eq!(StorageVersion::get::<NomineesElectionModule>(), 1);
+		assert_eq!(NomineesElectionModule::bonded_since(&ALICE), Some(8));
+		assert_eq!(NomineesElectionModule::votes(&1), 500);
+		assert_eq!(NomineesElectionModule::maturing_votes(12, 2), 500);
+
+		// the migration only runs once
+		BondedSince::<Runtime>::remove(&ALICE);
+		migrations::v1::migrate::<Runtime, ()>();
+		assert_eq!(NomineesElectionModule::bonded_since(&ALICE), None);
+		BondedSince::<Runtime>::insert(&ALICE, 8);
+
+		NomineesElectionModule::on_new_era(10);
+		assert_eq!(NomineesElectionModule::votes(&1), 750);
 	});
 }
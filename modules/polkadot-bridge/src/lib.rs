@@ -16,6 +16,18 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
+//! # Polkadot Bridge Module
+//!
+//! ## Overview
+//!
+//! This module provides two implementations of `PolkadotBridge` for the staking pool:
+//!
+//! - `Pallet<T>` is a simulator which keeps the staking ledgers of the sub accounts in local
+//!   storage, and is only intended to be used for testing.
+//! - `XcmPolkadotBridge<T>` sends the equivalent relaychain calls over XCM, keeps the expected
+//!   staking ledgers of the sub accounts, and tracks the in-flight operations until they are
+//!   confirmed or marked as failed by `UpdateOrigin`.
+
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
 
@@ -24,14 +36,18 @@ use frame_system::pallet_prelude::*;
 use orml_traits::BasicCurrency;
 use primitives::{Balance, EraIndex};
 use sp_runtime::{
-	traits::{CheckedSub, MaybeDisplay, MaybeSerializeDeserialize, Member, StaticLookup, Zero},
+	traits::{CheckedSub, Convert, MaybeDisplay, MaybeSerializeDeserialize, Member, StaticLookup, Zero},
 	ArithmeticError, DispatchResult, FixedPointNumber, RuntimeDebug,
 };
-use sp_std::{convert::TryInto, fmt::Debug, prelude::*};
+use sp_std::{convert::TryInto, fmt::Debug, marker::PhantomData, prelude::*};
 use support::{
-	OnNewEra, PolkadotBridge, PolkadotBridgeCall, PolkadotBridgeState, PolkadotBridgeType, PolkadotStakingLedger,
-	PolkadotUnlockChunk, Rate,
+	CallBuilder, OnNewEra, PolkadotBridge, PolkadotBridgeCall, PolkadotBridgeState, PolkadotBridgeType,
+	PolkadotStakingLedger, PolkadotUnlockChunk, Rate, RewardDestination,
 };
+use xcm::latest::prelude::{MultiLocation, SendXcm};
+
+mod mock;
+mod tests;
 
 pub use module::*;
 
@@ -47,12 +63,37 @@ pub struct SubAccountStatus<Unbonding> {
 	pub mock_reward_rate: Rate,
 }
 
+/// The relaychain operation sent by the sub account via XCM
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
+pub enum RelaychainOperation<AccountId, PolkadotAccountId> {
+	/// Bond extra amount, bond if it's the first time
+	BondExtra(Balance),
+	/// Unbond amount
+	Unbond(Balance),
+	/// Rebond amount
+	Rebond(Balance),
+	/// Withdraw all unbonded
+	WithdrawUnbonded,
+	/// Payout the stakers of the nominated validators at era
+	PayoutStakers(EraIndex),
+	/// Nominate validators
+	Nominate(Vec<PolkadotAccountId>),
+	/// Transfer amount of the account from the parachain account to the sub account, the amount
+	/// is refunded to the account if the operation failed
+	TransferToBridge(AccountId, Balance),
+	/// Transfer amount from the sub account to the parachain account, the amount is deposited to
+	/// the account once the operation is confirmed, or credited back to the sub account if the
+	/// operation failed
+	ReceiveFromBridge(AccountId, Balance),
+}
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 		type DOTCurrency: BasicCurrency<Self::AccountId, Balance = Balance>;
 		type OnNewEra: OnNewEra<EraIndex>;
 		#[pallet::constant]
@@ -62,15 +103,58 @@ pub mod module {
 		type PolkadotAccountId: Parameter + Member + MaybeSerializeDeserialize + Debug + MaybeDisplay + Ord + Default;
 		#[pallet::constant]
 		type MaxUnbonding: Get<u32>;
+		/// The Call builder for communicating with Relaychain via XCM messaging.
+		type RelaychainCallBuilder: CallBuilder<AccountId = Self::PolkadotAccountId, Balance = Balance>;
+		/// The XCM sender used to send messages to the relaychain.
+		type XcmSender: SendXcm;
+		/// The fee(in staking currency) to buy execution of a call on the relaychain.
+		#[pallet::constant]
+		type RelaychainCallFee: Get<Balance>;
+		/// The weight limit of a call on the relaychain.
+		#[pallet::constant]
+		type RelaychainCallWeight: Get<Weight>;
+		/// Unbonding slashing spans for withdrawing unbonded on the relaychain.
+		#[pallet::constant]
+		type RelaychainUnbondingSlashingSpans: Get<u32>;
+		/// The account of the parachain on the relaychain.
+		#[pallet::constant]
+		type ParachainAccount: Get<Self::PolkadotAccountId>;
+		/// Convert the derivative index of sub account to the account on the relaychain.
+		type SubAccountIdConvert: Convert<u16, Self::PolkadotAccountId>;
+		/// The origin which may confirm the relaychain operations and update the staking ledgers
+		/// of the sub accounts.
+		type UpdateOrigin: EnsureOrigin<Self::Origin>;
 	}
 
 	#[pallet::error]
 	pub enum Error<T> {
 		NotEnough,
 		MaxUnbondingExceeded,
+		/// The sub account index can not be used as derivative index
+		InvalidSubAccountIndex,
+		/// Failed to send the XCM message to relaychain
+		XcmFailed,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	#[pallet::metadata(RelaychainOperationOf<T> = "RelaychainOperation")]
+	pub enum Event<T: Config> {
+		/// The relaychain operation of sub account has been sent via XCM. \[operation_id,
+		/// account_index, operation\]
+		RelaychainOperationSent(u32, u32, RelaychainOperationOf<T>),
+		/// The in-flight relaychain operation has been confirmed. \[operation_id\]
+		RelaychainOperationConfirmed(u32),
+		/// The in-flight relaychain operation has failed on relaychain. \[operation_id\]
+		RelaychainOperationFailed(u32),
+		/// The staking ledger of sub account has been updated. \[account_index\]
+		SubAccountLedgerUpdated(u32),
 	}
 
-	type Unbonding<T> = BoundedVec<(EraIndex, Balance), <T as Config>::MaxUnbonding>;
+	pub type Unbonding<T> = BoundedVec<(EraIndex, Balance), <T as Config>::MaxUnbonding>;
+	pub type SubAccountStatusOf<T> = SubAccountStatus<Unbonding<T>>;
+	pub type RelaychainOperationOf<T> =
+		RelaychainOperation<<T as frame_system::Config>::AccountId, <T as Config>::PolkadotAccountId>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn current_era)]
@@ -86,14 +170,45 @@ pub mod module {
 
 	#[pallet::storage]
 	#[pallet::getter(fn sub_accounts)]
-	pub type SubAccounts<T: Config> = StorageMap<_, Twox64Concat, u32, SubAccountStatus<Unbonding<T>>, ValueQuery>;
+	pub type SubAccounts<T: Config> = StorageMap<_, Twox64Concat, u32, SubAccountStatusOf<T>, ValueQuery>;
+
+	/// The expected staking ledgers of the sub accounts on relaychain, tracked by
+	/// `XcmPolkadotBridge`.
+	///
+	/// RelaychainSubAccounts: map u32 => SubAccountStatus
+	#[pallet::storage]
+	#[pallet::getter(fn relaychain_sub_accounts)]
+	pub type RelaychainSubAccounts<T: Config> = StorageMap<_, Twox64Concat, u32, SubAccountStatusOf<T>, ValueQuery>;
+
+	/// The validators nominated by the sub accounts, on relaychain for `XcmPolkadotBridge` or
+	/// simulated.
+	///
+	/// SubAccountNominations: map u32 => Vec<PolkadotAccountId>
+	#[pallet::storage]
+	#[pallet::getter(fn sub_account_nominations)]
+	pub type SubAccountNominations<T: Config> = StorageMap<_, Twox64Concat, u32, Vec<T::PolkadotAccountId>, ValueQuery>;
+
+	/// The id of the next relaychain operation.
+	///
+	/// NextOperationId: u32
+	#[pallet::storage]
+	#[pallet::getter(fn next_operation_id)]
+	pub type NextOperationId<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// The relaychain operations which have been sent but not yet confirmed.
+	///
+	/// InFlightOperations: map u32 => Option<(u32, RelaychainOperation)>
+	#[pallet::storage]
+	#[pallet::getter(fn in_flight_operations)]
+	pub type InFlightOperations<T: Config> =
+		StorageMap<_, Twox64Concat, u32, (u32, RelaychainOperationOf<T>), OptionQuery>;
 
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
-		fn on_finalize(now: T::BlockNumber) {
+		fn on_initialize(now: T::BlockNumber) -> Weight {
 			let force_era = Self::forced_era().map_or(false, |block| {
 				if block == now {
 					<ForcedEra<T>>::kill();
@@ -104,8 +219,11 @@ pub mod module {
 			});
 			let len = now.checked_sub(&Self::era_start_block_number()).unwrap_or_default();
 
+			let weight = T::DbWeight::get().reads_writes(2, 1);
 			if len >= T::EraLength::get() || force_era {
-				Self::new_era(now);
+				weight.saturating_add(Self::new_era(now))
+			} else {
+				weight
 			}
 		}
 	}
@@ -235,27 +353,181 @@ pub mod module {
 			}
 			Ok(())
 		}
+
+		/// Confirm the in-flight relaychain operations have been executed on relaychain. The amount
+		/// of a confirmed `ReceiveFromBridge` operation is deposited to its receiver.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `operation_ids`: the ids of the confirmed operations.
+		#[pallet::weight(10_000)]
+		#[transactional]
+		pub fn confirm_relaychain_operations(origin: OriginFor<T>, operation_ids: Vec<u32>) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			for operation_id in operation_ids {
+				if let Some((_, operation)) = InFlightOperations::<T>::take(operation_id) {
+					if let RelaychainOperation::ReceiveFromBridge(to, amount) = operation {
+						T::DOTCurrency::deposit(&to, amount)?;
+					}
+					Self::deposit_event(Event::RelaychainOperationConfirmed(operation_id));
+				}
+			}
+			Ok(())
+		}
+
+		/// Mark the in-flight relaychain operations as failed on relaychain. The amount of a failed
+		/// `ReceiveFromBridge` operation is credited back to the free amount of its sub account,
+		/// and the amount of a failed `TransferToBridge` operation is refunded to its sender. The
+		/// ledger changes of the other failed operations are corrected by
+		/// `update_sub_account_ledger`.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `operation_ids`: the ids of the failed operations.
+		#[pallet::weight(10_000)]
+		#[transactional]
+		pub fn fail_relaychain_operations(origin: OriginFor<T>, operation_ids: Vec<u32>) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			for operation_id in operation_ids {
+				if let Some((account_index, operation)) = InFlightOperations::<T>::take(operation_id) {
+					match operation {
+						RelaychainOperation::ReceiveFromBridge(_, amount) => {
+							RelaychainSubAccounts::<T>::try_mutate(account_index, |status| -> DispatchResult {
+								status.available =
+									status.available.checked_add(amount).ok_or(ArithmeticError::Overflow)?;
+								Ok(())
+							})?;
+						}
+						RelaychainOperation::TransferToBridge(from, amount) => {
+							RelaychainSubAccounts::<T>::mutate(account_index, |status| {
+								status.available = status.available.saturating_sub(amount);
+							});
+							T::DOTCurrency::deposit(&from, amount)?;
+						}
+						_ => {}
+					}
+					Self::deposit_event(Event::RelaychainOperationFailed(operation_id));
+				}
+			}
+			Ok(())
+		}
+
+		/// Update the expected staking ledger of sub account to the actual one on relaychain.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `account_index`: the index of sub account.
+		/// - `bonded`: the bonded amount.
+		/// - `available`: the free amount.
+		/// - `unbonding`: the unbonding chunks.
+		#[pallet::weight(10_000)]
+		#[transactional]
+		pub fn update_sub_account_ledger(
+			origin: OriginFor<T>,
+			#[pallet::compact] account_index: u32,
+			#[pallet::compact] bonded: Balance,
+			#[pallet::compact] available: Balance,
+			unbonding: Vec<(EraIndex, Balance)>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			let unbonding: Unbonding<T> = unbonding.try_into().map_err(|_| Error::<T>::MaxUnbondingExceeded)?;
+			RelaychainSubAccounts::<T>::mutate(account_index, |status| {
+				status.bonded = bonded;
+				status.available = available;
+				status.unbonding = unbonding;
+			});
+			Self::deposit_event(Event::SubAccountLedgerUpdated(account_index));
+			Ok(())
+		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
-	pub fn new_era(now: T::BlockNumber) {
+	pub fn new_era(now: T::BlockNumber) -> Weight {
 		let new_era = CurrentEra::<T>::mutate(|era| {
 			*era += 1;
 			*era
 		});
 		EraStartBlockNumber::<T>::put(now);
-		T::OnNewEra::on_new_era(new_era);
+		T::OnNewEra::on_new_era(new_era).saturating_add(T::DbWeight::get().reads_writes(1, 2))
+	}
+
+	/// bond extra on the staking ledger
+	fn do_bond_extra(status: &mut SubAccountStatusOf<T>, amount: Balance) -> DispatchResult {
+		status.available = status.available.checked_sub(amount).ok_or(Error::<T>::NotEnough)?;
+		status.bonded = status.bonded.checked_add(amount).ok_or(ArithmeticError::Overflow)?;
+		Ok(())
+	}
+
+	/// unbond on the staking ledger
+	fn do_unbond(status: &mut SubAccountStatusOf<T>, amount: Balance) -> DispatchResult {
+		status.bonded = status.bonded.checked_sub(amount).ok_or(Error::<T>::NotEnough)?;
+		let unbonded_era_index = Self::current_era() + T::BondingDuration::get();
+		status
+			.unbonding
+			.try_push((unbonded_era_index, amount))
+			.map_err(|_| Error::<T>::MaxUnbondingExceeded)?;
+		Ok(())
+	}
+
+	/// rebond on the staking ledger, return the rebonded amount
+	fn do_rebond(status: &mut SubAccountStatusOf<T>, amount: Balance) -> Result<Balance, DispatchError> {
+		let mut unbonding = status.unbonding.clone().into_inner();
+		let mut bonded = status.bonded;
+		let mut rebond_balance: Balance = Zero::zero();
+
+		while let Some(last) = unbonding.last_mut() {
+			if rebond_balance + last.1 <= amount {
+				rebond_balance += last.1;
+				bonded += last.1;
+				unbonding.pop();
+			} else {
+				let diff = amount - rebond_balance;
+
+				rebond_balance += diff;
+				bonded += diff;
+				last.1 -= diff;
+			}
+
+			if rebond_balance >= amount {
+				break;
+			}
+		}
+		ensure!(rebond_balance >= amount, Error::<T>::NotEnough);
+		if !rebond_balance.is_zero() {
+			status.bonded = bonded;
+			status.unbonding = unbonding.try_into().map_err(|_| Error::<T>::MaxUnbondingExceeded)?;
+		}
+
+		Ok(rebond_balance)
+	}
+
+	/// withdraw the unbonded on the staking ledger
+	fn do_withdraw_unbonded(status: &mut SubAccountStatusOf<T>) {
+		let current_era = Self::current_era();
+		let mut available = status.available;
+		let unbonding = status
+			.unbonding
+			.clone()
+			.into_iter()
+			.filter(|(era_index, value)| {
+				if *era_index > current_era {
+					true
+				} else {
+					available = available.saturating_add(*value);
+					false
+				}
+			})
+			.collect::<Vec<_>>();
+
+		status.available = available;
+		status.unbonding = unbonding.try_into().expect("Exceeded MaxUnBonding");
 	}
 
 	/// simulate bond extra by sub account
 	fn sub_account_bond_extra(account_index: u32, amount: Balance) -> DispatchResult {
 		if !amount.is_zero() {
-			SubAccounts::<T>::try_mutate(account_index, |status| -> DispatchResult {
-				status.available = status.available.checked_sub(amount).ok_or(Error::<T>::NotEnough)?;
-				status.bonded = status.bonded.checked_add(amount).ok_or(ArithmeticError::Overflow)?;
-				Ok(())
-			})?;
+			SubAccounts::<T>::try_mutate(account_index, |status| Self::do_bond_extra(status, amount))?;
 		}
 
 		Ok(())
@@ -264,22 +536,12 @@ impl<T: Config> Pallet<T> {
 	/// simulate unbond by sub account
 	fn sub_account_unbond(account_index: u32, amount: Balance) -> DispatchResult {
 		if !amount.is_zero() {
-			SubAccounts::<T>::try_mutate(account_index, |status| -> DispatchResult {
-				status.bonded = status.bonded.checked_sub(amount).ok_or(Error::<T>::NotEnough)?;
-				let current_era = Self::current_era();
-				let unbonded_era_index = current_era + T::BondingDuration::get();
-				status
-					.unbonding
-					.try_push((unbonded_era_index, amount))
-					.map_err(|_| Error::<T>::MaxUnbondingExceeded)?;
-				log::debug!(
-					target: "polkadot bridge simulator",
-					"sub account {:?} unbond: {:?} at {:?}",
-					account_index, amount, current_era,
-				);
-
-				Ok(())
-			})?;
+			SubAccounts::<T>::try_mutate(account_index, |status| Self::do_unbond(status, amount))?;
+			log::debug!(
+				target: "polkadot bridge simulator",
+				"sub account {:?} unbond: {:?} at {:?}",
+				account_index, amount, Self::current_era(),
+			);
 		}
 
 		Ok(())
@@ -287,66 +549,21 @@ impl<T: Config> Pallet<T> {
 
 	/// simulate rebond by sub account
 	fn sub_account_rebond(account_index: u32, amount: Balance) -> DispatchResult {
-		SubAccounts::<T>::try_mutate(account_index, |status| -> DispatchResult {
-			let mut unbonding = status.unbonding.clone().into_inner();
-			let mut bonded = status.bonded;
-			let mut rebond_balance: Balance = Zero::zero();
-
-			while let Some(last) = unbonding.last_mut() {
-				if rebond_balance + last.1 <= amount {
-					rebond_balance += last.1;
-					bonded += last.1;
-					unbonding.pop();
-				} else {
-					let diff = amount - rebond_balance;
-
-					rebond_balance += diff;
-					bonded += diff;
-					last.1 -= diff;
-				}
-
-				if rebond_balance >= amount {
-					break;
-				}
-			}
-			ensure!(rebond_balance >= amount, Error::<T>::NotEnough);
-			if !rebond_balance.is_zero() {
-				status.bonded = bonded;
-				status.unbonding = unbonding.try_into().map_err(|_| Error::<T>::MaxUnbondingExceeded)?;
-
-				log::debug!(
-					target: "polkadot bridge simulator",
-					"sub account {:?} rebond: {:?}",
-					account_index, rebond_balance,
-				);
-			}
+		let rebond_balance = SubAccounts::<T>::try_mutate(account_index, |status| Self::do_rebond(status, amount))?;
+		if !rebond_balance.is_zero() {
+			log::debug!(
+				target: "polkadot bridge simulator",
+				"sub account {:?} rebond: {:?}",
+				account_index, rebond_balance,
+			);
+		}
 
-			Ok(())
-		})
+		Ok(())
 	}
 
 	/// simulate withdraw unbonded by sub account
 	fn sub_account_withdraw_unbonded(account_index: u32) {
-		SubAccounts::<T>::mutate(account_index, |status| {
-			let current_era = Self::current_era();
-			let mut available = status.available;
-			let unbonding = status
-				.unbonding
-				.clone()
-				.into_iter()
-				.filter(|(era_index, value)| {
-					if *era_index > current_era {
-						true
-					} else {
-						available = available.saturating_add(*value);
-						false
-					}
-				})
-				.collect::<Vec<_>>();
-
-			status.available = available;
-			status.unbonding = unbonding.try_into().expect("Exceeded MaxUnBonding");
-		});
+		SubAccounts::<T>::mutate(account_index, Self::do_withdraw_unbonded);
 	}
 
 	/// simulate receive staking reward by sub account
@@ -364,7 +581,18 @@ impl<T: Config> Pallet<T> {
 	}
 
 	/// simulate nominate by sub account
-	fn sub_account_nominate(_account_index: u32, _targets: Vec<T::PolkadotAccountId>) {}
+	fn sub_account_nominate(account_index: u32, targets: Vec<T::PolkadotAccountId>) {
+		if targets.is_empty() {
+			return;
+		}
+
+		log::debug!(
+			target: "polkadot bridge simulator",
+			"sub account {:?} nominate: {:?}",
+			account_index, targets,
+		);
+		SubAccountNominations::<T>::insert(account_index, targets);
+	}
 
 	/// simulate transfer dot from acala to parachain sub account in
 	/// polkadot
@@ -383,6 +611,55 @@ impl<T: Config> Pallet<T> {
 			T::DOTCurrency::deposit(to, amount)
 		})
 	}
+
+	/// convert the sub account index to the derivative index on relaychain
+	fn derivative_index(account_index: u32) -> Result<u16, DispatchError> {
+		account_index
+			.try_into()
+			.map_err(|_| Error::<T>::InvalidSubAccountIndex.into())
+	}
+
+	/// send the call to relaychain via XCM, and track it as an in-flight operation
+	fn send_relaychain_call(
+		account_index: u32,
+		call: <T::RelaychainCallBuilder as CallBuilder>::RelaychainCall,
+		operation: RelaychainOperationOf<T>,
+	) -> DispatchResult {
+		let weight = T::RelaychainCallWeight::get();
+		let msg =
+			T::RelaychainCallBuilder::finalize_call_into_xcm_message(call, T::RelaychainCallFee::get(), weight, weight);
+		T::XcmSender::send_xcm(MultiLocation::parent(), msg).map_err(|e| {
+			log::warn!(
+				target: "polkadot bridge",
+				"failed to send XCM for sub account {:?}, operation {:?}: {:?}",
+				account_index, operation, e,
+			);
+			Error::<T>::XcmFailed
+		})?;
+
+		let operation_id = NextOperationId::<T>::mutate(|id| {
+			let current = *id;
+			*id = id.wrapping_add(1);
+			current
+		});
+		InFlightOperations::<T>::insert(operation_id, (account_index, operation.clone()));
+		Self::deposit_event(Event::RelaychainOperationSent(operation_id, account_index, operation));
+		Ok(())
+	}
+
+	/// send the call dispatched by the sub account to relaychain via XCM
+	fn send_sub_account_call(
+		account_index: u32,
+		call: <T::RelaychainCallBuilder as CallBuilder>::RelaychainCall,
+		operation: RelaychainOperationOf<T>,
+	) -> DispatchResult {
+		let derivative_index = Self::derivative_index(account_index)?;
+		Self::send_relaychain_call(
+			account_index,
+			T::RelaychainCallBuilder::utility_as_derivative_call(call, derivative_index),
+			operation,
+		)
+	}
 }
 
 impl<T: Config> PolkadotBridgeType<T::BlockNumber, EraIndex> for Pallet<T> {
@@ -425,27 +702,31 @@ impl<T: Config> PolkadotBridgeCall<T::AccountId, T::BlockNumber, Balance, EraInd
 	}
 }
 
+/// Convert the sub account status to the staking ledger
+fn staking_ledger_of<T: Config>(status: SubAccountStatusOf<T>) -> PolkadotStakingLedger<Balance, EraIndex> {
+	let mut total = status.bonded;
+	let unlocking = status
+		.unbonding
+		.into_iter()
+		.map(|(era_index, balance)| {
+			total = total.saturating_add(balance);
+			PolkadotUnlockChunk {
+				value: balance,
+				era: era_index,
+			}
+		})
+		.collect::<_>();
+
+	PolkadotStakingLedger {
+		total,
+		active: status.bonded,
+		unlocking,
+	}
+}
+
 impl<T: Config> PolkadotBridgeState<Balance, EraIndex> for Pallet<T> {
 	fn staking_ledger(account_index: u32) -> PolkadotStakingLedger<Balance, EraIndex> {
-		let status = Self::sub_accounts(account_index);
-		let mut total = status.bonded;
-		let unlocking = status
-			.unbonding
-			.into_iter()
-			.map(|(era_index, balance)| {
-				total = total.saturating_add(balance);
-				PolkadotUnlockChunk {
-					value: balance,
-					era: era_index,
-				}
-			})
-			.collect::<_>();
-
-		PolkadotStakingLedger {
-			total,
-			active: status.bonded,
-			unlocking,
-		}
+		staking_ledger_of::<T>(Self::sub_accounts(account_index))
 	}
 
 	fn free_balance(account_index: u32) -> Balance {
@@ -458,3 +739,181 @@ impl<T: Config> PolkadotBridgeState<Balance, EraIndex> for Pallet<T> {
 }
 
 impl<T: Config> PolkadotBridge<T::AccountId, T::BlockNumber, Balance, EraIndex> for Pallet<T> {}
+
+/// The `PolkadotBridge` implementation which sends the staking operations of the sub accounts to
+/// relaychain via XCM.
+pub struct XcmPolkadotBridge<T>(PhantomData<T>);
+
+impl<T: Config> PolkadotBridgeType<T::BlockNumber, EraIndex> for XcmPolkadotBridge<T> {
+	type BondingDuration = T::BondingDuration;
+	type EraLength = T::EraLength;
+	type PolkadotAccountId = T::PolkadotAccountId;
+}
+
+impl<T: Config> PolkadotBridgeCall<T::AccountId, T::BlockNumber, Balance, EraIndex> for XcmPolkadotBridge<T> {
+	fn bond_extra(account_index: u32, amount: Balance) -> DispatchResult {
+		if amount.is_zero() {
+			return Ok(());
+		}
+
+		RelaychainSubAccounts::<T>::try_mutate(account_index, |status| -> DispatchResult {
+			// the sub account has to bond before bonding extra on relaychain
+			let call = if status.bonded.is_zero() && status.unbonding.is_empty() {
+				let controller = T::SubAccountIdConvert::convert(Pallet::<T>::derivative_index(account_index)?);
+				T::RelaychainCallBuilder::staking_bond(controller, amount, RewardDestination::Staked)
+			} else {
+				T::RelaychainCallBuilder::staking_bond_extra(amount)
+			};
+			Pallet::<T>::do_bond_extra(status, amount)?;
+			Pallet::<T>::send_sub_account_call(account_index, call, RelaychainOperation::BondExtra(amount))
+		})
+	}
+
+	fn unbond(account_index: u32, amount: Balance) -> DispatchResult {
+		if amount.is_zero() {
+			return Ok(());
+		}
+
+		RelaychainSubAccounts::<T>::try_mutate(account_index, |status| -> DispatchResult {
+			Pallet::<T>::do_unbond(status, amount)?;
+			Pallet::<T>::send_sub_account_call(
+				account_index,
+				T::RelaychainCallBuilder::staking_unbond(amount),
+				RelaychainOperation::Unbond(amount),
+			)
+		})
+	}
+
+	fn rebond(account_index: u32, amount: Balance) -> DispatchResult {
+		RelaychainSubAccounts::<T>::try_mutate(account_index, |status| -> DispatchResult {
+			let rebond_balance = Pallet::<T>::do_rebond(status, amount)?;
+			if rebond_balance.is_zero() {
+				return Ok(());
+			}
+
+			Pallet::<T>::send_sub_account_call(
+				account_index,
+				T::RelaychainCallBuilder::staking_rebond(rebond_balance),
+				RelaychainOperation::Rebond(rebond_balance),
+			)
+		})
+	}
+
+	fn withdraw_unbonded(account_index: u32) {
+		let res = RelaychainSubAccounts::<T>::try_mutate(account_index, |status| -> DispatchResult {
+			Pallet::<T>::do_withdraw_unbonded(status);
+			Pallet::<T>::send_sub_account_call(
+				account_index,
+				T::RelaychainCallBuilder::staking_withdraw_unbonded(T::RelaychainUnbondingSlashingSpans::get()),
+				RelaychainOperation::WithdrawUnbonded,
+			)
+		});
+		if let Err(e) = res {
+			log::warn!(
+				target: "polkadot bridge",
+				"withdraw_unbonded: failed for sub account {:?}: {:?}. \
+				This is unexpected but should be safe",
+				account_index, e
+			);
+		}
+	}
+
+	fn payout_stakers(account_index: u32, era: EraIndex) {
+		let validators = Pallet::<T>::sub_account_nominations(account_index);
+		if validators.is_empty() {
+			return;
+		}
+
+		// the reward is bonded to the sub account directly, the ledger will be updated by
+		// `UpdateOrigin` once it's received.
+		let res = Pallet::<T>::send_relaychain_call(
+			account_index,
+			T::RelaychainCallBuilder::utility_batch_call(
+				validators
+					.into_iter()
+					.map(|validator| T::RelaychainCallBuilder::staking_payout_stakers(validator, era))
+					.collect(),
+			),
+			RelaychainOperation::PayoutStakers(era),
+		);
+		if let Err(e) = res {
+			log::warn!(
+				target: "polkadot bridge",
+				"payout_stakers: failed for sub account {:?} at era {:?}: {:?}. \
+				This is unexpected but should be safe",
+				account_index, era, e
+			);
+		}
+	}
+
+	fn nominate(account_index: u32, targets: Vec<Self::PolkadotAccountId>) {
+		if targets.is_empty() {
+			return;
+		}
+
+		let res = Pallet::<T>::send_sub_account_call(
+			account_index,
+			T::RelaychainCallBuilder::staking_nominate(targets.clone()),
+			RelaychainOperation::Nominate(targets.clone()),
+		);
+		match res {
+			Ok(()) => SubAccountNominations::<T>::insert(account_index, targets),
+			Err(e) => log::warn!(
+				target: "polkadot bridge",
+				"nominate: failed for sub account {:?}: {:?}. \
+				This is unexpected but should be safe",
+				account_index, e
+			),
+		}
+	}
+
+	#[transactional]
+	fn transfer_to_bridge(account_index: u32, from: &T::AccountId, amount: Balance) -> DispatchResult {
+		let sub_account = T::SubAccountIdConvert::convert(Pallet::<T>::derivative_index(account_index)?);
+		T::DOTCurrency::withdraw(from, amount)?;
+		RelaychainSubAccounts::<T>::try_mutate(account_index, |status| -> DispatchResult {
+			status.available = status.available.checked_add(amount).ok_or(ArithmeticError::Overflow)?;
+			Ok(())
+		})?;
+
+		// transfer from the parachain account to the sub account on relaychain
+		Pallet::<T>::send_relaychain_call(
+			account_index,
+			T::RelaychainCallBuilder::balances_transfer_keep_alive(sub_account, amount),
+			RelaychainOperation::TransferToBridge(from.clone(), amount),
+		)
+	}
+
+	/// The amount is deposited to `to` by `confirm_relaychain_operations` once the transfer has
+	/// been executed on relaychain.
+	#[transactional]
+	fn receive_from_bridge(account_index: u32, to: &T::AccountId, amount: Balance) -> DispatchResult {
+		RelaychainSubAccounts::<T>::try_mutate(account_index, |status| -> DispatchResult {
+			status.available = status.available.checked_sub(amount).ok_or(Error::<T>::NotEnough)?;
+			Ok(())
+		})?;
+
+		// transfer from the sub account to the parachain account on relaychain
+		Pallet::<T>::send_sub_account_call(
+			account_index,
+			T::RelaychainCallBuilder::balances_transfer_keep_alive(T::ParachainAccount::get(), amount),
+			RelaychainOperation::ReceiveFromBridge(to.clone(), amount),
+		)
+	}
+}
+
+impl<T: Config> PolkadotBridgeState<Balance, EraIndex> for XcmPolkadotBridge<T> {
+	fn staking_ledger(account_index: u32) -> PolkadotStakingLedger<Balance, EraIndex> {
+		staking_ledger_of::<T>(Pallet::<T>::relaychain_sub_accounts(account_index))
+	}
+
+	fn free_balance(account_index: u32) -> Balance {
+		Pallet::<T>::relaychain_sub_accounts(account_index).available
+	}
+
+	fn current_era() -> EraIndex {
+		Pallet::<T>::current_era()
+	}
+}
+
+impl<T: Config> PolkadotBridge<T::AccountId, T::BlockNumber, Balance, EraIndex> for XcmPolkadotBridge<T> {}
@@ -0,0 +1,339 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Mocks for polkadot bridge module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{construct_runtime, parameter_types};
+use frame_system::EnsureRoot;
+use orml_traits::parameter_type_with_key;
+use primitives::{Amount, CurrencyId, TokenSymbol};
+use sp_core::H256;
+use sp_runtime::{testing::Header, traits::IdentityLookup};
+use sp_std::cell::RefCell;
+use xcm::latest::prelude::*;
+
+pub type AccountId = u128;
+pub type BlockNumber = u64;
+pub type PolkadotAccountId = u128;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+pub const PARACHAIN_ACCOUNT: PolkadotAccountId = 1000;
+pub const VALIDATOR_1: PolkadotAccountId = 2001;
+pub const VALIDATOR_2: PolkadotAccountId = 2002;
+pub const ACA: CurrencyId = CurrencyId::Token(TokenSymbol::ACA);
+pub const DOT: CurrencyId = CurrencyId::Token(TokenSymbol::DOT);
+
+mod polkadot_bridge {
+	pub use super::super::*;
+}
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+}
+
+impl frame_system::Config for Runtime {
+	type Origin = Origin;
+	type Index = u64;
+	type BlockNumber = BlockNumber;
+	type Call = Call;
+	type Hash = H256;
+	type Hashing = ::sp_runtime::traits::BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = BlockHashCount;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type DbWeight = ();
+	type BaseCallFilter = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+}
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: CurrencyId| -> Balance {
+		Default::default()
+	};
+}
+
+impl orml_tokens::Config for Runtime {
+	type Event = Event;
+	type Balance = Balance;
+	type Amount = Amount;
+	type CurrencyId = CurrencyId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type OnDust = ();
+	type MaxLocks = ();
+	type DustRemovalWhitelist = ();
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: Balance = 1;
+}
+
+impl pallet_balances::Config for Runtime {
+	type Balance = Balance;
+	type DustRemoval = ();
+	type Event = Event;
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type WeightInfo = ();
+}
+pub type NativeCurrency = orml_currencies::BasicCurrencyAdapter<Runtime, PalletBalances, Amount, BlockNumber>;
+
+parameter_types! {
+	pub const GetNativeCurrencyId: CurrencyId = ACA;
+	pub const GetStakingCurrencyId: CurrencyId = DOT;
+}
+
+impl orml_currencies::Config for Runtime {
+	type Event = Event;
+	type MultiCurrency = TokensModule;
+	type NativeCurrency = NativeCurrency;
+	type GetNativeCurrencyId = GetNativeCurrencyId;
+	type WeightInfo = ();
+}
+
+/// The relaychain calls built by `MockCallBuilder`.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
+pub enum MockRelaychainCall {
+	Batch(Vec<MockRelaychainCall>),
+	AsDerivative(u16, Box<MockRelaychainCall>),
+	Bond(PolkadotAccountId, Balance, RewardDestination<PolkadotAccountId>),
+	BondExtra(Balance),
+	Unbond(Balance),
+	Rebond(Balance),
+	WithdrawUnbonded(u32),
+	Nominate(Vec<PolkadotAccountId>),
+	Chill,
+	PayoutStakers(PolkadotAccountId, EraIndex),
+	Proxy(PolkadotAccountId, Box<MockRelaychainCall>),
+	TransferKeepAlive(PolkadotAccountId, Balance),
+}
+
+pub struct MockCallBuilder;
+impl CallBuilder for MockCallBuilder {
+	type AccountId = PolkadotAccountId;
+	type Balance = Balance;
+	type RelaychainCall = MockRelaychainCall;
+
+	fn utility_batch_call(calls: Vec<Self::RelaychainCall>) -> Self::RelaychainCall {
+		MockRelaychainCall::Batch(calls)
+	}
+
+	fn utility_as_derivative_call(call: Self::RelaychainCall, index: u16) -> Self::RelaychainCall {
+		MockRelaychainCall::AsDerivative(index, Box::new(call))
+	}
+
+	fn staking_bond(
+		controller: Self::AccountId,
+		amount: Self::Balance,
+		payee: RewardDestination<Self::AccountId>,
+	) -> Self::RelaychainCall {
+		MockRelaychainCall::Bond(controller, amount, payee)
+	}
+
+	fn staking_bond_extra(amount: Self::Balance) -> Self::RelaychainCall {
+		MockRelaychainCall::BondExtra(amount)
+	}
+
+	fn staking_unbond(amount: Self::Balance) -> Self::RelaychainCall {
+		MockRelaychainCall::Unbond(amount)
+	}
+
+	fn staking_rebond(amount: Self::Balance) -> Self::RelaychainCall {
+		MockRelaychainCall::Rebond(amount)
+	}
+
+	fn staking_withdraw_unbonded(num_slashing_spans: u32) -> Self::RelaychainCall {
+		MockRelaychainCall::WithdrawUnbonded(num_slashing_spans)
+	}
+
+	fn staking_nominate(targets: Vec<Self::AccountId>) -> Self::RelaychainCall {
+		MockRelaychainCall::Nominate(targets)
+	}
+
+	fn staking_chill() -> Self::RelaychainCall {
+		MockRelaychainCall::Chill
+	}
+
+	fn staking_payout_stakers(validator_stash: Self::AccountId, era: EraIndex) -> Self::RelaychainCall {
+		MockRelaychainCall::PayoutStakers(validator_stash, era)
+	}
+
+	fn proxy_call(real: Self::AccountId, call: Self::RelaychainCall) -> Self::RelaychainCall {
+		MockRelaychainCall::Proxy(real, Box::new(call))
+	}
+
+	fn balances_transfer_keep_alive(to: Self::AccountId, amount: Self::Balance) -> Self::RelaychainCall {
+		MockRelaychainCall::TransferKeepAlive(to, amount)
+	}
+
+	fn finalize_call_into_xcm_message(
+		call: Self::RelaychainCall,
+		extra_fee: Self::Balance,
+		weight: Weight,
+		debt: Weight,
+	) -> Xcm<()> {
+		let asset = MultiAsset {
+			id: Concrete(MultiLocation::here()),
+			fun: Fungibility::Fungible(extra_fee),
+		};
+		Xcm::WithdrawAsset {
+			assets: vec![asset.clone()].into(),
+			effects: vec![Order::BuyExecution {
+				fees: asset,
+				weight,
+				debt,
+				halt_on_error: true,
+				instructions: vec![Xcm::Transact {
+					origin_type: OriginKind::SovereignAccount,
+					require_weight_at_most: weight,
+					call: call.encode().into(),
+				}],
+			}],
+		}
+	}
+}
+
+thread_local! {
+	pub static SENT_XCM: RefCell<Vec<(MultiLocation, Xcm<()>)>> = RefCell::new(Vec::new());
+	pub static XCM_FAILED: RefCell<bool> = RefCell::new(false);
+}
+
+/// Records the sent messages, and fails to send if `XCM_FAILED` is set.
+pub struct MockXcmSender;
+impl SendXcm for MockXcmSender {
+	fn send_xcm(destination: MultiLocation, message: Xcm<()>) -> XcmResult {
+		if XCM_FAILED.with(|v| *v.borrow()) {
+			return Err(XcmError::Unroutable);
+		}
+		SENT_XCM.with(|v| v.borrow_mut().push((destination, message)));
+		Ok(())
+	}
+}
+
+pub fn sent_xcm() -> Vec<(MultiLocation, Xcm<()>)> {
+	SENT_XCM.with(|v| v.borrow().clone())
+}
+
+pub fn set_xcm_failed(failed: bool) {
+	XCM_FAILED.with(|v| *v.borrow_mut() = failed);
+}
+
+pub struct MockSubAccountIdConvert;
+impl Convert<u16, PolkadotAccountId> for MockSubAccountIdConvert {
+	fn convert(index: u16) -> PolkadotAccountId {
+		100 + index as PolkadotAccountId
+	}
+}
+
+parameter_types! {
+	pub const BondingDuration: EraIndex = 4;
+	pub const EraLength: BlockNumber = 10;
+	pub const MaxUnbonding: u32 = 3;
+	pub const RelaychainCallFee: Balance = 10;
+	pub const RelaychainCallWeight: Weight = 1_000;
+	pub const RelaychainUnbondingSlashingSpans: u32 = 5;
+	pub const ParachainAccount: PolkadotAccountId = PARACHAIN_ACCOUNT;
+}
+
+impl Config for Runtime {
+	type Event = Event;
+	type DOTCurrency = orml_currencies::Currency<Runtime, GetStakingCurrencyId>;
+	type OnNewEra = ();
+	type BondingDuration = BondingDuration;
+	type EraLength = EraLength;
+	type PolkadotAccountId = PolkadotAccountId;
+	type MaxUnbonding = MaxUnbonding;
+	type RelaychainCallBuilder = MockCallBuilder;
+	type XcmSender = MockXcmSender;
+	type RelaychainCallFee = RelaychainCallFee;
+	type RelaychainCallWeight = RelaychainCallWeight;
+	type RelaychainUnbondingSlashingSpans = RelaychainUnbondingSlashingSpans;
+	type ParachainAccount = ParachainAccount;
+	type SubAccountIdConvert = MockSubAccountIdConvert;
+	type UpdateOrigin = EnsureRoot<AccountId>;
+}
+
+pub type XcmBridge = XcmPolkadotBridge<Runtime>;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+construct_runtime!(
+	pub enum Runtime where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		PolkadotBridgeModule: polkadot_bridge::{Pallet, Call, Storage, Event<T>},
+		PalletBalances: pallet_balances::{Pallet, Call, Storage, Event<T>},
+		TokensModule: orml_tokens::{Pallet, Storage, Event<T>, Config<T>},
+		CurrenciesModule: orml_currencies::{Pallet, Call, Event<T>},
+	}
+);
+
+pub struct ExtBuilder {
+	balances: Vec<(AccountId, CurrencyId, Balance)>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		Self {
+			balances: vec![(ALICE, DOT, 1000), (BOB, DOT, 1000)],
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::default()
+			.build_storage::<Runtime>()
+			.unwrap();
+
+		orml_tokens::GenesisConfig::<Runtime> {
+			balances: self.balances,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		SENT_XCM.with(|v| v.borrow_mut().clear());
+		set_xcm_failed(false);
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
@@ -0,0 +1,381 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for polkadot bridge module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use mock::{
+	sent_xcm, set_xcm_failed, CurrenciesModule, Event, ExtBuilder, MockCallBuilder, MockRelaychainCall, Origin,
+	PolkadotBridgeModule, Runtime, System, XcmBridge, ALICE, BOB, DOT, PARACHAIN_ACCOUNT, VALIDATOR_1, VALIDATOR_2,
+};
+use orml_traits::MultiCurrency;
+use sp_runtime::traits::BadOrigin;
+use xcm::latest::prelude::Xcm;
+
+fn relaychain_call_message(call: MockRelaychainCall) -> (MultiLocation, Xcm<()>) {
+	(
+		MultiLocation::parent(),
+		MockCallBuilder::finalize_call_into_xcm_message(call, 10, 1_000, 1_000),
+	)
+}
+
+fn sub_account_call_message(account_index: u16, call: MockRelaychainCall) -> (MultiLocation, Xcm<()>) {
+	relaychain_call_message(MockRelaychainCall::AsDerivative(account_index, Box::new(call)))
+}
+
+#[test]
+fn new_era_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(PolkadotBridgeModule::current_era(), 0);
+
+		PolkadotBridgeModule::on_initialize(9);
+		assert_eq!(PolkadotBridgeModule::current_era(), 0);
+
+		PolkadotBridgeModule::on_initialize(10);
+		assert_eq!(PolkadotBridgeModule::current_era(), 1);
+		assert_eq!(PolkadotBridgeModule::era_start_block_number(), 10);
+
+		assert_noop!(PolkadotBridgeModule::force_era(Origin::signed(ALICE), 15), BadOrigin);
+		assert_ok!(PolkadotBridgeModule::force_era(Origin::root(), 15));
+		assert_eq!(PolkadotBridgeModule::forced_era(), Some(15));
+
+		PolkadotBridgeModule::on_initialize(15);
+		assert_eq!(PolkadotBridgeModule::current_era(), 2);
+		assert_eq!(PolkadotBridgeModule::era_start_block_number(), 15);
+		assert_eq!(PolkadotBridgeModule::forced_era(), None);
+	});
+}
+
+#[test]
+fn simulator_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(PolkadotBridgeModule::transfer_to_bridge(1, &ALICE, 500));
+		assert_eq!(CurrenciesModule::free_balance(DOT, &ALICE), 500);
+		assert_eq!(PolkadotBridgeModule::free_balance(1), 500);
+
+		assert_ok!(PolkadotBridgeModule::bond_extra(1, 300));
+		assert_ok!(PolkadotBridgeModule::unbond(1, 100));
+		assert_eq!(
+			PolkadotBridgeModule::staking_ledger(1),
+			PolkadotStakingLedger {
+				total: 300,
+				active: 200,
+				unlocking: vec![PolkadotUnlockChunk { value: 100, era: 4 }],
+			}
+		);
+
+		assert_ok!(PolkadotBridgeModule::rebond(1, 50));
+		assert_eq!(PolkadotBridgeModule::staking_ledger(1).active, 250);
+		assert_noop!(PolkadotBridgeModule::rebond(1, 100), Error::<Runtime>::NotEnough);
+
+		assert_noop!(
+			PolkadotBridgeModule::receive_from_bridge(1, &BOB, 201),
+			Error::<Runtime>::NotEnough
+		);
+		assert_ok!(PolkadotBridgeModule::receive_from_bridge(1, &BOB, 200));
+		assert_eq!(CurrenciesModule::free_balance(DOT, &BOB), 1200);
+		assert_eq!(PolkadotBridgeModule::free_balance(1), 0);
+
+		// the simulator does not send any relaychain call
+		assert!(sent_xcm().is_empty());
+	});
+}
+
+#[test]
+fn simulator_nominate_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		PolkadotBridgeModule::nominate(1, vec![]);
+		assert_eq!(PolkadotBridgeModule::sub_account_nominations(1), Vec::<u128>::new());
+
+		PolkadotBridgeModule::nominate(1, vec![VALIDATOR_1, VALIDATOR_2]);
+		assert_eq!(
+			PolkadotBridgeModule::sub_account_nominations(1),
+			vec![VALIDATOR_1, VALIDATOR_2]
+		);
+		assert!(sent_xcm().is_empty());
+	});
+}
+
+#[test]
+fn xcm_bridge_bond_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(XcmBridge::transfer_to_bridge(1, &ALICE, 500));
+		assert_eq!(CurrenciesModule::free_balance(DOT, &ALICE), 500);
+		assert_eq!(XcmBridge::free_balance(1), 500);
+		System::assert_last_event(Event::PolkadotBridgeModule(crate::Event::RelaychainOperationSent(
+			0,
+			1,
+			RelaychainOperation::TransferToBridge(ALICE, 500),
+		)));
+
+		assert_noop!(XcmBridge::bond_extra(1, 501), Error::<Runtime>::NotEnough);
+
+		// bond for the first time
+		assert_ok!(XcmBridge::bond_extra(1, 300));
+		// bond extra afterwards
+		assert_ok!(XcmBridge::bond_extra(1, 100));
+		assert_ok!(XcmBridge::unbond(1, 150));
+		assert_ok!(XcmBridge::rebond(1, 50));
+		assert_eq!(
+			XcmBridge::staking_ledger(1),
+			PolkadotStakingLedger {
+				total: 400,
+				active: 300,
+				unlocking: vec![PolkadotUnlockChunk { value: 100, era: 4 }],
+			}
+		);
+		assert_eq!(XcmBridge::free_balance(1), 100);
+
+		assert_eq!(
+			sent_xcm(),
+			vec![
+				relaychain_call_message(MockRelaychainCall::TransferKeepAlive(101, 500)),
+				sub_account_call_message(1, MockRelaychainCall::Bond(101, 300, RewardDestination::Staked)),
+				sub_account_call_message(1, MockRelaychainCall::BondExtra(100)),
+				sub_account_call_message(1, MockRelaychainCall::Unbond(150)),
+				sub_account_call_message(1, MockRelaychainCall::Rebond(50)),
+			]
+		);
+		assert_eq!(PolkadotBridgeModule::next_operation_id(), 5);
+		assert_eq!(
+			PolkadotBridgeModule::in_flight_operations(4),
+			Some((1, RelaychainOperation::Rebond(50)))
+		);
+
+		// the simulator ledger is untouched
+		assert_eq!(PolkadotBridgeModule::free_balance(1), 0);
+	});
+}
+
+#[test]
+fn xcm_bridge_withdraw_unbonded_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(XcmBridge::transfer_to_bridge(1, &ALICE, 500));
+		assert_ok!(XcmBridge::bond_extra(1, 500));
+		assert_ok!(XcmBridge::unbond(1, 200));
+
+		for era in 1..=4 {
+			PolkadotBridgeModule::on_initialize(era * 10);
+		}
+		assert_eq!(XcmBridge::current_era(), 4);
+
+		XcmBridge::withdraw_unbonded(1);
+		assert_eq!(XcmBridge::free_balance(1), 200);
+		assert_eq!(XcmBridge::staking_ledger(1).total, 300);
+		assert_eq!(
+			sent_xcm().last(),
+			Some(&sub_account_call_message(1, MockRelaychainCall::WithdrawUnbonded(5)))
+		);
+	});
+}
+
+#[test]
+fn xcm_bridge_nominate_and_payout_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		// no nominations yet
+		XcmBridge::payout_stakers(1, 1);
+		assert!(sent_xcm().is_empty());
+
+		XcmBridge::nominate(1, vec![VALIDATOR_1, VALIDATOR_2]);
+		assert_eq!(
+			PolkadotBridgeModule::sub_account_nominations(1),
+			vec![VALIDATOR_1, VALIDATOR_2]
+		);
+		assert_eq!(
+			PolkadotBridgeModule::in_flight_operations(0),
+			Some((1, RelaychainOperation::Nominate(vec![VALIDATOR_1, VALIDATOR_2])))
+		);
+
+		XcmBridge::payout_stakers(1, 1);
+		assert_eq!(
+			sent_xcm(),
+			vec![
+				sub_account_call_message(1, MockRelaychainCall::Nominate(vec![VALIDATOR_1, VALIDATOR_2])),
+				relaychain_call_message(MockRelaychainCall::Batch(vec![
+					MockRelaychainCall::PayoutStakers(VALIDATOR_1, 1),
+					MockRelaychainCall::PayoutStakers(VALIDATOR_2, 1),
+				])),
+			]
+		);
+	});
+}
+
+#[test]
+fn xcm_bridge_receive_from_bridge_credits_after_confirmed() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(XcmBridge::transfer_to_bridge(1, &ALICE, 500));
+		assert_noop!(
+			XcmBridge::receive_from_bridge(1, &BOB, 501),
+			Error::<Runtime>::NotEnough
+		);
+
+		assert_ok!(XcmBridge::receive_from_bridge(1, &BOB, 200));
+		assert_eq!(XcmBridge::free_balance(1), 300);
+		assert_eq!(
+			sent_xcm().last(),
+			Some(&sub_account_call_message(
+				1,
+				MockRelaychainCall::TransferKeepAlive(PARACHAIN_ACCOUNT, 200)
+			))
+		);
+		// not credited before the transfer is confirmed
+		assert_eq!(CurrenciesModule::free_balance(DOT, &BOB), 1000);
+		assert_eq!(
+			PolkadotBridgeModule::in_flight_operations(1),
+			Some((1, RelaychainOperation::ReceiveFromBridge(BOB, 200)))
+		);
+
+		assert_noop!(
+			PolkadotBridgeModule::confirm_relaychain_operations(Origin::signed(ALICE), vec![1]),
+			BadOrigin
+		);
+		assert_ok!(PolkadotBridgeModule::confirm_relaychain_operations(
+			Origin::root(),
+			vec![0, 1, 2]
+		));
+		System::assert_last_event(Event::PolkadotBridgeModule(crate::Event::RelaychainOperationConfirmed(
+			1,
+		)));
+		assert_eq!(CurrenciesModule::free_balance(DOT, &BOB), 1200);
+		assert_eq!(PolkadotBridgeModule::in_flight_operations(0), None);
+		assert_eq!(PolkadotBridgeModule::in_flight_operations(1), None);
+
+		// confirming again does not credit twice
+		assert_ok!(PolkadotBridgeModule::confirm_relaychain_operations(
+			Origin::root(),
+			vec![1]
+		));
+		assert_eq!(CurrenciesModule::free_balance(DOT, &BOB), 1200);
+	});
+}
+
+#[test]
+fn xcm_bridge_failed_operations_are_reverted() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(XcmBridge::transfer_to_bridge(1, &ALICE, 500));
+		assert_ok!(XcmBridge::receive_from_bridge(1, &BOB, 200));
+		assert_ok!(XcmBridge::bond_extra(1, 100));
+		assert_eq!(XcmBridge::free_balance(1), 200);
+		assert_eq!(CurrenciesModule::free_balance(DOT, &ALICE), 500);
+
+		assert_noop!(
+			PolkadotBridgeModule::fail_relaychain_operations(Origin::signed(ALICE), vec![1]),
+			BadOrigin
+		);
+
+		// the amount of the failed ReceiveFromBridge is credited back to the sub account
+		assert_ok!(PolkadotBridgeModule::fail_relaychain_operations(
+			Origin::root(),
+			vec![1]
+		));
+		System::assert_last_event(Event::PolkadotBridgeModule(crate::Event::RelaychainOperationFailed(1)));
+		assert_eq!(XcmBridge::free_balance(1), 400);
+		assert_eq!(CurrenciesModule::free_balance(DOT, &BOB), 1000);
+		assert_eq!(PolkadotBridgeModule::in_flight_operations(1), None);
+
+		// the amount of the failed TransferToBridge is refunded to the sender
+		assert_ok!(PolkadotBridgeModule::fail_relaychain_operations(
+			Origin::root(),
+			vec![0]
+		));
+		assert_eq!(XcmBridge::free_balance(1), 0);
+		assert_eq!(CurrenciesModule::free_balance(DOT, &ALICE), 1000);
+
+		// the other failed operations are dropped, the ledger is corrected by UpdateOrigin
+		assert_ok!(PolkadotBridgeModule::fail_relaychain_operations(
+			Origin::root(),
+			vec![2]
+		));
+		assert_eq!(XcmBridge::staking_ledger(1).active, 100);
+		assert_eq!(InFlightOperations::<Runtime>::iter().count(), 0);
+
+		// failed operations can not be confirmed
+		assert_ok!(PolkadotBridgeModule::confirm_relaychain_operations(
+			Origin::root(),
+			vec![1]
+		));
+		assert_eq!(CurrenciesModule::free_balance(DOT, &BOB), 1000);
+	});
+}
+
+#[test]
+fn xcm_bridge_send_failed() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(XcmBridge::transfer_to_bridge(1, &ALICE, 500));
+		set_xcm_failed(true);
+
+		assert_noop!(XcmBridge::bond_extra(1, 100), Error::<Runtime>::XcmFailed);
+		assert_noop!(
+			XcmBridge::transfer_to_bridge(1, &ALICE, 100),
+			Error::<Runtime>::XcmFailed
+		);
+		assert_noop!(
+			XcmBridge::receive_from_bridge(1, &BOB, 100),
+			Error::<Runtime>::XcmFailed
+		);
+		assert_noop!(
+			XcmBridge::bond_extra(u32::MAX, 100),
+			Error::<Runtime>::InvalidSubAccountIndex
+		);
+
+		XcmBridge::nominate(1, vec![VALIDATOR_1]);
+		assert!(PolkadotBridgeModule::sub_account_nominations(1).is_empty());
+		assert_eq!(PolkadotBridgeModule::next_operation_id(), 1);
+	});
+}
+
+#[test]
+fn update_sub_account_ledger_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			PolkadotBridgeModule::update_sub_account_ledger(Origin::signed(ALICE), 1, 100, 10, vec![]),
+			BadOrigin
+		);
+		assert_noop!(
+			PolkadotBridgeModule::update_sub_account_ledger(
+				Origin::root(),
+				1,
+				100,
+				10,
+				vec![(1, 1), (2, 2), (3, 3), (4, 4)]
+			),
+			Error::<Runtime>::MaxUnbondingExceeded
+		);
+
+		assert_ok!(PolkadotBridgeModule::update_sub_account_ledger(
+			Origin::root(),
+			1,
+			100,
+			10,
+			vec![(3, 20)]
+		));
+		System::assert_last_event(Event::PolkadotBridgeModule(crate::Event::SubAccountLedgerUpdated(1)));
+		assert_eq!(XcmBridge::free_balance(1), 10);
+		assert_eq!(
+			XcmBridge::staking_ledger(1),
+			PolkadotStakingLedger {
+				total: 120,
+				active: 100,
+				unlocking: vec![PolkadotUnlockChunk { value: 20, era: 3 }],
+			}
+		);
+	});
+}
@@ -25,18 +25,54 @@
 //!   - specify a fixed price for stable currency
 //!   - feed price in USD or related price bewteen two currencies
 //!   - lock/unlock the price data get from oracle
+//!   - aggregate the prices of multiple sources
+//!   - lock the price automatically when it moves abruptly
+//!   - derive the price from DEX for the currencies without oracle feeds
+//!   - price the stable asset pool tokens by the underlying currencies
+//!
+//! The price of a currency from the sources is aggregated as follows: the prices older than
+//! `MaxSourcePriceAge` and the sources with zero weight are ignored, the prices deviating from the
+//! median of the remaining prices beyond `MaxSourceDeviation` are rejected, and the weighted mean
+//! of the accepted prices is returned if at least `MinSourcesQuorum` sources agree. Otherwise no
+//! price is returned and the operations depending on it are halted, and the
+//! `SourcesQuorumNotReached` event is deposited at most once per block for the currency.
+//!
+//! The circuit breaker of a currency is enabled by configuring its price change bound. Every block
+//! the real-time price is compared with the last accepted price, and if the change exceeds the
+//! bound, the last accepted price is locked and the liquidations of the currency are paused until
+//! the price is unlocked by `LockOrigin` or `CircuitBreakerCoolDown` blocks pass.
+//!
+//! The currency not fed by any source falls back to the price derived from the DEX path of its
+//! price rule, the currency fed by the sources never does even if their quorum is not reached. The
+//! relative price along the path is sampled every block into a cumulative price, and the
+//! time-weighted average is taken over every `DexPriceAveragingPeriod`. The path is not trusted if
+//! any of its pools holds less than the minimum liquidity on either side, valued in the anchor
+//! currency at the end of the path. Erc20 currencies are priced like
+//! tokens, by the sources feeding them or by the DEX price rules set by `LockOrigin`.
+//!
+//! The price of the stable asset pool token is the virtual price of the pool times the lowest
+//! price of the underlying currencies. The decimals of all currencies are resolved through
+//! `CurrencyIdMapping`.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
 
-use frame_support::{pallet_prelude::*, transactional};
+use codec::{Decode, Encode};
+use frame_support::{log, pallet_prelude::*, traits::UnixTime, transactional};
 use frame_system::pallet_prelude::*;
-use orml_traits::{DataFeeder, DataProvider, MultiCurrency};
-use primitives::{Balance, CurrencyId};
+use orml_traits::MultiCurrency;
+use primitives::{Balance, CurrencyId, Moment};
 use sp_core::U256;
-use sp_runtime::{traits::CheckedMul, FixedPointNumber};
-use sp_std::{convert::TryInto, marker::PhantomData};
-use support::{CurrencyIdMapping, DEXManager, ExchangeRateProvider, LockablePrice, Price, PriceProvider};
+use sp_runtime::{
+	helpers_128bit::multiply_by_rational,
+	traits::{CheckedDiv, CheckedMul, One, SaturatedConversion, Saturating, Zero},
+	FixedPointNumber, RuntimeDebug,
+};
+use sp_std::{convert::TryInto, marker::PhantomData, prelude::*};
+use support::{
+	CurrencyIdMapping, DEXManager, ExchangeRateProvider, LockablePrice, MultiSourcePriceProvider, Price,
+	PriceCircuitBreaker, PriceProvider, Ratio, StableAssetPoolInfo,
+};
 
 mod mock;
 mod tests;
@@ -45,6 +81,31 @@ pub mod weights;
 pub use module::*;
 pub use weights::WeightInfo;
 
+/// The rule to derive the price of a currency from DEX.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
+pub struct DexPriceRule {
+	/// The swap path from the currency to the priced anchor currency.
+	pub path: Vec<CurrencyId>,
+	/// The minimum liquidity valued in the anchor currency of every pool in the path.
+	pub min_liquidity: Balance,
+}
+
+/// The accumulator of the relative price derived from DEX, from which the time-weighted average
+/// price is taken.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, Default)]
+pub struct DexPriceAccumulator {
+	/// The spot price of the last sample.
+	pub spot_price: Price,
+	/// The sum of the inner spot prices weighted by the milliseconds they held, wraps on overflow.
+	pub cumulative_price: u128,
+	/// The time of the last sample.
+	pub updated_at: Moment,
+	/// The cumulative price at the start of the current averaging window.
+	pub window_cumulative_price: u128,
+	/// The start time of the current averaging window.
+	pub window_start: Moment,
+}
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
@@ -53,8 +114,33 @@ pub mod module {
 	pub trait Config: frame_system::Config {
 		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 
-		/// The data source, such as Oracle.
-		type Source: DataProvider<CurrencyId, Price> + DataFeeder<CurrencyId, Price, Self::AccountId>;
+		/// The data sources, such as multiple Oracle instances.
+		type Source: MultiSourcePriceProvider<CurrencyId>;
+
+		/// Time used for checking the staleness of the source prices.
+		type UnixTime: UnixTime;
+
+		/// The maximum age(in milliseconds) of the source price, older prices are ignored.
+		#[pallet::constant]
+		type MaxSourcePriceAge: Get<Moment>;
+
+		/// The maximum deviation ratio of the source price from the median of all sources,
+		/// deviated prices are rejected.
+		#[pallet::constant]
+		type MaxSourceDeviation: Get<Ratio>;
+
+		/// The minimum number of agreed sources to aggregate the price.
+		#[pallet::constant]
+		type MinSourcesQuorum: Get<u32>;
+
+		/// The number of blocks after which the price locked by the circuit breaker is unlocked
+		/// automatically.
+		#[pallet::constant]
+		type CircuitBreakerCoolDown: Get<Self::BlockNumber>;
+
+		/// The period(in milliseconds) over which the price derived from DEX is averaged.
+		#[pallet::constant]
+		type DexPriceAveragingPeriod: Get<Moment>;
 
 		/// The stable currency id, it should be AUSD in Acala.
 		#[pallet::constant]
@@ -72,7 +158,8 @@ pub mod module {
 		#[pallet::constant]
 		type GetLiquidCurrencyId: Get<CurrencyId>;
 
-		/// The origin which may lock and unlock prices feed to system.
+		/// The origin which may lock and unlock prices feed to system, update the weights of the
+		/// sources, the price change bounds of the circuit breaker and the DEX price rules.
 		type LockOrigin: EnsureOrigin<Self::Origin>;
 
 		/// The provider of the exchange rate between liquid currency and
@@ -82,6 +169,9 @@ pub mod module {
 		/// DEX provide liquidity info.
 		type DEX: DEXManager<Self::AccountId, CurrencyId, Balance>;
 
+		/// Stable asset provide the pool info of the pool tokens.
+		type StableAsset: StableAssetPoolInfo<CurrencyId>;
+
 		/// Currency provide the total insurance of LPToken.
 		type Currency: MultiCurrency<Self::AccountId, CurrencyId = CurrencyId, Balance = Balance>;
 
@@ -98,15 +188,33 @@ pub mod module {
 		AccessPriceFailed,
 		/// There's no locked price
 		NoLockedPrice,
+		/// The DEX price rule is invalid
+		InvalidDexPriceRule,
 	}
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	#[pallet::metadata(Option<Ratio> = "OptionRatio")]
 	pub enum Event<T: Config> {
 		/// Lock price. \[currency_id, locked_price\]
 		LockPrice(CurrencyId, Price),
 		/// Unlock price. \[currency_id\]
 		UnlockPrice(CurrencyId),
+		/// The weights of the sources updated. \[currency_id, source_weights\]
+		SourceWeightsUpdated(CurrencyId, Vec<u32>),
+		/// The agreed sources are less than quorum, no price can be aggregated. \[currency_id,
+		/// agreed_sources_count\]
+		SourcesQuorumNotReached(CurrencyId, u32),
+		/// The price change bound of the circuit breaker updated. \[currency_id,
+		/// new_price_change_bound\]
+		PriceChangeBoundUpdated(CurrencyId, Option<Ratio>),
+		/// The price moved beyond the bound and the last accepted price is locked.
+		/// \[currency_id, last_accepted_price, new_price\]
+		CircuitBreakerTriggered(CurrencyId, Price, Price),
+		/// The circuit breaker is reset. \[currency_id\]
+		CircuitBreakerReset(CurrencyId),
+		/// The DEX price rule updated. \[currency_id, new_dex_price_rule\]
+		DexPriceRuleUpdated(CurrencyId, Option<DexPriceRule>),
 	}
 
 	/// Mapping from currency id to it's locked price
@@ -116,11 +224,100 @@ pub mod module {
 	#[pallet::getter(fn locked_price)]
 	pub type LockedPrice<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, Price, OptionQuery>;
 
+	/// Mapping from currency id to the weights of the sources, the index of the weights is the
+	/// index of the source. The sources not specified have the weight of 1.
+	///
+	/// SourceWeights: map CurrencyId => Vec<Weight>
+	#[pallet::storage]
+	#[pallet::getter(fn source_weights)]
+	pub type SourceWeights<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, Vec<u32>, ValueQuery>;
+
+	/// Mapping from currency id to the maximum change ratio of its price between two checks,
+	/// the circuit breaker is enabled only for the currencies having the bound.
+	///
+	/// PriceChangeBound: map CurrencyId => Option<Ratio>
+	#[pallet::storage]
+	#[pallet::getter(fn price_change_bound)]
+	pub type PriceChangeBound<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, Ratio, OptionQuery>;
+
+	/// Mapping from currency id to the last price accepted by the circuit breaker.
+	///
+	/// LastAcceptedPrice: map CurrencyId => Option<Price>
+	#[pallet::storage]
+	#[pallet::getter(fn last_accepted_price)]
+	pub type LastAcceptedPrice<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, Price, OptionQuery>;
+
+	/// Mapping from currency id to the price locked by the circuit breaker and the block number
+	/// at which it will be unlocked.
+	///
+	/// TriggeredCircuitBreakers: map CurrencyId => Option<(Price, BlockNumber)>
+	#[pallet::storage]
+	#[pallet::getter(fn triggered_circuit_breakers)]
+	pub type TriggeredCircuitBreakers<T: Config> =
+		StorageMap<_, Twox64Concat, CurrencyId, (Price, T::BlockNumber), OptionQuery>;
+
+	/// Mapping from currency id to the rule deriving its price from DEX.
+	///
+	/// DexPriceRules: map CurrencyId => Option<DexPriceRule>
+	#[pallet::storage]
+	#[pallet::getter(fn dex_price_rules)]
+	pub type DexPriceRules<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, DexPriceRule, OptionQuery>;
+
+	/// Mapping from currency id to the time-weighted average of its relative price to the anchor
+	/// currency derived from DEX, and the time it was updated.
+	///
+	/// DexAveragePrices: map CurrencyId => Option<(Price, Moment)>
+	#[pallet::storage]
+	#[pallet::getter(fn dex_average_prices)]
+	pub type DexAveragePrices<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, (Price, Moment), OptionQuery>;
+
+	/// Mapping from currency id to the accumulator of its relative price derived from DEX.
+	///
+	/// DexPriceAccumulators: map CurrencyId => Option<DexPriceAccumulator>
+	#[pallet::storage]
+	#[pallet::getter(fn dex_price_accumulators)]
+	pub type DexPriceAccumulators<T: Config> =
+		StorageMap<_, Twox64Concat, CurrencyId, DexPriceAccumulator, OptionQuery>;
+
+	/// Mapping from currency id to the block number at which `SourcesQuorumNotReached` was last
+	/// deposited for it.
+	///
+	/// QuorumNotReachedReportedAt: map CurrencyId => Option<BlockNumber>
+	#[pallet::storage]
+	#[pallet::getter(fn quorum_not_reached_reported_at)]
+	pub type QuorumNotReachedReportedAt<T: Config> =
+		StorageMap<_, Twox64Concat, CurrencyId, T::BlockNumber, OptionQuery>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		/// Update the average prices derived from DEX, reset the circuit breakers whose cool-down
+		/// has passed and check the price changes of the currencies having the bound.
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			let mut count: u32 = 0;
+			let now_moment: Moment = T::UnixTime::now().as_millis().saturated_into();
+			for (currency_id, rule) in DexPriceRules::<T>::iter() {
+				count += 1;
+				Self::update_dex_average_price(currency_id, &rule, now_moment);
+			}
+
+			let cooled_down: Vec<CurrencyId> = TriggeredCircuitBreakers::<T>::iter()
+				.filter(|(_, (_, unlock_at))| *unlock_at <= now)
+				.map(|(currency_id, _)| currency_id)
+				.collect();
+			for currency_id in cooled_down {
+				Self::reset_circuit_breaker(currency_id);
+			}
+
+			for (currency_id, bound) in PriceChangeBound::<T>::iter() {
+				count += 1;
+				Self::check_price_change(currency_id, bound, now);
+			}
+			T::WeightInfo::on_initialize(count)
+		}
+	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
@@ -149,6 +346,88 @@ pub mod module {
 			<Pallet<T> as LockablePrice<CurrencyId>>::unlock_price(currency_id)?;
 			Ok(())
 		}
+
+		/// Update the weights of the sources for the currency, zero weight disables the source.
+		///
+		/// The dispatch origin of this call must be `LockOrigin`.
+		///
+		/// - `currency_id`: currency type.
+		/// - `weights`: the weights of the sources by the index of the source.
+		#[pallet::weight((T::WeightInfo::set_source_weights(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_source_weights(origin: OriginFor<T>, currency_id: CurrencyId, weights: Vec<u32>) -> DispatchResult {
+			T::LockOrigin::ensure_origin(origin)?;
+			if weights.is_empty() {
+				SourceWeights::<T>::remove(currency_id);
+			} else {
+				SourceWeights::<T>::insert(currency_id, weights.clone());
+			}
+			Self::deposit_event(Event::SourceWeightsUpdated(currency_id, weights));
+			Ok(())
+		}
+
+		/// Update the price change bound of the circuit breaker for the currency, `None` disables
+		/// the circuit breaker.
+		///
+		/// The dispatch origin of this call must be `LockOrigin`.
+		///
+		/// - `currency_id`: currency type.
+		/// - `bound`: the maximum change ratio of the price between two checks.
+		#[pallet::weight((T::WeightInfo::set_price_change_bound(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_price_change_bound(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			bound: Option<Ratio>,
+		) -> DispatchResult {
+			T::LockOrigin::ensure_origin(origin)?;
+			match bound {
+				Some(bound) => PriceChangeBound::<T>::insert(currency_id, bound),
+				None => {
+					PriceChangeBound::<T>::remove(currency_id);
+					LastAcceptedPrice::<T>::remove(currency_id);
+				}
+			}
+			Self::deposit_event(Event::PriceChangeBoundUpdated(currency_id, bound));
+			Ok(())
+		}
+
+		/// Update the rule deriving the price of the currency from DEX, `None` removes the rule.
+		///
+		/// The dispatch origin of this call must be `LockOrigin`.
+		///
+		/// - `currency_id`: currency type.
+		/// - `rule`: the swap path from the currency to the anchor currency and the minimum
+		///   liquidity.
+		#[pallet::weight((T::WeightInfo::set_dex_price_rule(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_dex_price_rule(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			rule: Option<DexPriceRule>,
+		) -> DispatchResult {
+			T::LockOrigin::ensure_origin(origin)?;
+			DexAveragePrices::<T>::remove(currency_id);
+			DexPriceAccumulators::<T>::remove(currency_id);
+			match rule.clone() {
+				Some(rule) => {
+					let anchor = rule.path.last().ok_or(Error::<T>::InvalidDexPriceRule)?;
+					// the anchor must be priced without DEX rule to avoid circular pricing
+					ensure!(
+						rule.path.len() >= 2
+							&& rule.path[0] == currency_id
+							&& !rule.path[1..].contains(&currency_id)
+							&& !DexPriceRules::<T>::contains_key(anchor)
+							&& DexPriceRules::<T>::iter_values().all(|r| r.path.last() != Some(&currency_id)),
+						Error::<T>::InvalidDexPriceRule
+					);
+					DexPriceRules::<T>::insert(currency_id, rule);
+				}
+				None => DexPriceRules::<T>::remove(currency_id),
+			}
+			Self::deposit_event(Event::DexPriceRuleUpdated(currency_id, rule));
+			Ok(())
+		}
 	}
 }
 
@@ -179,9 +458,35 @@ impl<T: Config> Pallet<T> {
 					None
 				}
 			};
+		} else if let CurrencyId::StableAssetPoolToken(_) = currency_id {
+			// the virtual price times the lowest price of the underlying currencies
+			let (underlying_currency_ids, virtual_price) = T::StableAsset::get_pool_info(currency_id)?;
+			underlying_currency_ids
+				.into_iter()
+				.map(Self::access_whole_unit_price)
+				.collect::<Option<Vec<Price>>>()?
+				.into_iter()
+				.min()
+				.and_then(|lowest_price| lowest_price.checked_mul(&virtual_price))
 		} else {
-			// get real-time price from oracle
-			T::Source::get(&currency_id)
+			match Self::aggregate_source_prices(currency_id) {
+				// get real-time price aggregated from the sources
+				Ok(price) => Some(price),
+				Err(agreed_count) => {
+					if Self::is_fed_by_sources(currency_id) {
+						// the price rejected by the quorum must not be replaced by the DEX price
+						log::debug!(
+							target: "prices",
+							"aggregate_source_prices: quorum is not reached for {:?}, agreed sources: {:?}",
+							currency_id, agreed_count
+						);
+						Self::report_quorum_not_reached(currency_id, agreed_count);
+						return None;
+					}
+					// fallback to the price derived from DEX, it's already the price for 1 basic unit
+					return Self::dex_price(currency_id);
+				}
+			}
 		};
 
 		let maybe_adjustment_multiplier = 10u128.checked_pow(T::CurrencyIdMapping::decimals(currency_id)?.into());
@@ -193,6 +498,228 @@ impl<T: Config> Pallet<T> {
 			None
 		}
 	}
+
+	/// access the price for 1 whole unit of the currency, the reverse of the decimals adjustment
+	/// in `access_price`.
+	fn access_whole_unit_price(currency_id: CurrencyId) -> Option<Price> {
+		let adjustment_multiplier = 10u128.checked_pow(T::CurrencyIdMapping::decimals(currency_id)?.into())?;
+		let price = Self::access_price(currency_id)?;
+		multiply_by_rational(price.into_inner(), adjustment_multiplier, Price::accuracy())
+			.ok()
+			.map(Price::from_inner)
+	}
+
+	/// Aggregate the weighted mean price of the agreed sources, return the number of the agreed
+	/// sources as error if the quorum is not reached.
+	fn aggregate_source_prices(currency_id: CurrencyId) -> Result<Price, u32> {
+		let now: Moment = T::UnixTime::now().as_millis().saturated_into();
+		let weights = Self::source_weights(currency_id);
+
+		// (price, weight) of the sources which are fresh and enabled
+		let candidates: Vec<(Price, u32)> = T::Source::get_prices(&currency_id)
+			.into_iter()
+			.enumerate()
+			.filter_map(|(index, maybe_price)| {
+				let (price, timestamp) = maybe_price?;
+				let weight = weights.get(index).copied().unwrap_or(1);
+				if weight.is_zero() || now.saturating_sub(timestamp) > T::MaxSourcePriceAge::get() {
+					None
+				} else {
+					Some((price, weight))
+				}
+			})
+			.collect();
+
+		let agreed: Vec<(Price, u32)> = match median(candidates.iter().map(|(price, _)| *price).collect()) {
+			Some(median_price) => {
+				let max_deviation = T::MaxSourceDeviation::get().saturating_mul(median_price);
+				candidates
+					.into_iter()
+					.filter(|(price, _)| abs_diff(*price, median_price) <= max_deviation)
+					.collect()
+			}
+			None => vec![],
+		};
+
+		let agreed_count = agreed.len() as u32;
+		if agreed_count.is_zero() || agreed_count < T::MinSourcesQuorum::get() {
+			return Err(agreed_count);
+		}
+
+		let (total_price, total_weight) = agreed.into_iter().fold(
+			(Price::zero(), Price::zero()),
+			|(total_price, total_weight), (price, weight)| {
+				let weight = Price::saturating_from_integer(weight);
+				(
+					total_price.saturating_add(price.saturating_mul(weight)),
+					total_weight.saturating_add(weight),
+				)
+			},
+		);
+		total_price.checked_div(&total_weight).ok_or(agreed_count)
+	}
+
+	/// Deposit `SourcesQuorumNotReached` for the currency if it's not deposited in this block yet.
+	fn report_quorum_not_reached(currency_id: CurrencyId, agreed_count: u32) {
+		let now = <frame_system::Pallet<T>>::block_number();
+		if Self::quorum_not_reached_reported_at(currency_id) != Some(now) {
+			QuorumNotReachedReportedAt::<T>::insert(currency_id, now);
+			Self::deposit_event(Event::SourcesQuorumNotReached(currency_id, agreed_count));
+		}
+	}
+
+	/// Whether any source feeds the price of the currency, fresh or not.
+	fn is_fed_by_sources(currency_id: CurrencyId) -> bool {
+		T::Source::get_prices(&currency_id).iter().any(Option::is_some)
+	}
+
+	/// Compare the real-time price with the last accepted price, lock the last accepted price if
+	/// the change exceeds the bound. Locked prices are not checked.
+	fn check_price_change(currency_id: CurrencyId, bound: Ratio, now: T::BlockNumber) {
+		if LockedPrice::<T>::contains_key(currency_id) {
+			return;
+		}
+		let price = match Self::access_price(currency_id) {
+			Some(price) => price,
+			None => return,
+		};
+
+		match Self::last_accepted_price(currency_id) {
+			Some(last_price) if abs_diff(price, last_price) > bound.saturating_mul(last_price) => {
+				LockedPrice::<T>::insert(currency_id, last_price);
+				TriggeredCircuitBreakers::<T>::insert(
+					currency_id,
+					(last_price, now.saturating_add(T::CircuitBreakerCoolDown::get())),
+				);
+				Self::deposit_event(Event::LockPrice(currency_id, last_price));
+				Self::deposit_event(Event::CircuitBreakerTriggered(currency_id, last_price, price));
+			}
+			_ => LastAcceptedPrice::<T>::insert(currency_id, price),
+		}
+	}
+
+	/// The price derived from the DEX average price of the currency and the price of the anchor.
+	fn dex_price(currency_id: CurrencyId) -> Option<Price> {
+		let anchor = *Self::dex_price_rules(currency_id)?.path.last()?;
+		let (average_price, _) = Self::dex_average_prices(currency_id)?;
+		average_price.checked_mul(&Self::access_price(anchor)?)
+	}
+
+	/// The relative price of the first currency of the path to the anchor currency. Every currency
+	/// of the path is valued in the anchor currency walking backwards, then both sides of every
+	/// pool must hold at least the minimum liquidity.
+	fn dex_spot_price(rule: &DexPriceRule) -> Option<Price> {
+		if rule.path.len() < 2 {
+			return None;
+		}
+
+		// the relative prices of the currencies of the path to the anchor currency
+		let mut prices = vec![Price::one(); rule.path.len()];
+		let mut pools = Vec::with_capacity(rule.path.len() - 1);
+		for (index, pair) in rule.path.windows(2).enumerate().rev() {
+			let (supply_pool, target_pool) = T::DEX::get_liquidity_pool(pair[0], pair[1]);
+			prices[index] = prices[index + 1].checked_mul(&Price::checked_from_rational(target_pool, supply_pool)?)?;
+			pools.push((index, supply_pool, target_pool));
+		}
+
+		for (index, supply_pool, target_pool) in pools {
+			if prices[index].checked_mul_int(supply_pool)? < rule.min_liquidity
+				|| prices[index + 1].checked_mul_int(target_pool)? < rule.min_liquidity
+			{
+				return None;
+			}
+		}
+		prices.first().copied()
+	}
+
+	/// Accumulate the last spot price for the elapsed time and sample the new one, take the
+	/// time-weighted average once the averaging window passes. Remove the accumulator and the
+	/// average price if the spot price is unavailable.
+	fn update_dex_average_price(currency_id: CurrencyId, rule: &DexPriceRule, now: Moment) {
+		let spot_price = match Self::dex_spot_price(rule) {
+			Some(price) => price,
+			None => {
+				DexPriceAccumulators::<T>::remove(currency_id);
+				DexAveragePrices::<T>::remove(currency_id);
+				return;
+			}
+		};
+
+		let mut accumulator = match Self::dex_price_accumulators(currency_id) {
+			Some(accumulator) => accumulator,
+			None => {
+				// the first sample starts the averaging window
+				DexPriceAccumulators::<T>::insert(
+					currency_id,
+					DexPriceAccumulator {
+						spot_price,
+						updated_at: now,
+						window_start: now,
+						..Default::default()
+					},
+				);
+				return;
+			}
+		};
+
+		let elapsed = now.saturating_sub(accumulator.updated_at);
+		accumulator.cumulative_price = accumulator
+			.cumulative_price
+			.wrapping_add(accumulator.spot_price.into_inner().wrapping_mul(elapsed.into()));
+		accumulator.spot_price = spot_price;
+		accumulator.updated_at = now;
+
+		let window = now.saturating_sub(accumulator.window_start);
+		if !window.is_zero() && window >= T::DexPriceAveragingPeriod::get() {
+			let average_price = Price::from_inner(
+				accumulator
+					.cumulative_price
+					.wrapping_sub(accumulator.window_cumulative_price)
+					/ u128::from(window),
+			);
+			DexAveragePrices::<T>::insert(currency_id, (average_price, now));
+			accumulator.window_cumulative_price = accumulator.cumulative_price;
+			accumulator.window_start = now;
+		}
+		DexPriceAccumulators::<T>::insert(currency_id, accumulator);
+	}
+
+	/// Reset the triggered circuit breaker, unlock the price if it's still the one locked by the
+	/// circuit breaker. The next price will be accepted as the new base.
+	fn reset_circuit_breaker(currency_id: CurrencyId) {
+		if let Some((locked_price, _)) = TriggeredCircuitBreakers::<T>::take(currency_id) {
+			LastAcceptedPrice::<T>::remove(currency_id);
+			if Self::locked_price(currency_id) == Some(locked_price) {
+				LockedPrice::<T>::remove(currency_id);
+				Self::deposit_event(Event::UnlockPrice(currency_id));
+			}
+			Self::deposit_event(Event::CircuitBreakerReset(currency_id));
+		}
+	}
+}
+
+fn abs_diff(a: Price, b: Price) -> Price {
+	if a > b {
+		a.saturating_sub(b)
+	} else {
+		b.saturating_sub(a)
+	}
+}
+
+/// The median of the prices, the mean of the two middle prices if the count is even.
+fn median(mut prices: Vec<Price>) -> Option<Price> {
+	if prices.is_empty() {
+		return None;
+	}
+	prices.sort();
+	let mid = prices.len() / 2;
+	if prices.len() % 2 == 0 {
+		prices[mid - 1]
+			.saturating_add(prices[mid])
+			.checked_div(&Price::saturating_from_integer(2))
+	} else {
+		Some(prices[mid])
+	}
 }
 
 impl<T: Config> LockablePrice<CurrencyId> for Pallet<T> {
@@ -204,14 +731,24 @@ impl<T: Config> LockablePrice<CurrencyId> for Pallet<T> {
 		Ok(())
 	}
 
-	/// Unlock the locked price
+	/// Unlock the locked price, and reset the circuit breaker
 	fn unlock_price(currency_id: CurrencyId) -> DispatchResult {
 		let _ = LockedPrice::<T>::take(currency_id).ok_or(Error::<T>::NoLockedPrice)?;
 		Pallet::<T>::deposit_event(Event::UnlockPrice(currency_id));
+		LastAcceptedPrice::<T>::remove(currency_id);
+		if TriggeredCircuitBreakers::<T>::take(currency_id).is_some() {
+			Pallet::<T>::deposit_event(Event::CircuitBreakerReset(currency_id));
+		}
 		Ok(())
 	}
 }
 
+impl<T: Config> PriceCircuitBreaker<CurrencyId> for Pallet<T> {
+	fn is_triggered(currency_id: CurrencyId) -> bool {
+		TriggeredCircuitBreakers::<T>::contains_key(currency_id)
+	}
+}
+
 /// PriceProvider that always provider real-time prices from oracle
 pub struct RealTimePriceProvider<T>(PhantomData<T>);
 impl<T: Config> PriceProvider<CurrencyId> for RealTimePriceProvider<T> {
@@ -23,7 +23,7 @@
 use super::*;
 use frame_support::{construct_runtime, ord_parameter_types, parameter_types};
 use frame_system::EnsureSignedBy;
-use orml_traits::{parameter_type_with_key, DataFeeder};
+use orml_traits::parameter_type_with_key;
 use primitives::{currency::DexShare, Amount, TokenSymbol};
 use sp_core::{H160, H256};
 use sp_runtime::{
@@ -31,7 +31,7 @@ use sp_runtime::{
 	traits::{IdentityLookup, One as OneT, Zero},
 	DispatchError, FixedPointNumber,
 };
-use sp_std::cell::RefCell;
+use sp_std::{cell::RefCell, time::Duration};
 use support::{mocks::MockCurrencyIdMapping, ExchangeRate};
 
 pub type AccountId = u128;
@@ -45,6 +45,8 @@ pub const LDOT: CurrencyId = CurrencyId::Token(TokenSymbol::LDOT);
 pub const KSM: CurrencyId = CurrencyId::Token(TokenSymbol::KSM);
 pub const LP_AUSD_DOT: CurrencyId =
 	CurrencyId::DexShare(DexShare::Token(TokenSymbol::AUSD), DexShare::Token(TokenSymbol::DOT));
+pub const STABLE_ASSET_POOL_TOKEN: CurrencyId = CurrencyId::StableAssetPoolToken(0);
+pub const ERC20: CurrencyId = CurrencyId::Erc20(H160([1; 20]));
 
 mod prices {
 	pub use super::super::*;
@@ -82,40 +84,93 @@ impl frame_system::Config for Runtime {
 
 thread_local! {
 	static CHANGED: RefCell<bool> = RefCell::new(false);
+	static SOURCE_PRICES: RefCell<Option<Vec<Option<(Price, Moment)>>>> = RefCell::new(None);
+	static NOW: RefCell<Moment> = RefCell::new(1_000_000);
+	static KSM_AUSD_POOL: RefCell<(Balance, Balance)> = RefCell::new((0, 0));
+	static KSM_DOT_POOL: RefCell<(Balance, Balance)> = RefCell::new((0, 0));
+	static STABLE_ASSET_POOL: RefCell<Option<(Vec<CurrencyId>, Price)>> = RefCell::new(None);
 }
 
 pub fn mock_oracle_update() {
 	CHANGED.with(|v| *v.borrow_mut() = true)
 }
 
-pub struct MockDataProvider;
-impl DataProvider<CurrencyId, Price> for MockDataProvider {
-	fn get(currency_id: &CurrencyId) -> Option<Price> {
-		if CHANGED.with(|v| *v.borrow_mut()) {
-			match *currency_id {
-				AUSD => None,
-				BTC => Some(Price::saturating_from_integer(40000)),
-				DOT => Some(Price::saturating_from_integer(10)),
-				ACA => Some(Price::saturating_from_integer(30)),
-				KSM => Some(Price::saturating_from_integer(200)),
-				_ => None,
-			}
+/// Mock the prices of multiple sources for all currencies
+pub fn mock_source_prices(prices: Vec<Option<(Price, Moment)>>) {
+	SOURCE_PRICES.with(|v| *v.borrow_mut() = Some(prices))
+}
+
+pub fn now() -> Moment {
+	NOW.with(|v| *v.borrow())
+}
+
+pub fn set_now(now: Moment) {
+	NOW.with(|v| *v.borrow_mut() = now)
+}
+
+pub fn mock_ksm_ausd_pool(pool: (Balance, Balance)) {
+	KSM_AUSD_POOL.with(|v| *v.borrow_mut() = pool)
+}
+
+pub fn mock_ksm_dot_pool(pool: (Balance, Balance)) {
+	KSM_DOT_POOL.with(|v| *v.borrow_mut() = pool)
+}
+
+/// Mock the underlying currencies and the virtual price of `STABLE_ASSET_POOL_TOKEN`
+pub fn mock_stable_asset_pool(pool_info: Option<(Vec<CurrencyId>, Price)>) {
+	STABLE_ASSET_POOL.with(|v| *v.borrow_mut() = pool_info)
+}
+
+pub struct MockStableAsset;
+impl StableAssetPoolInfo<CurrencyId> for MockStableAsset {
+	fn get_pool_info(pool_token: CurrencyId) -> Option<(Vec<CurrencyId>, Price)> {
+		if pool_token == STABLE_ASSET_POOL_TOKEN {
+			STABLE_ASSET_POOL.with(|v| v.borrow().clone())
 		} else {
-			match *currency_id {
-				AUSD => Some(Price::saturating_from_rational(99, 100)),
-				BTC => Some(Price::saturating_from_integer(50000)),
-				DOT => Some(Price::saturating_from_integer(100)),
-				ACA => Some(Price::zero()),
-				KSM => None,
-				_ => None,
-			}
+			None
 		}
 	}
+
+	fn get_pool_token_precision(_: CurrencyId) -> Option<(CurrencyId, u128)> {
+		None
+	}
+}
+
+pub struct MockUnixTime;
+impl UnixTime for MockUnixTime {
+	fn now() -> Duration {
+		Duration::from_millis(now())
+	}
 }
 
-impl DataFeeder<CurrencyId, Price, AccountId> for MockDataProvider {
-	fn feed_value(_: AccountId, _: CurrencyId, _: Price) -> sp_runtime::DispatchResult {
-		Ok(())
+pub struct MockPriceSource;
+impl MultiSourcePriceProvider<CurrencyId> for MockPriceSource {
+	fn get_prices(currency_id: &CurrencyId) -> Vec<Option<(Price, Moment)>> {
+		SOURCE_PRICES
+			.with(|v| v.borrow().clone())
+			.unwrap_or_else(|| vec![mock_oracle_price(currency_id).map(|price| (price, now()))])
+	}
+}
+
+fn mock_oracle_price(currency_id: &CurrencyId) -> Option<Price> {
+	if CHANGED.with(|v| *v.borrow_mut()) {
+		match *currency_id {
+			AUSD => None,
+			BTC => Some(Price::saturating_from_integer(40000)),
+			DOT => Some(Price::saturating_from_integer(10)),
+			ACA => Some(Price::saturating_from_integer(30)),
+			KSM => Some(Price::saturating_from_integer(200)),
+			_ => None,
+		}
+	} else {
+		match *currency_id {
+			AUSD => Some(Price::saturating_from_rational(99, 100)),
+			BTC => Some(Price::saturating_from_integer(50000)),
+			DOT => Some(Price::saturating_from_integer(100)),
+			ACA => Some(Price::zero()),
+			KSM => None,
+			_ => None,
+		}
 	}
 }
 
@@ -135,6 +190,13 @@ impl DEXManager<AccountId, CurrencyId, Balance> for MockDEX {
 	fn get_liquidity_pool(currency_id_a: CurrencyId, currency_id_b: CurrencyId) -> (Balance, Balance) {
 		match (currency_id_a, currency_id_b) {
 			(AUSD, DOT) => (10000, 200),
+			(DOT, AUSD) => (200, 10000),
+			(KSM, DOT) => KSM_DOT_POOL.with(|v| *v.borrow()),
+			(KSM, AUSD) => KSM_AUSD_POOL.with(|v| *v.borrow()),
+			(AUSD, KSM) => KSM_AUSD_POOL.with(|v| {
+				let (pool_0, pool_1) = *v.borrow();
+				(pool_1, pool_0)
+			}),
 			_ => (0, 0),
 		}
 	}
@@ -221,11 +283,22 @@ parameter_types! {
 	pub const GetStakingCurrencyId: CurrencyId = DOT;
 	pub const GetLiquidCurrencyId: CurrencyId = LDOT;
 	pub StableCurrencyFixedPrice: Price = Price::one();
+	pub const MaxSourcePriceAge: Moment = 60_000;
+	pub MaxSourceDeviation: Ratio = Ratio::saturating_from_rational(10, 100);
+	pub static MinSourcesQuorum: u32 = 1;
+	pub const CircuitBreakerCoolDown: BlockNumber = 10;
+	pub const DexPriceAveragingPeriod: Moment = 60_000;
 }
 
 impl Config for Runtime {
 	type Event = Event;
-	type Source = MockDataProvider;
+	type Source = MockPriceSource;
+	type UnixTime = MockUnixTime;
+	type MaxSourcePriceAge = MaxSourcePriceAge;
+	type MaxSourceDeviation = MaxSourceDeviation;
+	type MinSourcesQuorum = MinSourcesQuorum;
+	type CircuitBreakerCoolDown = CircuitBreakerCoolDown;
+	type DexPriceAveragingPeriod = DexPriceAveragingPeriod;
 	type GetStableCurrencyId = GetStableCurrencyId;
 	type StableCurrencyFixedPrice = StableCurrencyFixedPrice;
 	type GetStakingCurrencyId = GetStakingCurrencyId;
@@ -233,6 +306,7 @@ impl Config for Runtime {
 	type LockOrigin = EnsureSignedBy<One, AccountId>;
 	type LiquidStakingExchangeRateProvider = MockLiquidStakingExchangeProvider;
 	type DEX = MockDEX;
+	type StableAsset = MockStableAsset;
 	type Currency = Tokens;
 	type CurrencyIdMapping = MockCurrencyIdMapping;
 	type WeightInfo = ();
@@ -508,3 +508,416 @@ fn price_providers_work() {
 		assert_eq!(LockedPriceProvider::<Runtime>::get_relative_price(BTC, KSM), None);
 	});
 }
+
+#[test]
+fn set_source_weights_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(
+			PricesModule::set_source_weights(Origin::signed(5), BTC, vec![1, 2]),
+			BadOrigin
+		);
+
+		assert_ok!(PricesModule::set_source_weights(Origin::signed(1), BTC, vec![1, 2]));
+		System::assert_last_event(Event::PricesModule(crate::Event::SourceWeightsUpdated(BTC, vec![1, 2])));
+		assert_eq!(PricesModule::source_weights(BTC), vec![1, 2]);
+
+		assert_ok!(PricesModule::set_source_weights(Origin::signed(1), BTC, vec![]));
+		System::assert_last_event(Event::PricesModule(crate::Event::SourceWeightsUpdated(BTC, vec![])));
+		assert!(!SourceWeights::<Runtime>::contains_key(BTC));
+	});
+}
+
+#[test]
+fn aggregate_source_prices_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		// the outlier, the missing and the stale prices are ignored
+		mock_source_prices(vec![
+			Some((Price::saturating_from_integer(100), now())),
+			Some((Price::saturating_from_integer(104), now())),
+			Some((Price::saturating_from_integer(150), now())),
+			None,
+			Some((
+				Price::saturating_from_integer(102),
+				now() - MaxSourcePriceAge::get() - 1,
+			)),
+		]);
+		assert_eq!(
+			PricesModule::aggregate_source_prices(BTC),
+			Ok(Price::saturating_from_integer(102))
+		);
+
+		// weighted mean of the agreed prices
+		assert_ok!(PricesModule::set_source_weights(Origin::signed(1), BTC, vec![3, 1]));
+		assert_eq!(
+			PricesModule::aggregate_source_prices(BTC),
+			Ok(Price::saturating_from_integer(101))
+		);
+
+		// the sources with zero weight are disabled
+		assert_ok!(PricesModule::set_source_weights(Origin::signed(1), BTC, vec![1, 0, 0]));
+		assert_eq!(
+			PricesModule::aggregate_source_prices(BTC),
+			Ok(Price::saturating_from_integer(100))
+		);
+		assert_ok!(PricesModule::set_source_weights(Origin::signed(1), BTC, vec![0, 0, 0]));
+		assert_eq!(PricesModule::aggregate_source_prices(BTC), Err(0));
+
+		// quorum is not reached
+		assert_ok!(PricesModule::set_source_weights(Origin::signed(1), BTC, vec![]));
+		MinSourcesQuorum::set(3);
+		assert_eq!(PricesModule::aggregate_source_prices(BTC), Err(2));
+
+		// the event is deposited once per block when the price is read
+		assert_eq!(System::events().len(), 4);
+		assert_eq!(PricesModule::access_price(BTC), None);
+		System::assert_last_event(Event::PricesModule(crate::Event::SourcesQuorumNotReached(BTC, 2)));
+		assert_eq!(PricesModule::quorum_not_reached_reported_at(BTC), Some(1));
+		assert_eq!(System::events().len(), 5);
+		assert_eq!(PricesModule::access_price(BTC), None);
+		assert_eq!(System::events().len(), 5);
+
+		// also for the currency monitored by the circuit breaker
+		assert_ok!(PricesModule::set_price_change_bound(
+			Origin::signed(1),
+			BTC,
+			Some(Ratio::saturating_from_rational(20, 100))
+		));
+		System::set_block_number(2);
+		PricesModule::on_initialize(2);
+		System::assert_last_event(Event::PricesModule(crate::Event::SourcesQuorumNotReached(BTC, 2)));
+		assert_eq!(PricesModule::quorum_not_reached_reported_at(BTC), Some(2));
+	});
+}
+
+#[test]
+fn set_price_change_bound_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(
+			PricesModule::set_price_change_bound(
+				Origin::signed(5),
+				BTC,
+				Some(Ratio::saturating_from_rational(20, 100))
+			),
+			BadOrigin
+		);
+
+		assert_ok!(PricesModule::set_price_change_bound(
+			Origin::signed(1),
+			BTC,
+			Some(Ratio::saturating_from_rational(20, 100))
+		));
+		System::assert_last_event(Event::PricesModule(crate::Event::PriceChangeBoundUpdated(
+			BTC,
+			Some(Ratio::saturating_from_rational(20, 100)),
+		)));
+		assert_eq!(
+			PricesModule::price_change_bound(BTC),
+			Some(Ratio::saturating_from_rational(20, 100))
+		);
+
+		PricesModule::on_initialize(1);
+		assert!(PricesModule::last_accepted_price(BTC).is_some());
+
+		assert_ok!(PricesModule::set_price_change_bound(Origin::signed(1), BTC, None));
+		System::assert_last_event(Event::PricesModule(crate::Event::PriceChangeBoundUpdated(BTC, None)));
+		assert_eq!(PricesModule::price_change_bound(BTC), None);
+		assert_eq!(PricesModule::last_accepted_price(BTC), None);
+	});
+}
+
+#[test]
+fn circuit_breaker_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(PricesModule::set_price_change_bound(
+			Origin::signed(1),
+			BTC,
+			Some(Ratio::saturating_from_rational(20, 100))
+		));
+
+		// the prices within the bound are accepted
+		mock_source_prices(vec![Some((Price::saturating_from_integer(100), now()))]);
+		PricesModule::on_initialize(1);
+		assert_eq!(PricesModule::last_accepted_price(BTC), PricesModule::access_price(BTC));
+
+		mock_source_prices(vec![Some((Price::saturating_from_integer(110), now()))]);
+		let price_110 = PricesModule::access_price(BTC).unwrap();
+		PricesModule::on_initialize(2);
+		assert_eq!(PricesModule::last_accepted_price(BTC), Some(price_110));
+		assert!(!PricesModule::is_triggered(BTC));
+
+		// the price beyond the bound triggers the circuit breaker
+		mock_source_prices(vec![Some((Price::saturating_from_integer(150), now()))]);
+		let price_150 = PricesModule::access_price(BTC).unwrap();
+		PricesModule::on_initialize(3);
+		System::assert_has_event(Event::PricesModule(crate::Event::LockPrice(BTC, price_110)));
+		System::assert_last_event(Event::PricesModule(crate::Event::CircuitBreakerTriggered(
+			BTC, price_110, price_150,
+		)));
+		assert!(PricesModule::is_triggered(BTC));
+		assert_eq!(PricesModule::locked_price(BTC), Some(price_110));
+		assert_eq!(PricesModule::triggered_circuit_breakers(BTC), Some((price_110, 13)));
+		assert_eq!(PricesModule::last_accepted_price(BTC), Some(price_110));
+		assert_eq!(PriorityLockedPriceProvider::<Runtime>::get_price(BTC), Some(price_110));
+
+		// reset after cool-down, and the new price is accepted as the base
+		PricesModule::on_initialize(12);
+		assert!(PricesModule::is_triggered(BTC));
+		PricesModule::on_initialize(13);
+		System::assert_has_event(Event::PricesModule(crate::Event::UnlockPrice(BTC)));
+		System::assert_has_event(Event::PricesModule(crate::Event::CircuitBreakerReset(BTC)));
+		assert!(!PricesModule::is_triggered(BTC));
+		assert_eq!(PricesModule::locked_price(BTC), None);
+		assert_eq!(PricesModule::last_accepted_price(BTC), Some(price_150));
+
+		// reset by unlocking the price
+		mock_source_prices(vec![Some((Price::saturating_from_integer(300), now()))]);
+		PricesModule::on_initialize(14);
+		assert!(PricesModule::is_triggered(BTC));
+		assert_ok!(PricesModule::unlock_price(Origin::signed(1), BTC));
+		System::assert_last_event(Event::PricesModule(crate::Event::CircuitBreakerReset(BTC)));
+		assert!(!PricesModule::is_triggered(BTC));
+		assert_eq!(PricesModule::locked_price(BTC), None);
+		assert_eq!(PricesModule::last_accepted_price(BTC), None);
+	});
+}
+
+#[test]
+fn set_dex_price_rule_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let rule = DexPriceRule {
+			path: vec![KSM, AUSD],
+			min_liquidity: 10_000,
+		};
+
+		assert_noop!(
+			PricesModule::set_dex_price_rule(Origin::signed(5), KSM, Some(rule.clone())),
+			BadOrigin
+		);
+		for path in [vec![], vec![KSM], vec![AUSD, KSM], vec![KSM, DOT, KSM]].iter() {
+			assert_noop!(
+				PricesModule::set_dex_price_rule(
+					Origin::signed(1),
+					KSM,
+					Some(DexPriceRule {
+						path: path.clone(),
+						min_liquidity: 10_000
+					})
+				),
+				Error::<Runtime>::InvalidDexPriceRule
+			);
+		}
+
+		assert_ok!(PricesModule::set_dex_price_rule(
+			Origin::signed(1),
+			KSM,
+			Some(rule.clone())
+		));
+		System::assert_last_event(Event::PricesModule(crate::Event::DexPriceRuleUpdated(
+			KSM,
+			Some(rule.clone()),
+		)));
+		assert_eq!(PricesModule::dex_price_rules(KSM), Some(rule));
+
+		// circular pricing is not allowed
+		assert_noop!(
+			PricesModule::set_dex_price_rule(
+				Origin::signed(1),
+				DOT,
+				Some(DexPriceRule {
+					path: vec![DOT, KSM],
+					min_liquidity: 10_000
+				})
+			),
+			Error::<Runtime>::InvalidDexPriceRule
+		);
+		assert_noop!(
+			PricesModule::set_dex_price_rule(
+				Origin::signed(1),
+				AUSD,
+				Some(DexPriceRule {
+					path: vec![AUSD, DOT],
+					min_liquidity: 10_000
+				})
+			),
+			Error::<Runtime>::InvalidDexPriceRule
+		);
+
+		assert_ok!(PricesModule::set_dex_price_rule(Origin::signed(1), KSM, None));
+		System::assert_last_event(Event::PricesModule(crate::Event::DexPriceRuleUpdated(KSM, None)));
+		assert_eq!(PricesModule::dex_price_rules(KSM), None);
+	});
+}
+
+#[test]
+fn dex_price_fallback_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let ausd_price = PricesModule::access_price(AUSD).unwrap();
+		assert_eq!(PricesModule::access_price(KSM), None);
+
+		assert_ok!(PricesModule::set_dex_price_rule(
+			Origin::signed(1),
+			KSM,
+			Some(DexPriceRule {
+				path: vec![KSM, AUSD],
+				min_liquidity: 10_000,
+			})
+		));
+		mock_ksm_ausd_pool((1_000, 50_000));
+
+		// the first sample starts the averaging window
+		PricesModule::on_initialize(1);
+		assert_eq!(
+			PricesModule::dex_price_accumulators(KSM),
+			Some(DexPriceAccumulator {
+				spot_price: Price::saturating_from_integer(50),
+				cumulative_price: 0,
+				updated_at: now(),
+				window_cumulative_price: 0,
+				window_start: now(),
+			})
+		);
+		assert_eq!(PricesModule::dex_average_prices(KSM), None);
+		assert_eq!(PricesModule::access_price(KSM), None);
+
+		// no average price before the averaging window passes
+		mock_ksm_ausd_pool((1_000, 80_000));
+		set_now(now() + 30_000);
+		PricesModule::on_initialize(2);
+		assert_eq!(PricesModule::dex_average_prices(KSM), None);
+		assert_eq!(PricesModule::access_price(KSM), None);
+
+		// the spot prices are weighted by the time they held
+		set_now(now() + 30_000);
+		PricesModule::on_initialize(3);
+		assert_eq!(
+			PricesModule::dex_average_prices(KSM),
+			Some((Price::saturating_from_integer(65), now()))
+		);
+		assert_eq!(
+			PricesModule::access_price(KSM),
+			Some(ausd_price.saturating_mul(Price::saturating_from_integer(65)))
+		);
+
+		// the spot price manipulated for one block only moves the average by the time it held
+		mock_ksm_ausd_pool((1_000, 1_000_000));
+		set_now(now() + 6_000);
+		PricesModule::on_initialize(4);
+		mock_ksm_ausd_pool((1_000, 80_000));
+		set_now(now() + 6_000);
+		PricesModule::on_initialize(5);
+		assert_eq!(
+			PricesModule::access_price(KSM),
+			Some(ausd_price.saturating_mul(Price::saturating_from_integer(65)))
+		);
+		set_now(now() + 48_000);
+		PricesModule::on_initialize(6);
+		assert_eq!(
+			PricesModule::access_price(KSM),
+			Some(ausd_price.saturating_mul(Price::saturating_from_integer(172)))
+		);
+
+		// the price rejected by the quorum of the sources doesn't fall back to DEX
+		MinSourcesQuorum::set(2);
+		mock_source_prices(vec![Some((Price::saturating_from_integer(200), now())), None]);
+		assert_eq!(PricesModule::access_price(KSM), None);
+		MinSourcesQuorum::set(1);
+		assert_eq!(
+			PricesModule::access_price(KSM),
+			Some(Price::saturating_from_integer(200000000u128))
+		);
+		mock_source_prices(vec![None]);
+		assert_eq!(
+			PricesModule::access_price(KSM),
+			Some(ausd_price.saturating_mul(Price::saturating_from_integer(172)))
+		);
+
+		// the thin pool is not trusted
+		mock_ksm_ausd_pool((100, 5_000));
+		PricesModule::on_initialize(7);
+		assert_eq!(PricesModule::dex_price_accumulators(KSM), None);
+		assert_eq!(PricesModule::dex_average_prices(KSM), None);
+		assert_eq!(PricesModule::access_price(KSM), None);
+	});
+}
+
+#[test]
+fn dex_spot_price_of_long_path_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let rule = DexPriceRule {
+			path: vec![KSM, DOT, AUSD],
+			min_liquidity: 10_000,
+		};
+
+		// every pool of the path is valued in AUSD, 1 DOT = 50 AUSD
+		mock_ksm_dot_pool((1_000, 400));
+		assert_eq!(
+			PricesModule::dex_spot_price(&rule),
+			Some(Price::saturating_from_integer(20))
+		);
+
+		// the KSM-DOT pool holds 5_000 AUSD worth of liquidity on each side
+		mock_ksm_dot_pool((250, 100));
+		assert_eq!(PricesModule::dex_spot_price(&rule), None);
+
+		// the DOT-AUSD pool holds 10_000 AUSD worth of liquidity on each side
+		assert_eq!(
+			PricesModule::dex_spot_price(&DexPriceRule {
+				path: vec![DOT, AUSD],
+				min_liquidity: 10_001,
+			}),
+			None
+		);
+		mock_ksm_dot_pool((1_000, 400));
+		assert_eq!(
+			PricesModule::dex_spot_price(&DexPriceRule {
+				path: vec![KSM, DOT, AUSD],
+				min_liquidity: 10_001,
+			}),
+			None
+		);
+	});
+}
+
+#[test]
+fn stable_asset_pool_token_price_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(PricesModule::access_price(STABLE_ASSET_POOL_TOKEN), None);
+
+		// the lowest price of DOT and LDOT is 50 USD, the pool token has 18 decimals
+		mock_stable_asset_pool(Some((vec![DOT, LDOT], Price::saturating_from_rational(6, 5))));
+		assert_eq!(
+			PricesModule::access_whole_unit_price(LDOT),
+			Some(Price::saturating_from_integer(50))
+		);
+		assert_eq!(
+			PricesModule::access_price(STABLE_ASSET_POOL_TOKEN),
+			Some(Price::saturating_from_integer(60))
+		);
+
+		// no price if any of the underlying currencies has no price
+		mock_stable_asset_pool(Some((vec![DOT, KSM], Price::saturating_from_rational(6, 5))));
+		assert_eq!(PricesModule::access_price(STABLE_ASSET_POOL_TOKEN), None);
+	});
+}
+
+#[test]
+fn erc20_price_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(PricesModule::access_price(ERC20), None);
+
+		// the erc20 has 18 decimals
+		mock_source_prices(vec![Some((Price::saturating_from_integer(2), now()))]);
+		assert_eq!(
+			PricesModule::access_price(ERC20),
+			Some(Price::saturating_from_integer(2))
+		);
+	});
+}
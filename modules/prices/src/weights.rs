@@ -50,6 +50,10 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
 	fn lock_price() -> Weight;
 	fn unlock_price() -> Weight;
+	fn set_source_weights() -> Weight;
+	fn set_price_change_bound() -> Weight;
+	fn set_dex_price_rule() -> Weight;
+	fn on_initialize(c: u32) -> Weight;
 }
 
 /// Weights for module_prices using the Acala node and recommended hardware.
@@ -64,6 +68,26 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 		(12_000_000 as Weight)
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	fn set_source_weights() -> Weight {
+		(11_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn set_price_change_bound() -> Weight {
+		(12_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn set_dex_price_rule() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn on_initialize(c: u32) -> Weight {
+		(4_000_000 as Weight)
+			.saturating_add((40_000_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((8 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(c as Weight)))
+	}
 }
 
 // For backwards compatibility and tests
@@ -77,4 +101,24 @@ impl WeightInfo for () {
 		(12_000_000 as Weight)
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
+	fn set_source_weights() -> Weight {
+		(11_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_price_change_bound() -> Weight {
+		(12_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn set_dex_price_rule() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn on_initialize(c: u32) -> Weight {
+		(4_000_000 as Weight)
+			.saturating_add((40_000_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((8 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(c as Weight)))
+	}
 }
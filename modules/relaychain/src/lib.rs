@@ -27,8 +27,8 @@ use codec::{Decode, Encode, FullCodec};
 use sp_runtime::traits::StaticLookup;
 
 use frame_support::{traits::Get, weights::Weight, RuntimeDebug};
-use module_support::CallBuilder;
-use primitives::Balance;
+use module_support::{CallBuilder, RewardDestination};
+use primitives::{Balance, EraIndex};
 use sp_std::{boxed::Box, marker::PhantomData, prelude::*};
 
 pub use cumulus_primitives_core::ParaId;
@@ -36,6 +36,9 @@ use xcm::latest::prelude::*;
 
 use frame_system::Config;
 
+mod mock;
+mod tests;
+
 #[derive(Encode, Decode, RuntimeDebug)]
 pub enum BalancesCall<T: Config> {
 	#[codec(index = 3)]
@@ -51,9 +54,50 @@ pub enum UtilityCall<RelaychainCall> {
 }
 
 #[derive(Encode, Decode, RuntimeDebug)]
-pub enum StakingCall {
+pub enum StakingCall<T: Config> {
+	#[codec(index = 0)]
+	Bond(
+		<T::Lookup as StaticLookup>::Source,
+		#[codec(compact)] Balance,
+		RewardDestination<T::AccountId>,
+	),
+	#[codec(index = 1)]
+	BondExtra(#[codec(compact)] Balance),
+	#[codec(index = 2)]
+	Unbond(#[codec(compact)] Balance),
 	#[codec(index = 3)]
 	WithdrawUnbonded(u32),
+	#[codec(index = 5)]
+	Nominate(Vec<<T::Lookup as StaticLookup>::Source>),
+	#[codec(index = 6)]
+	Chill,
+	#[codec(index = 18)]
+	PayoutStakers(T::AccountId, EraIndex),
+	#[codec(index = 19)]
+	Rebond(#[codec(compact)] Balance),
+}
+
+/// The proxy types shared by Kusama's and Polkadot's Runtime configuration.
+#[derive(Encode, Decode, RuntimeDebug)]
+pub enum ProxyType {
+	#[codec(index = 0)]
+	Any,
+	#[codec(index = 1)]
+	NonTransfer,
+	#[codec(index = 2)]
+	Governance,
+	#[codec(index = 3)]
+	Staking,
+	#[codec(index = 5)]
+	IdentityJudgement,
+	#[codec(index = 6)]
+	CancelProxy,
+}
+
+#[derive(Encode, Decode, RuntimeDebug)]
+pub enum ProxyCall<T: Config, RelaychainCall> {
+	#[codec(index = 0)]
+	Proxy(T::AccountId, Option<ProxyType>, RelaychainCall),
 }
 
 mod kusama {
@@ -66,9 +110,11 @@ mod kusama {
 		#[codec(index = 4)]
 		Balances(BalancesCall<T>),
 		#[codec(index = 6)]
-		Staking(StakingCall),
+		Staking(StakingCall<T>),
 		#[codec(index = 24)]
 		Utility(Box<UtilityCall<Self>>),
+		#[codec(index = 30)]
+		Proxy(Box<ProxyCall<T, Self>>),
 	}
 }
 
@@ -82,9 +128,11 @@ mod polkadot {
 		#[codec(index = 5)]
 		Balances(BalancesCall<T>),
 		#[codec(index = 7)]
-		Staking(StakingCall),
+		Staking(StakingCall<T>),
 		#[codec(index = 26)]
 		Utility(Box<UtilityCall<Self>>),
+		#[codec(index = 29)]
+		Proxy(Box<ProxyCall<T, Self>>),
 	}
 }
 
@@ -113,10 +161,48 @@ where
 		RelaychainCall::Utility(Box::new(UtilityCall::AsDerivative(index, call)))
 	}
 
+	fn staking_bond(
+		controller: Self::AccountId,
+		amount: Self::Balance,
+		payee: RewardDestination<Self::AccountId>,
+	) -> Self::RelaychainCall {
+		RelaychainCall::Staking(StakingCall::Bond(T::Lookup::unlookup(controller), amount, payee))
+	}
+
+	fn staking_bond_extra(amount: Self::Balance) -> Self::RelaychainCall {
+		RelaychainCall::Staking(StakingCall::BondExtra(amount))
+	}
+
+	fn staking_unbond(amount: Self::Balance) -> Self::RelaychainCall {
+		RelaychainCall::Staking(StakingCall::Unbond(amount))
+	}
+
+	fn staking_rebond(amount: Self::Balance) -> Self::RelaychainCall {
+		RelaychainCall::Staking(StakingCall::Rebond(amount))
+	}
+
 	fn staking_withdraw_unbonded(num_slashing_spans: u32) -> Self::RelaychainCall {
 		RelaychainCall::Staking(StakingCall::WithdrawUnbonded(num_slashing_spans))
 	}
 
+	fn staking_nominate(targets: Vec<Self::AccountId>) -> Self::RelaychainCall {
+		RelaychainCall::Staking(StakingCall::Nominate(
+			targets.into_iter().map(T::Lookup::unlookup).collect(),
+		))
+	}
+
+	fn staking_chill() -> Self::RelaychainCall {
+		RelaychainCall::Staking(StakingCall::Chill)
+	}
+
+	fn staking_payout_stakers(validator_stash: Self::AccountId, era: EraIndex) -> Self::RelaychainCall {
+		RelaychainCall::Staking(StakingCall::PayoutStakers(validator_stash, era))
+	}
+
+	fn proxy_call(real: Self::AccountId, call: Self::RelaychainCall) -> Self::RelaychainCall {
+		RelaychainCall::Proxy(Box::new(ProxyCall::Proxy(real, None, call)))
+	}
+
 	fn balances_transfer_keep_alive(to: Self::AccountId, amount: Self::Balance) -> Self::RelaychainCall {
 		RelaychainCall::Balances(BalancesCall::TransferKeepAlive(T::Lookup::unlookup(to), amount))
 	}
@@ -0,0 +1,113 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the relaychain module.
+
+#![cfg(test)]
+
+use super::*;
+use hex_literal::hex;
+use mock::*;
+
+const AMOUNT: Balance = 1_000_000_000_000;
+
+#[cfg(feature = "kusama")]
+#[test]
+fn kusama_staking_call_encoding_work() {
+	assert_eq!(
+		RelaychainCallBuilderOf::staking_bond(ALICE, AMOUNT, RewardDestination::Staked).encode(),
+		hex!("0600000101010101010101010101010101010101010101010101010101010101010101070010a5d4e800").to_vec()
+	);
+	assert_eq!(
+		RelaychainCallBuilderOf::staking_bond(ALICE, AMOUNT, RewardDestination::Account(BOB)).encode(),
+		hex!("0600000101010101010101010101010101010101010101010101010101010101010101070010a5d4e8030202020202020202020202020202020202020202020202020202020202020202").to_vec()
+	);
+	assert_eq!(
+		RelaychainCallBuilderOf::staking_bond_extra(AMOUNT).encode(),
+		hex!("0601070010a5d4e8").to_vec()
+	);
+	assert_eq!(
+		RelaychainCallBuilderOf::staking_unbond(AMOUNT).encode(),
+		hex!("0602070010a5d4e8").to_vec()
+	);
+	assert_eq!(
+		RelaychainCallBuilderOf::staking_rebond(AMOUNT).encode(),
+		hex!("0613070010a5d4e8").to_vec()
+	);
+	assert_eq!(
+		RelaychainCallBuilderOf::staking_nominate(vec![ALICE, BOB]).encode(),
+		hex!("060508000101010101010101010101010101010101010101010101010101010101010101000202020202020202020202020202020202020202020202020202020202020202").to_vec()
+	);
+	assert_eq!(RelaychainCallBuilderOf::staking_chill().encode(), hex!("0606").to_vec());
+	assert_eq!(
+		RelaychainCallBuilderOf::staking_payout_stakers(ALICE, 100).encode(),
+		hex!("0612010101010101010101010101010101010101010101010101010101010101010164000000").to_vec()
+	);
+}
+
+#[cfg(feature = "kusama")]
+#[test]
+fn kusama_proxy_call_encoding_work() {
+	assert_eq!(
+		RelaychainCallBuilderOf::proxy_call(ALICE, RelaychainCallBuilderOf::staking_chill()).encode(),
+		hex!("1e000101010101010101010101010101010101010101010101010101010101010101000606").to_vec()
+	);
+}
+
+#[cfg(feature = "polkadot")]
+#[test]
+fn polkadot_staking_call_encoding_work() {
+	assert_eq!(
+		RelaychainCallBuilderOf::staking_bond(ALICE, AMOUNT, RewardDestination::Staked).encode(),
+		hex!("0700000101010101010101010101010101010101010101010101010101010101010101070010a5d4e800").to_vec()
+	);
+	assert_eq!(
+		RelaychainCallBuilderOf::staking_bond(ALICE, AMOUNT, RewardDestination::Account(BOB)).encode(),
+		hex!("0700000101010101010101010101010101010101010101010101010101010101010101070010a5d4e8030202020202020202020202020202020202020202020202020202020202020202").to_vec()
+	);
+	assert_eq!(
+		RelaychainCallBuilderOf::staking_bond_extra(AMOUNT).encode(),
+		hex!("0701070010a5d4e8").to_vec()
+	);
+	assert_eq!(
+		RelaychainCallBuilderOf::staking_unbond(AMOUNT).encode(),
+		hex!("0702070010a5d4e8").to_vec()
+	);
+	assert_eq!(
+		RelaychainCallBuilderOf::staking_rebond(AMOUNT).encode(),
+		hex!("0713070010a5d4e8").to_vec()
+	);
+	assert_eq!(
+		RelaychainCallBuilderOf::staking_nominate(vec![ALICE, BOB]).encode(),
+		hex!("070508000101010101010101010101010101010101010101010101010101010101010101000202020202020202020202020202020202020202020202020202020202020202").to_vec()
+	);
+	assert_eq!(RelaychainCallBuilderOf::staking_chill().encode(), hex!("0706").to_vec());
+	assert_eq!(
+		RelaychainCallBuilderOf::staking_payout_stakers(ALICE, 100).encode(),
+		hex!("0712010101010101010101010101010101010101010101010101010101010101010164000000").to_vec()
+	);
+}
+
+#[cfg(feature = "polkadot")]
+#[test]
+fn polkadot_proxy_call_encoding_work() {
+	assert_eq!(
+		RelaychainCallBuilderOf::proxy_call(ALICE, RelaychainCallBuilderOf::staking_chill()).encode(),
+		hex!("1d000101010101010101010101010101010101010101010101010101010101010101000706").to_vec()
+	);
+}
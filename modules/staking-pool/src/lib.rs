@@ -710,9 +710,10 @@ impl<T: Config> Pallet<T> {
 }
 
 impl<T: Config> OnNewEra<EraIndex> for Pallet<T> {
-	fn on_new_era(new_era: EraIndex) {
+	fn on_new_era(new_era: EraIndex) -> Weight {
 		CurrentEra::<T>::put(new_era);
 		RebalancePhase::<T>::put(Phase::Started);
+		T::DbWeight::get().writes(2)
 	}
 }
 
@@ -17,15 +17,24 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use super::*;
-use frame_support::{traits::Get, Parameter};
+use frame_support::{traits::Get, weights::Weight, Parameter};
 use sp_runtime::{
 	traits::{MaybeDisplay, MaybeSerializeDeserialize, Member},
 	RuntimeDebug,
 };
 
-#[impl_trait_for_tuples::impl_for_tuples(30)]
 pub trait OnNewEra<EraIndex> {
-	fn on_new_era(era: EraIndex);
+	/// Handle the start of the new era, returns the weight consumed.
+	fn on_new_era(era: EraIndex) -> Weight;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+impl<EraIndex: Clone> OnNewEra<EraIndex> for Tuple {
+	fn on_new_era(era: EraIndex) -> Weight {
+		let mut weight: Weight = 0;
+		for_tuples!( #( weight = weight.saturating_add(Tuple::on_new_era(era.clone())); )* );
+		weight
+	}
 }
 
 pub trait NomineesProvider<AccountId> {
@@ -85,6 +94,23 @@ impl<Balance, CurrencyId> OnCommission<Balance, CurrencyId> for () {
 	fn on_commission(_currency_id: CurrencyId, _amount: Balance) {}
 }
 
+pub trait HomaValidatorInsurance<RelaychainAccountId, Balance> {
+	/// The total insurance of the validator from all guarantors.
+	fn total_insurance(validator: &RelaychainAccountId) -> Balance;
+	/// Whether the validator is frozen.
+	fn is_frozen(validator: &RelaychainAccountId) -> bool;
+}
+
+impl<RelaychainAccountId, Balance: Default> HomaValidatorInsurance<RelaychainAccountId, Balance> for () {
+	fn total_insurance(_validator: &RelaychainAccountId) -> Balance {
+		Default::default()
+	}
+
+	fn is_frozen(_validator: &RelaychainAccountId) -> bool {
+		false
+	}
+}
+
 pub trait HomaProtocol<AccountId, Balance, EraIndex> {
 	type Balance: Decode + Encode + Debug + Eq + PartialEq + Clone + HasCompact;
 
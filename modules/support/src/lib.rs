@@ -23,7 +23,7 @@ use codec::{Decode, Encode, FullCodec, HasCompact};
 use frame_support::pallet_prelude::{DispatchClass, Pays, Weight};
 use primitives::{
 	evm::{CallInfo, EvmAddress},
-	CurrencyId,
+	CurrencyId, EraIndex, Moment,
 };
 use sp_core::H160;
 use sp_runtime::{
@@ -42,8 +42,8 @@ use xcm::latest::prelude::*;
 pub mod homa;
 pub mod mocks;
 pub use homa::{
-	HomaProtocol, NomineesProvider, OnCommission, OnNewEra, PolkadotBridge, PolkadotBridgeCall, PolkadotBridgeState,
-	PolkadotBridgeType, PolkadotStakingLedger, PolkadotUnlockChunk,
+	HomaProtocol, HomaValidatorInsurance, NomineesProvider, OnCommission, OnNewEra, PolkadotBridge, PolkadotBridgeCall,
+	PolkadotBridgeState, PolkadotBridgeType, PolkadotStakingLedger, PolkadotUnlockChunk,
 };
 
 pub type Price = FixedU128;
@@ -291,6 +291,45 @@ pub trait LockablePrice<CurrencyId> {
 	fn unlock_price(currency_id: CurrencyId) -> DispatchResult;
 }
 
+/// Check whether the circuit breaker of the price is triggered.
+pub trait PriceCircuitBreaker<CurrencyId> {
+	fn is_triggered(currency_id: CurrencyId) -> bool;
+}
+
+impl<CurrencyId> PriceCircuitBreaker<CurrencyId> for () {
+	fn is_triggered(_: CurrencyId) -> bool {
+		false
+	}
+}
+
+/// Provide the info of the stable asset pools for pricing the pool tokens.
+pub trait StableAssetPoolInfo<CurrencyId> {
+	/// Get the underlying currencies of the pool and the virtual price of the pool token, which is
+	/// the value of one pool token in the underlying currencies, both in whole units.
+	fn get_pool_info(pool_token: CurrencyId) -> Option<(Vec<CurrencyId>, Price)>;
+
+	/// Get an underlying currency of the pool and its precision, the multiplier normalizing the
+	/// balance of the underlying currency to the unit of the pool token.
+	fn get_pool_token_precision(pool_token: CurrencyId) -> Option<(CurrencyId, u128)>;
+}
+
+impl<CurrencyId> StableAssetPoolInfo<CurrencyId> for () {
+	fn get_pool_info(_: CurrencyId) -> Option<(Vec<CurrencyId>, Price)> {
+		None
+	}
+
+	fn get_pool_token_precision(_: CurrencyId) -> Option<(CurrencyId, u128)> {
+		None
+	}
+}
+
+/// Provide the prices of multiple sources, such as the oracle instances.
+pub trait MultiSourcePriceProvider<CurrencyId> {
+	/// Get the price and its timestamp(in milliseconds) of every source, the index of the
+	/// returned vec is the index of the source.
+	fn get_prices(currency_id: &CurrencyId) -> Vec<Option<(Price, Moment)>>;
+}
+
 pub trait ExchangeRateProvider {
 	fn get_exchange_rate() -> ExchangeRate;
 }
@@ -480,6 +519,61 @@ pub trait Contains<T> {
 	fn contains(t: &T) -> bool;
 }
 
+/// Hooks of the currency operations, which can veto the operation before it
+/// happens, or record it after it succeeded.
+///
+/// `repatriate_reserved` is hooked as a transfer. A slash can't be vetoed, it's
+/// only recorded by `post_withdraw`.
+pub trait CurrencyOperationHook<AccountId, CurrencyId, Balance> {
+	/// Called before `amount` of `currency_id` is transferred from `from` to
+	/// `to`. The transfer is rejected if it returns an error.
+	fn pre_transfer(_currency_id: CurrencyId, _from: &AccountId, _to: &AccountId, _amount: Balance) -> DispatchResult {
+		Ok(())
+	}
+	/// Called after `amount` of `currency_id` was transferred from `from` to
+	/// `to`.
+	fn post_transfer(_currency_id: CurrencyId, _from: &AccountId, _to: &AccountId, _amount: Balance) {}
+	/// Called before `amount` of `currency_id` is deposited to `who`. The
+	/// deposit is rejected if it returns an error.
+	fn pre_deposit(_currency_id: CurrencyId, _who: &AccountId, _amount: Balance) -> DispatchResult {
+		Ok(())
+	}
+	/// Called after `amount` of `currency_id` was deposited to `who`.
+	fn post_deposit(_currency_id: CurrencyId, _who: &AccountId, _amount: Balance) {}
+	/// Called before `amount` of `currency_id` is withdrawn from `who`. The
+	/// withdrawal is rejected if it returns an error.
+	fn pre_withdraw(_currency_id: CurrencyId, _who: &AccountId, _amount: Balance) -> DispatchResult {
+		Ok(())
+	}
+	/// Called after `amount` of `currency_id` was withdrawn from `who`.
+	fn post_withdraw(_currency_id: CurrencyId, _who: &AccountId, _amount: Balance) {}
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(5)]
+impl<AccountId, CurrencyId: Clone, Balance: Clone> CurrencyOperationHook<AccountId, CurrencyId, Balance> for Tuple {
+	fn pre_transfer(currency_id: CurrencyId, from: &AccountId, to: &AccountId, amount: Balance) -> DispatchResult {
+		for_tuples!( #( Tuple::pre_transfer(currency_id.clone(), from, to, amount.clone())?; )* );
+		Ok(())
+	}
+	fn post_transfer(currency_id: CurrencyId, from: &AccountId, to: &AccountId, amount: Balance) {
+		for_tuples!( #( Tuple::post_transfer(currency_id.clone(), from, to, amount.clone()); )* );
+	}
+	fn pre_deposit(currency_id: CurrencyId, who: &AccountId, amount: Balance) -> DispatchResult {
+		for_tuples!( #( Tuple::pre_deposit(currency_id.clone(), who, amount.clone())?; )* );
+		Ok(())
+	}
+	fn post_deposit(currency_id: CurrencyId, who: &AccountId, amount: Balance) {
+		for_tuples!( #( Tuple::post_deposit(currency_id.clone(), who, amount.clone()); )* );
+	}
+	fn pre_withdraw(currency_id: CurrencyId, who: &AccountId, amount: Balance) -> DispatchResult {
+		for_tuples!( #( Tuple::pre_withdraw(currency_id.clone(), who, amount.clone())?; )* );
+		Ok(())
+	}
+	fn post_withdraw(currency_id: CurrencyId, who: &AccountId, amount: Balance) {
+		for_tuples!( #( Tuple::post_withdraw(currency_id.clone(), who, amount.clone()); )* );
+	}
+}
+
 /// A mapping between `AccountId` and `EvmAddress`.
 pub trait AddressMapping<AccountId> {
 	/// Returns the AccountId used go generate the given EvmAddress.
@@ -560,11 +654,52 @@ impl CurrencyIdMapping for () {
 	}
 }
 
+/// A mapping between ForeignAssetId and its MultiLocation and AssetMetadata.
+pub trait ForeignAssetIdMapping<ForeignAssetId, MultiLocation, AssetMetadata> {
+	/// Returns the AssetMetadata associated with a given ForeignAssetId.
+	fn get_asset_metadata(foreign_asset_id: ForeignAssetId) -> Option<AssetMetadata>;
+	/// Returns the MultiLocation associated with a given ForeignAssetId.
+	fn get_multi_location(foreign_asset_id: ForeignAssetId) -> Option<MultiLocation>;
+	/// Returns the CurrencyId associated with a given MultiLocation.
+	fn get_currency_id(multi_location: MultiLocation) -> Option<CurrencyId>;
+}
+
+impl<ForeignAssetId, MultiLocation, AssetMetadata> ForeignAssetIdMapping<ForeignAssetId, MultiLocation, AssetMetadata>
+	for ()
+{
+	fn get_asset_metadata(_foreign_asset_id: ForeignAssetId) -> Option<AssetMetadata> {
+		None
+	}
+
+	fn get_multi_location(_foreign_asset_id: ForeignAssetId) -> Option<MultiLocation> {
+		None
+	}
+
+	fn get_currency_id(_multi_location: MultiLocation) -> Option<CurrencyId> {
+		None
+	}
+}
+
 /// Used to interface with the Compound's Cash module
 pub trait CompoundCashTrait<Balance, Moment> {
 	fn set_future_yield(next_cash_yield: Balance, yield_index: u128, timestamp_effective: Moment) -> DispatchResult;
 }
 
+/// A destination account for payment of staking rewards on the relay-chain.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug)]
+pub enum RewardDestination<AccountId> {
+	/// Pay into the stash account, increasing the amount at stake accordingly.
+	Staked,
+	/// Pay into the stash account, not increasing the amount at stake.
+	Stash,
+	/// Pay into the controller account.
+	Controller,
+	/// Pay into a specified account.
+	Account(AccountId),
+	/// Receive no reward.
+	None,
+}
+
 pub trait CallBuilder {
 	type AccountId: FullCodec;
 	type Balance: FullCodec;
@@ -581,11 +716,57 @@ pub trait CallBuilder {
 	/// - index: The index of sub-account to be used as the new origin.
 	fn utility_as_derivative_call(call: Self::RelaychainCall, index: u16) -> Self::RelaychainCall;
 
+	/// Bond the staking currency on the relay-chain, with the origin as stash.
+	///  params:
+	/// - controller: The controller account of the bonded stash.
+	/// - amount: The amount of staking currency to be bonded.
+	/// - payee: The destination of the staking rewards.
+	fn staking_bond(
+		controller: Self::AccountId,
+		amount: Self::Balance,
+		payee: RewardDestination<Self::AccountId>,
+	) -> Self::RelaychainCall;
+
+	/// Bond extra staking currency on the relay-chain.
+	///  params:
+	/// - amount: The amount of staking currency to be added to the bond.
+	fn staking_bond_extra(amount: Self::Balance) -> Self::RelaychainCall;
+
+	/// Schedule a portion of the bonded staking currency to be unlocked on the relay-chain.
+	///  params:
+	/// - amount: The amount of staking currency to be unbonded.
+	fn staking_unbond(amount: Self::Balance) -> Self::RelaychainCall;
+
+	/// Rebond a portion of the staking currency scheduled to be unlocked on the relay-chain.
+	///  params:
+	/// - amount: The amount of staking currency to be rebonded.
+	fn staking_rebond(amount: Self::Balance) -> Self::RelaychainCall;
+
 	/// Withdraw unbonded staking on the relay-chain.
 	///  params:
 	/// - num_slashing_spans: The number of slashing spans to withdraw from.
 	fn staking_withdraw_unbonded(num_slashing_spans: u32) -> Self::RelaychainCall;
 
+	/// Nominate validators on the relay-chain.
+	///  params:
+	/// - targets: The validators to be nominated.
+	fn staking_nominate(targets: Vec<Self::AccountId>) -> Self::RelaychainCall;
+
+	/// Declare no desire to either validate or nominate on the relay-chain.
+	fn staking_chill() -> Self::RelaychainCall;
+
+	/// Pay out all the stakers behind a single validator for a single era on the relay-chain.
+	///  params:
+	/// - validator_stash: The stash account of the validator.
+	/// - era: The era to be paid out.
+	fn staking_payout_stakers(validator_stash: Self::AccountId, era: EraIndex) -> Self::RelaychainCall;
+
+	/// Dispatch a call on the relay-chain on behalf of an account that the origin is a proxy of.
+	///  params:
+	/// - real: The account that the proxy will make the call on behalf of.
+	/// - call: The call to be executed.
+	fn proxy_call(real: Self::AccountId, call: Self::RelaychainCall) -> Self::RelaychainCall;
+
 	/// Transfer Staking currency to another account, disallowing "death".
 	///  params:
 	/// - to: The destination for the transfer
@@ -83,7 +83,10 @@ impl CurrencyIdMapping for MockCurrencyIdMapping {
 	}
 
 	fn decimals(currency_id: CurrencyId) -> Option<u8> {
-		currency_id.decimals()
+		match currency_id {
+			CurrencyId::Erc20(_) | CurrencyId::StableAssetPoolToken(_) => Some(18),
+			_ => currency_id.decimals(),
+		}
 	}
 
 	fn encode_evm_address(v: CurrencyId) -> Option<EvmAddress> {
@@ -0,0 +1,103 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime API definition for transaction payment module.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+// The `too_many_arguments` warning originates from `decl_runtime_apis` macro.
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::unnecessary_mut_passed)]
+
+use codec::{Codec, Decode, Encode};
+#[cfg(feature = "std")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use sp_runtime::{
+	traits::{MaybeDisplay, MaybeFromStr},
+	RuntimeDebug,
+};
+use sp_std::prelude::*;
+
+/// The fee estimation of an extrinsic charged to an account.
+#[derive(Eq, PartialEq, Encode, Decode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct FeeEstimation<CurrencyId, Balance> {
+	/// The fee in native currency
+	#[cfg_attr(feature = "std", serde(bound(serialize = "Balance: std::fmt::Display")))]
+	#[cfg_attr(feature = "std", serde(serialize_with = "serialize_as_string"))]
+	#[cfg_attr(feature = "std", serde(bound(deserialize = "Balance: std::str::FromStr")))]
+	#[cfg_attr(feature = "std", serde(deserialize_with = "deserialize_from_string"))]
+	pub native_fee: Balance,
+	/// The path to swap the supply currency to native currency if the native currency is not
+	/// enough to pay the fee
+	pub fee_swap_path: Option<Vec<CurrencyId>>,
+	/// The amount of the supply currency swapped by `fee_swap_path`
+	#[cfg_attr(feature = "std", serde(bound(serialize = "Balance: std::fmt::Display")))]
+	#[cfg_attr(feature = "std", serde(serialize_with = "serialize_option_as_string"))]
+	#[cfg_attr(feature = "std", serde(bound(deserialize = "Balance: std::str::FromStr")))]
+	#[cfg_attr(feature = "std", serde(deserialize_with = "deserialize_option_from_string"))]
+	pub supply_amount: Option<Balance>,
+	/// Whether the fee can be charged
+	pub can_charge_fee: bool,
+}
+
+#[cfg(feature = "std")]
+fn serialize_as_string<S: Serializer, T: std::fmt::Display>(t: &T, serializer: S) -> Result<S::Ok, S::Error> {
+	serializer.serialize_str(&t.to_string())
+}
+
+#[cfg(feature = "std")]
+fn deserialize_from_string<'de, D: Deserializer<'de>, T: std::str::FromStr>(deserializer: D) -> Result<T, D::Error> {
+	let s = String::deserialize(deserializer)?;
+	s.parse::<T>()
+		.map_err(|_| serde::de::Error::custom("Parse from string failed"))
+}
+
+#[cfg(feature = "std")]
+fn serialize_option_as_string<S: Serializer, T: std::fmt::Display>(
+	t: &Option<T>,
+	serializer: S,
+) -> Result<S::Ok, S::Error> {
+	match t {
+		Some(t) => serializer.serialize_some(&t.to_string()),
+		None => serializer.serialize_none(),
+	}
+}
+
+#[cfg(feature = "std")]
+fn deserialize_option_from_string<'de, D: Deserializer<'de>, T: std::str::FromStr>(
+	deserializer: D,
+) -> Result<Option<T>, D::Error> {
+	match Option::<String>::deserialize(deserializer)? {
+		Some(s) => s
+			.parse::<T>()
+			.map(Some)
+			.map_err(|_| serde::de::Error::custom("Parse from string failed")),
+		None => Ok(None),
+	}
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait FeeEstimationApi<AccountId, CurrencyId, Balance> where
+		AccountId: Codec,
+		CurrencyId: Codec,
+		Balance: Codec + MaybeDisplay + MaybeFromStr,
+	{
+		fn query_fee_estimation(uxt: Block::Extrinsic, len: u32, account: AccountId) -> FeeEstimation<CurrencyId, Balance>;
+	}
+}
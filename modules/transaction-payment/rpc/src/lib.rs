@@ -0,0 +1,113 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! RPC interface for the transaction payment module.
+
+use codec::{Codec, Decode};
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use module_transaction_payment_rpc_runtime_api::FeeEstimation;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_runtime::{
+	generic::BlockId,
+	traits::{Block as BlockT, MaybeDisplay, MaybeFromStr},
+};
+use std::sync::Arc;
+
+pub use self::gen_client::Client as FeeEstimationClient;
+pub use module_transaction_payment_rpc_runtime_api::FeeEstimationApi as FeeEstimationRuntimeApi;
+
+#[rpc]
+pub trait FeeEstimationApi<BlockHash, AccountId, ResponseType> {
+	#[rpc(name = "payment_queryFeeEstimation")]
+	fn query_fee_estimation(
+		&self,
+		encoded_xt: Bytes,
+		account: AccountId,
+		at: Option<BlockHash>,
+	) -> Result<ResponseType>;
+}
+
+/// A struct that implements the [`FeeEstimationApi`].
+pub struct FeeEstimator<C, B> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> FeeEstimator<C, B> {
+	/// Create new `FeeEstimator` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		FeeEstimator {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+pub enum Error {
+	RuntimeError,
+	DecodeError,
+}
+
+impl From<Error> for i64 {
+	fn from(e: Error) -> i64 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::DecodeError => 2,
+		}
+	}
+}
+
+impl<C, Block, AccountId, CurrencyId, Balance>
+	FeeEstimationApi<<Block as BlockT>::Hash, AccountId, FeeEstimation<CurrencyId, Balance>> for FeeEstimator<C, Block>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: FeeEstimationRuntimeApi<Block, AccountId, CurrencyId, Balance>,
+	AccountId: Codec,
+	CurrencyId: Codec,
+	Balance: Codec + MaybeDisplay + MaybeFromStr,
+{
+	fn query_fee_estimation(
+		&self,
+		encoded_xt: Bytes,
+		account: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<FeeEstimation<CurrencyId, Balance>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or(
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash,
+		));
+
+		let encoded_len = encoded_xt.len() as u32;
+		let uxt: Block::Extrinsic = Decode::decode(&mut &*encoded_xt).map_err(|e| RpcError {
+			code: ErrorCode::ServerError(Error::DecodeError.into()),
+			message: "Unable to query fee estimation.".into(),
+			data: Some(format!("{:?}", e).into()),
+		})?;
+		api.query_fee_estimation(&at, uxt, encoded_len, account)
+			.map_err(|e| RpcError {
+				code: ErrorCode::ServerError(Error::RuntimeError.into()),
+				message: "Unable to query fee estimation.".into(),
+				data: Some(format!("{:?}", e).into()),
+			})
+	}
+}
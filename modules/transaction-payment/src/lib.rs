@@ -22,17 +22,35 @@
 //!
 //! Transaction payment module is responsible for charge fee and tip in
 //! different currencies
+//!
+//! The fee is paid in native currency. If the native currency is not enough, it's swapped from the
+//! alternative fee swap path of the account or the default fee swap paths. The call wrapped by
+//! `with_fee_currency` pays the fee in the specified currency, the route to native currency is
+//! discovered from DEX automatically and the excess fee is refunded in the same currency.
+//!
+//! A sponsor account can set a sponsorship with a budget, the max fee per transaction and the
+//! quota per user, and allow the calls and users it sponsors. The fee of the call wrapped by
+//! `with_sponsor` is charged to the sponsor if the call and the user are allowed. Each allowed
+//! call and user reserves `SponsoredItemDeposit` from the sponsor until it's removed.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
 
 use frame_support::{
-	dispatch::{DispatchResult, Dispatchable},
+	dispatch::{
+		CallMetadata, DispatchErrorWithPostInfo, DispatchResult, DispatchResultWithPostInfo, Dispatchable,
+		GetCallMetadata,
+	},
 	pallet_prelude::*,
+	storage::with_transaction,
 	traits::{
-		Currency, ExistenceRequirement, Imbalance, NamedReservableCurrency, OnUnbalanced, SameOrOther, WithdrawReasons,
+		Currency, ExistenceRequirement, Imbalance, IsSubType, NamedReservableCurrency, OnUnbalanced, SameOrOther,
+		WithdrawReasons,
+	},
+	weights::{
+		extract_actual_weight, DispatchInfo, GetDispatchInfo, Pays, PostDispatchInfo, WeightToFeeCoefficient,
+		WeightToFeePolynomial,
 	},
-	weights::{DispatchInfo, GetDispatchInfo, Pays, PostDispatchInfo, WeightToFeeCoefficient, WeightToFeePolynomial},
 	BoundedVec,
 };
 use frame_system::pallet_prelude::*;
@@ -40,17 +58,22 @@ use orml_traits::MultiCurrency;
 use pallet_transaction_payment_rpc_runtime_api::RuntimeDispatchInfo;
 use pallet_transaction_payment_rpc_runtime_api::{FeeDetails, InclusionFee};
 use primitives::{Balance, CurrencyId, ReserveIdentifier};
+use sp_io::KillStorageResult;
 use sp_runtime::{
 	traits::{
-		Bounded, CheckedSub, Convert, DispatchInfoOf, One, PostDispatchInfoOf, SaturatedConversion, Saturating,
-		SignedExtension, UniqueSaturatedInto, Zero,
+		Bounded, CheckedSub, Convert, DispatchInfoOf, ExtrinsicCall, One, PostDispatchInfoOf, SaturatedConversion,
+		Saturating, SignedExtension, UniqueSaturatedInto, Zero,
 	},
 	transaction_validity::{
 		InvalidTransaction, TransactionPriority, TransactionValidity, TransactionValidityError, ValidTransaction,
 	},
-	FixedPointNumber, FixedPointOperand, FixedU128, Perquintill,
+	FixedPointNumber, FixedPointOperand, FixedU128, Perquintill, TransactionOutcome,
+};
+use sp_std::{
+	convert::{TryFrom, TryInto},
+	prelude::*,
+	vec,
 };
-use sp_std::{convert::TryInto, prelude::*, vec};
 use support::{DEXManager, PriceProvider, Ratio, TransactionPayment};
 
 mod mock;
@@ -66,6 +89,8 @@ pub type Multiplier = FixedU128;
 type PalletBalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 type NegativeImbalanceOf<T> =
 	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::NegativeImbalance;
+/// The pallet name or the function name of a sponsored call.
+pub type CallNameOf<T> = BoundedVec<u8, <T as Config>::MaxCallNameLength>;
 
 /// A struct to update the weight multiplier per block. It implements
 /// `Convert<Multiplier, Multiplier>`, meaning that it can convert the
@@ -212,14 +237,38 @@ where
 	}
 }
 
+/// The fee sponsorship of a sponsor account.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, Default)]
+pub struct SponsorshipInfo<Balance> {
+	/// The remaining budget to pay fees for the sponsored users.
+	pub budget: Balance,
+	/// The max fee of a single sponsored transaction.
+	pub max_fee_per_tx: Balance,
+	/// The max fees sponsored for a single user.
+	pub user_quota: Balance,
+}
+
+/// How the fee of a call is paid, decided by the wrapper of the call.
+struct FeePayment<AccountId> {
+	/// The swap path and the supply amount if the native currency is swapped to pay the fee.
+	swapped: Option<(Vec<CurrencyId>, Balance)>,
+	/// Whether the refund is swapped back to the fee currency specified by the wrapper.
+	refund_in_fee_currency: bool,
+	/// The sponsor paying the fee.
+	sponsor: Option<AccountId>,
+}
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
 
 	pub const RESERVE_ID: ReserveIdentifier = ReserveIdentifier::TransactionPayment;
+	pub const DEPOSIT_RESERVE_ID: ReserveIdentifier = ReserveIdentifier::TransactionPaymentDeposit;
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
 		/// Native currency id, the actual received currency type as fee for
 		/// treasury. Should be ACA
 		#[pallet::constant]
@@ -269,6 +318,20 @@ pub mod module {
 		/// The price source to provider external market price.
 		type PriceSource: PriceProvider<CurrencyId>;
 
+		/// The aggregated call type.
+		type Call: Parameter
+			+ Dispatchable<Origin = Self::Origin, PostInfo = PostDispatchInfo>
+			+ GetDispatchInfo
+			+ GetCallMetadata;
+
+		/// The max length of the pallet name and the function name of the sponsored calls.
+		#[pallet::constant]
+		type MaxCallNameLength: Get<u32>;
+
+		/// The deposit reserved from the sponsor for each sponsored call and user.
+		#[pallet::constant]
+		type SponsoredItemDeposit: Get<PalletBalanceOf<Self>>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -292,6 +355,42 @@ pub mod module {
 	pub enum Error<T> {
 		/// The swap path is invalid
 		InvalidSwapPath,
+		/// There's no route from the fee currency to native currency in DEX
+		NoFeeSwapRoute,
+		/// The sponsorship of the sponsor does not exist
+		SponsorshipNotFound,
+		/// The user is not sponsored by the sponsor
+		NotSponsoredUser,
+		/// The call is not sponsored by the sponsor
+		CallNotSponsored,
+		/// The fee exceeds the max fee per transaction of the sponsorship
+		ExceedMaxSponsoredFee,
+		/// The remaining budget of the sponsorship is not enough
+		InsufficientSponsorBudget,
+		/// The sponsored fees of the user exceed the user quota
+		ExceedUserQuota,
+		/// The pallet name or the function name is longer than MaxCallNameLength
+		CallNameTooLong,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	#[pallet::metadata(T::AccountId = "AccountId", PalletBalanceOf<T> = "Balance")]
+	pub enum Event<T: Config> {
+		/// The sponsorship is updated. \[sponsor, budget, max_fee_per_tx, user_quota\]
+		SponsorshipUpdated(T::AccountId, PalletBalanceOf<T>, PalletBalanceOf<T>, PalletBalanceOf<T>),
+		/// The sponsorship is removed. \[sponsor\]
+		SponsorshipRemoved(T::AccountId),
+		/// The sponsored call is updated. \[sponsor, pallet_name_bytes, function_name_bytes,
+		/// allowed\]
+		SponsoredCallUpdated(T::AccountId, Vec<u8>, Vec<u8>, bool),
+		/// The sponsored user is updated. \[sponsor, user, authorized\]
+		SponsoredUserUpdated(T::AccountId, T::AccountId, bool),
+		/// The fee of the user is paid by the sponsor. \[sponsor, user, fee\]
+		FeeSponsored(T::AccountId, T::AccountId, PalletBalanceOf<T>),
+		/// The remaining budget of the sponsorship is less than the max fee per transaction.
+		/// \[sponsor, remaining_budget\]
+		SponsorBudgetExhausted(T::AccountId, PalletBalanceOf<T>),
 	}
 
 	/// The next fee multiplier.
@@ -307,6 +406,31 @@ pub mod module {
 	pub type AlternativeFeeSwapPath<T: Config> =
 		StorageMap<_, Twox64Concat, T::AccountId, BoundedVec<CurrencyId, T::TradingPathLimit>, OptionQuery>;
 
+	/// The fee sponsorships of sponsors.
+	///
+	/// Sponsorships: map AccountId => Option<SponsorshipInfo>
+	#[pallet::storage]
+	#[pallet::getter(fn sponsorships)]
+	pub type Sponsorships<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, SponsorshipInfo<PalletBalanceOf<T>>, OptionQuery>;
+
+	/// The calls allowed to be sponsored by sponsors, the empty function name allows all calls of
+	/// the pallet.
+	///
+	/// SponsoredCalls: double_map AccountId, (PalletNameBytes, FunctionNameBytes) => Option<()>
+	#[pallet::storage]
+	#[pallet::getter(fn sponsored_calls)]
+	pub type SponsoredCalls<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::AccountId, Twox64Concat, (CallNameOf<T>, CallNameOf<T>), (), OptionQuery>;
+
+	/// The users sponsored by sponsors, and the fees sponsored for them.
+	///
+	/// SponsoredUsers: double_map AccountId, AccountId => Option<Balance>
+	#[pallet::storage]
+	#[pallet::getter(fn sponsored_users)]
+	pub type SponsoredUsers<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::AccountId, Twox64Concat, T::AccountId, PalletBalanceOf<T>, OptionQuery>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
@@ -391,6 +515,230 @@ pub mod module {
 			}
 			Ok(())
 		}
+
+		/// Dispatch the call and pay its fee in the specified currency, which is swapped to native
+		/// currency by the route discovered from DEX when the fee is withdrawn, and the excess fee
+		/// is refunded in the same currency.
+		///
+		/// The dispatch origin of this call must be `Signed`.
+		///
+		/// - `currency_id`: the currency to pay the fee.
+		/// - `call`: the call to dispatch.
+		#[pallet::weight({
+			let dispatch_info = call.get_dispatch_info();
+			(
+				<T as Config>::WeightInfo::with_fee_currency().saturating_add(dispatch_info.weight),
+				dispatch_info.class,
+			)
+		})]
+		pub fn with_fee_currency(
+			origin: OriginFor<T>,
+			_currency_id: CurrencyId,
+			call: Box<<T as Config>::Call>,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin.clone())?;
+			Self::dispatch_wrapped_call(origin, *call, <T as Config>::WeightInfo::with_fee_currency())
+		}
+
+		/// Set the sponsorship of the origin.
+		///
+		/// The dispatch origin of this call must be `Signed`.
+		///
+		/// - `budget`: the remaining budget to pay fees for the sponsored users.
+		/// - `max_fee_per_tx`: the max fee of a single sponsored transaction.
+		/// - `user_quota`: the max fees sponsored for a single user.
+		#[pallet::weight(<T as Config>::WeightInfo::set_sponsorship())]
+		pub fn set_sponsorship(
+			origin: OriginFor<T>,
+			#[pallet::compact] budget: PalletBalanceOf<T>,
+			#[pallet::compact] max_fee_per_tx: PalletBalanceOf<T>,
+			#[pallet::compact] user_quota: PalletBalanceOf<T>,
+		) -> DispatchResult {
+			let sponsor = ensure_signed(origin)?;
+			Sponsorships::<T>::insert(
+				&sponsor,
+				SponsorshipInfo {
+					budget,
+					max_fee_per_tx,
+					user_quota,
+				},
+			);
+			Self::deposit_event(Event::SponsorshipUpdated(sponsor, budget, max_fee_per_tx, user_quota));
+			Ok(())
+		}
+
+		/// Remove the sponsorship of the origin, and its sponsored calls and users. The deposits of
+		/// the removed calls and users are unreserved.
+		///
+		/// The dispatch origin of this call must be `Signed`.
+		///
+		/// - `limit`: the max number of the sponsored calls and users to remove. If some of them
+		///   remain, the sponsorship stops sponsoring and is removed by the later call removing
+		///   the rest.
+		#[pallet::weight(<T as Config>::WeightInfo::remove_sponsorship(*limit))]
+		pub fn remove_sponsorship(origin: OriginFor<T>, limit: u32) -> DispatchResultWithPostInfo {
+			let sponsor = ensure_signed(origin)?;
+			ensure!(
+				Sponsorships::<T>::contains_key(&sponsor),
+				Error::<T>::SponsorshipNotFound
+			);
+
+			let (removed_calls, all_calls_removed) = match SponsoredCalls::<T>::remove_prefix(&sponsor, Some(limit)) {
+				KillStorageResult::AllRemoved(removed) => (removed, true),
+				KillStorageResult::SomeRemaining(removed) => (removed, false),
+			};
+			let (removed_users, all_users_removed) = if all_calls_removed {
+				match SponsoredUsers::<T>::remove_prefix(&sponsor, Some(limit.saturating_sub(removed_calls))) {
+					KillStorageResult::AllRemoved(removed) => (removed, true),
+					KillStorageResult::SomeRemaining(removed) => (removed, false),
+				}
+			} else {
+				(0, false)
+			};
+
+			<T as Config>::Currency::unreserve_named(
+				&DEPOSIT_RESERVE_ID,
+				&sponsor,
+				T::SponsoredItemDeposit::get().saturating_mul(removed_calls.saturating_add(removed_users).into()),
+			);
+
+			if all_calls_removed && all_users_removed {
+				Sponsorships::<T>::remove(&sponsor);
+				Self::deposit_event(Event::SponsorshipRemoved(sponsor));
+			} else {
+				// stop sponsoring until the rest are removed
+				Sponsorships::<T>::mutate(&sponsor, |maybe_sponsorship| {
+					if let Some(sponsorship) = maybe_sponsorship {
+						sponsorship.budget = Zero::zero();
+					}
+				});
+			}
+			Ok(Some(<T as Config>::WeightInfo::remove_sponsorship(
+				removed_calls.saturating_add(removed_users),
+			))
+			.into())
+		}
+
+		/// Allow or disallow the call to be sponsored by the origin. `SponsoredItemDeposit` is
+		/// reserved for an allowed call, and unreserved when it's disallowed.
+		///
+		/// The dispatch origin of this call must be `Signed`.
+		///
+		/// - `pallet_name`: the pallet name of the call.
+		/// - `function_name`: the function name of the call, empty means all calls of the pallet.
+		/// - `allowed`: whether the call is allowed.
+		#[pallet::weight(<T as Config>::WeightInfo::set_sponsored_call())]
+		pub fn set_sponsored_call(
+			origin: OriginFor<T>,
+			pallet_name: Vec<u8>,
+			function_name: Vec<u8>,
+			allowed: bool,
+		) -> DispatchResult {
+			let sponsor = ensure_signed(origin)?;
+			ensure!(
+				Sponsorships::<T>::contains_key(&sponsor),
+				Error::<T>::SponsorshipNotFound
+			);
+			let call_name: (CallNameOf<T>, CallNameOf<T>) = (
+				pallet_name
+					.clone()
+					.try_into()
+					.map_err(|_| Error::<T>::CallNameTooLong)?,
+				function_name
+					.clone()
+					.try_into()
+					.map_err(|_| Error::<T>::CallNameTooLong)?,
+			);
+			let exists = SponsoredCalls::<T>::contains_key(&sponsor, &call_name);
+			if allowed && !exists {
+				<T as Config>::Currency::reserve_named(&DEPOSIT_RESERVE_ID, &sponsor, T::SponsoredItemDeposit::get())?;
+				SponsoredCalls::<T>::insert(&sponsor, &call_name, ());
+			} else if !allowed && exists {
+				SponsoredCalls::<T>::remove(&sponsor, &call_name);
+				<T as Config>::Currency::unreserve_named(&DEPOSIT_RESERVE_ID, &sponsor, T::SponsoredItemDeposit::get());
+			}
+			Self::deposit_event(Event::SponsoredCallUpdated(
+				sponsor,
+				pallet_name,
+				function_name,
+				allowed,
+			));
+			Ok(())
+		}
+
+		/// Authorize or deauthorize the user to be sponsored by the origin. `SponsoredItemDeposit`
+		/// is reserved for an authorized user, and unreserved when it's deauthorized.
+		///
+		/// The dispatch origin of this call must be `Signed`.
+		///
+		/// - `user`: the sponsored user.
+		/// - `authorized`: whether the user is authorized.
+		#[pallet::weight(<T as Config>::WeightInfo::set_sponsored_user())]
+		pub fn set_sponsored_user(origin: OriginFor<T>, user: T::AccountId, authorized: bool) -> DispatchResult {
+			let sponsor = ensure_signed(origin)?;
+			ensure!(
+				Sponsorships::<T>::contains_key(&sponsor),
+				Error::<T>::SponsorshipNotFound
+			);
+			// keep the sponsored fees if the user is authorized already
+			let exists = SponsoredUsers::<T>::contains_key(&sponsor, &user);
+			if authorized && !exists {
+				<T as Config>::Currency::reserve_named(&DEPOSIT_RESERVE_ID, &sponsor, T::SponsoredItemDeposit::get())?;
+				SponsoredUsers::<T>::insert(&sponsor, &user, PalletBalanceOf::<T>::zero());
+			} else if !authorized && exists {
+				SponsoredUsers::<T>::remove(&sponsor, &user);
+				<T as Config>::Currency::unreserve_named(&DEPOSIT_RESERVE_ID, &sponsor, T::SponsoredItemDeposit::get());
+			}
+			Self::deposit_event(Event::SponsoredUserUpdated(sponsor, user, authorized));
+			Ok(())
+		}
+
+		/// Dispatch the call and charge its fee to the sponsor, the call and the origin must be
+		/// allowed by the sponsorship of the sponsor.
+		///
+		/// The dispatch origin of this call must be `Signed`.
+		///
+		/// - `sponsor`: the sponsor to pay the fee.
+		/// - `call`: the call to dispatch.
+		#[pallet::weight({
+			let dispatch_info = call.get_dispatch_info();
+			(
+				<T as Config>::WeightInfo::with_sponsor().saturating_add(dispatch_info.weight),
+				dispatch_info.class,
+			)
+		})]
+		pub fn with_sponsor(
+			origin: OriginFor<T>,
+			_sponsor: T::AccountId,
+			call: Box<<T as Config>::Call>,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin.clone())?;
+			Self::dispatch_wrapped_call(origin, *call, <T as Config>::WeightInfo::with_sponsor())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Dispatch the call wrapped by the extrinsic of this module, the actual weight is the actual
+	/// weight of the call plus the weight of the wrapper.
+	fn dispatch_wrapped_call(
+		origin: OriginFor<T>,
+		call: <T as Config>::Call,
+		wrapper_weight: Weight,
+	) -> DispatchResultWithPostInfo {
+		let info = call.get_dispatch_info();
+		let result = call.dispatch(origin);
+		let post_info = PostDispatchInfo {
+			actual_weight: Some(extract_actual_weight(&result, &info).saturating_add(wrapper_weight)),
+			pays_fee: match &result {
+				Ok(post_info) => post_info.pays_fee(&info),
+				Err(err) => err.post_info.pays_fee(&info),
+			},
+		};
+		result.map(|_| post_info).map_err(|err| DispatchErrorWithPostInfo {
+			post_info,
+			error: err.error,
+		})
 	}
 }
 
@@ -440,20 +788,74 @@ where
 		len: u32,
 	) -> FeeDetails<PalletBalanceOf<T>>
 	where
-		T::Call: Dispatchable<Info = DispatchInfo>,
+		<T as frame_system::Config>::Call: Dispatchable<Info = DispatchInfo>,
 	{
 		let dispatch_info = <Extrinsic as GetDispatchInfo>::get_dispatch_info(&unchecked_extrinsic);
 		Self::compute_fee_details(len, &dispatch_info, 0u32.into())
 	}
 
+	/// Estimate the fee of a given `call` charged to the account, returns the native fee, the fee
+	/// swap path and the supply amount if the native currency is not enough, and whether the fee
+	/// can be charged.
+	///
+	/// The swap and the withdrawal are dry run and will be reverted. The call wrapped by
+	/// `with_fee_currency` or `with_sponsor` is estimated like it's charged.
+	pub fn query_fee_estimation<Extrinsic>(
+		who: T::AccountId,
+		unchecked_extrinsic: Extrinsic,
+		len: u32,
+	) -> (PalletBalanceOf<T>, Option<Vec<CurrencyId>>, Option<Balance>, bool)
+	where
+		Extrinsic: GetDispatchInfo + ExtrinsicCall<Call = <T as frame_system::Config>::Call>,
+		<T as frame_system::Config>::Call: Dispatchable<Info = DispatchInfo> + IsSubType<Call<T>>,
+	{
+		let dispatch_info = <Extrinsic as GetDispatchInfo>::get_dispatch_info(&unchecked_extrinsic);
+		let fee = Self::compute_fee(len, &dispatch_info, 0u32.into());
+		if fee.is_zero() {
+			return (fee, None, None, true);
+		}
+
+		// the amount of native currency to swap if the native currency is not enough
+		let native_existential_deposit = <T as Config>::Currency::minimum_balance();
+		let swap_amount =
+			fee.saturating_add(native_existential_deposit.saturating_sub(<T as Config>::Currency::total_balance(&who)));
+
+		with_transaction(|| {
+			let reason = WithdrawReasons::TRANSACTION_PAYMENT;
+			let (swapped, sponsor) = match Self::prepare_fee_payment(&who, unchecked_extrinsic.call(), fee, reason) {
+				Ok(FeePayment { swapped, sponsor, .. }) => (swapped, sponsor),
+				Err(_) => return TransactionOutcome::Rollback((fee, None, None, false)),
+			};
+			let payer = sponsor.as_ref().unwrap_or(&who);
+			let can_charge_fee =
+				<T as Config>::Currency::withdraw(payer, fee, reason, ExistenceRequirement::KeepAlive).is_ok();
+
+			let (fee_swap_path, supply_amount) = match swapped {
+				Some((fee_swap_path, supply_amount)) => (Some(fee_swap_path), Some(supply_amount)),
+				// report the configured fee swap path if the fee cannot be charged
+				None if !can_charge_fee && sponsor.is_none() => match AlternativeFeeSwapPath::<T>::get(&who) {
+					Some(fee_swap_path) => {
+						let supply_amount =
+							T::DEX::get_swap_supply_amount(&fee_swap_path, swap_amount.unique_saturated_into());
+						(Some(fee_swap_path.into_inner()), supply_amount)
+					}
+					None => (None, None),
+				},
+				None => (None, None),
+			};
+
+			TransactionOutcome::Rollback((fee, fee_swap_path, supply_amount, can_charge_fee))
+		})
+	}
+
 	/// Compute the fee details for a particular transaction.
 	pub fn compute_fee_details(
 		len: u32,
-		info: &DispatchInfoOf<T::Call>,
+		info: &DispatchInfoOf<<T as frame_system::Config>::Call>,
 		tip: PalletBalanceOf<T>,
 	) -> FeeDetails<PalletBalanceOf<T>>
 	where
-		T::Call: Dispatchable<Info = DispatchInfo>,
+		<T as frame_system::Config>::Call: Dispatchable<Info = DispatchInfo>,
 	{
 		Self::compute_fee_raw(len, info.weight, tip, info.pays_fee, info.class)
 	}
@@ -495,12 +897,12 @@ where
 	/// transaction.
 	pub fn compute_actual_fee_details(
 		len: u32,
-		info: &DispatchInfoOf<T::Call>,
-		post_info: &PostDispatchInfoOf<T::Call>,
+		info: &DispatchInfoOf<<T as frame_system::Config>::Call>,
+		post_info: &PostDispatchInfoOf<<T as frame_system::Config>::Call>,
 		tip: PalletBalanceOf<T>,
 	) -> FeeDetails<PalletBalanceOf<T>>
 	where
-		T::Call: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+		<T as frame_system::Config>::Call: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
 	{
 		Self::compute_fee_raw(
 			len,
@@ -571,7 +973,218 @@ where
 		T::WeightToFee::calc(&capped_weight)
 	}
 
-	pub fn ensure_can_charge_fee(who: &T::AccountId, fee: PalletBalanceOf<T>, reason: WithdrawReasons) {
+	/// Calculate the supply limit according to oracle price and the slippage limit when swap the
+	/// supply currency to the amount of native currency, if oracle price is not avalible, do not
+	/// limit.
+	fn max_supply_limit(supply_currency_id: CurrencyId, target_amount: PalletBalanceOf<T>) -> PalletBalanceOf<T> {
+		if let Some(target_price) = T::PriceSource::get_relative_price(T::NativeCurrencyId::get(), supply_currency_id) {
+			Ratio::one()
+				.saturating_sub(T::MaxSwapSlippageCompareToOracle::get())
+				.reciprocal()
+				.unwrap_or_else(Ratio::max_value)
+				.saturating_mul_int(target_price.saturating_mul_int(target_amount))
+		} else {
+			PalletBalanceOf::<T>::max_value()
+		}
+	}
+
+	/// Discover the route from the currency to native currency which requires the least supply
+	/// amount in DEX: the direct path, or the path through the default fee swap paths. Returns the
+	/// path and the supply amount.
+	pub fn get_fee_swap_route(
+		currency_id: CurrencyId,
+		target_amount: PalletBalanceOf<T>,
+	) -> Option<(Vec<CurrencyId>, PalletBalanceOf<T>)> {
+		let native_currency_id = T::NativeCurrencyId::get();
+		let mut candidate_paths = vec![vec![currency_id, native_currency_id]];
+		for path in T::DefaultFeeSwapPathList::get() {
+			if path.first() == Some(&currency_id) {
+				candidate_paths.push(path);
+			} else if !path.contains(&currency_id) {
+				candidate_paths.push(vec![vec![currency_id], path].concat());
+			}
+		}
+
+		candidate_paths
+			.into_iter()
+			.filter(|path| path.len() as u32 <= T::TradingPathLimit::get() && path.last() == Some(&native_currency_id))
+			.filter_map(|path| {
+				T::DEX::get_swap_supply_amount(&path, target_amount.unique_saturated_into())
+					.map(|supply_amount| (path, supply_amount.unique_saturated_into()))
+			})
+			.min_by_key(|(_, supply_amount)| *supply_amount)
+	}
+
+	/// Swap the fee currency to native currency by the route discovered from DEX to pay the fee
+	/// and keep the account alive, returns the swap path and the supply amount.
+	pub fn swap_to_pay_fee(
+		who: &T::AccountId,
+		currency_id: CurrencyId,
+		fee: PalletBalanceOf<T>,
+	) -> Result<(Vec<CurrencyId>, Balance), DispatchError> {
+		let native_existential_deposit = <T as Config>::Currency::minimum_balance();
+		let total_native = <T as Config>::Currency::total_balance(who);
+		let amount = fee.saturating_add(native_existential_deposit.saturating_sub(total_native));
+
+		let (path, _) = Self::get_fee_swap_route(currency_id, amount).ok_or(Error::<T>::NoFeeSwapRoute)?;
+		let max_supply_amount = <T as Config>::MultiCurrency::free_balance(currency_id, who)
+			.min(Self::max_supply_limit(currency_id, amount).unique_saturated_into());
+		let supply_amount =
+			T::DEX::swap_with_exact_target(who, &path, amount.unique_saturated_into(), max_supply_amount)?;
+		Ok((path, supply_amount))
+	}
+
+	/// Prepare to pay the fee of the call in native currency according to the wrapper of the call:
+	/// swap the specified fee currency, check the sponsorship, or swap the fee swap paths of the
+	/// account if the native currency is not enough.
+	fn prepare_fee_payment(
+		who: &T::AccountId,
+		call: &<T as frame_system::Config>::Call,
+		fee: PalletBalanceOf<T>,
+		reason: WithdrawReasons,
+	) -> Result<FeePayment<T::AccountId>, DispatchError>
+	where
+		<T as frame_system::Config>::Call: IsSubType<Call<T>>,
+	{
+		match call.is_sub_type() {
+			// swap the specified fee currency to pay the fee
+			Some(Call::with_fee_currency(currency_id, _)) if *currency_id != T::NativeCurrencyId::get() => {
+				Ok(FeePayment {
+					swapped: Some(Self::swap_to_pay_fee(who, *currency_id, fee)?),
+					refund_in_fee_currency: true,
+					sponsor: None,
+				})
+			}
+			// the sponsor pays the fee in native currency
+			Some(Call::with_sponsor(sponsor, sponsored_call)) => {
+				Self::ensure_can_sponsor(sponsor, who, sponsored_call, fee)?;
+				Ok(FeePayment {
+					swapped: None,
+					refund_in_fee_currency: false,
+					sponsor: Some(sponsor.clone()),
+				})
+			}
+			_ => Ok(FeePayment {
+				swapped: Self::ensure_can_charge_fee(who, fee, reason),
+				refund_in_fee_currency: false,
+				sponsor: None,
+			}),
+		}
+	}
+
+	/// Swap the refunded native currency back to the fee currency by the reverse swap path. The
+	/// refund is kept in native currency if the swap fails.
+	fn swap_refund(who: &T::AccountId, fee_swap_path: &[CurrencyId], refund: PalletBalanceOf<T>) {
+		let currency_id = match fee_swap_path.first() {
+			Some(currency_id) => *currency_id,
+			None => return,
+		};
+		// calculate the target limit according to oracle price and the slippage limit,
+		// if oracle price is not avalible, do not limit
+		let min_target_amount = T::PriceSource::get_relative_price(T::NativeCurrencyId::get(), currency_id)
+			.map(|target_price| {
+				Ratio::one()
+					.saturating_sub(T::MaxSwapSlippageCompareToOracle::get())
+					.saturating_mul_int(target_price.saturating_mul_int(refund))
+			})
+			.unwrap_or_else(Zero::zero);
+		let reverse_path: Vec<CurrencyId> = fee_swap_path.iter().rev().copied().collect();
+		let _ = T::DEX::swap_with_exact_supply(
+			who,
+			&reverse_path,
+			refund.unique_saturated_into(),
+			min_target_amount.unique_saturated_into(),
+		);
+	}
+
+	/// Check the sponsorship of the sponsor can pay the fee of the call for the user.
+	pub fn ensure_can_sponsor(
+		sponsor: &T::AccountId,
+		user: &T::AccountId,
+		call: &<T as Config>::Call,
+		fee: PalletBalanceOf<T>,
+	) -> DispatchResult {
+		let sponsorship = Self::sponsorships(sponsor).ok_or(Error::<T>::SponsorshipNotFound)?;
+		let sponsored_fee = Self::sponsored_users(sponsor, user).ok_or(Error::<T>::NotSponsoredUser)?;
+
+		let CallMetadata {
+			function_name,
+			pallet_name,
+		} = call.get_call_metadata();
+		// the names longer than MaxCallNameLength can't be sponsored
+		let is_sponsored = |function_name: &[u8]| -> bool {
+			match (
+				CallNameOf::<T>::try_from(pallet_name.as_bytes().to_vec()),
+				CallNameOf::<T>::try_from(function_name.to_vec()),
+			) {
+				(Ok(pallet_name), Ok(function_name)) => {
+					SponsoredCalls::<T>::contains_key(sponsor, (pallet_name, function_name))
+				}
+				_ => false,
+			}
+		};
+		ensure!(
+			is_sponsored(function_name.as_bytes()) || is_sponsored(b""),
+			Error::<T>::CallNotSponsored
+		);
+
+		ensure!(fee <= sponsorship.max_fee_per_tx, Error::<T>::ExceedMaxSponsoredFee);
+		ensure!(fee <= sponsorship.budget, Error::<T>::InsufficientSponsorBudget);
+		ensure!(
+			sponsored_fee.saturating_add(fee) <= sponsorship.user_quota,
+			Error::<T>::ExceedUserQuota
+		);
+		Ok(())
+	}
+
+	/// Spend the budget of the sponsorship and the quota of the user for the fee.
+	fn spend_sponsorship(sponsor: &T::AccountId, user: &T::AccountId, fee: PalletBalanceOf<T>) {
+		Sponsorships::<T>::mutate(sponsor, |maybe_sponsorship| {
+			if let Some(sponsorship) = maybe_sponsorship {
+				sponsorship.budget = sponsorship.budget.saturating_sub(fee);
+			}
+		});
+		SponsoredUsers::<T>::mutate(sponsor, user, |maybe_sponsored_fee| {
+			if let Some(sponsored_fee) = maybe_sponsored_fee {
+				*sponsored_fee = sponsored_fee.saturating_add(fee);
+			}
+		});
+	}
+
+	/// Return the refund to the budget of the sponsorship and the quota of the user after the
+	/// sponsored transaction is dispatched.
+	fn settle_sponsorship(
+		sponsor: &T::AccountId,
+		user: &T::AccountId,
+		fee: PalletBalanceOf<T>,
+		actual_fee: PalletBalanceOf<T>,
+	) {
+		let refund = fee.saturating_sub(actual_fee);
+		if let Some(sponsorship) = Sponsorships::<T>::mutate(sponsor, |maybe_sponsorship| {
+			if let Some(sponsorship) = maybe_sponsorship {
+				sponsorship.budget = sponsorship.budget.saturating_add(refund);
+			}
+			*maybe_sponsorship
+		}) {
+			if sponsorship.budget < sponsorship.max_fee_per_tx {
+				Self::deposit_event(Event::SponsorBudgetExhausted(sponsor.clone(), sponsorship.budget));
+			}
+		}
+		SponsoredUsers::<T>::mutate(sponsor, user, |maybe_sponsored_fee| {
+			if let Some(sponsored_fee) = maybe_sponsored_fee {
+				*sponsored_fee = sponsored_fee.saturating_sub(refund);
+			}
+		});
+		Self::deposit_event(Event::FeeSponsored(sponsor.clone(), user.clone(), actual_fee));
+	}
+
+	/// Swap the fee swap paths of the account to native currency if the native currency is not
+	/// enough to pay the fee, returns the swap path and the supply amount if swapped.
+	pub fn ensure_can_charge_fee(
+		who: &T::AccountId,
+		fee: PalletBalanceOf<T>,
+		reason: WithdrawReasons,
+	) -> Option<(Vec<CurrencyId>, Balance)> {
 		let native_existential_deposit = <T as Config>::Currency::minimum_balance();
 		let total_native = <T as Config>::Currency::total_balance(who);
 
@@ -600,37 +1213,25 @@ where
 				match trading_path.last() {
 					Some(target_currency_id) if *target_currency_id == native_currency_id => {
 						let supply_currency_id = *trading_path.first().expect("these's first guaranteed by match");
-						// calculate the supply limit according to oracle price and the slippage limit,
-						// if oracle price is not avalible, do not limit
-						let max_supply_limit = if let Some(target_price) =
-							T::PriceSource::get_relative_price(*target_currency_id, supply_currency_id)
-						{
-							Ratio::one()
-								.saturating_sub(T::MaxSwapSlippageCompareToOracle::get())
-								.reciprocal()
-								.unwrap_or_else(Ratio::max_value)
-								.saturating_mul_int(target_price.saturating_mul_int(amount))
-						} else {
-							PalletBalanceOf::<T>::max_value()
-						};
-
-						if T::DEX::swap_with_exact_target(
+						let max_supply_limit = Self::max_supply_limit(supply_currency_id, amount);
+
+						if let Ok(supply_amount) = T::DEX::swap_with_exact_target(
 							who,
 							&trading_path,
 							amount.unique_saturated_into(),
 							<T as Config>::MultiCurrency::free_balance(supply_currency_id, who)
 								.min(max_supply_limit.unique_saturated_into()),
-						)
-						.is_ok()
-						{
+						) {
 							// successfully swap, break iteration
-							break;
+							return Some((trading_path, supply_amount));
 						}
 					}
 					_ => {}
 				}
 			}
 		}
+
+		None
 	}
 }
 
@@ -668,7 +1269,8 @@ impl<T: Config + Send + Sync> sp_std::fmt::Debug for ChargeTransactionPayment<T>
 
 impl<T: Config + Send + Sync> ChargeTransactionPayment<T>
 where
-	<T as frame_system::Config>::Call: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+	<T as frame_system::Config>::Call:
+		Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo> + IsSubType<Call<T>>,
 	PalletBalanceOf<T>: Send + Sync + FixedPointOperand,
 {
 	/// utility constructor. Used only in client/factory code.
@@ -676,19 +1278,28 @@ where
 		Self(fee)
 	}
 
+	#[allow(clippy::type_complexity)]
 	fn withdraw_fee(
 		&self,
 		who: &T::AccountId,
-		_call: &<T as frame_system::Config>::Call,
+		call: &<T as frame_system::Config>::Call,
 		info: &DispatchInfoOf<<T as frame_system::Config>::Call>,
 		len: usize,
-	) -> Result<(PalletBalanceOf<T>, Option<NegativeImbalanceOf<T>>), TransactionValidityError> {
+	) -> Result<
+		(
+			PalletBalanceOf<T>,
+			Option<NegativeImbalanceOf<T>>,
+			Option<Vec<CurrencyId>>,
+			Option<T::AccountId>,
+		),
+		TransactionValidityError,
+	> {
 		let tip = self.0;
 		let fee = Pallet::<T>::compute_fee(len as u32, info, tip);
 
 		// Only mess with balances if fee is not zero.
 		if fee.is_zero() {
-			return Ok((fee, None));
+			return Ok((fee, None, None, None));
 		}
 
 		let reason = if tip.is_zero() {
@@ -697,11 +1308,25 @@ where
 			WithdrawReasons::TRANSACTION_PAYMENT | WithdrawReasons::TIP
 		};
 
-		Pallet::<T>::ensure_can_charge_fee(who, fee, reason);
+		let FeePayment {
+			swapped,
+			refund_in_fee_currency,
+			sponsor,
+		} = Pallet::<T>::prepare_fee_payment(who, call, fee, reason).map_err(|_| InvalidTransaction::Payment)?;
+		// only the refund of the fee currency specified by the wrapper is swapped back
+		let fee_swap_path = swapped
+			.filter(|_| refund_in_fee_currency)
+			.map(|(fee_swap_path, _)| fee_swap_path);
+		let payer = sponsor.as_ref().unwrap_or(who);
 
 		// withdraw native currency as fee
-		match <T as Config>::Currency::withdraw(who, fee, reason, ExistenceRequirement::KeepAlive) {
-			Ok(imbalance) => Ok((fee, Some(imbalance))),
+		match <T as Config>::Currency::withdraw(payer, fee, reason, ExistenceRequirement::KeepAlive) {
+			Ok(imbalance) => {
+				if let Some(sponsor) = &sponsor {
+					Pallet::<T>::spend_sponsorship(sponsor, who, fee);
+				}
+				Ok((fee, Some(imbalance), fee_swap_path, sponsor))
+			}
 			Err(_) => Err(InvalidTransaction::Payment.into()),
 		}
 	}
@@ -738,7 +1363,8 @@ where
 impl<T: Config + Send + Sync> SignedExtension for ChargeTransactionPayment<T>
 where
 	PalletBalanceOf<T>: Send + Sync + From<u64> + FixedPointOperand,
-	<T as frame_system::Config>::Call: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+	<T as frame_system::Config>::Call:
+		Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo> + IsSubType<Call<T>>,
 {
 	const IDENTIFIER: &'static str = "ChargeTransactionPayment";
 	type AccountId = T::AccountId;
@@ -749,6 +1375,8 @@ where
 		Self::AccountId,
 		Option<NegativeImbalanceOf<T>>,
 		PalletBalanceOf<T>,
+		Option<Vec<CurrencyId>>,
+		Option<Self::AccountId>,
 	);
 
 	fn additional_signed(&self) -> sp_std::result::Result<(), TransactionValidityError> {
@@ -762,7 +1390,7 @@ where
 		info: &DispatchInfoOf<Self::Call>,
 		len: usize,
 	) -> TransactionValidity {
-		let (fee, _) = self.withdraw_fee(who, call, info, len)?;
+		let (fee, _, _, _) = self.withdraw_fee(who, call, info, len)?;
 		Ok(ValidTransaction {
 			priority: Self::get_priority(len, info, fee),
 			..Default::default()
@@ -776,8 +1404,12 @@ where
 		info: &DispatchInfoOf<Self::Call>,
 		len: usize,
 	) -> Result<Self::Pre, TransactionValidityError> {
-		let (fee, imbalance) = self.withdraw_fee(who, call, info, len)?;
-		Ok((self.0, who.clone(), imbalance, fee))
+		let (fee, imbalance, fee_swap_path, sponsor) = self.withdraw_fee(who, call, info, len)?;
+		// the sponsor is the payer of the sponsored transaction
+		match sponsor {
+			Some(sponsor) => Ok((self.0, sponsor, imbalance, fee, fee_swap_path, Some(who.clone()))),
+			None => Ok((self.0, who.clone(), imbalance, fee, fee_swap_path, None)),
+		}
 	}
 
 	fn post_dispatch(
@@ -787,12 +1419,19 @@ where
 		len: usize,
 		_result: &DispatchResult,
 	) -> Result<(), TransactionValidityError> {
-		let (tip, who, imbalance, fee) = pre;
+		let (tip, who, imbalance, fee, fee_swap_path, sponsored_user) = pre;
 		if let Some(payed) = imbalance {
 			let actual_fee = Pallet::<T>::compute_actual_fee(len as u32, info, post_info, tip);
 			let refund = fee.saturating_sub(actual_fee);
 			let actual_payment = match <T as Config>::Currency::deposit_into_existing(&who, refund) {
 				Ok(refund_imbalance) => {
+					// refund in the fee currency
+					if let Some(fee_swap_path) = fee_swap_path {
+						if !refund.is_zero() {
+							Pallet::<T>::swap_refund(&who, &fee_swap_path, refund);
+						}
+					}
+
 					// The refund cannot be larger than the up front payed max weight.
 					// `PostDispatchInfo::calc_unspent` guards against such a case.
 					match payed.offset(refund_imbalance) {
@@ -805,6 +1444,11 @@ where
 				// is gone in that case.
 				Err(_) => payed,
 			};
+
+			if let Some(user) = sponsored_user {
+				Pallet::<T>::settle_sponsorship(&who, &user, fee, actual_payment.peek());
+			}
+
 			let (tip, fee) = actual_payment.split(tip);
 
 			// distribute fee
@@ -141,6 +141,7 @@ impl module_currencies::Config for Runtime {
 	type EVMBridge = ();
 	type SweepOrigin = EnsureSignedBy<Zero, AccountId>;
 	type OnDust = ();
+	type CurrencyHook = ();
 }
 
 thread_local! {
@@ -216,7 +217,13 @@ impl PriceProvider<CurrencyId> for MockPriceSource {
 	}
 }
 
+parameter_types! {
+	pub const MaxCallNameLength: u32 = 32;
+	pub static SponsoredItemDeposit: Balance = 0;
+}
+
 impl Config for Runtime {
+	type Event = Event;
 	type NativeCurrencyId = GetNativeCurrencyId;
 	type DefaultFeeSwapPathList = DefaultFeeSwapPathList;
 	type Currency = PalletBalances;
@@ -229,6 +236,9 @@ impl Config for Runtime {
 	type MaxSwapSlippageCompareToOracle = MaxSwapSlippageCompareToOracle;
 	type TradingPathLimit = TradingPathLimit;
 	type PriceSource = MockPriceSource;
+	type Call = Call;
+	type MaxCallNameLength = MaxCallNameLength;
+	type SponsoredItemDeposit = SponsoredItemDeposit;
 	type WeightInfo = ();
 }
 
@@ -260,7 +270,7 @@ construct_runtime!(
 		UncheckedExtrinsic = UncheckedExtrinsic
 	{
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
-		TransactionPayment: transaction_payment::{Pallet, Call, Storage},
+		TransactionPayment: transaction_payment::{Pallet, Call, Storage, Event<T>},
 		PalletBalances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 		Tokens: orml_tokens::{Pallet, Storage, Event<T>, Config<T>},
 		Currencies: module_currencies::{Pallet, Call, Event<T>},
@@ -26,10 +26,12 @@ use frame_support::{
 	weights::{DispatchClass, DispatchInfo, Pays},
 };
 use mock::{
-	AccountId, BlockWeights, Call, Currencies, DEXModule, ExtBuilder, MockPriceSource, Origin, Runtime,
-	TransactionPayment, ACA, ALICE, AUSD, BOB, CHARLIE, DOT, FEE_UNBALANCED_AMOUNT, TIP_UNBALANCED_AMOUNT,
+	AccountId, BlockWeights, Call, Currencies, DEXModule, Event, ExtBuilder, MockPriceSource, Origin, PalletBalances,
+	Runtime, SponsoredItemDeposit, System, TransactionPayment, ACA, ALICE, AUSD, BOB, CHARLIE, DOT,
+	FEE_UNBALANCED_AMOUNT, TIP_UNBALANCED_AMOUNT,
 };
 use orml_traits::MultiCurrency;
+use primitives::TokenSymbol;
 use sp_runtime::{testing::TestXt, traits::One};
 use support::Price;
 
@@ -39,6 +41,8 @@ const CALL: &<Runtime as frame_system::Config>::Call =
 const CALL2: &<Runtime as frame_system::Config>::Call =
 	&Call::Currencies(module_currencies::Call::transfer_native_currency(BOB, 12));
 
+const LDOT: CurrencyId = CurrencyId::Token(TokenSymbol::LDOT);
+
 const INFO: DispatchInfo = DispatchInfo {
 	weight: 1000,
 	class: DispatchClass::Normal,
@@ -378,6 +382,392 @@ fn charge_fee_by_default_swap_path() {
 		});
 }
 
+#[test]
+fn charge_fee_in_specified_currency_by_auto_route() {
+	ExtBuilder::default()
+		.one_hundred_thousand_for_alice_n_charlie()
+		.build()
+		.execute_with(|| {
+			// add liquidity to DEX
+			assert_ok!(DEXModule::add_liquidity(
+				Origin::signed(ALICE),
+				ACA,
+				AUSD,
+				10000,
+				1000,
+				0,
+				false
+			));
+			assert_ok!(DEXModule::add_liquidity(
+				Origin::signed(ALICE),
+				DOT,
+				AUSD,
+				100,
+				1000,
+				0,
+				false
+			));
+			assert_ok!(<Currencies as MultiCurrency<_>>::transfer(DOT, &ALICE, &BOB, 100));
+			assert_eq!(Currencies::free_balance(ACA, &BOB), 0);
+			assert_eq!(Currencies::free_balance(DOT, &BOB), 100);
+
+			MockPriceSource::set_relative_price(None);
+
+			// there's no DOT/ACA pool, route by the default fee swap path
+			assert_eq!(
+				TransactionPayment::get_fee_swap_route(DOT, 2010),
+				Some((vec![DOT, AUSD, ACA], 34))
+			);
+			assert_eq!(TransactionPayment::get_fee_swap_route(LDOT, 2010), None);
+
+			// fee currency without route to native currency
+			let call = Call::TransactionPayment(crate::Call::with_fee_currency(LDOT, Box::new(CALL2.clone())));
+			assert!(ChargeTransactionPayment::<Runtime>::from(0)
+				.pre_dispatch(&BOB, &call, &INFO, 500)
+				.is_err());
+
+			let call = Call::TransactionPayment(crate::Call::with_fee_currency(DOT, Box::new(CALL2.clone())));
+			let fee = 500 * 2 + 1000; // len * byte + weight
+			let pre = ChargeTransactionPayment::<Runtime>::from(0)
+				.pre_dispatch(&BOB, &call, &INFO, 500)
+				.unwrap();
+			assert_eq!(Currencies::free_balance(ACA, &BOB), Currencies::minimum_balance(ACA));
+			assert_eq!(Currencies::free_balance(DOT, &BOB), 100 - 34);
+			assert_eq!(FEE_UNBALANCED_AMOUNT.with(|a| *a.borrow()), 0);
+
+			// refund is swapped back to DOT
+			let refund = 200; // 1000 - 800
+			let refund_dot = DEXModule::get_swap_target_amount(&[ACA, AUSD, DOT], refund).unwrap();
+			assert!(refund_dot > 0);
+			assert_ok!(ChargeTransactionPayment::<Runtime>::post_dispatch(
+				pre,
+				&INFO,
+				&POST_INFO,
+				500,
+				&Ok(())
+			));
+			assert_eq!(Currencies::free_balance(ACA, &BOB), Currencies::minimum_balance(ACA));
+			assert_eq!(Currencies::free_balance(DOT, &BOB), 100 - 34 + refund_dot);
+			assert_eq!(FEE_UNBALANCED_AMOUNT.with(|a| *a.borrow()), fee - refund);
+		});
+}
+
+#[test]
+fn with_fee_currency_work() {
+	ExtBuilder::default()
+		.one_hundred_thousand_for_alice_n_charlie()
+		.build()
+		.execute_with(|| {
+			let wrapper_weight = <() as WeightInfo>::with_fee_currency();
+			let call_weight = CALL2.get_dispatch_info().weight;
+			let call = Call::TransactionPayment(crate::Call::with_fee_currency(DOT, Box::new(CALL2.clone())));
+			assert_eq!(call.get_dispatch_info().weight, wrapper_weight + call_weight);
+
+			// the actual weight of the wrapped call plus the wrapper
+			assert_eq!(
+				call.dispatch(Origin::signed(ALICE)),
+				Ok(PostDispatchInfo {
+					actual_weight: Some(wrapper_weight + call_weight),
+					pays_fee: Pays::Yes,
+				})
+			);
+			assert_eq!(Currencies::free_balance(ACA, &BOB), 12);
+
+			// the failed call keeps the actual weight
+			let failed_call = Call::TransactionPayment(crate::Call::with_fee_currency(
+				DOT,
+				Box::new(Call::Currencies(module_currencies::Call::transfer_native_currency(
+					BOB, 1_000_000,
+				))),
+			));
+			assert_eq!(
+				failed_call.dispatch(Origin::signed(ALICE)),
+				Err(DispatchErrorWithPostInfo {
+					post_info: PostDispatchInfo {
+						actual_weight: Some(wrapper_weight + call_weight),
+						pays_fee: Pays::Yes,
+					},
+					error: pallet_balances::Error::<Runtime>::InsufficientBalance.into(),
+				})
+			);
+		});
+}
+
+fn call_name(name: &[u8]) -> CallNameOf<Runtime> {
+	name.to_vec().try_into().unwrap()
+}
+
+#[test]
+fn set_sponsorship_work() {
+	ExtBuilder::default()
+		.one_hundred_thousand_for_alice_n_charlie()
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+			SponsoredItemDeposit::set(100);
+
+			assert_noop!(
+				TransactionPayment::set_sponsored_call(
+					Origin::signed(ALICE),
+					b"Currencies".to_vec(),
+					b"transfer_native_currency".to_vec(),
+					true
+				),
+				Error::<Runtime>::SponsorshipNotFound
+			);
+			assert_noop!(
+				TransactionPayment::set_sponsored_user(Origin::signed(ALICE), BOB, true),
+				Error::<Runtime>::SponsorshipNotFound
+			);
+			assert_noop!(
+				TransactionPayment::remove_sponsorship(Origin::signed(ALICE), 10),
+				Error::<Runtime>::SponsorshipNotFound
+			);
+
+			assert_ok!(TransactionPayment::set_sponsorship(
+				Origin::signed(ALICE),
+				5000,
+				3000,
+				2500
+			));
+			System::assert_last_event(Event::TransactionPayment(crate::Event::SponsorshipUpdated(
+				ALICE, 5000, 3000, 2500,
+			)));
+			assert_eq!(
+				TransactionPayment::sponsorships(&ALICE),
+				Some(SponsorshipInfo {
+					budget: 5000,
+					max_fee_per_tx: 3000,
+					user_quota: 2500,
+				})
+			);
+
+			assert_noop!(
+				TransactionPayment::set_sponsored_call(
+					Origin::signed(ALICE),
+					b"Currencies".to_vec(),
+					[b'a'; 33].to_vec(),
+					true
+				),
+				Error::<Runtime>::CallNameTooLong
+			);
+			assert_ok!(TransactionPayment::set_sponsored_call(
+				Origin::signed(ALICE),
+				b"Currencies".to_vec(),
+				b"transfer_native_currency".to_vec(),
+				true
+			));
+			System::assert_last_event(Event::TransactionPayment(crate::Event::SponsoredCallUpdated(
+				ALICE,
+				b"Currencies".to_vec(),
+				b"transfer_native_currency".to_vec(),
+				true,
+			)));
+			assert_eq!(
+				TransactionPayment::sponsored_calls(
+					&ALICE,
+					(call_name(b"Currencies"), call_name(b"transfer_native_currency"))
+				),
+				Some(())
+			);
+			assert_eq!(PalletBalances::reserved_balance(&ALICE), 100);
+
+			// allowing the call again doesn't reserve more
+			assert_ok!(TransactionPayment::set_sponsored_call(
+				Origin::signed(ALICE),
+				b"Currencies".to_vec(),
+				b"transfer_native_currency".to_vec(),
+				true
+			));
+			assert_eq!(PalletBalances::reserved_balance(&ALICE), 100);
+
+			assert_ok!(TransactionPayment::set_sponsored_user(Origin::signed(ALICE), BOB, true));
+			System::assert_last_event(Event::TransactionPayment(crate::Event::SponsoredUserUpdated(
+				ALICE, BOB, true,
+			)));
+			assert_eq!(TransactionPayment::sponsored_users(&ALICE, &BOB), Some(0));
+			assert_eq!(PalletBalances::reserved_balance(&ALICE), 200);
+
+			assert_ok!(TransactionPayment::set_sponsored_user(
+				Origin::signed(ALICE),
+				BOB,
+				false
+			));
+			assert_eq!(TransactionPayment::sponsored_users(&ALICE, &BOB), None);
+			assert_eq!(PalletBalances::reserved_balance(&ALICE), 100);
+			assert_ok!(TransactionPayment::set_sponsored_user(Origin::signed(ALICE), BOB, true));
+			assert_ok!(TransactionPayment::set_sponsored_user(
+				Origin::signed(ALICE),
+				CHARLIE,
+				true
+			));
+			assert_eq!(PalletBalances::reserved_balance(&ALICE), 300);
+
+			// the deposits of the removed calls and users are unreserved
+			assert_ok!(TransactionPayment::remove_sponsorship(Origin::signed(ALICE), 2));
+			assert!(TransactionPayment::sponsorships(&ALICE).is_some());
+			assert_eq!(PalletBalances::reserved_balance(&ALICE), 100);
+			assert_ok!(TransactionPayment::remove_sponsorship(Origin::signed(ALICE), 10));
+			System::assert_last_event(Event::TransactionPayment(crate::Event::SponsorshipRemoved(ALICE)));
+			assert_eq!(TransactionPayment::sponsorships(&ALICE), None);
+			assert_eq!(
+				TransactionPayment::sponsored_calls(
+					&ALICE,
+					(call_name(b"Currencies"), call_name(b"transfer_native_currency"))
+				),
+				None
+			);
+			assert_eq!(TransactionPayment::sponsored_users(&ALICE, &BOB), None);
+			assert_eq!(PalletBalances::reserved_balance(&ALICE), 0);
+		});
+}
+
+#[test]
+fn with_sponsor_work() {
+	ExtBuilder::default()
+		.one_hundred_thousand_for_alice_n_charlie()
+		.build()
+		.execute_with(|| {
+			let wrapper_weight = <() as WeightInfo>::with_sponsor();
+			let call_weight = CALL2.get_dispatch_info().weight;
+			let call = Call::TransactionPayment(crate::Call::with_sponsor(CHARLIE, Box::new(CALL2.clone())));
+			assert_eq!(call.get_dispatch_info().weight, wrapper_weight + call_weight);
+
+			// the actual weight of the wrapped call plus the wrapper
+			assert_eq!(
+				call.dispatch(Origin::signed(ALICE)),
+				Ok(PostDispatchInfo {
+					actual_weight: Some(wrapper_weight + call_weight),
+					pays_fee: Pays::Yes,
+				})
+			);
+			assert_eq!(Currencies::free_balance(ACA, &BOB), 12);
+
+			// the failed call keeps the actual weight
+			let failed_call = Call::TransactionPayment(crate::Call::with_sponsor(
+				CHARLIE,
+				Box::new(Call::Currencies(module_currencies::Call::transfer_native_currency(
+					BOB, 1_000_000,
+				))),
+			));
+			assert_eq!(
+				failed_call.dispatch(Origin::signed(ALICE)),
+				Err(DispatchErrorWithPostInfo {
+					post_info: PostDispatchInfo {
+						actual_weight: Some(wrapper_weight + call_weight),
+						pays_fee: Pays::Yes,
+					},
+					error: pallet_balances::Error::<Runtime>::InsufficientBalance.into(),
+				})
+			);
+		});
+}
+
+#[test]
+fn charge_fee_by_sponsor_work() {
+	ExtBuilder::default()
+		.one_hundred_thousand_for_alice_n_charlie()
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			let call = Call::TransactionPayment(crate::Call::with_sponsor(ALICE, Box::new(CALL2.clone())));
+			let fee = 500 * 2 + 1000; // len * byte + weight
+
+			// the sponsorship does not exist
+			assert!(ChargeTransactionPayment::<Runtime>::from(0)
+				.validate(&BOB, &call, &INFO, 500)
+				.is_err());
+
+			assert_ok!(TransactionPayment::set_sponsorship(
+				Origin::signed(ALICE),
+				5000,
+				3000,
+				2500
+			));
+			assert_ok!(TransactionPayment::set_sponsored_call(
+				Origin::signed(ALICE),
+				b"Currencies".to_vec(),
+				b"transfer_native_currency".to_vec(),
+				true
+			));
+
+			// BOB is not sponsored
+			assert!(ChargeTransactionPayment::<Runtime>::from(0)
+				.validate(&BOB, &call, &INFO, 500)
+				.is_err());
+			assert_ok!(TransactionPayment::set_sponsored_user(Origin::signed(ALICE), BOB, true));
+
+			// the call is not sponsored
+			let not_sponsored_call = Call::TransactionPayment(crate::Call::with_sponsor(ALICE, Box::new(CALL.clone())));
+			assert!(ChargeTransactionPayment::<Runtime>::from(0)
+				.validate(&BOB, &not_sponsored_call, &INFO, 500)
+				.is_err());
+
+			// the fee exceeds the max fee per transaction
+			assert!(ChargeTransactionPayment::<Runtime>::from(0)
+				.validate(&BOB, &call, &INFO, 1500)
+				.is_err());
+
+			let pre = ChargeTransactionPayment::<Runtime>::from(0)
+				.pre_dispatch(&BOB, &call, &INFO, 500)
+				.unwrap();
+			assert_eq!(Currencies::free_balance(ACA, &ALICE), 100000 - fee);
+			assert_eq!(Currencies::free_balance(ACA, &BOB), 0);
+			assert_eq!(TransactionPayment::sponsorships(&ALICE).unwrap().budget, 5000 - fee);
+			assert_eq!(TransactionPayment::sponsored_users(&ALICE, &BOB), Some(fee));
+
+			assert_ok!(ChargeTransactionPayment::<Runtime>::post_dispatch(
+				pre,
+				&INFO,
+				&POST_INFO,
+				500,
+				&Ok(())
+			));
+			let refund = 200; // 1000 - 800
+			assert_eq!(Currencies::free_balance(ACA, &ALICE), 100000 - fee + refund);
+			assert_eq!(
+				TransactionPayment::sponsorships(&ALICE).unwrap().budget,
+				5000 - fee + refund
+			);
+			assert_eq!(TransactionPayment::sponsored_users(&ALICE, &BOB), Some(fee - refund));
+			System::assert_last_event(Event::TransactionPayment(crate::Event::FeeSponsored(
+				ALICE,
+				BOB,
+				fee - refund,
+			)));
+
+			// exceed the user quota
+			assert!(ChargeTransactionPayment::<Runtime>::from(0)
+				.validate(&BOB, &call, &INFO, 500)
+				.is_err());
+
+			// the budget is exhausted after CHARLIE's transaction
+			assert_ok!(TransactionPayment::set_sponsored_user(
+				Origin::signed(ALICE),
+				CHARLIE,
+				true
+			));
+			let pre = ChargeTransactionPayment::<Runtime>::from(0)
+				.pre_dispatch(&CHARLIE, &call, &INFO, 500)
+				.unwrap();
+			assert_ok!(ChargeTransactionPayment::<Runtime>::post_dispatch(
+				pre,
+				&INFO,
+				&POST_INFO,
+				500,
+				&Ok(())
+			));
+			System::assert_has_event(Event::TransactionPayment(crate::Event::SponsorBudgetExhausted(
+				ALICE,
+				5000 - 2 * (fee - refund),
+			)));
+			assert!(ChargeTransactionPayment::<Runtime>::from(0)
+				.validate(&CHARLIE, &call, &INFO, 500)
+				.is_err());
+		});
+}
+
 #[test]
 fn query_info_works() {
 	ExtBuilder::default()
@@ -410,6 +800,182 @@ fn query_info_works() {
 		});
 }
 
+#[test]
+fn query_fee_estimation_works() {
+	ExtBuilder::default()
+		.one_hundred_thousand_for_alice_n_charlie()
+		.build()
+		.execute_with(|| {
+			assert_ok!(DEXModule::add_liquidity(
+				Origin::signed(ALICE),
+				ACA,
+				AUSD,
+				10000,
+				1000,
+				0,
+				false
+			));
+			assert_ok!(DEXModule::add_liquidity(
+				Origin::signed(ALICE),
+				DOT,
+				AUSD,
+				100,
+				1000,
+				0,
+				false
+			));
+			assert_ok!(<Currencies as MultiCurrency<_>>::transfer(DOT, &ALICE, &BOB, 100));
+
+			let xt = TestXt::new(CALL2.clone(), Some((111111, ())));
+			let fee = TransactionPayment::compute_fee(500, &xt.get_dispatch_info(), 0);
+			assert!(!fee.is_zero());
+
+			// native currency is enough
+			assert_eq!(
+				TransactionPayment::query_fee_estimation(ALICE, xt.clone(), 500),
+				(fee, None, None, true)
+			);
+
+			// swap by the default fee swap path
+			let supply_amount = DEXModule::get_swap_supply_amount(&[DOT, AUSD, ACA], fee + 10).unwrap();
+			assert_eq!(
+				TransactionPayment::query_fee_estimation(BOB, xt.clone(), 500),
+				(fee, Some(vec![DOT, AUSD, ACA]), Some(supply_amount), true)
+			);
+			// the estimation is dry run
+			assert_eq!(Currencies::free_balance(ACA, &BOB), 0);
+			assert_eq!(Currencies::free_balance(DOT, &BOB), 100);
+			assert_eq!(DEXModule::get_liquidity_pool(DOT, AUSD), (100, 1000));
+
+			// report the configured fee swap path if the fee cannot be charged
+			assert_ok!(<Currencies as MultiCurrency<_>>::transfer(DOT, &BOB, &ALICE, 100));
+			assert_ok!(TransactionPayment::set_alternative_fee_swap_path(
+				Origin::signed(BOB),
+				Some(vec![DOT, AUSD, ACA])
+			));
+			assert_eq!(
+				TransactionPayment::query_fee_estimation(BOB, xt, 500),
+				(fee, Some(vec![DOT, AUSD, ACA]), Some(supply_amount), false)
+			);
+		});
+}
+
+#[test]
+fn query_fee_estimation_of_wrapped_call_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		// the weights of the wrappers require deep pools
+		assert_ok!(<Currencies as MultiCurrency<_>>::deposit(
+			ACA,
+			&ALICE,
+			2_000_000_000_000_000
+		));
+		assert_ok!(<Currencies as MultiCurrency<_>>::deposit(
+			AUSD,
+			&ALICE,
+			200_000_000_000_000
+		));
+		assert_ok!(<Currencies as MultiCurrency<_>>::deposit(
+			DOT,
+			&ALICE,
+			20_000_000_000_000
+		));
+		assert_ok!(<Currencies as MultiCurrency<_>>::deposit(
+			ACA,
+			&CHARLIE,
+			1_000_000_000_000_000
+		));
+		assert_ok!(DEXModule::add_liquidity(
+			Origin::signed(ALICE),
+			ACA,
+			AUSD,
+			1_000_000_000_000_000,
+			100_000_000_000_000,
+			0,
+			false
+		));
+		assert_ok!(DEXModule::add_liquidity(
+			Origin::signed(ALICE),
+			DOT,
+			AUSD,
+			10_000_000_000_000,
+			100_000_000_000_000,
+			0,
+			false
+		));
+		assert_ok!(<Currencies as MultiCurrency<_>>::transfer(
+			DOT,
+			&ALICE,
+			&BOB,
+			10_000_000_000_000
+		));
+		MockPriceSource::set_relative_price(None);
+
+		// swap the fee currency specified by the wrapper
+		let xt = TestXt::new(
+			Call::TransactionPayment(crate::Call::with_fee_currency(DOT, Box::new(CALL2.clone()))),
+			Some((111111, ())),
+		);
+		let fee = TransactionPayment::compute_fee(500, &xt.get_dispatch_info(), 0);
+		let (fee_swap_path, supply_amount) = TransactionPayment::get_fee_swap_route(DOT, fee + 10).unwrap();
+		assert_eq!(
+			TransactionPayment::query_fee_estimation(BOB, xt, 500),
+			(fee, Some(fee_swap_path), Some(supply_amount), true)
+		);
+		// the estimation is dry run
+		assert_eq!(Currencies::free_balance(ACA, &BOB), 0);
+		assert_eq!(Currencies::free_balance(DOT, &BOB), 10_000_000_000_000);
+
+		// the fee currency without route to native currency
+		let xt = TestXt::new(
+			Call::TransactionPayment(crate::Call::with_fee_currency(LDOT, Box::new(CALL2.clone()))),
+			Some((111111, ())),
+		);
+		let fee = TransactionPayment::compute_fee(500, &xt.get_dispatch_info(), 0);
+		assert_eq!(
+			TransactionPayment::query_fee_estimation(BOB, xt, 500),
+			(fee, None, None, false)
+		);
+
+		// the sponsor pays the fee
+		assert_ok!(TransactionPayment::set_sponsorship(
+			Origin::signed(CHARLIE),
+			100_000_000_000_000,
+			10_000_000_000_000,
+			10_000_000_000_000
+		));
+		assert_ok!(TransactionPayment::set_sponsored_call(
+			Origin::signed(CHARLIE),
+			b"Currencies".to_vec(),
+			b"transfer_native_currency".to_vec(),
+			true
+		));
+		assert_ok!(TransactionPayment::set_sponsored_user(
+			Origin::signed(CHARLIE),
+			BOB,
+			true
+		));
+		let xt = TestXt::new(
+			Call::TransactionPayment(crate::Call::with_sponsor(CHARLIE, Box::new(CALL2.clone()))),
+			Some((111111, ())),
+		);
+		let fee = TransactionPayment::compute_fee(500, &xt.get_dispatch_info(), 0);
+		assert_eq!(
+			TransactionPayment::query_fee_estimation(BOB, xt.clone(), 500),
+			(fee, None, None, true)
+		);
+		assert_eq!(
+			TransactionPayment::sponsorships(&CHARLIE).unwrap().budget,
+			100_000_000_000_000
+		);
+
+		// the user is not sponsored
+		assert_eq!(
+			TransactionPayment::query_fee_estimation(ALICE, xt, 500),
+			(fee, None, None, false)
+		);
+	});
+}
+
 #[test]
 fn compute_fee_works_without_multiplier() {
 	ExtBuilder::default()
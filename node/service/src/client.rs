@@ -19,7 +19,7 @@
 //! Acala Client abstractions.
 
 use acala_primitives::{AccountId, Balance, Block, BlockNumber, CurrencyId, DataProviderId, Hash, Header, Nonce};
-use runtime_common::TimeStampedPrice;
+use runtime_common::{IncentivesPoolId, TimeStampedPrice};
 use sc_client_api::{Backend as BackendT, BlockchainEvents, KeyIterator};
 use sp_api::{CallApiAt, NumberFor, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
@@ -41,6 +41,8 @@ pub trait RuntimeApiCollection:
 	+ pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance>
 	+ orml_oracle_rpc::OracleRuntimeApi<Block, DataProviderId, CurrencyId, TimeStampedPrice>
 	+ module_staking_pool_rpc::StakingPoolRuntimeApi<Block, AccountId, Balance>
+	+ module_incentives_rpc::IncentivesRuntimeApi<Block, AccountId, IncentivesPoolId, CurrencyId, BlockNumber, Balance>
+	+ module_transaction_payment_rpc::FeeEstimationRuntimeApi<Block, AccountId, CurrencyId, Balance>
 	+ module_evm_rpc_runtime_api::EVMRuntimeRPCApi<Block, Balance>
 	+ sp_api::Metadata<Block>
 	+ sp_offchain::OffchainWorkerApi<Block>
@@ -60,6 +62,14 @@ where
 		+ pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance>
 		+ orml_oracle_rpc::OracleRuntimeApi<Block, DataProviderId, CurrencyId, TimeStampedPrice>
 		+ module_staking_pool_rpc::StakingPoolRuntimeApi<Block, AccountId, Balance>
+		+ module_incentives_rpc::IncentivesRuntimeApi<
+			Block,
+			AccountId,
+			IncentivesPoolId,
+			CurrencyId,
+			BlockNumber,
+			Balance,
+		> + module_transaction_payment_rpc::FeeEstimationRuntimeApi<Block, AccountId, CurrencyId, Balance>
 		+ module_evm_rpc_runtime_api::EVMRuntimeRPCApi<Block, Balance>
 		+ sp_api::Metadata<Block>
 		+ sp_offchain::OffchainWorkerApi<Block>
@@ -219,6 +219,9 @@ pub enum DexShare {
 	Erc20(EvmAddress),
 }
 
+/// The id of the foreign asset registered on-chain by the asset registry.
+pub type ForeignAssetId = u16;
+
 #[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, PartialOrd, Ord)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
@@ -227,6 +230,7 @@ pub enum CurrencyId {
 	DexShare(DexShare, DexShare),
 	Erc20(EvmAddress),
 	StableAssetPoolToken(nutsfinance_stable_asset::PoolId),
+	ForeignAsset(ForeignAssetId),
 }
 
 impl CurrencyId {
@@ -242,6 +246,14 @@ impl CurrencyId {
 		matches!(self, CurrencyId::Erc20(_))
 	}
 
+	pub fn is_stable_asset_pool_token_currency_id(&self) -> bool {
+		matches!(self, CurrencyId::StableAssetPoolToken(_))
+	}
+
+	pub fn is_foreign_asset_currency_id(&self) -> bool {
+		matches!(self, CurrencyId::ForeignAsset(_))
+	}
+
 	pub fn split_dex_share_currency_id(&self) -> Option<(Self, Self)> {
 		match self {
 			CurrencyId::DexShare(dex_share_0, dex_share_1) => {
@@ -311,6 +323,9 @@ impl TryFrom<CurrencyId> for EvmAddress {
 			}
 			CurrencyId::Erc20(address) => Ok(address),
 			CurrencyId::StableAssetPoolToken(_) => Err(()),
+			CurrencyId::ForeignAsset(foreign_asset_id) => Ok(EvmAddress::from_low_u64_be(
+				MIRRORED_FOREIGN_ASSETS_ADDRESS_START | u64::from(foreign_asset_id),
+			)),
 		}
 	}
 }
@@ -323,3 +338,14 @@ impl Into<CurrencyId> for DexShare {
 		}
 	}
 }
+
+/// The metadata of the foreign asset registered on-chain.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct AssetMetadata<Balance> {
+	pub name: Vec<u8>,
+	pub symbol: Vec<u8>,
+	pub decimals: u8,
+	pub minimal_balance: Balance,
+}
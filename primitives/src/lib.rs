@@ -34,7 +34,7 @@ use sp_runtime::{
 };
 use sp_std::{convert::Into, prelude::*};
 
-pub use currency::{CurrencyId, DexShare, TokenSymbol};
+pub use currency::{AssetMetadata, CurrencyId, DexShare, ForeignAssetId, TokenSymbol};
 
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
@@ -175,6 +175,7 @@ pub enum ReserveIdentifier {
 	Honzon,
 	Nft,
 	TransactionPayment,
+	TransactionPaymentDeposit,
 
 	// always the last, indicate number of variants
 	Count,
@@ -194,6 +195,9 @@ pub const MIRRORED_TOKENS_ADDRESS_START: u64 = 0x1000000;
 /// Mirrored NFT (ensure length <= 4 bytes, encode to u32 will take the first 4 non-zero bytes)
 /// 0x2000000
 pub const MIRRORED_NFT_ADDRESS_START: u64 = 0x2000000;
+/// Mirrored Foreign Assets
+/// 0x100000000
+pub const MIRRORED_FOREIGN_ASSETS_ADDRESS_START: u64 = 0x100000000;
 /// Mirrored LP Tokens
 /// 0x10000000000000000
 pub const MIRRORED_LP_TOKENS_ADDRESS_START: u128 = 0x10000000000000000;
@@ -211,6 +215,10 @@ pub const SYSTEM_CONTRACT_ADDRESS_PREFIX: [u8; 11] = [0u8; 11];
 /// - v[12..16] = dex left(4 bytes)
 /// - v[16..20] = dex right(4 bytes)
 ///
+/// ForeignAsset
+/// v[15] = 1 // MIRRORED_FOREIGN_ASSETS_ADDRESS_START
+/// - v[18..20] = foreign asset id(2 bytes)
+///
 /// Erc20
 /// - v[0..20] = evm address(20 bytes)
 pub const H160_TYPE_TOKEN: u8 = 1;
@@ -218,9 +226,11 @@ pub const H160_TYPE_DEXSHARE: u8 = 1;
 pub const H160_POSITION_TOKEN: usize = 19;
 pub const H160_POSITION_DEXSHARE_LEFT: Range<usize> = 12..16;
 pub const H160_POSITION_DEXSHARE_RIGHT: Range<usize> = 16..20;
+pub const H160_POSITION_FOREIGN_ASSET: Range<usize> = 18..20;
 pub const H160_POSITION_ERC20: Range<usize> = 0..20;
 pub const H160_PREFIX_TOKEN: [u8; 19] = [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0];
 pub const H160_PREFIX_DEXSHARE: [u8; 12] = [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1];
+pub const H160_PREFIX_FOREIGN_ASSET: [u8; 18] = [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0];
 
 pub type NFTBalance = u128;
 
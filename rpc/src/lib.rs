@@ -20,7 +20,7 @@
 
 #![warn(missing_docs)]
 
-use primitives::{AccountId, Balance, Block, CurrencyId, DataProviderId, Hash, Nonce};
+use primitives::{AccountId, Balance, Block, BlockNumber, CurrencyId, DataProviderId, Hash, Nonce};
 use sc_client_api::light::{Fetcher, RemoteBlockchain};
 pub use sc_rpc_api::DenyUnsafe;
 use sc_transaction_pool_api::TransactionPool;
@@ -68,11 +68,22 @@ where
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
 	C::Api: orml_oracle_rpc::OracleRuntimeApi<Block, DataProviderId, CurrencyId, runtime_common::TimeStampedPrice>,
 	C::Api: module_staking_pool_rpc::StakingPoolRuntimeApi<Block, AccountId, Balance>,
+	C::Api: module_incentives_rpc::IncentivesRuntimeApi<
+		Block,
+		AccountId,
+		runtime_common::IncentivesPoolId,
+		CurrencyId,
+		BlockNumber,
+		Balance,
+	>,
+	C::Api: module_transaction_payment_rpc::FeeEstimationRuntimeApi<Block, AccountId, CurrencyId, Balance>,
 	C::Api: EVMRuntimeRPCApi<Block, Balance>,
 	C::Api: BlockBuilder<Block>,
 	P: TransactionPool + Sync + Send + 'static,
 {
+	use module_incentives_rpc::{Incentives, IncentivesApi};
 	use module_staking_pool_rpc::{StakingPool, StakingPoolApi};
+	use module_transaction_payment_rpc::{FeeEstimationApi, FeeEstimator};
 	use orml_oracle_rpc::{Oracle, OracleApi};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApi};
 	use substrate_frame_rpc_system::{FullSystem, SystemApi};
@@ -97,6 +108,8 @@ where
 	// These RPCs should use an asynchronous caller instead.
 	io.extend_with(OracleApi::to_delegate(Oracle::new(client.clone())));
 	io.extend_with(StakingPoolApi::to_delegate(StakingPool::new(client.clone())));
+	io.extend_with(IncentivesApi::to_delegate(Incentives::new(client.clone())));
+	io.extend_with(FeeEstimationApi::to_delegate(FeeEstimator::new(client.clone())));
 	io.extend_with(EVMApiServer::to_delegate(EVMApi::new(client, deny_unsafe)));
 
 	io
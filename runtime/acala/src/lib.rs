@@ -39,7 +39,9 @@ use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_core::{crypto::KeyTypeId, OpaqueMetadata, H160};
 use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
-	traits::{AccountIdConversion, BadOrigin, BlakeTwo256, Block as BlockT, SaturatedConversion, StaticLookup, Zero},
+	traits::{
+		AccountIdConversion, BadOrigin, BlakeTwo256, Block as BlockT, Convert, SaturatedConversion, StaticLookup, Zero,
+	},
 	transaction_validity::{TransactionSource, TransactionValidity},
 	ApplyExtrinsicResult, DispatchResult, FixedPointNumber,
 };
@@ -54,6 +56,7 @@ use module_evm::Runner;
 use module_evm::{CallInfo, CreateInfo};
 use module_evm_accounts::EvmAddressMapping;
 use module_evm_manager::EvmCurrencyIdMapping;
+use module_relaychain::RelaychainCallBuilder;
 use module_transaction_payment::{Multiplier, TargetedFeeAdjustment};
 use orml_tokens::CurrencyAdapter;
 use orml_traits::{create_median_value_data_provider, parameter_type_with_key, DataFeeder, DataProviderExtended};
@@ -785,11 +788,33 @@ impl orml_tokens::Config for Runtime {
 
 parameter_types! {
 	pub StableCurrencyFixedPrice: Price = Price::saturating_from_rational(1, 1);
+	pub const MaxSourcePriceAge: Moment = 1000 * 60 * 60; // 60 mins
+	pub MaxSourceDeviation: Ratio = Ratio::saturating_from_rational(10, 100);
+	pub const MinSourcesQuorum: u32 = 1;
+	pub const CircuitBreakerCoolDown: BlockNumber = 6 * HOURS;
+	pub const DexPriceAveragingPeriod: Moment = 1000 * 60 * 30; // 30 mins
+}
+
+/// The oracle instances as the price sources of prices module.
+pub struct OraclePriceSources;
+impl module_support::MultiSourcePriceProvider<CurrencyId> for OraclePriceSources {
+	fn get_prices(currency_id: &CurrencyId) -> Vec<Option<(Price, Moment)>> {
+		vec![
+			AcalaOracle::get_no_op(currency_id).map(|v| (v.value, v.timestamp)),
+			BandOracle::get_no_op(currency_id).map(|v| (v.value, v.timestamp)),
+		]
+	}
 }
 
 impl module_prices::Config for Runtime {
 	type Event = Event;
-	type Source = AggregatedDataProvider;
+	type Source = OraclePriceSources;
+	type UnixTime = Timestamp;
+	type MaxSourcePriceAge = MaxSourcePriceAge;
+	type MaxSourceDeviation = MaxSourceDeviation;
+	type MinSourcesQuorum = MinSourcesQuorum;
+	type CircuitBreakerCoolDown = CircuitBreakerCoolDown;
+	type DexPriceAveragingPeriod = DexPriceAveragingPeriod;
 	type GetStableCurrencyId = GetStableCurrencyId;
 	type StableCurrencyFixedPrice = StableCurrencyFixedPrice;
 	type GetStakingCurrencyId = GetStakingCurrencyId;
@@ -797,6 +822,7 @@ impl module_prices::Config for Runtime {
 	type LockOrigin = EnsureRootOrTwoThirdsGeneralCouncil;
 	type LiquidStakingExchangeRateProvider = LiquidStakingExchangeRateProvider;
 	type DEX = Dex;
+	type StableAsset = ();
 	type Currency = Currencies;
 	type CurrencyIdMapping = EvmCurrencyIdMapping<Runtime>;
 	type WeightInfo = weights::module_prices::WeightInfo<Runtime>;
@@ -824,6 +850,7 @@ impl module_currencies::Config for Runtime {
 	type EVMBridge = EVMBridge;
 	type SweepOrigin = EnsureRootOrOneGeneralCouncil;
 	type OnDust = module_currencies::TransferDust<Runtime, TreasuryAccount>;
+	type CurrencyHook = module_currencies::NotifyBalanceChanged<module_incentives::OnBalanceChanged<Runtime>>;
 }
 
 pub struct EnsureRootOrTreasury;
@@ -989,6 +1016,7 @@ parameter_types! {
 impl module_cdp_engine::Config for Runtime {
 	type Event = Event;
 	type PriceSource = module_prices::PriorityLockedPriceProvider<Runtime>;
+	type PriceCircuitBreaker = Prices;
 	type CollateralCurrencyIds = CollateralCurrencyIds;
 	type DefaultLiquidationRatio = DefaultLiquidationRatio;
 	type DefaultDebitExchangeRate = DefaultDebitExchangeRate;
@@ -1086,7 +1114,14 @@ impl OnUnbalanced<NegativeImbalance> for DealWithFees {
 	}
 }
 
+parameter_types! {
+	pub const MaxCallNameLength: u32 = 64;
+	// the key of the longest sponsored call
+	pub SponsoredItemDeposit: Balance = deposit(1, 178);
+}
+
 impl module_transaction_payment::Config for Runtime {
+	type Event = Event;
 	type NativeCurrencyId = GetNativeCurrencyId;
 	type DefaultFeeSwapPathList = DefaultFeeSwapPathList;
 	type Currency = Balances;
@@ -1099,6 +1134,9 @@ impl module_transaction_payment::Config for Runtime {
 	type MaxSwapSlippageCompareToOracle = MaxSwapSlippageCompareToOracle;
 	type TradingPathLimit = TradingPathLimit;
 	type PriceSource = module_prices::RealTimePriceProvider<Runtime>;
+	type Call = Call;
+	type MaxCallNameLength = MaxCallNameLength;
+	type SponsoredItemDeposit = SponsoredItemDeposit;
 	type WeightInfo = weights::module_transaction_payment::WeightInfo<Runtime>;
 }
 
@@ -1111,24 +1149,36 @@ impl module_evm_accounts::Config for Runtime {
 }
 
 impl module_evm_manager::Config for Runtime {
+	type Event = Event;
 	type Currency = Balances;
 	type EVMBridge = EVMBridge;
+	type ForeignAssetIdMapping = ();
+	type StableAsset = ();
+	type RegistryOrigin = EnsureRootOrHalfGeneralCouncil;
+	type WeightInfo = weights::module_evm_manager::WeightInfo<Runtime>;
 }
 
 impl orml_rewards::Config for Runtime {
 	type Share = Balance;
 	type Balance = Balance;
-	type PoolId = module_incentives::PoolId;
+	type PoolId = module_incentives::PoolId<AccountId>;
 	type CurrencyId = CurrencyId;
 	type Handler = Incentives;
 }
 
 parameter_types! {
 	pub const AccumulatePeriod: BlockNumber = MINUTES;
+	pub const MaxBoostDuration: BlockNumber = 4 * 365 * DAYS;
+	pub MaxBoostMultiplier: Rate = Rate::saturating_from_rational(5, 2);
+	pub LoansBoostFullAmount: Balance = 10_000 * dollar(ACA);
+	pub EarlyUnlockPenaltyRate: Rate = Rate::saturating_from_rational(1, 2);
+	pub const MaxBoostExpiriesPerBlock: u32 = 50;
+	pub const MaxRewardProgramsPerPool: u32 = 10;
 }
 
 impl module_incentives::Config for Runtime {
 	type Event = Event;
+	type RelaychainAccountId = AccountId;
 	type RewardsSource = UnreleasedNativeVaultAccountId;
 	type StableCurrencyId = GetStableCurrencyId;
 	type AccumulatePeriod = AccumulatePeriod;
@@ -1136,8 +1186,20 @@ impl module_incentives::Config for Runtime {
 	type CDPTreasury = CdpTreasury;
 	type Currency = Currencies;
 	type DEX = Dex;
+	type PriceSource = module_prices::RealTimePriceProvider<Runtime>;
+	type MaxSwapSlippageCompareToOracle = MaxSwapSlippageCompareToOracle;
 	type EmergencyShutdown = EmergencyShutdown;
 	type PalletId = IncentivesPalletId;
+	type LiquidCurrencyId = GetLiquidCurrencyId;
+	type ExcludedShareHolders = DustRemovalWhitelist;
+	type ValidatorInsurance = HomaValidatorListModule;
+	type NativeCurrencyId = GetNativeCurrencyId;
+	type MaxBoostDuration = MaxBoostDuration;
+	type MaxBoostMultiplier = MaxBoostMultiplier;
+	type LoansBoostFullAmount = LoansBoostFullAmount;
+	type EarlyUnlockPenaltyRate = EarlyUnlockPenaltyRate;
+	type MaxBoostExpiriesPerBlock = MaxBoostExpiriesPerBlock;
+	type MaxRewardProgramsPerPool = MaxRewardProgramsPerPool;
 	type WeightInfo = weights::module_incentives::WeightInfo<Runtime>;
 }
 
@@ -1145,15 +1207,35 @@ parameter_types! {
 	pub const PolkadotBondingDuration: EraIndex = 7;
 	pub const EraLength: BlockNumber = DAYS;
 	pub const MaxUnbonding: u32 = 1000;
+	pub RelaychainCallFee: Balance = 10 * cent(DOT);
+	pub const RelaychainCallWeight: Weight = 10_000_000_000;
+	pub ParachainAccount: AccountId = ParachainInfo::get().into_account();
+	pub const RelaychainUnbondingSlashingSpans: u32 = 5;
+}
+
+pub struct SubAccountIdConvert;
+impl Convert<u16, AccountId> for SubAccountIdConvert {
+	fn convert(index: u16) -> AccountId {
+		Utility::derivative_account_id(ParachainInfo::get().into_account(), index)
+	}
 }
 
 impl module_polkadot_bridge::Config for Runtime {
+	type Event = Event;
 	type DOTCurrency = Currency<Runtime, GetStakingCurrencyId>;
 	type OnNewEra = (NomineesElection, StakingPool);
 	type BondingDuration = PolkadotBondingDuration;
 	type EraLength = EraLength;
 	type PolkadotAccountId = AccountId;
 	type MaxUnbonding = MaxUnbonding;
+	type RelaychainCallBuilder = RelaychainCallBuilder<Runtime, ParachainInfo>;
+	type XcmSender = ();
+	type RelaychainCallFee = RelaychainCallFee;
+	type RelaychainCallWeight = RelaychainCallWeight;
+	type RelaychainUnbondingSlashingSpans = RelaychainUnbondingSlashingSpans;
+	type ParachainAccount = ParachainAccount;
+	type SubAccountIdConvert = SubAccountIdConvert;
+	type UpdateOrigin = EnsureRootOrHalfHomaCouncil;
 }
 
 parameter_types! {
@@ -1173,6 +1255,7 @@ impl module_staking_pool::Config for Runtime {
 	type UpdateOrigin = EnsureRootOrHalfHomaCouncil;
 	type FeeModel = CurveFeeModel;
 	type Nominees = NomineesElection;
+	// keep the simulator until a XCM router to relaychain is available on Acala
 	type Bridge = PolkadotBridge;
 	type Currency = Currencies;
 }
@@ -1187,6 +1270,7 @@ parameter_types! {
 	pub const NominateesCount: u32 = 7;
 	pub const MaxUnlockingChunks: u32 = 7;
 	pub const NomineesElectionBondingDuration: EraIndex = 7;
+	pub const MaxVoteWeightEras: EraIndex = 28;
 }
 
 impl module_nominees_election::Config for Runtime {
@@ -1198,13 +1282,16 @@ impl module_nominees_election::Config for Runtime {
 	type BondingDuration = NomineesElectionBondingDuration;
 	type NominateesCount = NominateesCount;
 	type MaxUnlockingChunks = MaxUnlockingChunks;
-	type NomineeFilter = runtime_common::DummyNomineeFilter;
+	type MaxVoteWeightEras = MaxVoteWeightEras;
+	type NomineeFilter = HomaValidatorListModule;
 	type WeightInfo = weights::module_nominees_election::WeightInfo<Runtime>;
 }
 
 parameter_types! {
 	pub MinGuaranteeAmount: Balance = dollar(LDOT);
 	pub const ValidatorInsuranceThreshold: Balance = 0;
+	pub SlashFreezeThreshold: Balance = 100 * dollar(DOT);
+	pub const ReportedSlashesEraDepth: EraIndex = 28;
 }
 
 impl module_homa_validator_list::Config for Runtime {
@@ -1216,11 +1303,14 @@ impl module_homa_validator_list::Config for Runtime {
 	type ValidatorInsuranceThreshold = ValidatorInsuranceThreshold;
 	type FreezeOrigin = EnsureRootOrHalfHomaCouncil;
 	type SlashOrigin = EnsureRootOrHalfHomaCouncil;
+	type SlashReportOrigin = EnsureRootOrHalfHomaCouncil;
+	type SlashFreezeThreshold = SlashFreezeThreshold;
+	type ReportedSlashesEraDepth = ReportedSlashesEraDepth;
 	type OnSlash = module_staking_pool::OnSlash<Runtime>;
 	type LiquidStakingExchangeRateProvider = LiquidStakingExchangeRateProvider;
 	type WeightInfo = ();
-	type OnIncreaseGuarantee = ();
-	type OnDecreaseGuarantee = ();
+	type OnIncreaseGuarantee = module_incentives::OnIncreaseGuarantee<Runtime>;
+	type OnDecreaseGuarantee = module_incentives::OnDecreaseGuarantee<Runtime>;
 	type BlockNumberProvider = RelaychainBlockNumberProvider<Runtime>;
 }
 
@@ -1553,6 +1643,14 @@ impl cumulus_pallet_aura_ext::Config for Runtime {}
 // 	type Event = Event;
 // }
 
+pub struct OnRuntimeUpgrade;
+impl frame_support::traits::OnRuntimeUpgrade for OnRuntimeUpgrade {
+	fn on_runtime_upgrade() -> u64 {
+		module_nominees_election::migrations::v1::migrate::<Runtime, ()>()
+			.saturating_add(module_incentives::migrations::v1::migrate::<Runtime>())
+	}
+}
+
 #[allow(clippy::large_enum_variant)]
 construct_runtime!(
 	pub enum Runtime where
@@ -1570,7 +1668,7 @@ construct_runtime!(
 		Tokens: orml_tokens::{Pallet, Storage, Event<T>, Config<T>} = 11,
 		Currencies: module_currencies::{Pallet, Call, Event<T>} = 12,
 		Vesting: orml_vesting::{Pallet, Storage, Call, Event<T>, Config<T>} = 13,
-		TransactionPayment: module_transaction_payment::{Pallet, Call, Storage} = 14,
+		TransactionPayment: module_transaction_payment::{Pallet, Call, Storage, Event<T>} = 14,
 
 		// Treasury
 		Treasury: pallet_treasury::{Pallet, Call, Storage, Config, Event<T>} = 20,
@@ -1628,7 +1726,7 @@ construct_runtime!(
 		Homa: module_homa::{Pallet, Call} = 130,
 		NomineesElection: module_nominees_election::{Pallet, Call, Storage, Event<T>} = 131,
 		StakingPool: module_staking_pool::{Pallet, Call, Storage, Event<T>, Config} = 132,
-		PolkadotBridge: module_polkadot_bridge::{Pallet, Call, Storage} = 133,
+		PolkadotBridge: module_polkadot_bridge::{Pallet, Call, Storage, Event<T>} = 133,
 		HomaValidatorListModule: module_homa_validator_list::{Pallet, Call, Storage, Event<T>} = 134,
 
 		// Acala Other
@@ -1651,7 +1749,7 @@ construct_runtime!(
 		EVM: module_evm::{Pallet, Config<T>, Call, Storage, Event<T>} = 180,
 		EVMBridge: module_evm_bridge::{Pallet} = 181,
 		EvmAccounts: module_evm_accounts::{Pallet, Call, Storage, Event<T>} = 182,
-		EvmManager: module_evm_manager::{Pallet, Storage} = 183,
+		EvmManager: module_evm_manager::{Pallet, Call, Storage, Event<T>} = 183,
 
 		// Collator support. the order of these 4 are important and shall not change.
 		Authorship: pallet_authorship::{Pallet, Call, Storage} = 190,
@@ -1692,8 +1790,14 @@ pub type SignedPayload = generic::SignedPayload<Call, SignedExtra>;
 /// Extrinsic type that has already been checked.
 pub type CheckedExtrinsic = generic::CheckedExtrinsic<AccountId, Call, SignedExtra>;
 /// Executive: handles dispatch to the various modules.
-pub type Executive =
-	frame_executive::Executive<Runtime, Block, frame_system::ChainContext<Runtime>, Runtime, AllPallets, ()>;
+pub type Executive = frame_executive::Executive<
+	Runtime,
+	Block,
+	frame_system::ChainContext<Runtime>,
+	Runtime,
+	AllPallets,
+	OnRuntimeUpgrade,
+>;
 
 #[cfg(not(feature = "disable-runtime-api"))]
 impl_runtime_apis! {
@@ -1795,6 +1899,28 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl module_transaction_payment_rpc_runtime_api::FeeEstimationApi<
+		Block,
+		AccountId,
+		CurrencyId,
+		Balance,
+	> for Runtime {
+		fn query_fee_estimation(
+			uxt: <Block as BlockT>::Extrinsic,
+			len: u32,
+			account: AccountId,
+		) -> module_transaction_payment_rpc_runtime_api::FeeEstimation<CurrencyId, Balance> {
+			let (native_fee, fee_swap_path, supply_amount, can_charge_fee) =
+				TransactionPayment::query_fee_estimation(account, uxt, len);
+			module_transaction_payment_rpc_runtime_api::FeeEstimation {
+				native_fee,
+				fee_swap_path,
+				supply_amount,
+				can_charge_fee,
+			}
+		}
+	}
+
 	impl orml_oracle_rpc_runtime_api::OracleApi<
 		Block,
 		DataProviderId,
@@ -1816,6 +1942,66 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl module_incentives_rpc_runtime_api::IncentivesApi<
+		Block,
+		AccountId,
+		module_incentives::PoolId<AccountId>,
+		CurrencyId,
+		BlockNumber,
+		Balance,
+	> for Runtime {
+		fn active_reward_programs() -> Vec<module_incentives_rpc_runtime_api::RewardProgramInfo<
+			module_incentives::PoolId<AccountId>,
+			CurrencyId,
+			BlockNumber,
+			Balance,
+		>> {
+			Incentives::active_reward_programs(System::block_number())
+				.into_iter()
+				.map(|(pool_id, program_id, program)| module_incentives_rpc_runtime_api::RewardProgramInfo {
+					pool_total_shares: Rewards::pool_infos(&pool_id).total_shares,
+					pool_id,
+					program_id,
+					currency_id: program.currency_id,
+					start: program.start,
+					end: program.end,
+					total_budget: program.total_budget,
+					remaining_budget: program.remaining_budget,
+					amount_per_period: program.amount_per_period,
+					accumulate_period: AccumulatePeriod::get(),
+				})
+				.collect()
+		}
+
+		fn pending_rewards(account: AccountId) -> Vec<module_incentives_rpc_runtime_api::PendingRewardsInfo<
+			module_incentives::PoolId<AccountId>,
+			CurrencyId,
+			Balance,
+		>> {
+			Incentives::pending_rewards(&account)
+				.into_iter()
+				.map(|(pool_id, shares, rewards)| module_incentives_rpc_runtime_api::PendingRewardsInfo {
+					pool_id,
+					shares,
+					rewards: rewards
+						.into_iter()
+						.map(|(currency_id, pending_amount, actual_amount)| module_incentives_rpc_runtime_api::PendingReward {
+							currency_id,
+							pending_amount,
+							actual_amount,
+						})
+						.collect(),
+				})
+				.collect()
+		}
+	}
+
+	impl module_evm_manager_rpc_runtime_api::EvmManagerApi<Block> for Runtime {
+		fn registered_erc20s() -> Vec<(u32, primitives::evm::Erc20Info)> {
+			EvmManager::registered_erc20s()
+		}
+	}
+
 	impl module_staking_pool_rpc_runtime_api::StakingPoolApi<
 		Block,
 		AccountId,
@@ -28,6 +28,7 @@ pub mod module_dex;
 pub mod module_emergency_shutdown;
 pub mod module_evm;
 pub mod module_evm_accounts;
+pub mod module_evm_manager;
 pub mod module_homa;
 pub mod module_honzon;
 pub mod module_incentives;
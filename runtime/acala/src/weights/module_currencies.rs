@@ -73,4 +73,12 @@ impl<T: frame_system::Config> module_currencies::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(c as Weight)))
 	}
+	fn transfer_batch(c: u32, ) -> Weight {
+		// composed of the benchmarked transfers
+		<Self as module_currencies::WeightInfo>::transfer_non_native_currency().saturating_mul(c as Weight)
+	}
+	fn transfer_all() -> Weight {
+		// composed of the benchmarked transfer
+		<Self as module_currencies::WeightInfo>::transfer_non_native_currency()
+	}
 }
@@ -0,0 +1,48 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Weights for module_evm_manager, not generated by the benchmark CLI yet.
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// Weight functions for module_evm_manager.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> module_evm_manager::WeightInfo for WeightInfo<T> {
+	// not generated by the benchmark CLI yet, estimated from the similar benchmarked calls
+	fn register_erc20() -> Weight {
+		(151_203_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// not generated by the benchmark CLI yet, estimated from the similar benchmarked calls
+	fn refresh_erc20_metadata() -> Weight {
+		(143_894_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// not generated by the benchmark CLI yet, estimated from the similar benchmarked calls
+	fn deregister_erc20() -> Weight {
+		(22_107_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+}
@@ -61,6 +61,8 @@ pub use primitives::{
 
 pub type TimeStampedPrice = orml_oracle::TimestampedValue<Price, primitives::Moment>;
 
+pub type IncentivesPoolId = module_incentives::PoolId<AccountId>;
+
 // Priority of unsigned transactions
 parameter_types! {
 	// Operational is 3/4 of TransactionPriority::max_value().
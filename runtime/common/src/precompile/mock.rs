@@ -30,10 +30,11 @@ use frame_support::{
 use frame_system::{EnsureRoot, EnsureSignedBy};
 use module_support::{
 	mocks::MockAddressMapping, AddressMapping as AddressMappingT, DEXIncentives, ExchangeRate, ExchangeRateProvider,
+	MultiSourcePriceProvider,
 };
-use orml_traits::{parameter_type_with_key, MultiReservableCurrency};
+use orml_traits::{parameter_type_with_key, DataProviderExtended, MultiReservableCurrency};
 pub use primitives::{
-	evm::EvmAddress, Amount, BlockNumber, CurrencyId, DexShare, Header, Nonce, ReserveIdentifier, TokenSymbol,
+	evm::EvmAddress, Amount, BlockNumber, CurrencyId, DexShare, Header, Moment, Nonce, ReserveIdentifier, TokenSymbol,
 	TradingPair,
 };
 use sp_core::{crypto::AccountId32, H160, H256};
@@ -170,6 +171,7 @@ impl module_currencies::Config for Test {
 	type EVMBridge = EVMBridge;
 	type SweepOrigin = EnsureSignedBy<CouncilAccount, AccountId>;
 	type OnDust = ();
+	type CurrencyHook = ();
 }
 
 impl module_evm_bridge::Config for Test {
@@ -177,8 +179,13 @@ impl module_evm_bridge::Config for Test {
 }
 
 impl module_evm_manager::Config for Test {
+	type Event = Event;
 	type Currency = Balances;
 	type EVMBridge = EVMBridge;
+	type ForeignAssetIdMapping = ();
+	type StableAsset = ();
+	type RegistryOrigin = EnsureSignedBy<CouncilAccount, AccountId>;
+	type WeightInfo = ();
 }
 
 parameter_types! {
@@ -218,9 +225,12 @@ parameter_types! {
 	pub const GetStableCurrencyId: CurrencyId = CurrencyId::Token(TokenSymbol::AUSD);
 	pub DefaultFeeSwapPathList: Vec<Vec<CurrencyId>> = vec![vec![CurrencyId::Token(TokenSymbol::AUSD), CurrencyId::Token(TokenSymbol::ACA)]];
 	pub MaxSwapSlippageCompareToOracle: Ratio = Ratio::one();
+	pub const MaxCallNameLength: u32 = 64;
+	pub const SponsoredItemDeposit: Balance = 0;
 }
 
 impl module_transaction_payment::Config for Test {
+	type Event = Event;
 	type NativeCurrencyId = GetNativeCurrencyId;
 	type DefaultFeeSwapPathList = DefaultFeeSwapPathList;
 	type Currency = Balances;
@@ -233,6 +243,9 @@ impl module_transaction_payment::Config for Test {
 	type MaxSwapSlippageCompareToOracle = MaxSwapSlippageCompareToOracle;
 	type TradingPathLimit = TradingPathLimit;
 	type PriceSource = module_prices::RealTimePriceProvider<Test>;
+	type Call = Call;
+	type MaxCallNameLength = MaxCallNameLength;
+	type SponsoredItemDeposit = SponsoredItemDeposit;
 	type WeightInfo = ();
 }
 pub type ChargeTransactionPayment = module_transaction_payment::ChargeTransactionPayment<Test>;
@@ -432,6 +445,18 @@ parameter_types! {
 	pub StableCurrencyFixedPrice: Price = Price::saturating_from_rational(1, 1);
 	pub const GetStakingCurrencyId: CurrencyId = DOT;
 	pub const GetLiquidCurrencyId: CurrencyId = LDOT;
+	pub const MaxSourcePriceAge: Moment = 1000 * 60 * 60;
+	pub MaxSourceDeviation: Ratio = Ratio::saturating_from_rational(10, 100);
+	pub const MinSourcesQuorum: u32 = 1;
+	pub const CircuitBreakerCoolDown: BlockNumber = 100;
+	pub const DexPriceAveragingPeriod: Moment = 1000 * 60 * 30;
+}
+
+pub struct OraclePriceSources;
+impl MultiSourcePriceProvider<CurrencyId> for OraclePriceSources {
+	fn get_prices(currency_id: &CurrencyId) -> Vec<Option<(Price, Moment)>> {
+		vec![Oracle::get_no_op(currency_id).map(|v| (v.value, v.timestamp))]
+	}
 }
 
 ord_parameter_types! {
@@ -440,7 +465,13 @@ ord_parameter_types! {
 
 impl module_prices::Config for Test {
 	type Event = Event;
-	type Source = Oracle;
+	type Source = OraclePriceSources;
+	type UnixTime = Timestamp;
+	type MaxSourcePriceAge = MaxSourcePriceAge;
+	type MaxSourceDeviation = MaxSourceDeviation;
+	type MinSourcesQuorum = MinSourcesQuorum;
+	type CircuitBreakerCoolDown = CircuitBreakerCoolDown;
+	type DexPriceAveragingPeriod = DexPriceAveragingPeriod;
 	type GetStableCurrencyId = GetStableCurrencyId;
 	type StableCurrencyFixedPrice = StableCurrencyFixedPrice;
 	type GetStakingCurrencyId = GetStakingCurrencyId;
@@ -448,6 +479,7 @@ impl module_prices::Config for Test {
 	type LockOrigin = EnsureSignedBy<One, AccountId>;
 	type LiquidStakingExchangeRateProvider = MockLiquidStakingExchangeProvider;
 	type DEX = DexModule;
+	type StableAsset = ();
 	type Currency = Currencies;
 	type CurrencyIdMapping = EvmCurrencyIdMapping;
 	type WeightInfo = ();
@@ -511,9 +543,9 @@ frame_support::construct_runtime!(
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 		Currencies: module_currencies::{Pallet, Call, Event<T>},
 		EVMBridge: module_evm_bridge::{Pallet},
-		EVMManager: module_evm_manager::{Pallet, Storage},
+		EVMManager: module_evm_manager::{Pallet, Call, Storage, Event<T>},
 		NFTModule: module_nft::{Pallet, Call, Event<T>},
-		TransactionPayment: module_transaction_payment::{Pallet, Call, Storage},
+		TransactionPayment: module_transaction_payment::{Pallet, Call, Storage, Event<T>},
 		Prices: module_prices::{Pallet, Storage, Call, Event<T>},
 		Proxy: pallet_proxy::{Pallet, Call, Storage, Event<T>},
 		Utility: pallet_utility::{Pallet, Call, Event},
@@ -21,6 +21,9 @@
 pub mod utils;
 
 // module benchmarking
+pub mod asset_registry {
+	include!("../../../mandala/src/benchmarking/asset_registry.rs");
+}
 pub mod auction_manager {
 	include!("../../../mandala/src/benchmarking/auction_manager.rs");
 }
@@ -36,6 +39,9 @@ pub mod collator_selection {
 pub mod currencies {
 	include!("../../../mandala/src/benchmarking/currencies.rs");
 }
+pub mod currency_filter {
+	include!("../../../mandala/src/benchmarking/currency_filter.rs");
+}
 pub mod dex {
 	include!("../../../mandala/src/benchmarking/dex.rs");
 }
@@ -48,6 +54,9 @@ pub mod evm {
 pub mod evm_accounts {
 	include!("../../../mandala/src/benchmarking/evm_accounts.rs");
 }
+pub mod evm_manager {
+	include!("../../../mandala/src/benchmarking/evm_manager.rs");
+}
 pub mod honzon {
 	include!("../../../mandala/src/benchmarking/honzon.rs");
 }
@@ -205,7 +205,7 @@ impl Contains<Call> for BaseCallFilter {
 
 		let is_evm = matches!(
 			call,
-			Call::EVM(_) | Call::EvmAccounts(_) // EvmBridge / EvmManager does not have call
+			Call::EVM(_) | Call::EvmAccounts(_) | Call::EvmManager(_) // EvmBridge does not have call
 		);
 		if is_evm {
 			// no evm call
@@ -225,6 +225,17 @@ impl Contains<Call> for BaseCallFilter {
 			return false;
 		}
 
+		let is_filtered_native_transfer = matches!(
+			call,
+			Call::Balances(pallet_balances::Call::transfer(..))
+				| Call::Balances(pallet_balances::Call::transfer_keep_alive(..))
+				| Call::Balances(pallet_balances::Call::transfer_all(..))
+		) && CurrencyFilter::is_filtered(GetNativeCurrencyId::get());
+		if is_filtered_native_transfer {
+			// the filtered native currency can only be transferred by `Currencies`
+			return false;
+		}
+
 		true
 	}
 }
@@ -759,6 +770,10 @@ parameter_type_with_key! {
 			},
 			CurrencyId::Erc20(_) => Balance::max_value(), // not handled by orml-tokens
 			CurrencyId::StableAssetPoolToken(_) => Balance::max_value(), // TODO: update this before we enable StableAsset
+			CurrencyId::ForeignAsset(foreign_asset_id) => {
+				AssetRegistry::asset_metadatas(foreign_asset_id)
+					.map_or(Balance::max_value(), |metadata| metadata.minimal_balance)
+			},
 		}
 	};
 }
@@ -788,11 +803,30 @@ impl orml_tokens::Config for Runtime {
 
 parameter_types! {
 	pub StableCurrencyFixedPrice: Price = Price::saturating_from_rational(1, 1);
+	pub const MaxSourcePriceAge: Moment = 1000 * 60 * 60; // 60 mins
+	pub MaxSourceDeviation: Ratio = Ratio::saturating_from_rational(10, 100);
+	pub const MinSourcesQuorum: u32 = 1;
+	pub const CircuitBreakerCoolDown: BlockNumber = 6 * HOURS;
+	pub const DexPriceAveragingPeriod: Moment = 1000 * 60 * 30; // 30 mins
+}
+
+/// The oracle instances as the price sources of prices module.
+pub struct OraclePriceSources;
+impl module_support::MultiSourcePriceProvider<CurrencyId> for OraclePriceSources {
+	fn get_prices(currency_id: &CurrencyId) -> Vec<Option<(Price, Moment)>> {
+		vec![AcalaOracle::get_no_op(currency_id).map(|v| (v.value, v.timestamp))]
+	}
 }
 
 impl module_prices::Config for Runtime {
 	type Event = Event;
-	type Source = AggregatedDataProvider;
+	type Source = OraclePriceSources;
+	type UnixTime = Timestamp;
+	type MaxSourcePriceAge = MaxSourcePriceAge;
+	type MaxSourceDeviation = MaxSourceDeviation;
+	type MinSourcesQuorum = MinSourcesQuorum;
+	type CircuitBreakerCoolDown = CircuitBreakerCoolDown;
+	type DexPriceAveragingPeriod = DexPriceAveragingPeriod;
 	type GetStableCurrencyId = GetStableCurrencyId;
 	type StableCurrencyFixedPrice = StableCurrencyFixedPrice;
 	type GetStakingCurrencyId = GetStakingCurrencyId;
@@ -800,6 +834,7 @@ impl module_prices::Config for Runtime {
 	type LockOrigin = EnsureRootOrTwoThirdsGeneralCouncil;
 	type LiquidStakingExchangeRateProvider = module_homa_lite::LiquidExchangeProvider<Runtime>;
 	type DEX = Dex;
+	type StableAsset = ();
 	type Currency = Currencies;
 	type CurrencyIdMapping = EvmCurrencyIdMapping<Runtime>;
 	type WeightInfo = weights::module_prices::WeightInfo<Runtime>;
@@ -822,6 +857,10 @@ impl module_currencies::Config for Runtime {
 	type EVMBridge = EVMBridge;
 	type SweepOrigin = EnsureRootOrOneGeneralCouncil;
 	type OnDust = module_currencies::TransferDust<Runtime, KaruraTreasuryAccount>;
+	type CurrencyHook = (
+		CurrencyFilter,
+		module_currencies::NotifyBalanceChanged<module_incentives::OnBalanceChanged<Runtime>>,
+	);
 }
 
 parameter_types! {
@@ -996,6 +1035,7 @@ parameter_types! {
 impl module_cdp_engine::Config for Runtime {
 	type Event = Event;
 	type PriceSource = module_prices::PriorityLockedPriceProvider<Runtime>;
+	type PriceCircuitBreaker = Prices;
 	type CollateralCurrencyIds = CollateralCurrencyIds;
 	type DefaultLiquidationRatio = DefaultLiquidationRatio;
 	type DefaultDebitExchangeRate = DefaultDebitExchangeRate;
@@ -1074,6 +1114,20 @@ impl module_transaction_pause::Config for Runtime {
 	type WeightInfo = weights::module_transaction_pause::WeightInfo<Runtime>;
 }
 
+impl module_asset_registry::Config for Runtime {
+	type Event = Event;
+	type Currency = Balances;
+	type RegisterOrigin = EnsureRootOrHalfGeneralCouncil;
+	type FixedLocationTokens = FixedLocationTokens;
+	type WeightInfo = weights::module_asset_registry::WeightInfo<Runtime>;
+}
+
+impl module_currency_filter::Config for Runtime {
+	type Event = Event;
+	type UpdateOrigin = EnsureRootOrHalfGeneralCouncil;
+	type WeightInfo = weights::module_currency_filter::WeightInfo<Runtime>;
+}
+
 parameter_types! {
 	// Sort by fee charge order
 	pub DefaultFeeSwapPathList: Vec<Vec<CurrencyId>> = vec![vec![KUSD, KSM, KAR], vec![KSM, KAR], vec![LKSM, KSM, KAR]];
@@ -1093,7 +1147,14 @@ impl OnUnbalanced<NegativeImbalance> for DealWithFees {
 	}
 }
 
+parameter_types! {
+	pub const MaxCallNameLength: u32 = 64;
+	// the key of the longest sponsored call
+	pub SponsoredItemDeposit: Balance = deposit(1, 178);
+}
+
 impl module_transaction_payment::Config for Runtime {
+	type Event = Event;
 	type NativeCurrencyId = GetNativeCurrencyId;
 	type DefaultFeeSwapPathList = DefaultFeeSwapPathList;
 	type Currency = Balances;
@@ -1106,6 +1167,9 @@ impl module_transaction_payment::Config for Runtime {
 	type MaxSwapSlippageCompareToOracle = MaxSwapSlippageCompareToOracle;
 	type TradingPathLimit = TradingPathLimit;
 	type PriceSource = module_prices::RealTimePriceProvider<Runtime>;
+	type Call = Call;
+	type MaxCallNameLength = MaxCallNameLength;
+	type SponsoredItemDeposit = SponsoredItemDeposit;
 	type WeightInfo = weights::module_transaction_payment::WeightInfo<Runtime>;
 }
 
@@ -1118,24 +1182,36 @@ impl module_evm_accounts::Config for Runtime {
 }
 
 impl module_evm_manager::Config for Runtime {
+	type Event = Event;
 	type Currency = Balances;
 	type EVMBridge = EVMBridge;
+	type ForeignAssetIdMapping = AssetRegistry;
+	type StableAsset = ();
+	type RegistryOrigin = EnsureRootOrHalfGeneralCouncil;
+	type WeightInfo = weights::module_evm_manager::WeightInfo<Runtime>;
 }
 
 impl orml_rewards::Config for Runtime {
 	type Share = Balance;
 	type Balance = Balance;
-	type PoolId = module_incentives::PoolId;
+	type PoolId = module_incentives::PoolId<AccountId>;
 	type CurrencyId = CurrencyId;
 	type Handler = Incentives;
 }
 
 parameter_types! {
 	pub const AccumulatePeriod: BlockNumber = MINUTES;
+	pub const MaxBoostDuration: BlockNumber = 4 * 365 * DAYS;
+	pub MaxBoostMultiplier: Rate = Rate::saturating_from_rational(5, 2);
+	pub LoansBoostFullAmount: Balance = 10_000 * dollar(KAR);
+	pub EarlyUnlockPenaltyRate: Rate = Rate::saturating_from_rational(1, 2);
+	pub const MaxBoostExpiriesPerBlock: u32 = 50;
+	pub const MaxRewardProgramsPerPool: u32 = 10;
 }
 
 impl module_incentives::Config for Runtime {
 	type Event = Event;
+	type RelaychainAccountId = AccountId;
 	type RewardsSource = UnreleasedNativeVaultAccountId;
 	type StableCurrencyId = GetStableCurrencyId;
 	type AccumulatePeriod = AccumulatePeriod;
@@ -1143,8 +1219,20 @@ impl module_incentives::Config for Runtime {
 	type CDPTreasury = CdpTreasury;
 	type Currency = Currencies;
 	type DEX = Dex;
+	type PriceSource = module_prices::RealTimePriceProvider<Runtime>;
+	type MaxSwapSlippageCompareToOracle = MaxSwapSlippageCompareToOracle;
 	type EmergencyShutdown = EmergencyShutdown;
 	type PalletId = IncentivesPalletId;
+	type LiquidCurrencyId = GetLiquidCurrencyId;
+	type ExcludedShareHolders = DustRemovalWhitelist;
+	type ValidatorInsurance = ();
+	type NativeCurrencyId = GetNativeCurrencyId;
+	type MaxBoostDuration = MaxBoostDuration;
+	type MaxBoostMultiplier = MaxBoostMultiplier;
+	type LoansBoostFullAmount = LoansBoostFullAmount;
+	type EarlyUnlockPenaltyRate = EarlyUnlockPenaltyRate;
+	type MaxBoostExpiriesPerBlock = MaxBoostExpiriesPerBlock;
+	type MaxRewardProgramsPerPool = MaxRewardProgramsPerPool;
 	type WeightInfo = weights::module_incentives::WeightInfo<Runtime>;
 }
 
@@ -1447,6 +1535,8 @@ parameter_types! {
 }
 
 pub type Trader = (
+	// the fee rates set on-chain take precedence
+	module_asset_registry::FixedRateOfAsset<Runtime, ToTreasury>,
 	FixedRateOfFungible<KsmPerSecond, ToTreasury>,
 	FixedRateOfFungible<KusdPerSecond, ToTreasury>,
 	FixedRateOfFungible<BncPerSecond, ToTreasury>,
@@ -1458,7 +1548,7 @@ impl xcm_executor::Config for XcmConfig {
 	type Call = Call;
 	type XcmSender = XcmRouter;
 	// How to withdraw and deposit an asset.
-	type AssetTransactor = LocalAssetTransactor;
+	type AssetTransactor = HomaLiteAssetTransactor;
 	type OriginConverter = XcmOriginToCallOrigin;
 	type IsReserve = MultiNativeAsset;
 	// Teleporting is disabled.
@@ -1545,6 +1635,7 @@ parameter_types! {
 	pub MaximumRedeemRequestMatchesForMint: u32 = 20;
 	pub RelaychainUnbondingSlashingSpans: u32 = 5;
 	pub MaxScheduledUnbonds: u32 = 14;
+	pub MaxXcmDepositMintsPerBlock: u32 = 10;
 	pub ParachainAccount: AccountId = ParachainInfo::get().into_account();
 	pub SubAccountIndex: u16 = RelaychainSubAccountId::HomaLite as u16;
 	pub const XcmUnbondFee: Balance = 600_000_000; // From homa-lite integration test.
@@ -1572,6 +1663,7 @@ impl module_homa_lite::Config for Runtime {
 	type MaximumRedeemRequestMatchesForMint = MaximumRedeemRequestMatchesForMint;
 	type RelaychainUnbondingSlashingSpans = RelaychainUnbondingSlashingSpans;
 	type MaxScheduledUnbonds = MaxScheduledUnbonds;
+	type MaxXcmDepositMintsPerBlock = MaxXcmDepositMintsPerBlock;
 }
 
 pub type LocalAssetTransactor = MultiCurrencyAdapter<
@@ -1584,16 +1676,42 @@ pub type LocalAssetTransactor = MultiCurrencyAdapter<
 	CurrencyIdConvert,
 >;
 
+/// Staking currency deposited into the Homa-lite sub-account of an account, i.e.
+/// `X2(AccountId32 { .. }, GeneralIndex(SubAccountIndex))`, is minted into Liquid currency.
+pub type HomaLiteAssetTransactor =
+	module_homa_lite::MintFromXcmDeposit<Runtime, LocationToAccountId, CurrencyIdConvert, LocalAssetTransactor>;
+
 //TODO: use token registry currency type encoding
 fn native_currency_location(id: CurrencyId) -> MultiLocation {
 	MultiLocation::new(1, X2(Parachain(ParachainInfo::get().into()), GeneralKey(id.encode())))
 }
 
+/// The native and locally issued tokens and the tokens of which the location is
+/// hard-coded in `CurrencyIdConvert`, their location can't be registered.
+pub struct FixedLocationTokens;
+impl Contains<CurrencyId> for FixedLocationTokens {
+	fn contains(currency_id: &CurrencyId) -> bool {
+		use CurrencyId::Token;
+		use TokenSymbol::*;
+
+		matches!(
+			currency_id,
+			Token(KSM) | Token(KAR) | Token(KUSD) | Token(LKSM) | Token(RENBTC) | Token(CASH) | Token(BNC) | Token(VSKSM)
+		)
+	}
+}
+
 pub struct CurrencyIdConvert;
 impl Convert<CurrencyId, Option<MultiLocation>> for CurrencyIdConvert {
 	fn convert(id: CurrencyId) -> Option<MultiLocation> {
 		use CurrencyId::Token;
 		use TokenSymbol::*;
+
+		// the locations registered on-chain take precedence
+		if let Some(location) = AssetRegistry::currency_id_to_location(id) {
+			return Some(location);
+		}
+
 		match id {
 			Token(KSM) => Some(MultiLocation::parent()),
 			Token(KAR) | Token(KUSD) | Token(LKSM) | Token(RENBTC) => Some(native_currency_location(id)),
@@ -1622,9 +1740,15 @@ impl Convert<MultiLocation, Option<CurrencyId>> for CurrencyIdConvert {
 		use CurrencyId::Token;
 		use TokenSymbol::*;
 
+		// the locations registered on-chain take precedence
+		if let Some(currency_id) = AssetRegistry::location_to_currency_ids(location.clone()) {
+			return Some(currency_id);
+		}
+
 		if location == MultiLocation::parent() {
 			return Some(Token(KSM));
 		}
+
 		match location {
 			MultiLocation {
 				parents,
@@ -1710,8 +1834,7 @@ impl orml_xcm::Config for Runtime {
 pub struct OnRuntimeUpgrade;
 impl frame_support::traits::OnRuntimeUpgrade for OnRuntimeUpgrade {
 	fn on_runtime_upgrade() -> u64 {
-		// no migration
-		0
+		module_incentives::migrations::v1::migrate::<Runtime>()
 	}
 }
 
@@ -1736,7 +1859,9 @@ construct_runtime!(
 		Tokens: orml_tokens::{Pallet, Storage, Event<T>, Config<T>} = 11,
 		Currencies: module_currencies::{Pallet, Call, Event<T>} = 12,
 		Vesting: orml_vesting::{Pallet, Storage, Call, Event<T>, Config<T>} = 13,
-		TransactionPayment: module_transaction_payment::{Pallet, Call, Storage} = 14,
+		TransactionPayment: module_transaction_payment::{Pallet, Call, Storage, Event<T>} = 14,
+		CurrencyFilter: module_currency_filter::{Pallet, Call, Storage, Event<T>} = 15,
+		AssetRegistry: module_asset_registry::{Pallet, Call, Storage, Event<T>} = 16,
 
 		// Treasury
 		Treasury: pallet_treasury::{Pallet, Call, Storage, Config, Event<T>} = 20,
@@ -1815,7 +1940,7 @@ construct_runtime!(
 		EVM: module_evm::{Pallet, Config<T>, Call, Storage, Event<T>} = 130,
 		EVMBridge: module_evm_bridge::{Pallet} = 131,
 		EvmAccounts: module_evm_accounts::{Pallet, Call, Storage, Event<T>} = 132,
-		EvmManager: module_evm_manager::{Pallet, Storage} = 133,
+		EvmManager: module_evm_manager::{Pallet, Call, Storage, Event<T>} = 133,
 
 		// Temporary
 		Sudo: pallet_sudo::{Pallet, Call, Config<T>, Storage, Event<T>} = 255,
@@ -1957,6 +2082,28 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl module_transaction_payment_rpc_runtime_api::FeeEstimationApi<
+		Block,
+		AccountId,
+		CurrencyId,
+		Balance,
+	> for Runtime {
+		fn query_fee_estimation(
+			uxt: <Block as BlockT>::Extrinsic,
+			len: u32,
+			account: AccountId,
+		) -> module_transaction_payment_rpc_runtime_api::FeeEstimation<CurrencyId, Balance> {
+			let (native_fee, fee_swap_path, supply_amount, can_charge_fee) =
+				TransactionPayment::query_fee_estimation(account, uxt, len);
+			module_transaction_payment_rpc_runtime_api::FeeEstimation {
+				native_fee,
+				fee_swap_path,
+				supply_amount,
+				can_charge_fee,
+			}
+		}
+	}
+
 	impl orml_oracle_rpc_runtime_api::OracleApi<
 		Block,
 		DataProviderId,
@@ -1978,6 +2125,66 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl module_incentives_rpc_runtime_api::IncentivesApi<
+		Block,
+		AccountId,
+		module_incentives::PoolId<AccountId>,
+		CurrencyId,
+		BlockNumber,
+		Balance,
+	> for Runtime {
+		fn active_reward_programs() -> Vec<module_incentives_rpc_runtime_api::RewardProgramInfo<
+			module_incentives::PoolId<AccountId>,
+			CurrencyId,
+			BlockNumber,
+			Balance,
+		>> {
+			Incentives::active_reward_programs(System::block_number())
+				.into_iter()
+				.map(|(pool_id, program_id, program)| module_incentives_rpc_runtime_api::RewardProgramInfo {
+					pool_total_shares: Rewards::pool_infos(&pool_id).total_shares,
+					pool_id,
+					program_id,
+					currency_id: program.currency_id,
+					start: program.start,
+					end: program.end,
+					total_budget: program.total_budget,
+					remaining_budget: program.remaining_budget,
+					amount_per_period: program.amount_per_period,
+					accumulate_period: AccumulatePeriod::get(),
+				})
+				.collect()
+		}
+
+		fn pending_rewards(account: AccountId) -> Vec<module_incentives_rpc_runtime_api::PendingRewardsInfo<
+			module_incentives::PoolId<AccountId>,
+			CurrencyId,
+			Balance,
+		>> {
+			Incentives::pending_rewards(&account)
+				.into_iter()
+				.map(|(pool_id, shares, rewards)| module_incentives_rpc_runtime_api::PendingRewardsInfo {
+					pool_id,
+					shares,
+					rewards: rewards
+						.into_iter()
+						.map(|(currency_id, pending_amount, actual_amount)| module_incentives_rpc_runtime_api::PendingReward {
+							currency_id,
+							pending_amount,
+							actual_amount,
+						})
+						.collect(),
+				})
+				.collect()
+		}
+	}
+
+	impl module_evm_manager_rpc_runtime_api::EvmManagerApi<Block> for Runtime {
+		fn registered_erc20s() -> Vec<(u32, primitives::evm::Erc20Info)> {
+			EvmManager::registered_erc20s()
+		}
+	}
+
 	impl module_staking_pool_rpc_runtime_api::StakingPoolApi<
 		Block,
 		AccountId,
@@ -2125,10 +2332,13 @@ impl_runtime_apis! {
 			orml_list_benchmark!(list, extra, module_collator_selection, benchmarking::collator_selection);
 			// orml_list_benchmark!(list, extra, module_nominees_election, benchmarking::nominees_election);
 			orml_list_benchmark!(list, extra, module_transaction_pause, benchmarking::transaction_pause);
+			orml_list_benchmark!(list, extra, module_currency_filter, benchmarking::currency_filter);
+			orml_list_benchmark!(list, extra, module_asset_registry, benchmarking::asset_registry);
 			orml_list_benchmark!(list, extra, module_transaction_payment, benchmarking::transaction_payment);
 			orml_list_benchmark!(list, extra, module_incentives, benchmarking::incentives);
 			orml_list_benchmark!(list, extra, module_prices, benchmarking::prices);
 			orml_list_benchmark!(list, extra, module_evm_accounts, benchmarking::evm_accounts);
+			orml_list_benchmark!(list, extra, module_evm_manager, benchmarking::evm_manager);
 			// orml_list_benchmark!(list, extra, module_homa, benchmarking::homa);
 			orml_list_benchmark!(list, extra, module_currencies, benchmarking::currencies);
 			orml_list_benchmark!(list, extra, module_session_manager, benchmarking::session_manager);
@@ -2185,10 +2395,13 @@ impl_runtime_apis! {
 			orml_add_benchmark!(params, batches, module_collator_selection, benchmarking::collator_selection);
 			// orml_add_benchmark!(params, batches, module_nominees_election, benchmarking::nominees_election);
 			orml_add_benchmark!(params, batches, module_transaction_pause, benchmarking::transaction_pause);
+			orml_add_benchmark!(params, batches, module_currency_filter, benchmarking::currency_filter);
+			orml_add_benchmark!(params, batches, module_asset_registry, benchmarking::asset_registry);
 			orml_add_benchmark!(params, batches, module_transaction_payment, benchmarking::transaction_payment);
 			orml_add_benchmark!(params, batches, module_incentives, benchmarking::incentives);
 			orml_add_benchmark!(params, batches, module_prices, benchmarking::prices);
 			orml_add_benchmark!(params, batches, module_evm_accounts, benchmarking::evm_accounts);
+			orml_add_benchmark!(params, batches, module_evm_manager, benchmarking::evm_manager);
 			// orml_add_benchmark!(params, batches, module_homa, benchmarking::homa);
 			orml_add_benchmark!(params, batches, module_currencies, benchmarking::currencies);
 			orml_add_benchmark!(params, batches, module_session_manager, benchmarking::session_manager);
@@ -69,6 +69,17 @@ impl<T: frame_system::Config> module_incentives::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(7 as Weight))
 			.saturating_add(T::DbWeight::get().writes(5 as Weight))
 	}
+	fn claim_and_restake() -> Weight {
+		// composed of the benchmarked steps: claim, a swap of the reward currency, a swap to
+		// balance the amounts, add liquidity and deposit the share
+		<Self as module_incentives::WeightInfo>::claim_rewards()
+			.saturating_add(
+				<super::module_dex::WeightInfo<T> as module_dex::WeightInfo>::swap_with_exact_supply(2)
+					.saturating_mul(2),
+			)
+			.saturating_add(<super::module_dex::WeightInfo<T> as module_dex::WeightInfo>::add_liquidity())
+			.saturating_add(<Self as module_incentives::WeightInfo>::deposit_dex_share())
+	}
 	fn update_incentive_rewards(c: u32, ) -> Weight {
 		(2_507_000 as Weight)
 			// Standard Error: 45_000
@@ -76,6 +87,25 @@ impl<T: frame_system::Config> module_incentives::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(c as Weight)))
 			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
 	}
+	fn add_reward_program() -> Weight {
+		// not generated by the benchmark CLI yet, estimated from the similar benchmarked calls
+		(63_714_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(15 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	fn remove_reward_program() -> Weight {
+		// not generated by the benchmark CLI yet, estimated from the similar benchmarked calls
+		(58_210_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	fn accumulate_reward_programs(c: u32, ) -> Weight {
+		// not generated by the benchmark CLI yet, estimated from the similar benchmarked calls
+		(0 as Weight)
+			.saturating_add((46_372_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes((4 as Weight).saturating_mul(c as Weight)))
+	}
 	fn update_dex_saving_rewards(c: u32, ) -> Weight {
 		(1_953_000 as Weight)
 			// Standard Error: 18_000
@@ -90,4 +120,24 @@ impl<T: frame_system::Config> module_incentives::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(c as Weight)))
 			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
 	}
+	fn lock_boost() -> Weight {
+		// not generated by the benchmark CLI yet, estimated from the similar benchmarked calls
+		(231_328_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(15 as Weight))
+			.saturating_add(T::DbWeight::get().writes(12 as Weight))
+	}
+	fn unlock_boost() -> Weight {
+		// not generated by the benchmark CLI yet, estimated from the similar benchmarked calls
+		(239_402_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(15 as Weight))
+			.saturating_add(T::DbWeight::get().writes(13 as Weight))
+	}
+	fn expire_boosts(c: u32, ) -> Weight {
+		// not generated by the benchmark CLI yet, estimated from the similar benchmarked calls
+		(13_303_000 as Weight)
+			.saturating_add((91_909_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((9 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes((6 as Weight).saturating_mul(c as Weight)))
+	}
 }
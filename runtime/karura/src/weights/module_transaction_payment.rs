@@ -56,4 +56,41 @@ impl<T: frame_system::Config> module_transaction_payment::WeightInfo for WeightI
 			.saturating_add(T::DbWeight::get().reads(2 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	fn with_fee_currency() -> Weight {
+		// not generated by the benchmark CLI yet, estimated from the similar benchmarked calls
+		(93_412_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+	fn set_sponsorship() -> Weight {
+		// not generated by the benchmark CLI yet, estimated from the similar benchmarked calls
+		(8_820_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn remove_sponsorship(c: u32, ) -> Weight {
+		// not generated by the benchmark CLI yet, estimated from the similar benchmarked calls
+		(22_100_000 as Weight)
+			.saturating_add((3_020_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
+	fn set_sponsored_call() -> Weight {
+		// not generated by the benchmark CLI yet, estimated from the similar benchmarked calls
+		(11_760_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	fn set_sponsored_user() -> Weight {
+		// not generated by the benchmark CLI yet, estimated from the similar benchmarked calls
+		(13_230_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	fn with_sponsor() -> Weight {
+		// not generated by the benchmark CLI yet, estimated from the similar benchmarked calls
+		(17_640_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
 }
@@ -0,0 +1,77 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{AssetRegistry, CurrencyId, Runtime, TokenSymbol};
+
+use frame_system::RawOrigin;
+use orml_benchmarking::runtime_benchmarks;
+use primitives::AssetMetadata;
+use sp_std::{boxed::Box, vec};
+use xcm::{latest::prelude::*, VersionedMultiLocation};
+
+fn dummy_location() -> VersionedMultiLocation {
+	MultiLocation::new(1, X2(Parachain(1000), GeneralKey(vec![0]))).into()
+}
+
+const CURRENCY_ID: CurrencyId = CurrencyId::Token(TokenSymbol::BNC);
+
+fn dummy_metadata() -> AssetMetadata<u128> {
+	AssetMetadata {
+		name: b"Token Name".to_vec(),
+		symbol: b"TN".to_vec(),
+		decimals: 12,
+		minimal_balance: 1,
+	}
+}
+
+runtime_benchmarks! {
+	{ Runtime, module_asset_registry }
+
+	register_foreign_asset {
+	}: _(RawOrigin::Root, Box::new(dummy_location()), Box::new(dummy_metadata()))
+
+	update_foreign_asset {
+		AssetRegistry::register_foreign_asset(RawOrigin::Root.into(), Box::new(dummy_location()), Box::new(dummy_metadata()))?;
+		let location: VersionedMultiLocation = MultiLocation::new(1, X2(Parachain(1000), GeneralKey(vec![1]))).into();
+	}: _(RawOrigin::Root, 0, Box::new(location), Box::new(dummy_metadata()))
+
+	// replace the registered location and move its fee rate
+	register_location {
+		AssetRegistry::register_location(RawOrigin::Root.into(), CURRENCY_ID, Box::new(dummy_location()))?;
+		AssetRegistry::set_fee_rate(RawOrigin::Root.into(), Box::new(dummy_location()), Some(1_000_000))?;
+		let location: VersionedMultiLocation = MultiLocation::new(1, X2(Parachain(1000), GeneralKey(vec![1]))).into();
+	}: _(RawOrigin::Root, CURRENCY_ID, Box::new(location))
+
+	deregister_location {
+		AssetRegistry::register_location(RawOrigin::Root.into(), CURRENCY_ID, Box::new(dummy_location()))?;
+		AssetRegistry::set_fee_rate(RawOrigin::Root.into(), Box::new(dummy_location()), Some(1_000_000))?;
+	}: _(RawOrigin::Root, CURRENCY_ID)
+
+	set_fee_rate {
+		AssetRegistry::register_location(RawOrigin::Root.into(), CURRENCY_ID, Box::new(dummy_location()))?;
+	}: _(RawOrigin::Root, Box::new(dummy_location()), Some(1_000_000))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::benchmarking::utils::tests::new_test_ext;
+	use orml_benchmarking::impl_benchmark_test_suite;
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
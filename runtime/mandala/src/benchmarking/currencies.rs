@@ -121,6 +121,36 @@ runtime_benchmarks! {
 		assert_eq!(<Currencies as MultiCurrency<_>>::free_balance(NATIVE, &who), 0);
 	}
 
+	// `transfer_batch` non-native currency
+	transfer_batch {
+		let c in 1..100u32;
+		let amount: Balance = 1_000 * dollar(STAKING);
+		let from: AccountId = whitelisted_caller();
+		set_balance(STAKING, &from, amount * c as Balance);
+
+		let mut transfers = vec![];
+		for i in 0..c {
+			let to: AccountId = account("to", i, SEED);
+			transfers.push((lookup_of_account(to), STAKING, amount));
+		}
+	}: _(RawOrigin::Signed(from.clone()), transfers)
+	verify {
+		assert_eq!(<Currencies as MultiCurrency<_>>::free_balance(STAKING, &from), 0);
+	}
+
+	// `transfer_all` non-native currency
+	transfer_all {
+		let amount: Balance = 1_000 * dollar(STAKING);
+		let from: AccountId = whitelisted_caller();
+		set_balance(STAKING, &from, amount);
+
+		let to: AccountId = account("to", 0, SEED);
+		let to_lookup = lookup_of_account(to.clone());
+	}: _(RawOrigin::Signed(from), to_lookup, STAKING, false)
+	verify {
+		assert_eq!(<Currencies as MultiCurrency<_>>::total_balance(STAKING, &to), amount);
+	}
+
 	sweep_dust {
 		let c in 1..3u32;
 		let treasury: AccountId = TreasuryPalletId::get().into_account();
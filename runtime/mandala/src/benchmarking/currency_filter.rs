@@ -0,0 +1,48 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{AccountId, CurrencyId, GetStableCurrencyId, Runtime};
+
+use frame_benchmarking::account;
+use frame_system::RawOrigin;
+use module_currency_filter::FilterMode;
+use orml_benchmarking::runtime_benchmarks;
+
+const SEED: u32 = 0;
+
+const STABLECOIN: CurrencyId = GetStableCurrencyId::get();
+
+runtime_benchmarks! {
+	{ Runtime, module_currency_filter }
+
+	set_filter_mode {
+	}: _(RawOrigin::Root, STABLECOIN, Some(FilterMode::DenyList))
+
+	set_listed_account {
+		let who: AccountId = account("who", 0, SEED);
+	}: _(RawOrigin::Root, STABLECOIN, who, true)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::benchmarking::utils::tests::new_test_ext;
+	use orml_benchmarking::impl_benchmark_test_suite;
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
@@ -0,0 +1,83 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+	dollar, CurrencyId, EvmManager, GetNativeCurrencyId, NetworkContractSource, Origin, Runtime, System, EVM,
+};
+
+use super::utils::set_balance;
+use frame_support::dispatch::DispatchError;
+use frame_system::RawOrigin;
+use module_support::AddressMapping;
+use orml_benchmarking::runtime_benchmarks;
+use primitives::evm::EvmAddress;
+use sp_std::prelude::*;
+
+const NATIVE: CurrencyId = GetNativeCurrencyId::get();
+
+fn erc20_code() -> Vec<u8> {
+	let code: &str = include!("../../../../modules/evm-bridge/src/erc20_demo_contract");
+	(2..code.len())
+		.step_by(2)
+		.map(|i| u8::from_str_radix(&code[i..i + 2], 16).unwrap())
+		.collect()
+}
+
+fn deploy_erc20() -> Result<EvmAddress, DispatchError> {
+	System::set_block_number(1);
+	let source = module_evm_accounts::EvmAddressMapping::<Runtime>::get_account_id(&NetworkContractSource::get());
+	set_balance(NATIVE, &source, 1_000 * dollar(NATIVE));
+
+	let address = EvmAddress::from_low_u64_be(EVM::network_contract_index());
+	EVM::create_network_contract(Origin::root(), erc20_code(), 0, 2_100_000, 10_000).map_err(|e| e.error)?;
+	EVM::deploy_free(Origin::root(), address).map_err(|e| e.error)?;
+	Ok(address)
+}
+
+runtime_benchmarks! {
+	{ Runtime, module_evm_manager }
+
+	register_erc20 {
+		let address = deploy_erc20()?;
+	}: _(RawOrigin::Root, address)
+	verify {
+		assert!(EvmManager::get_currency_id(address).is_some());
+	}
+
+	refresh_erc20_metadata {
+		let address = deploy_erc20()?;
+		EvmManager::register_erc20(Origin::root(), address)?;
+	}: _(RawOrigin::Root, address)
+
+	deregister_erc20 {
+		let address = deploy_erc20()?;
+		EvmManager::register_erc20(Origin::root(), address)?;
+	}: _(RawOrigin::Root, address)
+	verify {
+		assert_eq!(EvmManager::get_currency_id(address), None);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::benchmarking::utils::tests::new_test_ext;
+	use orml_benchmarking::impl_benchmark_test_suite;
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
@@ -0,0 +1,63 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+use crate::{
+	AccountId, CurrencyId, GetLiquidCurrencyId, HomaValidatorListModule, MinGuaranteeAmount, ReportedSlashesEraDepth,
+	Runtime, SlashFreezeThreshold,
+};
+
+use super::utils::set_balance;
+use frame_benchmarking::account;
+use frame_system::RawOrigin;
+use module_homa_validator_list::SlashInfo;
+use orml_benchmarking::runtime_benchmarks;
+use sp_std::prelude::*;
+
+const SEED: u32 = 0;
+
+const LIQUID: CurrencyId = GetLiquidCurrencyId::get();
+
+runtime_benchmarks! {
+	{ Runtime, module_homa_validator_list }
+
+	report_slashes {
+		let n in 1 .. 100;
+
+		let guarantor: AccountId = account("guarantor", 0, SEED);
+		set_balance(LIQUID, &guarantor, MinGuaranteeAmount::get() * n as u128);
+		let mut slashes = vec![];
+		let mut pruned_slashes = vec![];
+		for i in 0..n {
+			let validator: AccountId = account("validator", i, SEED);
+			HomaValidatorListModule::bond(RawOrigin::Signed(guarantor.clone()).into(), validator.clone(), MinGuaranteeAmount::get())?;
+			slashes.push(SlashInfo::new(validator, SlashFreezeThreshold::get() + 1));
+			pruned_slashes.push(SlashInfo::new(account("pruned", i, SEED), 1));
+		}
+
+		// fill the era that will be pruned by this report
+		HomaValidatorListModule::report_slashes(RawOrigin::Root.into(), 0, pruned_slashes)?;
+	}: _(RawOrigin::Root, ReportedSlashesEraDepth::get(), slashes)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::benchmarking::utils::tests::new_test_ext;
+	use orml_benchmarking::impl_benchmark_test_suite;
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
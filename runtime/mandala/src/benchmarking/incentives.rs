@@ -17,22 +17,26 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-	dollar, AccountId, AccumulatePeriod, CollateralCurrencyIds, Currencies, CurrencyId, GetNativeCurrencyId,
-	GetStableCurrencyId, GetStakingCurrencyId, Incentives, Rate, Rewards, Runtime, System,
+	dollar, AccountId, AccumulatePeriod, CollateralCurrencyIds, Currencies, CurrencyId, Dex, GetNativeCurrencyId,
+	GetStableCurrencyId, GetStakingCurrencyId, Incentives, MaxBoostDuration, MaxBoostExpiriesPerBlock,
+	MaxRewardProgramsPerPool, Price, Rate, Rewards, Runtime, System, UnreleasedNativeVaultAccountId,
 };
 
-use super::utils::set_balance;
+use super::utils::{feed_price, set_balance};
 use frame_benchmarking::{account, whitelisted_caller};
 use frame_support::traits::OnInitialize;
 use frame_system::RawOrigin;
-use module_incentives::PoolId;
+use module_incentives::{BoostTarget, OnUpdateLoan};
 use orml_benchmarking::runtime_benchmarks;
-use orml_traits::MultiCurrency;
+use orml_traits::{Happened, MultiCurrency};
 use primitives::DexShare;
+use sp_runtime::FixedPointNumber;
 use sp_std::prelude::*;
 
 const SEED: u32 = 0;
 
+type PoolId = module_incentives::PoolId<AccountId>;
+
 const NATIVE: CurrencyId = GetNativeCurrencyId::get();
 const STAKING: CurrencyId = GetStakingCurrencyId::get();
 const STABLECOIN: CurrencyId = GetStableCurrencyId::get();
@@ -78,6 +82,47 @@ runtime_benchmarks! {
 		)?;
 	}: _(RawOrigin::Signed(caller), native_stablecoin_lp, 8000 * dollar(STABLECOIN))
 
+	// worst case: boost the shares of all Pool::Loans
+	lock_boost {
+		let caller: AccountId = whitelisted_caller();
+		set_balance(NATIVE, &caller, 10_000 * dollar(NATIVE));
+		for currency_id in CollateralCurrencyIds::get() {
+			OnUpdateLoan::<Runtime>::happened(&(caller.clone(), currency_id, 100, 0));
+		}
+	}: _(RawOrigin::Signed(caller), BoostTarget::Loans, 10_000 * dollar(NATIVE), MaxBoostDuration::get())
+
+	// worst case: unlock before expiry with penalty, and update the shares of all Pool::Loans
+	unlock_boost {
+		let caller: AccountId = whitelisted_caller();
+		set_balance(NATIVE, &caller, 10_000 * dollar(NATIVE));
+		for currency_id in CollateralCurrencyIds::get() {
+			OnUpdateLoan::<Runtime>::happened(&(caller.clone(), currency_id, 100, 0));
+		}
+		Incentives::lock_boost(
+			RawOrigin::Signed(caller.clone()).into(),
+			BoostTarget::Loans,
+			10_000 * dollar(NATIVE),
+			MaxBoostDuration::get()
+		)?;
+	}: _(RawOrigin::Signed(caller), BoostTarget::Loans)
+
+	expire_boosts {
+		let c in 1 .. MaxBoostExpiriesPerBlock::get().saturating_sub(1);
+		let currency_ids = CollateralCurrencyIds::get();
+
+		for i in 0 .. c {
+			let caller: AccountId = account("caller", i, SEED);
+			set_balance(NATIVE, &caller, 10_000 * dollar(NATIVE));
+			for currency_id in currency_ids.iter() {
+				OnUpdateLoan::<Runtime>::happened(&(caller.clone(), *currency_id, 100, 0));
+			}
+			Incentives::lock_boost(RawOrigin::Signed(caller).into(), BoostTarget::Loans, 10_000 * dollar(NATIVE), 1)?;
+		}
+		let block_number = System::block_number() + 1;
+	}: {
+		Incentives::on_initialize(block_number);
+	}
+
 	claim_rewards {
 		let caller: AccountId = whitelisted_caller();
 		let pool_id = PoolId::Loans(STAKING);
@@ -88,6 +133,31 @@ runtime_benchmarks! {
 		Rewards::accumulate_reward(&pool_id, native_currency_id, 80 * dollar(native_currency_id))?;
 	}: _(RawOrigin::Signed(caller), pool_id)
 
+	claim_and_restake {
+		let caller: AccountId = whitelisted_caller();
+		let maker: AccountId = account("maker", 0, SEED);
+		let native_stablecoin_lp = CurrencyId::join_dex_share_currency_id(NATIVE, STABLECOIN).unwrap();
+		let pool_id = PoolId::Dex(native_stablecoin_lp);
+
+		set_balance(NATIVE, &maker, 10_000 * dollar(NATIVE));
+		set_balance(STABLECOIN, &maker, 10_000 * dollar(STABLECOIN));
+		let _ = Dex::enable_trading_pair(RawOrigin::Root.into(), NATIVE, STABLECOIN);
+		Dex::add_liquidity(
+			RawOrigin::Signed(maker).into(),
+			NATIVE,
+			STABLECOIN,
+			10_000 * dollar(NATIVE),
+			10_000 * dollar(STABLECOIN),
+			Default::default(),
+			false,
+		)?;
+
+		feed_price(vec![(NATIVE, Price::saturating_from_integer(1))])?;
+		Rewards::add_share(&caller, &pool_id, 100);
+		Currencies::deposit(NATIVE, &Incentives::account_id(), 80 * dollar(NATIVE))?;
+		Rewards::accumulate_reward(&pool_id, NATIVE, 80 * dollar(NATIVE))?;
+	}: _(RawOrigin::Signed(caller), native_stablecoin_lp, 0)
+
 	update_incentive_rewards {
 		let c in 0 .. CollateralCurrencyIds::get().len().saturating_sub(1) as u32;
 		let currency_ids = CollateralCurrencyIds::get();
@@ -99,6 +169,59 @@ runtime_benchmarks! {
 		}
 	}: _(RawOrigin::Root, updates)
 
+	// worst case: the pool has MaxRewardProgramsPerPool - 1 reward programs
+	add_reward_program {
+		let block_number = System::block_number();
+		set_balance(NATIVE, &UnreleasedNativeVaultAccountId::get(), 10_000 * dollar(NATIVE));
+		for _ in 1 .. MaxRewardProgramsPerPool::get() {
+			Incentives::add_reward_program(
+				RawOrigin::Root.into(),
+				PoolId::Loans(STAKING),
+				NATIVE,
+				block_number,
+				block_number + 100 * AccumulatePeriod::get(),
+				100 * dollar(NATIVE)
+			)?;
+		}
+	}: _(RawOrigin::Root, PoolId::Loans(STAKING), NATIVE, block_number, block_number + 100 * AccumulatePeriod::get(), 100 * dollar(NATIVE))
+
+	remove_reward_program {
+		let block_number = System::block_number();
+		set_balance(NATIVE, &UnreleasedNativeVaultAccountId::get(), 10_000 * dollar(NATIVE));
+		Incentives::add_reward_program(
+			RawOrigin::Root.into(),
+			PoolId::Loans(STAKING),
+			NATIVE,
+			block_number,
+			block_number + 100 * AccumulatePeriod::get(),
+			100 * dollar(NATIVE)
+		)?;
+	}: _(RawOrigin::Root, PoolId::Loans(STAKING), 0)
+
+	// worst case: all the reward programs of the pool finish
+	accumulate_reward_programs {
+		let c in 1 .. MaxRewardProgramsPerPool::get();
+		let block_number = AccumulatePeriod::get();
+		let pool_id = PoolId::Loans(STAKING);
+		set_balance(NATIVE, &UnreleasedNativeVaultAccountId::get(), 10_000 * dollar(NATIVE));
+		for _ in 0 .. c {
+			Incentives::add_reward_program(
+				RawOrigin::Root.into(),
+				pool_id.clone(),
+				NATIVE,
+				block_number,
+				block_number + AccumulatePeriod::get(),
+				100 * dollar(NATIVE)
+			)?;
+		}
+		orml_rewards::PoolInfos::<Runtime>::mutate(&pool_id, |pool_info| {
+			pool_info.total_shares += 100;
+		});
+		System::set_block_number(block_number);
+	}: {
+		Incentives::on_initialize(System::block_number());
+	}
+
 	update_dex_saving_rewards {
 		let c in 0 .. CollateralCurrencyIds::get().len().saturating_sub(1) as u32;
 		let currency_ids = CollateralCurrencyIds::get();
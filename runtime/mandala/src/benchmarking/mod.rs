@@ -23,16 +23,20 @@ use sp_runtime::traits::AccountIdConversion;
 pub mod utils;
 
 // module benchmarking
+pub mod asset_registry;
 pub mod auction_manager;
 pub mod cdp_engine;
 pub mod cdp_treasury;
 pub mod collator_selection;
 pub mod currencies;
+pub mod currency_filter;
 pub mod dex;
 pub mod emergency_shutdown;
 pub mod evm;
 pub mod evm_accounts;
+pub mod evm_manager;
 pub mod homa;
+pub mod homa_validator_list;
 pub mod honzon;
 pub mod incentives;
 pub mod nominees_election;
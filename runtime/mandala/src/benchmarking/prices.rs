@@ -16,15 +16,20 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{CurrencyId, GetStableCurrencyId, Origin, Price, Prices, Runtime};
+use crate::{
+	CollateralCurrencyIds, CurrencyId, GetNativeCurrencyId, GetStableCurrencyId, Origin, Price, Prices, Ratio, Runtime,
+};
 
 use super::utils::feed_price;
+use frame_support::traits::OnInitialize;
 use frame_system::RawOrigin;
+use module_prices::DexPriceRule;
 use orml_benchmarking::runtime_benchmarks;
-use sp_runtime::traits::One;
-use sp_std::vec;
+use sp_runtime::{traits::One, FixedPointNumber};
+use sp_std::{prelude::*, vec};
 
 const STAKING: CurrencyId = GetStableCurrencyId::get();
+const NATIVE: CurrencyId = GetNativeCurrencyId::get();
 
 runtime_benchmarks! {
 	{ Runtime, module_prices }
@@ -39,6 +44,31 @@ runtime_benchmarks! {
 		feed_price(vec![(STAKING, Price::one())])?;
 		Prices::lock_price(Origin::root(), STAKING)?;
 	}: _(RawOrigin::Root, STAKING)
+
+	set_source_weights {
+	}: _(RawOrigin::Root, STAKING, vec![1, 1])
+
+	set_price_change_bound {
+	}: _(RawOrigin::Root, STAKING, Some(Ratio::saturating_from_rational(20, 100)))
+
+	set_dex_price_rule {
+	}: _(RawOrigin::Root, NATIVE, Some(DexPriceRule { path: vec![NATIVE, STAKING], min_liquidity: 1_000_000 }))
+
+	on_initialize {
+		let c in 0 .. CollateralCurrencyIds::get().len() as u32;
+		let currency_ids: Vec<CurrencyId> = CollateralCurrencyIds::get().into_iter().take(c as usize).collect();
+
+		let bound = Ratio::saturating_from_rational(20, 100);
+		for currency_id in currency_ids.iter() {
+			Prices::set_price_change_bound(Origin::root(), *currency_id, Some(bound))?;
+		}
+		feed_price(currency_ids.iter().map(|currency_id| (*currency_id, Price::one())).collect())?;
+		Prices::on_initialize(1);
+		// every price moves beyond the bound and triggers the circuit breaker
+		feed_price(currency_ids.iter().map(|currency_id| (*currency_id, Price::saturating_from_integer(2))).collect())?;
+	}: {
+		Prices::on_initialize(2);
+	}
 }
 
 #[cfg(test)]
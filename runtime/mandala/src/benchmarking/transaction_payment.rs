@@ -16,15 +16,21 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{AccountId, CurrencyId, GetNativeCurrencyId, GetStableCurrencyId, Runtime, System, TransactionPayment};
-use frame_benchmarking::whitelisted_caller;
+use crate::{
+	dollar, AccountId, Call, CurrencyId, GetNativeCurrencyId, GetStableCurrencyId, Runtime, System, TransactionPayment,
+};
+
+use super::utils::set_balance;
+use frame_benchmarking::{account, whitelisted_caller};
 use frame_support::traits::OnFinalize;
 use frame_system::RawOrigin;
+use module_transaction_payment::CallNameOf;
 use orml_benchmarking::runtime_benchmarks;
-use sp_std::prelude::*;
+use sp_std::{convert::TryInto, prelude::*};
 
 const STABLECOIN: CurrencyId = GetStableCurrencyId::get();
 const NATIVECOIN: CurrencyId = GetNativeCurrencyId::get();
+const SEED: u32 = 0;
 
 runtime_benchmarks! {
 	{ Runtime, module_transaction_payment }
@@ -40,6 +46,57 @@ runtime_benchmarks! {
 	}: {
 		TransactionPayment::on_finalize(System::block_number());
 	}
+
+	with_fee_currency {
+		let caller: AccountId = whitelisted_caller();
+		let call = Box::new(Call::System(frame_system::Call::remark(vec![])));
+	}: _(RawOrigin::Signed(caller), STABLECOIN, call)
+
+	set_sponsorship {
+		let caller: AccountId = whitelisted_caller();
+	}: _(RawOrigin::Signed(caller.clone()), 1_000_000, 1_000, 10_000)
+	verify {
+		assert!(TransactionPayment::sponsorships(&caller).is_some());
+	}
+
+	remove_sponsorship {
+		let c in 0 .. 100;
+		let caller: AccountId = whitelisted_caller();
+		set_balance(NATIVECOIN, &caller, 10_000 * dollar(NATIVECOIN));
+		TransactionPayment::set_sponsorship(RawOrigin::Signed(caller.clone()).into(), 1_000_000, 1_000, 10_000)?;
+		for i in 0 .. c {
+			TransactionPayment::set_sponsored_user(RawOrigin::Signed(caller.clone()).into(), account("user", i, SEED), true)?;
+		}
+	}: _(RawOrigin::Signed(caller.clone()), c)
+	verify {
+		assert!(TransactionPayment::sponsorships(&caller).is_none());
+	}
+
+	set_sponsored_call {
+		let caller: AccountId = whitelisted_caller();
+		set_balance(NATIVECOIN, &caller, 10_000 * dollar(NATIVECOIN));
+		TransactionPayment::set_sponsorship(RawOrigin::Signed(caller.clone()).into(), 1_000_000, 1_000, 10_000)?;
+	}: _(RawOrigin::Signed(caller.clone()), b"System".to_vec(), b"remark".to_vec(), true)
+	verify {
+		let call_name: (CallNameOf<Runtime>, CallNameOf<Runtime>) = (b"System".to_vec().try_into().unwrap(), b"remark".to_vec().try_into().unwrap());
+		assert!(TransactionPayment::sponsored_calls(&caller, call_name).is_some());
+	}
+
+	set_sponsored_user {
+		let caller: AccountId = whitelisted_caller();
+		let user: AccountId = account("user", 0, SEED);
+		set_balance(NATIVECOIN, &caller, 10_000 * dollar(NATIVECOIN));
+		TransactionPayment::set_sponsorship(RawOrigin::Signed(caller.clone()).into(), 1_000_000, 1_000, 10_000)?;
+	}: _(RawOrigin::Signed(caller.clone()), user.clone(), true)
+	verify {
+		assert_eq!(TransactionPayment::sponsored_users(&caller, &user), Some(0));
+	}
+
+	with_sponsor {
+		let caller: AccountId = whitelisted_caller();
+		let sponsor: AccountId = account("sponsor", 0, SEED);
+		let call = Box::new(Call::System(frame_system::Call::remark(vec![])));
+	}: _(RawOrigin::Signed(caller), sponsor, call)
 }
 
 #[cfg(test)]
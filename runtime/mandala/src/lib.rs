@@ -206,8 +206,17 @@ parameter_types! {
 pub struct BaseCallFilter;
 impl Contains<Call> for BaseCallFilter {
 	fn contains(call: &Call) -> bool {
+		let is_filtered_native_transfer = matches!(
+			call,
+			Call::Balances(pallet_balances::Call::transfer(..))
+				| Call::Balances(pallet_balances::Call::transfer_keep_alive(..))
+				| Call::Balances(pallet_balances::Call::transfer_all(..))
+		) && CurrencyFilter::is_filtered(GetNativeCurrencyId::get());
+
 		!module_transaction_pause::PausedTransactionFilter::<Runtime>::contains(call)
 			&& !matches!(call, Call::Democracy(pallet_democracy::Call::propose(..)),)
+			// the filtered native currency can only be transferred by `Currencies`
+			&& !is_filtered_native_transfer
 	}
 }
 
@@ -817,6 +826,10 @@ parameter_type_with_key! {
 			},
 			CurrencyId::Erc20(_) => Balance::max_value(), // not handled by orml-tokens
 			CurrencyId::StableAssetPoolToken(_) => 1, // TODO: update this before we enable StableAsset
+			CurrencyId::ForeignAsset(foreign_asset_id) => {
+				AssetRegistry::asset_metadatas(foreign_asset_id)
+					.map_or(Balance::max_value(), |metadata| metadata.minimal_balance)
+			},
 		}
 	};
 }
@@ -839,11 +852,33 @@ impl orml_tokens::Config for Runtime {
 
 parameter_types! {
 	pub StableCurrencyFixedPrice: Price = Price::saturating_from_rational(1, 1);
+	pub const MaxSourcePriceAge: Moment = 1000 * 60 * 60; // 60 mins
+	pub MaxSourceDeviation: Ratio = Ratio::saturating_from_rational(10, 100);
+	pub const MinSourcesQuorum: u32 = 1;
+	pub const CircuitBreakerCoolDown: BlockNumber = 6 * HOURS;
+	pub const DexPriceAveragingPeriod: Moment = 1000 * 60 * 30; // 30 mins
+}
+
+/// The oracle instances as the price sources of prices module.
+pub struct OraclePriceSources;
+impl module_support::MultiSourcePriceProvider<CurrencyId> for OraclePriceSources {
+	fn get_prices(currency_id: &CurrencyId) -> Vec<Option<(Price, Moment)>> {
+		vec![
+			AcalaOracle::get_no_op(currency_id).map(|v| (v.value, v.timestamp)),
+			BandOracle::get_no_op(currency_id).map(|v| (v.value, v.timestamp)),
+		]
+	}
 }
 
 impl module_prices::Config for Runtime {
 	type Event = Event;
-	type Source = AggregatedDataProvider;
+	type Source = OraclePriceSources;
+	type UnixTime = Timestamp;
+	type MaxSourcePriceAge = MaxSourcePriceAge;
+	type MaxSourceDeviation = MaxSourceDeviation;
+	type MinSourcesQuorum = MinSourcesQuorum;
+	type CircuitBreakerCoolDown = CircuitBreakerCoolDown;
+	type DexPriceAveragingPeriod = DexPriceAveragingPeriod;
 	type GetStableCurrencyId = GetStableCurrencyId;
 	type StableCurrencyFixedPrice = StableCurrencyFixedPrice;
 	type GetStakingCurrencyId = GetStakingCurrencyId;
@@ -851,6 +886,7 @@ impl module_prices::Config for Runtime {
 	type LockOrigin = EnsureRootOrTwoThirdsGeneralCouncil;
 	type LiquidStakingExchangeRateProvider = LiquidStakingExchangeRateProvider;
 	type DEX = Dex;
+	type StableAsset = StableAssetPools;
 	type Currency = Currencies;
 	type CurrencyIdMapping = EvmCurrencyIdMapping<Runtime>;
 	type WeightInfo = weights::module_prices::WeightInfo<Runtime>;
@@ -878,6 +914,10 @@ impl module_currencies::Config for Runtime {
 	type EVMBridge = EVMBridge;
 	type SweepOrigin = EnsureRootOrOneGeneralCouncil;
 	type OnDust = module_currencies::TransferDust<Runtime, TreasuryAccount>;
+	type CurrencyHook = (
+		CurrencyFilter,
+		module_currencies::NotifyBalanceChanged<module_incentives::OnBalanceChanged<Runtime>>,
+	);
 }
 
 pub struct EnsureRootOrTreasury;
@@ -1042,6 +1082,7 @@ parameter_types! {
 impl module_cdp_engine::Config for Runtime {
 	type Event = Event;
 	type PriceSource = module_prices::PriorityLockedPriceProvider<Runtime>;
+	type PriceCircuitBreaker = Prices;
 	type CollateralCurrencyIds = CollateralCurrencyIds;
 	type DefaultLiquidationRatio = DefaultLiquidationRatio;
 	type DefaultDebitExchangeRate = DefaultDebitExchangeRate;
@@ -1126,6 +1167,20 @@ impl module_transaction_pause::Config for Runtime {
 	type WeightInfo = weights::module_transaction_pause::WeightInfo<Runtime>;
 }
 
+impl module_asset_registry::Config for Runtime {
+	type Event = Event;
+	type Currency = Balances;
+	type RegisterOrigin = EnsureRootOrHalfGeneralCouncil;
+	type FixedLocationTokens = FixedLocationTokens;
+	type WeightInfo = weights::module_asset_registry::WeightInfo<Runtime>;
+}
+
+impl module_currency_filter::Config for Runtime {
+	type Event = Event;
+	type UpdateOrigin = EnsureRootOrHalfGeneralCouncil;
+	type WeightInfo = weights::module_currency_filter::WeightInfo<Runtime>;
+}
+
 parameter_types! {
 	// Sort by fee charge order
 	pub DefaultFeeSwapPathList: Vec<Vec<CurrencyId>> = vec![vec![AUSD, ACA], vec![AUSD, LDOT], vec![AUSD, DOT], vec![AUSD, RENBTC]];
@@ -1152,7 +1207,14 @@ impl OnUnbalanced<NegativeImbalance> for DealWithFees {
 	}
 }
 
+parameter_types! {
+	pub const MaxCallNameLength: u32 = 64;
+	// the key of the longest sponsored call
+	pub SponsoredItemDeposit: Balance = deposit(1, 178);
+}
+
 impl module_transaction_payment::Config for Runtime {
+	type Event = Event;
 	type NativeCurrencyId = GetNativeCurrencyId;
 	type DefaultFeeSwapPathList = DefaultFeeSwapPathList;
 	type Currency = Balances;
@@ -1165,6 +1227,9 @@ impl module_transaction_payment::Config for Runtime {
 	type MaxSwapSlippageCompareToOracle = MaxSwapSlippageCompareToOracle;
 	type TradingPathLimit = TradingPathLimit;
 	type PriceSource = module_prices::RealTimePriceProvider<Runtime>;
+	type Call = Call;
+	type MaxCallNameLength = MaxCallNameLength;
+	type SponsoredItemDeposit = SponsoredItemDeposit;
 	type WeightInfo = weights::module_transaction_payment::WeightInfo<Runtime>;
 }
 
@@ -1177,24 +1242,36 @@ impl module_evm_accounts::Config for Runtime {
 }
 
 impl module_evm_manager::Config for Runtime {
+	type Event = Event;
 	type Currency = Balances;
 	type EVMBridge = EVMBridge;
+	type ForeignAssetIdMapping = AssetRegistry;
+	type StableAsset = StableAssetPools;
+	type RegistryOrigin = EnsureRootOrHalfGeneralCouncil;
+	type WeightInfo = weights::module_evm_manager::WeightInfo<Runtime>;
 }
 
 impl orml_rewards::Config for Runtime {
 	type Share = Balance;
 	type Balance = Balance;
-	type PoolId = module_incentives::PoolId;
+	type PoolId = module_incentives::PoolId<AccountId>;
 	type CurrencyId = CurrencyId;
 	type Handler = Incentives;
 }
 
 parameter_types! {
 	pub const AccumulatePeriod: BlockNumber = MINUTES;
+	pub const MaxBoostDuration: BlockNumber = 4 * 365 * DAYS;
+	pub MaxBoostMultiplier: Rate = Rate::saturating_from_rational(5, 2);
+	pub LoansBoostFullAmount: Balance = 10_000 * dollar(ACA);
+	pub EarlyUnlockPenaltyRate: Rate = Rate::saturating_from_rational(1, 2);
+	pub const MaxBoostExpiriesPerBlock: u32 = 50;
+	pub const MaxRewardProgramsPerPool: u32 = 10;
 }
 
 impl module_incentives::Config for Runtime {
 	type Event = Event;
+	type RelaychainAccountId = AccountId;
 	type RewardsSource = UnreleasedNativeVaultAccountId;
 	type StableCurrencyId = GetStableCurrencyId;
 	type AccumulatePeriod = AccumulatePeriod;
@@ -1202,8 +1279,20 @@ impl module_incentives::Config for Runtime {
 	type CDPTreasury = CdpTreasury;
 	type Currency = Currencies;
 	type DEX = Dex;
+	type PriceSource = module_prices::RealTimePriceProvider<Runtime>;
+	type MaxSwapSlippageCompareToOracle = MaxSwapSlippageCompareToOracle;
 	type EmergencyShutdown = EmergencyShutdown;
 	type PalletId = IncentivesPalletId;
+	type LiquidCurrencyId = GetLiquidCurrencyId;
+	type ExcludedShareHolders = DustRemovalWhitelist;
+	type ValidatorInsurance = HomaValidatorListModule;
+	type NativeCurrencyId = GetNativeCurrencyId;
+	type MaxBoostDuration = MaxBoostDuration;
+	type MaxBoostMultiplier = MaxBoostMultiplier;
+	type LoansBoostFullAmount = LoansBoostFullAmount;
+	type EarlyUnlockPenaltyRate = EarlyUnlockPenaltyRate;
+	type MaxBoostExpiriesPerBlock = MaxBoostExpiriesPerBlock;
+	type MaxRewardProgramsPerPool = MaxRewardProgramsPerPool;
 	type WeightInfo = weights::module_incentives::WeightInfo<Runtime>;
 }
 
@@ -1215,15 +1304,33 @@ parameter_types! {
 	pub const PolkadotBondingDuration: EraIndex = 7;
 	pub const EraLength: BlockNumber = DAYS;
 	pub const MaxUnbonding: u32 = 1000;
+	pub RelaychainCallFee: Balance = 10 * cent(DOT);
+	pub const RelaychainCallWeight: Weight = 10_000_000_000;
+}
+
+pub struct SubAccountIdConvert;
+impl Convert<u16, AccountId> for SubAccountIdConvert {
+	fn convert(index: u16) -> AccountId {
+		Utility::derivative_account_id(ParachainInfo::get().into_account(), index)
+	}
 }
 
 impl module_polkadot_bridge::Config for Runtime {
+	type Event = Event;
 	type DOTCurrency = Currency<Runtime, GetStakingCurrencyId>;
 	type OnNewEra = (NomineesElection, StakingPool);
 	type BondingDuration = PolkadotBondingDuration;
 	type EraLength = EraLength;
 	type PolkadotAccountId = AccountId;
 	type MaxUnbonding = MaxUnbonding;
+	type RelaychainCallBuilder = RelaychainCallBuilder<Runtime, ParachainInfo>;
+	type XcmSender = XcmRouter;
+	type RelaychainCallFee = RelaychainCallFee;
+	type RelaychainCallWeight = RelaychainCallWeight;
+	type RelaychainUnbondingSlashingSpans = RelaychainUnbondingSlashingSpans;
+	type ParachainAccount = ParachainAccount;
+	type SubAccountIdConvert = SubAccountIdConvert;
+	type UpdateOrigin = EnsureRootOrHalfHomaCouncil;
 }
 
 parameter_types! {
@@ -1243,6 +1350,10 @@ impl module_staking_pool::Config for Runtime {
 	type UpdateOrigin = EnsureRootOrHalfHomaCouncil;
 	type FeeModel = CurveFeeModel;
 	type Nominees = NomineesElection;
+	#[cfg(not(feature = "runtime-benchmarks"))]
+	type Bridge = module_polkadot_bridge::XcmPolkadotBridge<Runtime>;
+	// the benchmarks of Homa rely on the staking ledgers progressing without relaychain
+	#[cfg(feature = "runtime-benchmarks")]
 	type Bridge = PolkadotBridge;
 	type Currency = Currencies;
 }
@@ -1276,6 +1387,7 @@ parameter_types! {
 	pub MaximumRedeemRequestMatchesForMint: u32 = 20;
 	pub RelaychainUnbondingSlashingSpans: u32 = 5;
 	pub MaxScheduledUnbonds: u32 = 35;
+	pub MaxXcmDepositMintsPerBlock: u32 = 10;
 	pub ParachainAccount: AccountId = ParachainInfo::get().into_account();
 	pub SubAccountIndex: u16 = RelaychainSubAccountId::HomaLite as u16;
 	pub const XcmUnbondFee: Balance = 600_000_000; // From homa-lite integration test.
@@ -1303,6 +1415,7 @@ impl module_homa_lite::Config for Runtime {
 	type MaximumRedeemRequestMatchesForMint = MaximumRedeemRequestMatchesForMint;
 	type RelaychainUnbondingSlashingSpans = RelaychainUnbondingSlashingSpans;
 	type MaxScheduledUnbonds = MaxScheduledUnbonds;
+	type MaxXcmDepositMintsPerBlock = MaxXcmDepositMintsPerBlock;
 }
 
 parameter_types! {
@@ -1310,6 +1423,7 @@ parameter_types! {
 	pub const NominateesCount: u32 = 7;
 	pub const MaxUnlockingChunks: u32 = 7;
 	pub const NomineesElectionBondingDuration: EraIndex = 7;
+	pub const MaxVoteWeightEras: EraIndex = 28;
 }
 
 impl module_nominees_election::Config for Runtime {
@@ -1321,13 +1435,35 @@ impl module_nominees_election::Config for Runtime {
 	type BondingDuration = NomineesElectionBondingDuration;
 	type NominateesCount = NominateesCount;
 	type MaxUnlockingChunks = MaxUnlockingChunks;
-	type NomineeFilter = runtime_common::DummyNomineeFilter;
+	type MaxVoteWeightEras = MaxVoteWeightEras;
+	type NomineeFilter = HomaValidatorListModule;
 	type WeightInfo = weights::module_nominees_election::WeightInfo<Runtime>;
 }
 
 parameter_types! {
 	pub MinGuaranteeAmount: Balance = dollar(LDOT);
 	pub const ValidatorInsuranceThreshold: Balance = 0;
+	pub SlashFreezeThreshold: Balance = 100 * dollar(DOT);
+	pub const ReportedSlashesEraDepth: EraIndex = 28;
+}
+
+pub struct EnsureRootOrRelayChain;
+impl EnsureOrigin<Origin> for EnsureRootOrRelayChain {
+	type Success = ();
+
+	fn try_origin(o: Origin) -> Result<Self::Success, Origin> {
+		EnsureRoot::<AccountId>::try_origin(o).or_else(|o| {
+			Into::<Result<cumulus_pallet_xcm::Origin, Origin>>::into(o).and_then(|o| match o {
+				cumulus_pallet_xcm::Origin::Relay => Ok(()),
+				r => Err(Origin::from(r)),
+			})
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn successful_origin() -> Origin {
+		Origin::root()
+	}
 }
 
 impl module_homa_validator_list::Config for Runtime {
@@ -1339,11 +1475,14 @@ impl module_homa_validator_list::Config for Runtime {
 	type ValidatorInsuranceThreshold = ValidatorInsuranceThreshold;
 	type FreezeOrigin = EnsureRootOrHalfHomaCouncil;
 	type SlashOrigin = EnsureRootOrHalfHomaCouncil;
+	type SlashReportOrigin = EnsureRootOrRelayChain;
+	type SlashFreezeThreshold = SlashFreezeThreshold;
+	type ReportedSlashesEraDepth = ReportedSlashesEraDepth;
 	type OnSlash = module_staking_pool::OnSlash<Runtime>;
 	type LiquidStakingExchangeRateProvider = LiquidStakingExchangeRateProvider;
 	type WeightInfo = ();
-	type OnIncreaseGuarantee = ();
-	type OnDecreaseGuarantee = ();
+	type OnIncreaseGuarantee = module_incentives::OnIncreaseGuarantee<Runtime>;
+	type OnDecreaseGuarantee = module_incentives::OnDecreaseGuarantee<Runtime>;
 	type BlockNumberProvider = RelaychainBlockNumberProvider<Runtime>;
 }
 
@@ -1687,12 +1826,18 @@ impl TakeRevenue for ToTreasury {
 	}
 }
 
+pub type Trader = (
+	// the fee rates set on-chain take precedence
+	module_asset_registry::FixedRateOfAsset<Runtime, ToTreasury>,
+	FixedRateOfFungible<DotPerSecond, ToTreasury>,
+);
+
 pub struct XcmConfig;
 impl xcm_executor::Config for XcmConfig {
 	type Call = Call;
 	type XcmSender = XcmRouter;
 	// How to withdraw and deposit an asset.
-	type AssetTransactor = LocalAssetTransactor;
+	type AssetTransactor = HomaLiteAssetTransactor;
 	type OriginConverter = XcmOriginToCallOrigin;
 	type IsReserve = MultiNativeAsset;
 	// Teleporting is disabled.
@@ -1700,8 +1845,8 @@ impl xcm_executor::Config for XcmConfig {
 	type LocationInverter = LocationInverter<Ancestry>;
 	type Barrier = Barrier;
 	type Weigher = FixedWeightBounds<UnitWeightCost, Call>;
-	// Only receiving DOT is handled, and all fees must be paid in DOT.
-	type Trader = FixedRateOfFungible<DotPerSecond, ToTreasury>;
+	// The fees can be paid in DOT or the assets of which the fee rates are set on-chain.
+	type Trader = Trader;
 	type ResponseHandler = (); // Don't handle responses for now.
 	type SubscriptionService = PolkadotXcm;
 }
@@ -1763,16 +1908,42 @@ pub type LocalAssetTransactor = MultiCurrencyAdapter<
 	CurrencyIdConvert,
 >;
 
+/// Staking currency deposited into the Homa-lite sub-account of an account, i.e.
+/// `X2(AccountId32 { .. }, GeneralIndex(SubAccountIndex))`, is minted into Liquid currency.
+pub type HomaLiteAssetTransactor =
+	module_homa_lite::MintFromXcmDeposit<Runtime, LocationToAccountId, CurrencyIdConvert, LocalAssetTransactor>;
+
 //TODO: use token registry currency type encoding
 fn native_currency_location(id: CurrencyId) -> MultiLocation {
 	MultiLocation::new(1, X2(Parachain(ParachainInfo::get().into()), GeneralKey(id.encode())))
 }
 
+/// The native and locally issued tokens and the tokens of which the location is
+/// hard-coded in `CurrencyIdConvert`, their location can't be registered.
+pub struct FixedLocationTokens;
+impl Contains<CurrencyId> for FixedLocationTokens {
+	fn contains(currency_id: &CurrencyId) -> bool {
+		use CurrencyId::Token;
+		use TokenSymbol::*;
+
+		matches!(
+			currency_id,
+			Token(DOT) | Token(ACA) | Token(AUSD) | Token(LDOT) | Token(RENBTC) | Token(CASH)
+		)
+	}
+}
+
 pub struct CurrencyIdConvert;
 impl Convert<CurrencyId, Option<MultiLocation>> for CurrencyIdConvert {
 	fn convert(id: CurrencyId) -> Option<MultiLocation> {
 		use CurrencyId::Token;
 		use TokenSymbol::*;
+
+		// the locations registered on-chain take precedence
+		if let Some(location) = AssetRegistry::currency_id_to_location(id) {
+			return Some(location);
+		}
+
 		match id {
 			Token(DOT) => Some(MultiLocation::parent()),
 			Token(ACA) | Token(AUSD) | Token(LDOT) | Token(RENBTC) => Some(native_currency_location(id)),
@@ -1785,6 +1956,11 @@ impl Convert<MultiLocation, Option<CurrencyId>> for CurrencyIdConvert {
 		use CurrencyId::Token;
 		use TokenSymbol::*;
 
+		// the locations registered on-chain take precedence
+		if let Some(currency_id) = AssetRegistry::location_to_currency_ids(location.clone()) {
+			return Some(currency_id);
+		}
+
 		if location == MultiLocation::parent() {
 			return Some(Token(DOT));
 		}
@@ -1875,6 +2051,30 @@ impl nutsfinance_stable_asset::traits::ValidateAssetId<CurrencyId> for EnsurePoo
 	}
 }
 
+/// The virtual price of the stable asset pool token is the total normalized balances of the
+/// underlying currencies per pool token, and the pool token is minted in the normalized balances.
+pub struct StableAssetPools;
+impl module_support::StableAssetPoolInfo<CurrencyId> for StableAssetPools {
+	fn get_pool_info(pool_token: CurrencyId) -> Option<(Vec<CurrencyId>, Price)> {
+		let pool_info = (0..StableAsset::pool_count())
+			.filter_map(StableAsset::pools)
+			.find(|pool_info| pool_info.pool_asset == pool_token)?;
+		let total_balance = pool_info
+			.balances
+			.iter()
+			.fold(0, |total: Balance, balance| total.saturating_add(*balance));
+		let virtual_price = Price::checked_from_rational(total_balance, pool_info.total_supply)?;
+		Some((pool_info.assets, virtual_price))
+	}
+
+	fn get_pool_token_precision(pool_token: CurrencyId) -> Option<(CurrencyId, u128)> {
+		let pool_info = (0..StableAsset::pool_count())
+			.filter_map(StableAsset::pools)
+			.find(|pool_info| pool_info.pool_asset == pool_token)?;
+		Some((*pool_info.assets.first()?, *pool_info.precisions.first()?))
+	}
+}
+
 pub struct ConvertBalanceHomaLite;
 impl orml_tokens::ConvertBalance<Balance, Balance> for ConvertBalanceHomaLite {
 	type AssetId = CurrencyId;
@@ -1918,7 +2118,8 @@ impl nutsfinance_stable_asset::Config for Runtime {
 	type Event = Event;
 	type AssetId = CurrencyId;
 	type Balance = Balance;
-	type Assets = RebaseTokens;
+	type Assets =
+		module_currencies::NotifyBalanceChangedAssets<RebaseTokens, module_incentives::OnBalanceChanged<Runtime>>;
 	type PalletId = StableAssetPalletId;
 
 	type AtLeast64BitUnsigned = u128;
@@ -2002,8 +2203,22 @@ pub type SignedPayload = generic::SignedPayload<Call, SignedExtra>;
 /// Extrinsic type that has already been checked.
 pub type CheckedExtrinsic = generic::CheckedExtrinsic<AccountId, Call, SignedExtra>;
 /// Executive: handles dispatch to the various modules.
-pub type Executive =
-	frame_executive::Executive<Runtime, Block, frame_system::ChainContext<Runtime>, Runtime, AllPallets, ()>;
+pub type Executive = frame_executive::Executive<
+	Runtime,
+	Block,
+	frame_system::ChainContext<Runtime>,
+	Runtime,
+	AllPallets,
+	OnRuntimeUpgrade,
+>;
+
+pub struct OnRuntimeUpgrade;
+impl frame_support::traits::OnRuntimeUpgrade for OnRuntimeUpgrade {
+	fn on_runtime_upgrade() -> u64 {
+		module_nominees_election::migrations::v1::migrate::<Runtime, ()>()
+			.saturating_add(module_incentives::migrations::v1::migrate::<Runtime>())
+	}
+}
 
 #[allow(clippy::large_enum_variant)]
 construct_runtime! {
@@ -2023,7 +2238,9 @@ construct_runtime! {
 		Tokens: orml_tokens::{Pallet, Storage, Event<T>, Config<T>} = 11,
 		Currencies: module_currencies::{Pallet, Call, Event<T>} = 12,
 		Vesting: orml_vesting::{Pallet, Storage, Call, Event<T>, Config<T>} = 13,
-		TransactionPayment: module_transaction_payment::{Pallet, Call, Storage} = 14,
+		TransactionPayment: module_transaction_payment::{Pallet, Call, Storage, Event<T>} = 14,
+		CurrencyFilter: module_currency_filter::{Pallet, Call, Storage, Event<T>} = 15,
+		AssetRegistry: module_asset_registry::{Pallet, Call, Storage, Event<T>} = 16,
 
 		// Treasury
 		Treasury: pallet_treasury::{Pallet, Call, Storage, Config, Event<T>} = 20,
@@ -2081,7 +2298,7 @@ construct_runtime! {
 		Homa: module_homa::{Pallet, Call} = 130,
 		NomineesElection: module_nominees_election::{Pallet, Call, Storage, Event<T>} = 131,
 		StakingPool: module_staking_pool::{Pallet, Call, Storage, Event<T>, Config} = 132,
-		PolkadotBridge: module_polkadot_bridge::{Pallet, Call, Storage} = 133,
+		PolkadotBridge: module_polkadot_bridge::{Pallet, Call, Storage, Event<T>} = 133,
 		HomaValidatorListModule: module_homa_validator_list::{Pallet, Call, Storage, Event<T>} = 134,
 		HomaLite: module_homa_lite::{Pallet, Call, Storage, Event<T>} = 135,
 
@@ -2113,7 +2330,7 @@ construct_runtime! {
 		EVM: module_evm::{Pallet, Config<T>, Call, Storage, Event<T>} = 180,
 		EVMBridge: module_evm_bridge::{Pallet} = 181,
 		EvmAccounts: module_evm_accounts::{Pallet, Call, Storage, Event<T>} = 182,
-		EvmManager: module_evm_manager::{Pallet, Storage} = 183,
+		EvmManager: module_evm_manager::{Pallet, Call, Storage, Event<T>} = 183,
 
 		// Collator support. the order of these 4 are important and shall not change.
 		Authorship: pallet_authorship::{Pallet, Call, Storage} = 190,
@@ -2230,6 +2447,28 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl module_transaction_payment_rpc_runtime_api::FeeEstimationApi<
+		Block,
+		AccountId,
+		CurrencyId,
+		Balance,
+	> for Runtime {
+		fn query_fee_estimation(
+			uxt: <Block as BlockT>::Extrinsic,
+			len: u32,
+			account: AccountId,
+		) -> module_transaction_payment_rpc_runtime_api::FeeEstimation<CurrencyId, Balance> {
+			let (native_fee, fee_swap_path, supply_amount, can_charge_fee) =
+				TransactionPayment::query_fee_estimation(account, uxt, len);
+			module_transaction_payment_rpc_runtime_api::FeeEstimation {
+				native_fee,
+				fee_swap_path,
+				supply_amount,
+				can_charge_fee,
+			}
+		}
+	}
+
 	impl orml_oracle_rpc_runtime_api::OracleApi<
 		Block,
 		DataProviderId,
@@ -2251,6 +2490,66 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl module_incentives_rpc_runtime_api::IncentivesApi<
+		Block,
+		AccountId,
+		module_incentives::PoolId<AccountId>,
+		CurrencyId,
+		BlockNumber,
+		Balance,
+	> for Runtime {
+		fn active_reward_programs() -> Vec<module_incentives_rpc_runtime_api::RewardProgramInfo<
+			module_incentives::PoolId<AccountId>,
+			CurrencyId,
+			BlockNumber,
+			Balance,
+		>> {
+			Incentives::active_reward_programs(System::block_number())
+				.into_iter()
+				.map(|(pool_id, program_id, program)| module_incentives_rpc_runtime_api::RewardProgramInfo {
+					pool_total_shares: Rewards::pool_infos(&pool_id).total_shares,
+					pool_id,
+					program_id,
+					currency_id: program.currency_id,
+					start: program.start,
+					end: program.end,
+					total_budget: program.total_budget,
+					remaining_budget: program.remaining_budget,
+					amount_per_period: program.amount_per_period,
+					accumulate_period: AccumulatePeriod::get(),
+				})
+				.collect()
+		}
+
+		fn pending_rewards(account: AccountId) -> Vec<module_incentives_rpc_runtime_api::PendingRewardsInfo<
+			module_incentives::PoolId<AccountId>,
+			CurrencyId,
+			Balance,
+		>> {
+			Incentives::pending_rewards(&account)
+				.into_iter()
+				.map(|(pool_id, shares, rewards)| module_incentives_rpc_runtime_api::PendingRewardsInfo {
+					pool_id,
+					shares,
+					rewards: rewards
+						.into_iter()
+						.map(|(currency_id, pending_amount, actual_amount)| module_incentives_rpc_runtime_api::PendingReward {
+							currency_id,
+							pending_amount,
+							actual_amount,
+						})
+						.collect(),
+				})
+				.collect()
+		}
+	}
+
+	impl module_evm_manager_rpc_runtime_api::EvmManagerApi<Block> for Runtime {
+		fn registered_erc20s() -> Vec<(u32, primitives::evm::Erc20Info)> {
+			EvmManager::registered_erc20s()
+		}
+	}
+
 	impl module_staking_pool_rpc_runtime_api::StakingPoolApi<
 		Block,
 		AccountId,
@@ -2398,11 +2697,15 @@ impl_runtime_apis! {
 			orml_list_benchmark!(list, extra, module_honzon, benchmarking::honzon);
 			orml_list_benchmark!(list, extra, module_cdp_treasury, benchmarking::cdp_treasury);
 			orml_list_benchmark!(list, extra, module_transaction_pause, benchmarking::transaction_pause);
+			orml_list_benchmark!(list, extra, module_currency_filter, benchmarking::currency_filter);
+			orml_list_benchmark!(list, extra, module_asset_registry, benchmarking::asset_registry);
 			orml_list_benchmark!(list, extra, module_transaction_payment, benchmarking::transaction_payment);
 			orml_list_benchmark!(list, extra, module_incentives, benchmarking::incentives);
 			orml_list_benchmark!(list, extra, module_prices, benchmarking::prices);
 			orml_list_benchmark!(list, extra, module_evm_accounts, benchmarking::evm_accounts);
+			orml_list_benchmark!(list, extra, module_evm_manager, benchmarking::evm_manager);
 			orml_list_benchmark!(list, extra, module_homa, benchmarking::homa);
+			orml_list_benchmark!(list, extra, module_homa_validator_list, benchmarking::homa_validator_list);
 			orml_list_benchmark!(list, extra, module_currencies, benchmarking::currencies);
 			orml_list_benchmark!(list, extra, module_session_manager, benchmarking::session_manager);
 
@@ -2460,11 +2763,15 @@ impl_runtime_apis! {
 			orml_add_benchmark!(params, batches, module_honzon, benchmarking::honzon);
 			orml_add_benchmark!(params, batches, module_cdp_treasury, benchmarking::cdp_treasury);
 			orml_add_benchmark!(params, batches, module_transaction_pause, benchmarking::transaction_pause);
+			orml_add_benchmark!(params, batches, module_currency_filter, benchmarking::currency_filter);
+			orml_add_benchmark!(params, batches, module_asset_registry, benchmarking::asset_registry);
 			orml_add_benchmark!(params, batches, module_transaction_payment, benchmarking::transaction_payment);
 			orml_add_benchmark!(params, batches, module_incentives, benchmarking::incentives);
 			orml_add_benchmark!(params, batches, module_prices, benchmarking::prices);
 			orml_add_benchmark!(params, batches, module_evm_accounts, benchmarking::evm_accounts);
+			orml_add_benchmark!(params, batches, module_evm_manager, benchmarking::evm_manager);
 			orml_add_benchmark!(params, batches, module_homa, benchmarking::homa);
+			orml_add_benchmark!(params, batches, module_homa_validator_list, benchmarking::homa_validator_list);
 			orml_add_benchmark!(params, batches, module_currencies, benchmarking::currencies);
 			orml_add_benchmark!(params, batches, module_session_manager, benchmarking::session_manager);
 
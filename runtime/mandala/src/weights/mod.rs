@@ -19,15 +19,18 @@
 //! A list of the different weight modules for our runtime.
 #![allow(clippy::unnecessary_cast)]
 
+pub mod module_asset_registry;
 pub mod module_auction_manager;
 pub mod module_cdp_engine;
 pub mod module_cdp_treasury;
 pub mod module_collator_selection;
 pub mod module_currencies;
+pub mod module_currency_filter;
 pub mod module_dex;
 pub mod module_emergency_shutdown;
 pub mod module_evm;
 pub mod module_evm_accounts;
+pub mod module_evm_manager;
 pub mod module_homa;
 pub mod module_homa_lite;
 pub mod module_honzon;
@@ -56,4 +56,28 @@ impl<T: frame_system::Config> module_prices::WeightInfo for WeightInfo<T> {
 		(24_114_000 as Weight)
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	fn set_source_weights() -> Weight {
+		// not generated by the benchmark CLI yet, estimated from the similar benchmarked calls
+		(22_307_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn set_price_change_bound() -> Weight {
+		// not generated by the benchmark CLI yet, estimated from the similar benchmarked calls
+		(22_512_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn set_dex_price_rule() -> Weight {
+		// not generated by the benchmark CLI yet, estimated from the similar benchmarked calls
+		(27_133_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn on_initialize(c: u32, ) -> Weight {
+		// not generated by the benchmark CLI yet, estimated from the similar benchmarked calls
+		(3_912_000 as Weight)
+			.saturating_add((41_736_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((8 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(c as Weight)))
+	}
 }